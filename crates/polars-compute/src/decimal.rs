@@ -520,6 +520,38 @@ pub fn dec128_sub(l: i128, r: i128, p: usize) -> Option<i128> {
     l.checked_sub(r).filter(|x| dec128_fits(*x, p))
 }
 
+/// The scale of the product of two Decimal128s with scales ls and rs.
+///
+/// This is the scale of the exact product, capped at the maximum precision.
+#[inline]
+pub fn dec128_mul_scale(ls: usize, rs: usize) -> usize {
+    (ls + rs).min(DEC128_MAX_PREC)
+}
+
+/// The minimum scale of the mean of Decimal128s, see [`dec128_mean_scale`].
+pub const DEC128_MEAN_MIN_SCALE: usize = 6;
+
+/// The scale of the mean of Decimal128s with scale s.
+///
+/// The mean keeps at least [`DEC128_MEAN_MIN_SCALE`] digits after the decimal point, so that
+/// means of values with a small scale are not rounded to whole numbers.
+#[inline]
+pub fn dec128_mean_scale(s: usize) -> usize {
+    s.max(DEC128_MEAN_MIN_SCALE)
+}
+
+/// The mean of `count` Decimal128s with scale s given their (exact) sum, at the scale returned
+/// by [`dec128_mean_scale`]. Returns `None` if it doesn't fit in the maximum precision.
+#[inline]
+pub fn dec128_mean(sum: i128, count: usize, s: usize) -> Option<i128> {
+    dec128_div(
+        sum,
+        count as i128,
+        DEC128_MAX_PREC,
+        dec128_mean_scale(s) - s,
+    )
+}
+
 /// Multiplies two Decimal128s, assuming they have the same scale s.
 #[inline]
 pub fn dec128_mul(l: i128, r: i128, p: usize, s: usize) -> Option<i128> {
@@ -1014,4 +1046,20 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_mean() {
+        // (0.01 + 0.02 + 0.04) / 3 = 0.023333..
+        assert_eq!(dec128_mean_scale(2), 6);
+        assert_eq!(dec128_mean(7, 3, 2), Some(23333));
+        // (1 + 2) / 2 = 1.5
+        assert_eq!(dec128_mean(3, 2, 0), Some(1_500_000));
+        assert_eq!(dec128_mean_scale(10), 10);
+        assert_eq!(dec128_mean(-5, 2, 10), Some(-2));
+        // The sum may exceed the maximum precision, as long as the mean fits.
+        let sum = 16 * POW10_I128[DEC128_MAX_PREC - 1];
+        let mean = 8 * POW10_I128[DEC128_MAX_PREC - 1];
+        assert_eq!(dec128_mean(sum, 2, DEC128_MAX_PREC), Some(mean));
+        assert_eq!(dec128_mean(POW10_I128[DEC128_MAX_PREC] - 1, 1, 0), None);
+    }
 }
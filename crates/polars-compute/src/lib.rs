@@ -31,6 +31,8 @@ pub mod propagate_nulls;
 pub mod rolling;
 pub mod size;
 pub mod sum;
+#[cfg(feature = "quantile_approx")]
+pub mod tdigest;
 pub mod trim_lists_to_normalized_offsets;
 pub mod unique;
 
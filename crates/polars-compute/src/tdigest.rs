@@ -0,0 +1,258 @@
+//! # t-digest
+//!
+//! A merging t-digest (Dunning & Ertl) for approximate quantiles using bounded memory. Values are
+//! buffered and periodically compressed into a sorted list of weighted centroids, where centroids
+//! near the tails are kept small so extreme quantiles stay accurate.
+//!
+//! Digests built on separate chunks can be merged, which makes them suitable for parallel and
+//! streaming aggregations.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::TDigest;
+//!     let mut digest = TDigest::new(100);
+//!     for x in 0..=100 {
+//!         digest.insert(x as f64);
+//!     }
+//!
+//!     let median = digest.quantile(0.5).unwrap();
+//!     assert!((median - 50.0).abs() <= 1.0);
+//! ```
+
+use std::f64::consts::PI;
+
+/// Default compression parameter, bounding the number of centroids to roughly this many.
+pub const DEFAULT_COMPRESSION: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Compressed centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values not yet merged into the centroids.
+    buffer: Vec<f64>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    pub fn new(compression: usize) -> Self {
+        Self {
+            compression: compression.max(1) as f64,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Number of values inserted into this digest.
+    pub fn count(&self) -> usize {
+        self.count as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0.0
+    }
+
+    /// Inserts a value. NaN values are ignored.
+    #[inline]
+    pub fn insert(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        self.buffer.push(x);
+        self.count += 1.0;
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.flush();
+        }
+    }
+
+    /// Merges the values summarized by `other` into this digest.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+        let incoming = other.centroids.iter().copied().chain(
+            other
+                .buffer
+                .iter()
+                .map(|&mean| Centroid { mean, weight: 1.0 }),
+        );
+        self.centroids.extend(incoming);
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.merge_buffer();
+    }
+
+    /// Estimates the value at quantile `q` in `[0, 1]`, or `None` if the digest is empty.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.flush();
+        self.estimate_quantile(q)
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression as usize).saturating_mul(5).max(64)
+    }
+
+    fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            self.merge_buffer();
+        }
+    }
+
+    fn merge_buffer(&mut self) {
+        self.centroids.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        self.centroids
+            .sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        self.compress();
+    }
+
+    /// k1 scale function, mapping a quantile to the index space in which every centroid spans at
+    /// most one unit.
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn k_inv(&self, k: f64) -> f64 {
+        let angle = (k * 2.0 * PI / self.compression).clamp(-PI / 2.0, PI / 2.0);
+        (angle.sin() + 1.0) / 2.0
+    }
+
+    /// Greedily merges adjacent sorted centroids as long as they fit within the scale bound.
+    fn compress(&mut self) {
+        if self.centroids.len() <= 1 {
+            return;
+        }
+        let total = self.count;
+        let mut out = Vec::with_capacity(self.compression as usize);
+        let mut iter = std::mem::take(&mut self.centroids).into_iter();
+        let mut current = iter.next().unwrap();
+        let mut q0 = 0.0;
+        let mut q_limit = self.k_inv(self.k(q0) + 1.0);
+
+        for c in iter {
+            let q = q0 + (current.weight + c.weight) / total;
+            if q <= q_limit {
+                let weight = current.weight + c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / weight;
+                current.weight = weight;
+            } else {
+                q0 += current.weight / total;
+                q_limit = self.k_inv(self.k(q0) + 1.0);
+                out.push(current);
+                current = c;
+            }
+        }
+        out.push(current);
+        self.centroids = out;
+    }
+
+    fn estimate_quantile(&self, q: f64) -> Option<f64> {
+        debug_assert!(self.buffer.is_empty());
+        let (first, last) = (self.centroids.first()?, self.centroids.last()?);
+        let q = q.clamp(0.0, 1.0);
+        let total = self.count;
+        let index = q * total;
+
+        // Interpolate between the observed extremes and the outermost centroid centers.
+        if index <= first.weight / 2.0 {
+            let half = first.weight / 2.0;
+            return Some(if half > 0.0 {
+                self.min + (first.mean - self.min) * (index / half)
+            } else {
+                first.mean
+            });
+        }
+        if index >= total - last.weight / 2.0 {
+            let half = last.weight / 2.0;
+            return Some(if half > 0.0 {
+                self.max - (self.max - last.mean) * ((total - index) / half)
+            } else {
+                last.mean
+            });
+        }
+
+        let mut cum = 0.0;
+        for w in self.centroids.windows(2) {
+            let (left, right) = (w[0], w[1]);
+            let left_center = cum + left.weight / 2.0;
+            let right_center = cum + left.weight + right.weight / 2.0;
+            if index <= right_center {
+                let frac = (index - left_center) / (right_center - left_center);
+                return Some(left.mean + (right.mean - left.mean) * frac);
+            }
+            cum += left.weight;
+        }
+        Some(last.mean)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest_empty_and_single() {
+        let mut digest = TDigest::new(100);
+        assert_eq!(digest.quantile(0.5), None);
+
+        digest.insert(f64::NAN);
+        assert!(digest.is_empty());
+
+        digest.insert(3.0);
+        assert_eq!(digest.quantile(0.0), Some(3.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(3.0));
+    }
+
+    #[test]
+    fn test_tdigest_merge_uniform() {
+        let n = 100_000;
+        let mut a = TDigest::new(100);
+        let mut b = TDigest::new(100);
+        for i in 0..n {
+            if i % 2 == 0 {
+                a.insert(i as f64);
+            } else {
+                b.insert(i as f64);
+            }
+        }
+        a.merge(&b);
+        assert_eq!(a.count(), n);
+        assert!(a.centroids.len() <= 2 * 100);
+
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let expected = q * (n - 1) as f64;
+            let estimate = a.quantile(q).unwrap();
+            assert!(
+                (estimate - expected).abs() / n as f64 <= 0.01,
+                "q={q}: expected {expected}, got {estimate}"
+            );
+        }
+        assert_eq!(a.quantile(0.0), Some(0.0));
+        assert_eq!(a.quantile(1.0), Some((n - 1) as f64));
+    }
+}
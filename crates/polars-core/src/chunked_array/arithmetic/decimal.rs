@@ -1,5 +1,6 @@
 use polars_compute::decimal::{
-    DEC128_MAX_PREC, dec128_add, dec128_div, dec128_mul, dec128_rescale, dec128_sub,
+    DEC128_MAX_PREC, dec128_add, dec128_div, dec128_mul, dec128_mul_scale, dec128_rescale,
+    dec128_sub,
 };
 
 use super::*;
@@ -73,27 +74,21 @@ impl Mul for &DecimalChunked {
     fn mul(self, rhs: Self) -> Self::Output {
         let left_s = self.scale();
         let right_s = rhs.scale();
-        let scale = left_s.max(right_s);
+        // The exact product has the sum of both scales; only round if that exceeds the
+        // maximum precision.
+        let scale = dec128_mul_scale(left_s, right_s);
+        let excess_s = left_s + right_s - scale;
         let prec = DEC128_MAX_PREC;
-        let phys = broadcast_try_binary_elementwise(
-            self.physical(),
-            rhs.physical(),
-            |opt_l, opt_r| {
+        let phys =
+            broadcast_try_binary_elementwise(self.physical(), rhs.physical(), |opt_l, opt_r| {
                 let (Some(l), Some(r)) = (opt_l, opt_r) else {
                     return PolarsResult::Ok(None);
                 };
-                let ls = dec128_rescale(l, left_s, prec, scale).ok_or_else(|| {
-                    polars_err!(ComputeError: "overflow in Decimal cast for {l} from scale {left_s} to {scale}")
-                })?;
-                let rs = dec128_rescale(r, right_s, prec, scale).ok_or_else(|| {
-                    polars_err!(ComputeError: "overflow in Decimal cast for {r} from scale {right_s} to {scale}")
-                })?;
-                let ret = dec128_mul(ls, rs, prec, scale).ok_or_else(|| {
-                    polars_err!(ComputeError: "overflow in decimal multiplication for {ls} * {rs}")
+                let ret = dec128_mul(l, r, prec, excess_s).ok_or_else(|| {
+                    polars_err!(ComputeError: "overflow in decimal multiplication for {l} * {r}")
                 })?;
                 Ok(Some(ret))
-            },
-        );
+            });
         Ok(phys?.into_decimal_unchecked(prec, scale))
     }
 }
@@ -184,4 +184,83 @@ pub(crate) mod test {
         let _ = &a1 / &a1;
         let _ = &a1 * &a1;
     }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_decimal_arithmetic_scale() -> PolarsResult<()> {
+        // 1.25, 2.50 and 0.125, 4.000
+        let a = Int128Chunked::new(PlSmallStr::from_static("a"), &[125, 250])
+            .into_decimal(10, 2)?
+            .into_series();
+        let b = Int128Chunked::new(PlSmallStr::from_static("b"), &[125, 4000])
+            .into_decimal(10, 3)?
+            .into_series();
+
+        let sum = (&a + &b)?;
+        assert_eq!(sum.dtype(), &DataType::Decimal(38, 3));
+        assert_eq!(sum.decimal()?.physical().to_vec(), [Some(1375), Some(6500)]);
+
+        let product = (&a * &b)?;
+        assert_eq!(product.dtype(), &DataType::Decimal(38, 5));
+        assert_eq!(
+            product.decimal()?.physical().to_vec(),
+            [Some(15625), Some(1000000)]
+        );
+
+        let quotient = (&a / &b)?;
+        assert_eq!(quotient.dtype(), &DataType::Decimal(38, 3));
+        assert_eq!(
+            quotient.decimal()?.physical().to_vec(),
+            [Some(10000), Some(625)]
+        );
+        let zero = Int128Chunked::new(PlSmallStr::from_static("z"), &[0, 0])
+            .into_decimal(10, 3)?
+            .into_series();
+        assert!((&a / &zero).is_err());
+
+        // Comparisons are done at the common scale.
+        let gt = a.gt(&b)?;
+        assert_eq!(Vec::from(&gt), [Some(true), Some(false)]);
+        let a3 = a.cast(&DataType::Decimal(10, 3))?;
+        let eq = a.equal(&a3)?;
+        assert_eq!(Vec::from(&eq), [Some(true), Some(true)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-decimal", feature = "algorithm_group_by"))]
+    fn test_decimal_mean() -> PolarsResult<()> {
+        // 0.01, 0.02, null, 0.04
+        let s = Int128Chunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some(1), Some(2), None, Some(4)],
+        )
+        .into_decimal(10, 2)?
+        .into_series();
+
+        // The mean is widened to (at least) 6 digits after the decimal point.
+        let mean = s.mean_reduce()?;
+        assert_eq!(mean.dtype(), &DataType::Decimal(38, 6));
+        assert_eq!(mean.value(), &AnyValue::Decimal(23333, 38, 6));
+
+        let keys = Series::new(PlSmallStr::from_static("k"), [0, 0, 1, 1]);
+        let groups = keys.group_tuples(false, true)?;
+        let means = unsafe { s.try_agg_mean(&groups)? };
+        assert_eq!(means.dtype(), &DataType::Decimal(38, 6));
+        assert_eq!(
+            means.decimal()?.physical().to_vec(),
+            [Some(15000), Some(40000)]
+        );
+
+        // The sum overflows, even though the mean would fit.
+        let big = 9 * 10i128.pow(37);
+        let s = Int128Chunked::new(PlSmallStr::from_static("a"), &[big, big])
+            .into_decimal(38, 0)?
+            .into_series();
+        assert!(s.mean_reduce().is_err());
+        let groups = Series::new(PlSmallStr::from_static("k"), [0, 0]).group_tuples(false, true)?;
+        assert!(unsafe { s.try_agg_mean(&groups) }.is_err());
+        assert_eq!(unsafe { s.agg_mean(&groups) }.null_count(), 1);
+        Ok(())
+    }
 }
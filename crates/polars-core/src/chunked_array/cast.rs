@@ -189,12 +189,19 @@ where
             #[cfg(feature = "dtype-categorical")]
             DataType::Enum(fcats, _mapping) => {
                 let s = self.cast_with_options(&fcats.physical().dtype(), options)?;
-                with_match_categorical_physical_type!(fcats.physical(), |$C| {
+                let ret = with_match_categorical_physical_type!(fcats.physical(), |$C| {
                     // SAFETY: we are guarded by the type system.
                     type PhysCa = ChunkedArray<<$C as PolarsCategoricalType>::PolarsPhysical>;
                     let ca: &PhysCa = s.as_ref().as_ref();
-                    Ok(CategoricalChunked::<$C>::from_cats_and_dtype(ca.clone(), dtype.clone()).into_series())
-                })
+                    CategoricalChunked::<$C>::from_cats_and_dtype(ca.clone(), dtype.clone()).into_series()
+                });
+
+                // Ids outside of the declared categories are nulled out above.
+                if options.is_strict() && self.null_count() != ret.null_count() {
+                    handle_casting_failures(&self.clone().into_series(), &ret)?;
+                }
+
+                Ok(ret)
             },
 
             #[cfg(feature = "dtype-struct")]
@@ -727,4 +734,26 @@ mod test {
         let out = out.cast(&DataType::from_categories(cats)).unwrap();
         assert!(matches!(out.dtype(), &DataType::Categorical(_, _)))
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_cast_enum_membership() -> PolarsResult<()> {
+        let dtype = DataType::from_frozen_categories(FrozenCategories::new(["b", "a"])?);
+
+        let ca = StringChunked::new(PlSmallStr::from_static("foo"), &["a", "b", "c"]);
+        assert!(ca.cast_with_options(&dtype, CastOptions::Strict).is_err());
+        let out = ca.cast_with_options(&dtype, CastOptions::NonStrict)?;
+        assert_eq!(out.null_count(), 1);
+
+        // The declared order, not the lexical one, determines the sort order.
+        let out = out.sort(SortOptions::default().with_nulls_last(true))?;
+        let out = out.cat8()?.iter_str().collect::<Vec<_>>();
+        assert_eq!(out, [Some("b"), Some("a"), None]);
+
+        let ids = UInt32Chunked::new(PlSmallStr::from_static("ids"), &[0, 1, 2]);
+        assert!(ids.cast_with_options(&dtype, CastOptions::Strict).is_err());
+        let out = ids.cast_with_options(&dtype, CastOptions::NonStrict)?;
+        assert_eq!(out.null_count(), 1);
+        Ok(())
+    }
 }
@@ -5,6 +5,14 @@ fn str_to_cat_enum(map: &CategoricalMapping, s: &str) -> PolarsResult<CatSize> {
     map.get_cat(s).ok_or_else(|| polars_err!(InvalidOperation: "conversion from `str` to `enum` failed for value \"{s}\""))
 }
 
+/// Comparing the category ids only makes sense if both sides share their categories.
+fn ensure_comparable(lhs: &DataType, rhs: &DataType) -> PolarsResult<()> {
+    match (lhs, rhs) {
+        (DataType::Categorical(l, _), DataType::Categorical(r, _)) => ensure_same_categories(l, r),
+        _ => lhs.matches_schema_type(rhs).map(|_| ()),
+    }
+}
+
 fn cat_equality_helper<T: PolarsCategoricalType, EqPhys>(
     lhs: &CategoricalChunked<T>,
     rhs: &CategoricalChunked<T>,
@@ -14,7 +22,7 @@ where
     EqPhys:
         Fn(&ChunkedArray<T::PolarsPhysical>, &ChunkedArray<T::PolarsPhysical>) -> BooleanChunked,
 {
-    lhs.dtype().matches_schema_type(rhs.dtype())?;
+    ensure_comparable(lhs.dtype(), rhs.dtype())?;
     Ok(eq_phys(lhs.physical(), rhs.physical()))
 }
 
@@ -29,7 +37,7 @@ where
     CmpPhys:
         Fn(&ChunkedArray<T::PolarsPhysical>, &ChunkedArray<T::PolarsPhysical>) -> BooleanChunked,
 {
-    lhs.dtype().matches_schema_type(rhs.dtype())?;
+    ensure_comparable(lhs.dtype(), rhs.dtype())?;
     if lhs.is_enum() {
         return Ok(cmp_phys(lhs.physical(), rhs.physical()));
     }
@@ -251,8 +259,23 @@ fn cat_str_scalar_compare_helper<T: PolarsCategoricalType, Cmp>(
 where
     Cmp: Fn(&str, &str) -> bool,
 {
-    lhs.iter_str()
-        .map(|opt_l| opt_l.map(|l| cmp(l, rhs)))
+    let mapping = lhs.get_mapping();
+    let num_cats = mapping.num_cats_upper_bound();
+    if num_cats > lhs.len() {
+        return lhs
+            .iter_str()
+            .map(|opt_l| opt_l.map(|l| cmp(l, rhs)))
+            .collect_ca_trusted(lhs.name().clone());
+    }
+
+    // Compare every category with the scalar once, and look up the result by the physical
+    // value, so that we don't compare a string per row.
+    let cat_cmp = (0..num_cats as CatSize)
+        .map(|cat| mapping.cat_to_str(cat).is_some_and(|l| cmp(l, rhs)))
+        .collect::<Vec<_>>();
+    lhs.physical()
+        .iter()
+        .map(|opt_l| opt_l.map(|l| cat_cmp[l.as_cat() as usize]))
         .collect_ca_trusted(lhs.name().clone())
 }
 
@@ -465,3 +488,38 @@ impl<T: PolarsCategoricalType> ChunkCompareIneq<&str> for CategoricalChunked<T>
         cat_str_scalar_compare_helper(self, rhs, |l, r| l <= r)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_compare_str_scalar() -> PolarsResult<()> {
+        let cats = Categories::random(PlSmallStr::EMPTY, CategoricalPhysical::U32);
+        let s = Series::new(
+            PlSmallStr::from_static("size"),
+            [Some("M"), None, Some("S"), Some("XL"), Some("M")],
+        )
+        .cast(&DataType::from_categories(cats))?;
+        let ca = s.cat32()?;
+        // Few categories, so every category is compared once.
+        assert_eq!(
+            Vec::from(&ca.gt("M")),
+            [Some(false), None, Some(true), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&ca.lt_eq("M")),
+            [Some(true), None, Some(false), Some(false), Some(true)]
+        );
+        // Equality encodes the literal and compares the physical codes.
+        assert_eq!(
+            Vec::from(&ca.equal("M")),
+            [Some(true), None, Some(false), Some(false), Some(true)]
+        );
+
+        // More categories than rows, so every row is compared.
+        let sliced = ca.slice(0, 2);
+        assert_eq!(Vec::from(&sliced.gt_eq("N")), [Some(false), None]);
+        Ok(())
+    }
+}
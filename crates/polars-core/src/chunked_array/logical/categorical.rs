@@ -29,6 +29,17 @@ impl CategoricalPhysicalDtypeExt for CategoricalPhysical {
     }
 }
 
+/// The order in which the values of a categorical column sort, and in which `min` and `max`
+/// compare them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CategoricalOrdering {
+    /// By category id, that is, in the order in which the categories were declared. This is
+    /// the ordering of [`DataType::Enum`].
+    Physical,
+    /// By the string values. This is the ordering of [`DataType::Categorical`].
+    Lexical,
+}
+
 impl<T: PolarsCategoricalType> CategoricalChunked<T> {
     pub fn is_enum(&self) -> bool {
         matches!(self.dtype(), DataType::Enum(_, _))
@@ -65,6 +76,73 @@ impl<T: PolarsCategoricalType> CategoricalChunked<T> {
         unsafe { Self::from_cats_and_dtype_unchecked(phys, dtype) }
     }
 
+    /// The [`CategoricalOrdering`] of this column.
+    pub fn ordering(&self) -> CategoricalOrdering {
+        if self.uses_lexical_ordering() {
+            CategoricalOrdering::Lexical
+        } else {
+            CategoricalOrdering::Physical
+        }
+    }
+
+    /// Change the [`CategoricalOrdering`] of this column.
+    ///
+    /// A physically ordered column is an [`DataType::Enum`], whose categories are the ones
+    /// used by this column, ordered by their current category id. A lexically ordered column
+    /// is a [`DataType::Categorical`] using the global categories.
+    pub fn set_ordering(&self, ordering: CategoricalOrdering) -> PolarsResult<Series> {
+        if self.ordering() == ordering {
+            return Ok(self.clone().into_series());
+        }
+        match ordering {
+            CategoricalOrdering::Physical => self.reorder_categories(self.used_categories()),
+            CategoricalOrdering::Lexical => self.cast_with_options(
+                &DataType::from_categories(Categories::global()),
+                CastOptions::Strict,
+            ),
+        }
+    }
+
+    /// Convert this column to an [`DataType::Enum`] with the given categories, in the given
+    /// order.
+    ///
+    /// Returns an error if the categories are not unique, or if a value of this column is not
+    /// among them.
+    pub fn reorder_categories<'a, I: IntoIterator<Item = &'a str>>(
+        &self,
+        categories: I,
+    ) -> PolarsResult<Series> {
+        let dtype = DataType::from_frozen_categories(FrozenCategories::new(categories)?);
+        self.cast_with_options(&dtype, CastOptions::Strict)
+    }
+
+    /// Drop the categories of an [`DataType::Enum`] which are not used by this column, keeping
+    /// the order of the remaining ones.
+    ///
+    /// A [`DataType::Categorical`] shares its categories with other columns, so it is returned
+    /// unchanged.
+    pub fn remove_unused_categories(&self) -> PolarsResult<Series> {
+        if !self.is_enum() {
+            return Ok(self.clone().into_series());
+        }
+        self.reorder_categories(self.used_categories())
+    }
+
+    /// The categories with at least one occurrence in this column, ordered by category id.
+    fn used_categories(&self) -> Vec<&str> {
+        let mapping = self.get_mapping();
+        let mut used = vec![false; mapping.num_cats_upper_bound()];
+        for cat_id in self.phys.iter().flatten() {
+            used[cat_id.as_cat() as usize] = true;
+        }
+        used.iter()
+            .enumerate()
+            .filter(|(_, used)| **used)
+            // SAFETY: the category ids occur in this column, so they are valid.
+            .map(|(cat_id, _)| unsafe { mapping.cat_to_str_unchecked(cat_id as CatSize) })
+            .collect()
+    }
+
     /// Create a [`CategoricalChunked`] from a physical array and dtype.
     ///
     /// Checks that all the category ids are valid, mapping invalid ones to nulls.
@@ -290,3 +368,98 @@ impl<T: PolarsCategoricalType> LogicalType for CategoricalChunked<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn sizes() -> Series {
+        Series::new(PlSmallStr::from_static("size"), ["M", "XL", "S", "M"])
+            .cast(&DataType::from_categories(Categories::global()))
+            .unwrap()
+    }
+
+    fn to_strs(s: &Series) -> Vec<Option<String>> {
+        s.cast(&DataType::String)
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_iter()
+            .map(|s| s.map(String::from))
+            .collect()
+    }
+
+    #[test]
+    fn test_reorder_categories() -> PolarsResult<()> {
+        let s = sizes();
+        assert_eq!(s.cat32()?.ordering(), CategoricalOrdering::Lexical);
+        assert!(s.cat32()?.reorder_categories(["S", "M"]).is_err());
+
+        let ordinal = s.cat32()?.reorder_categories(["S", "M", "L", "XL"])?;
+        assert!(ordinal.dtype().is_enum());
+        let sorted = ordinal.sort(SortOptions::default())?;
+        assert_eq!(
+            to_strs(&sorted),
+            [Some("S"), Some("M"), Some("M"), Some("XL")].map(|s| s.map(String::from))
+        );
+        assert_eq!(ordinal.max_reduce()?.value().str_value(), "XL");
+
+        let used = ordinal.cat8()?.remove_unused_categories()?;
+        let DataType::Enum(fcats, _) = used.dtype() else {
+            unreachable!()
+        };
+        assert_eq!(fcats.categories().len(), 3);
+        assert_eq!(to_strs(&used), to_strs(&ordinal));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_ordering() -> PolarsResult<()> {
+        let s = sizes();
+        let physical = s.cat32()?.set_ordering(CategoricalOrdering::Physical)?;
+        assert_eq!(physical.cat8()?.ordering(), CategoricalOrdering::Physical);
+        assert_eq!(to_strs(&physical), to_strs(&s));
+
+        let lexical = physical
+            .cat8()?
+            .set_ordering(CategoricalOrdering::Lexical)?;
+        assert_eq!(lexical.cat32()?.ordering(), CategoricalOrdering::Lexical);
+        let sorted = lexical.sort(SortOptions::default())?;
+        assert_eq!(
+            to_strs(&sorted),
+            [Some("M"), Some("M"), Some("S"), Some("XL")].map(|s| s.map(String::from))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_remaps_categories() -> PolarsResult<()> {
+        let new_dtype = |name: &'static str| {
+            DataType::from_categories(Categories::new(
+                PlSmallStr::from_static(name),
+                PlSmallStr::EMPTY,
+                CategoricalPhysical::U32,
+            ))
+        };
+        let a = Series::new(PlSmallStr::from_static("x"), ["p", "q"]).cast(&new_dtype("a"))?;
+        let b = Series::new(PlSmallStr::from_static("x"), ["r", "q"]).cast(&new_dtype("b"))?;
+        let global = DataType::from_categories(Categories::global());
+        assert_eq!(
+            crate::utils::get_supertype(a.dtype(), b.dtype()).as_ref(),
+            Some(&global)
+        );
+        assert_eq!(
+            crate::utils::get_supertype(b.dtype(), a.dtype()).as_ref(),
+            Some(&global)
+        );
+
+        let mut out = a.clone();
+        out.append(&b)?;
+        assert_eq!(out.dtype(), &global);
+        assert_eq!(
+            to_strs(&out),
+            [Some("p"), Some("q"), Some("r"), Some("q")].map(|s| s.map(String::from))
+        );
+        Ok(())
+    }
+}
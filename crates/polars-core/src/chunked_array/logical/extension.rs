@@ -67,8 +67,20 @@ impl ExtensionChunked {
     pub fn cast_with_options(
         &self,
         dtype: &DataType,
-        _options: CastOptions,
+        options: CastOptions,
     ) -> PolarsResult<Series> {
-        polars_bail!(ComputeError: "cannot cast extension types to {dtype:?}")
+        if let Some(out) = self
+            .extension_type()
+            .0
+            .cast_to(&self.storage, dtype, options)
+        {
+            return out;
+        }
+        match dtype {
+            DataType::Extension(_, _) => {
+                polars_bail!(ComputeError: "cannot cast extension type {:?} to {dtype:?}", self.dtype)
+            },
+            _ => self.storage.cast_with_options(dtype, options),
+        }
     }
 }
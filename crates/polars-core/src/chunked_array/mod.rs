@@ -408,8 +408,33 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     }
 
     /// Shrink the capacity of this array to fit its length.
+    ///
+    /// The data is always copied into a single chunk with exactly sized buffers. This also
+    /// holds for an array with a single chunk, as that chunk may be a slice that keeps the
+    /// buffers of a much larger array alive. The data buffers of string and binary views are
+    /// garbage collected, which doesn't apply to views nested in lists, arrays or structs.
     pub fn shrink_to_fit(&mut self) {
-        self.chunks = vec![concatenate_unchecked(self.chunks.as_slice()).unwrap()];
+        let Some(first) = self.chunks.first() else {
+            return;
+        };
+        // Concatenating a single array returns it as is, so add an empty one to force a copy.
+        let mut arrays = Vec::with_capacity(self.chunks.len() + 1);
+        arrays.extend(self.chunks.iter().cloned());
+        arrays.push(new_empty_array(first.dtype().clone()));
+        let out = concatenate_unchecked(arrays.as_slice()).unwrap();
+        // The concatenated views still point into the original data buffers.
+        let out = match out.dtype() {
+            ArrowDataType::BinaryView => {
+                let arr = out.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+                arr.clone().gc().boxed()
+            },
+            ArrowDataType::Utf8View => {
+                let arr = out.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+                arr.clone().gc().boxed()
+            },
+            _ => out,
+        };
+        self.chunks = vec![out];
     }
 
     pub fn clear(&self) -> Self {
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::POOL;
 use crate::prelude::*;
+use crate::utils::{dtypes_to_supertype, try_get_supertype};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -14,6 +15,22 @@ pub enum IndexOrder {
     Fortran,
 }
 
+/// How [`DataFrame::to_ndarray2`] casts columns whose dtype differs from the array elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NdArrayCastPolicy {
+    /// All columns must already have the dtype of the array elements.
+    Strict,
+    /// Columns are cast to the dtype of the array elements if that dtype is the supertype of
+    /// the columns and the element dtype, following the usual supertype rules. Note that these
+    /// rules allow some lossy casts, e.g. `Int64` and `UInt64` columns are accepted for `Float64`
+    /// elements, which can't represent all of their values exactly.
+    #[default]
+    Supertype,
+    /// Columns are cast to the dtype of the array elements, even if that loses precision.
+    Lossy,
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -180,6 +197,89 @@ impl DataFrame {
     }
 }
 
+impl DataFrame {
+    /// Create a 2D [`ndarray::Array`] from this [`DataFrame`], where the columns may have
+    /// different numeric dtypes, which are cast to `N` as allowed by `cast_policy`.
+    ///
+    /// The `ordering` determines the memory layout of the array; as with
+    /// [`DataFrame::to_ndarray`], floating point nulls become `NaN` and other nulls are an error.
+    pub fn to_ndarray2<N>(
+        &self,
+        ordering: IndexOrder,
+        cast_policy: NdArrayCastPolicy,
+    ) -> PolarsResult<Array2<N::Native>>
+    where
+        N: PolarsNumericType,
+    {
+        let target = N::get_static_dtype();
+        for c in self.get_columns() {
+            polars_ensure!(
+                c.dtype().is_primitive_numeric() || c.dtype().is_bool() || c.dtype().is_null(),
+                InvalidOperation: "cannot create an ndarray from column '{}' of dtype {}", c.name(), c.dtype()
+            );
+        }
+        match cast_policy {
+            NdArrayCastPolicy::Strict => {
+                if let Some(c) = self.get_columns().iter().find(|c| c.dtype() != &target) {
+                    polars_bail!(
+                        SchemaMismatch: "cannot create an ndarray of {} from column '{}' of dtype {} without casting",
+                        target, c.name(), c.dtype()
+                    );
+                }
+            },
+            NdArrayCastPolicy::Supertype => {
+                let supertype = dtypes_to_supertype(self.get_columns().iter().map(|c| c.dtype()))?;
+                polars_ensure!(
+                    try_get_supertype(&supertype, &target)? == target,
+                    InvalidOperation: "cannot create an ndarray of {} from columns with supertype {}; use `NdArrayCastPolicy::Lossy` to cast anyway",
+                    target, supertype
+                );
+            },
+            NdArrayCastPolicy::Lossy => {},
+        }
+        self.to_ndarray::<N>(ordering)
+    }
+
+    /// Create a [`DataFrame`] from a 2D [`ndarray::Array`], with a column per array column.
+    ///
+    /// The array can have any memory layout; Fortran order (as produced by
+    /// `to_ndarray(IndexOrder::Fortran)`) is the fastest to convert.
+    ///
+    /// ```rust
+    /// use ndarray::array;
+    /// use polars_core::prelude::*;
+    /// let arr = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    /// let df = DataFrame::from_ndarray::<Float64Type>(arr.view(), ["a", "b"]).unwrap();
+    /// assert_eq!(df.shape(), (3, 2));
+    /// ```
+    pub fn from_ndarray<N>(
+        array: ArrayView2<'_, N::Native>,
+        column_names: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+    ) -> PolarsResult<Self>
+    where
+        N: PolarsNumericType,
+    {
+        let names = column_names.into_iter().map(Into::into).collect::<Vec<_>>();
+        polars_ensure!(
+            names.len() == array.ncols(),
+            ShapeMismatch: "expected {} column names for an ndarray with {} columns, got {}",
+            array.ncols(), array.ncols(), names.len()
+        );
+        let columns = names
+            .into_iter()
+            .zip(array.columns())
+            .map(|(name, values)| {
+                let ca = match values.as_slice() {
+                    Some(values) => ChunkedArray::<N>::from_slice(name, values),
+                    None => ChunkedArray::<N>::from_vec(name, values.iter().copied().collect()),
+                };
+                ca.into_column()
+            })
+            .collect();
+        DataFrame::new(columns)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -247,4 +347,39 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_ndarray2_cast_policy() -> PolarsResult<()> {
+        let df = df!["a" => [1i32, 2, 3], "b" => [2.0f32, 3.0, 4.0]]?;
+
+        let ndarr = df.to_ndarray2::<Float64Type>(IndexOrder::C, NdArrayCastPolicy::Supertype)?;
+        assert_eq!(ndarr, array![[1.0, 2.0], [2.0, 3.0], [3.0, 4.0]]);
+        assert!(
+            df.to_ndarray2::<Float64Type>(IndexOrder::C, NdArrayCastPolicy::Strict)
+                .is_err()
+        );
+        assert!(
+            df.to_ndarray2::<Float32Type>(IndexOrder::C, NdArrayCastPolicy::Supertype)
+                .is_err()
+        );
+        let ndarr = df.to_ndarray2::<Int64Type>(IndexOrder::Fortran, NdArrayCastPolicy::Lossy)?;
+        assert_eq!(ndarr, array![[1, 2], [2, 3], [3, 4]]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ndarray_roundtrip() -> PolarsResult<()> {
+        let df = df!["a"=> [1.0, 2.0, 3.0],
+            "b" => [2.0, 3.0, 4.0]
+        ]?;
+
+        for ordering in [IndexOrder::C, IndexOrder::Fortran] {
+            let ndarr = df.to_ndarray::<Float64Type>(ordering)?;
+            let out = DataFrame::from_ndarray::<Float64Type>(ndarr.view(), ["a", "b"])?;
+            assert!(out.equals(&df));
+        }
+        let ndarr = df.to_ndarray::<Float64Type>(IndexOrder::C)?;
+        assert!(DataFrame::from_ndarray::<Float64Type>(ndarr.view(), ["a"]).is_err());
+        Ok(())
+    }
 }
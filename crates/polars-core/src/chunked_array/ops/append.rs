@@ -1,5 +1,6 @@
 use polars_error::constants::LENGTH_LIMIT_MSG;
 
+use crate::diagnostics::{DiagnosticKind, emit_diagnostic};
 use crate::prelude::*;
 use crate::series::IsSorted;
 
@@ -137,6 +138,14 @@ where
         },
     };
 
+    if sorted_flag == IsSorted::Not && ca.is_sorted_any() {
+        emit_diagnostic(DiagnosticKind::SortednessDropped, || {
+            format!(
+                "sorted flag of '{}' dropped, the appended values are not sorted in the same order",
+                ca.name()
+            )
+        });
+    }
     ca.set_sorted_flag(sorted_flag);
 }
 
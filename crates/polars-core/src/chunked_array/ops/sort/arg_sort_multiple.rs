@@ -89,6 +89,7 @@ pub(crate) fn argsort_multiple_row_fmt(
     mut descending: Vec<bool>,
     mut nulls_last: Vec<bool>,
     parallel: bool,
+    maintain_order: bool,
 ) -> PolarsResult<IdxCa> {
     _broadcast_bools(by.len(), &mut descending);
     _broadcast_bools(by.len(), &mut nulls_last);
@@ -96,10 +97,11 @@ pub(crate) fn argsort_multiple_row_fmt(
     let rows_encoded = _get_rows_encoded(by, &descending, &nulls_last)?;
     let mut items: Vec<_> = rows_encoded.iter().enumerate_idx().collect();
 
-    if parallel {
-        POOL.install(|| items.par_sort_by_key(|i| i.1));
-    } else {
-        items.sort_by_key(|i| i.1);
+    match (parallel, maintain_order) {
+        (true, true) => POOL.install(|| items.par_sort_by_key(|i| i.1)),
+        (true, false) => POOL.install(|| items.par_sort_unstable_by_key(|i| i.1)),
+        (false, true) => items.sort_by_key(|i| i.1),
+        (false, false) => items.sort_unstable_by_key(|i| i.1),
     }
 
     let ca: NoNull<IdxCa> = items.into_iter().map(|tpl| tpl.0).collect();
@@ -4,6 +4,7 @@ pub mod arg_sort_multiple;
 
 pub mod arg_bottom_k;
 pub mod options;
+mod radix;
 
 #[cfg(feature = "dtype-categorical")]
 mod categorical;
@@ -192,7 +193,9 @@ where
     if ca.null_count() == 0 {
         let mut vals = ca.to_vec_null_aware().left().unwrap();
 
-        sort_impl_unstable(vals.as_mut_slice(), options);
+        if !radix::try_radix_sort::<T>(vals.as_mut_slice(), options) {
+            sort_impl_unstable(vals.as_mut_slice(), options);
+        }
 
         let mut ca = ChunkedArray::from_vec(ca.name().clone(), vals);
         let s = if options.descending {
@@ -223,7 +226,9 @@ where
             &mut vals[null_count..]
         };
 
-        sort_impl_unstable(mut_slice, options);
+        if !radix::try_radix_sort::<T>(mut_slice, options) {
+            sort_impl_unstable(mut_slice, options);
+        }
 
         if options.nulls_last {
             vals.extend(std::iter::repeat_n(T::Native::default(), ca.null_count()));
@@ -251,6 +256,11 @@ where
 {
     options.multithreaded &= POOL.current_num_threads() > 1;
     arg_sort_fast_path!(ca, options);
+    if ca.is_sorted_flag() == IsSorted::Not
+        && let Some(idx) = radix::try_arg_sort_radix(ca, options)
+    {
+        return idx;
+    }
     if ca.null_count() == 0 {
         let iter = ca
             .downcast_iter()
@@ -817,6 +827,12 @@ pub fn _broadcast_bools(n_cols: usize, values: &mut Vec<bool>) {
     }
 }
 
+/// Get the indices that would sort `columns`, comparing by the first column and breaking ties
+/// with the next columns.
+///
+/// Multiple columns are sorted by their row encoding, which compares all keys and their null
+/// ordering with a single `memcmp`.
+///
 /// # Panics
 /// Panics if `columns` is empty.
 pub fn arg_sort(columns: &[Column], mut sort_options: SortMultipleOptions) -> PolarsResult<IdxCa> {
@@ -830,17 +846,16 @@ pub fn arg_sort(columns: &[Column], mut sort_options: SortMultipleOptions) -> Po
             maintain_order: sort_options.maintain_order,
             limit: sort_options.limit,
         }))
-    } else if sort_options.nulls_last.iter().all(|&x| x)
-        || columns.iter().any(|c| c.dtype().is_nested())
-        || std::env::var("POLARS_ROW_FMT_SORT").is_ok()
-    {
+    } else if !columns.iter().any(|c| c.dtype().is_object()) {
         argsort_multiple_row_fmt(
             columns,
             sort_options.descending,
             sort_options.nulls_last,
             sort_options.multithreaded,
+            sort_options.maintain_order,
         )
     } else {
+        // Objects can't be row encoded.
         let n_cols = columns.len();
 
         _broadcast_bools(n_cols, &mut sort_options.descending);
@@ -1067,6 +1082,34 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arg_sort_multiple_nulls() -> PolarsResult<()> {
+        let a = Column::new("a".into(), [Some(1), None, Some(1), None, Some(2)]);
+        let b = Column::new("b".into(), [Some("x"), Some("y"), None, None, Some("z")]);
+
+        // Every combination of descending and nulls_last compares all keys in a single pass.
+        let options = SortMultipleOptions::default()
+            .with_order_descending_multi([true, false])
+            .with_nulls_last_multi([false, true])
+            .with_maintain_order(true);
+        let idx = arg_sort(&[a.clone(), b.clone()], options)?;
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(1), Some(3), Some(4), Some(0), Some(2)]
+        );
+
+        let options = SortMultipleOptions::default()
+            .with_order_descending_multi([false, true])
+            .with_nulls_last_multi([true, false])
+            .with_maintain_order(true);
+        let idx = arg_sort(&[a, b], options)?;
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(2), Some(0), Some(4), Some(3), Some(1)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new(
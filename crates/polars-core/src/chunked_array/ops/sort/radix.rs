@@ -0,0 +1,341 @@
+//! LSD radix sort for integer keys.
+//!
+//! The physical representation of integers, dates, datetimes, durations, times and categoricals
+//! are integers, so these are all sorted with a radix sort if they're long enough. The radix sort
+//! is stable, so it's also used if the order of equal elements must be maintained.
+use bytemuck::Pod;
+use polars_utils::sync::SyncPtr;
+
+use super::*;
+
+/// The minimum number of values for which a radix sort is faster than a comparison sort.
+const RADIX_SORT_MIN_LEN: usize = 256;
+/// The minimum number of values per thread for which the passes are run in parallel.
+const RADIX_SORT_MIN_LEN_PER_THREAD: usize = 1 << 16;
+
+/// An integer that can be radix sorted by the bytes of an order preserving unsigned key.
+pub(crate) trait RadixKey: Copy + Pod {
+    const BYTES: usize = size_of::<Self>();
+
+    /// An unsigned integer with the same order as `self`.
+    fn radix_key(self) -> u64;
+}
+
+macro_rules! impl_radix_key {
+    ($unsigned:ty $(, $signed:ty)?) => {
+        impl RadixKey for $unsigned {
+            #[inline(always)]
+            fn radix_key(self) -> u64 {
+                self as u64
+            }
+        }
+
+        $(
+            impl RadixKey for $signed {
+                #[inline(always)]
+                fn radix_key(self) -> u64 {
+                    // Flipping the sign bit orders negative integers before positive ones.
+                    (self as $unsigned ^ (1 << (<$unsigned>::BITS - 1))) as u64
+                }
+            }
+        )?
+    };
+}
+
+impl_radix_key!(u8, i8);
+impl_radix_key!(u16, i16);
+impl_radix_key!(u32, i32);
+impl_radix_key!(u64, i64);
+
+/// Apply `f` to every chunk of `chunk_len` values of `vals` and its index, on the thread pool if
+/// `parallel`.
+fn map_chunks<V, R, F>(vals: &[V], chunk_len: usize, parallel: bool, f: F) -> Vec<R>
+where
+    V: Sync,
+    R: Send,
+    F: Fn(usize, &[V]) -> R + Send + Sync,
+{
+    if parallel {
+        POOL.install(|| {
+            vals.par_chunks(chunk_len)
+                .enumerate()
+                .map(|(i, chunk)| f(i, chunk))
+                .collect()
+        })
+    } else {
+        vals.chunks(chunk_len)
+            .enumerate()
+            .map(|(i, chunk)| f(i, chunk))
+            .collect()
+    }
+}
+
+/// Sort `vals` by `key` with a stable LSD radix sort.
+///
+/// Bytes that are the same for all keys are skipped, so small integers in a wide type only take
+/// a few passes. If `multithreaded`, long inputs are split in a chunk per thread, of which the
+/// histograms are computed and the values are scattered in parallel.
+pub(crate) fn radix_sort_by_key<V, K, F>(
+    vals: &mut [V],
+    descending: bool,
+    multithreaded: bool,
+    key: F,
+) where
+    V: Copy + Send + Sync,
+    K: RadixKey,
+    F: Fn(&V) -> K + Send + Sync,
+{
+    let len = vals.len();
+    let n_threads = POOL.current_num_threads();
+    let parallel = multithreaded && n_threads > 1 && len >= 2 * RADIX_SORT_MIN_LEN_PER_THREAD;
+    let chunk_len = if parallel {
+        len.div_ceil(n_threads).max(RADIX_SORT_MIN_LEN_PER_THREAD)
+    } else {
+        len.max(1)
+    };
+    let radix_key = |v: &V| {
+        let k = key(v).radix_key();
+        if descending { !k } else { k }
+    };
+
+    let mut histograms = vec![[0usize; 256]; K::BYTES];
+    let chunk_histograms = map_chunks(vals, chunk_len, parallel, |_, chunk| {
+        let mut histograms = vec![[0usize; 256]; K::BYTES];
+        for v in chunk {
+            let k = radix_key(v);
+            for (byte, histogram) in histograms.iter_mut().enumerate() {
+                histogram[(k >> (8 * byte)) as u8 as usize] += 1;
+            }
+        }
+        histograms
+    });
+    for chunk_histogram in chunk_histograms {
+        for (histogram, chunk_histogram) in histograms.iter_mut().zip(chunk_histogram) {
+            for (count, chunk_count) in histogram.iter_mut().zip(chunk_histogram) {
+                *count += chunk_count;
+            }
+        }
+    }
+
+    let mut buf = vals.to_vec();
+    let mut sorted_in_vals = true;
+    for (byte, histogram) in histograms.iter().enumerate() {
+        if histogram.contains(&len) {
+            continue;
+        }
+        let bucket = |v: &V| (radix_key(v) >> (8 * byte)) as u8 as usize;
+
+        let (src, dst) = if sorted_in_vals {
+            (&*vals, &mut *buf)
+        } else {
+            (&*buf, &mut *vals)
+        };
+
+        // The values of a bucket are placed in the order of the chunks they come from, which
+        // keeps the sort stable.
+        let mut offsets = map_chunks(src, chunk_len, parallel, |_, chunk| {
+            let mut histogram = [0usize; 256];
+            for v in chunk {
+                histogram[bucket(v)] += 1;
+            }
+            histogram
+        });
+        let mut offset = 0;
+        for b in 0..256 {
+            for chunk_offsets in offsets.iter_mut() {
+                let count = chunk_offsets[b];
+                chunk_offsets[b] = offset;
+                offset += count;
+            }
+        }
+
+        // SAFETY: every chunk writes to its own offsets in `dst`.
+        let dst = unsafe { SyncPtr::new(dst.as_mut_ptr()) };
+        map_chunks(src, chunk_len, parallel, |i, chunk| {
+            let mut offsets = offsets[i];
+            for v in chunk {
+                let o = &mut offsets[bucket(v)];
+                // SAFETY: the offsets are the prefix sum of the histograms of this byte, so
+                // they stay below `len`.
+                unsafe { *dst.get().add(*o) = *v };
+                *o += 1;
+            }
+        });
+        sorted_in_vals = !sorted_in_vals;
+    }
+
+    if !sorted_in_vals {
+        vals.copy_from_slice(&buf);
+    }
+}
+
+macro_rules! with_match_radix_key {
+    ($dtype:expr, |$K:ident| $body:expr) => {
+        match $dtype {
+            DataType::UInt8 => {
+                type $K = u8;
+                Some($body)
+            },
+            DataType::UInt16 => {
+                type $K = u16;
+                Some($body)
+            },
+            DataType::UInt32 => {
+                type $K = u32;
+                Some($body)
+            },
+            DataType::UInt64 => {
+                type $K = u64;
+                Some($body)
+            },
+            DataType::Int8 => {
+                type $K = i8;
+                Some($body)
+            },
+            DataType::Int16 => {
+                type $K = i16;
+                Some($body)
+            },
+            DataType::Int32 => {
+                type $K = i32;
+                Some($body)
+            },
+            DataType::Int64 => {
+                type $K = i64;
+                Some($body)
+            },
+            _ => None,
+        }
+    };
+}
+
+/// Sort `vals` with a radix sort if `T` is an integer type of at most 64 bits and `vals` is long
+/// enough. Returns whether `vals` was sorted.
+pub(super) fn try_radix_sort<T: PolarsNumericType>(
+    vals: &mut [T::Native],
+    options: SortOptions,
+) -> bool {
+    if vals.len() < RADIX_SORT_MIN_LEN {
+        return false;
+    }
+    with_match_radix_key!(T::get_static_dtype(), |K| {
+        let vals = bytemuck::cast_slice_mut::<T::Native, K>(vals);
+        radix_sort_by_key(vals, options.descending, options.multithreaded, |v| *v)
+    })
+    .is_some()
+}
+
+/// The indices that sort `ca` if `T` is an integer type of at most 64 bits and `ca` is long
+/// enough, computed with a radix sort.
+pub(super) fn try_arg_sort_radix<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    options: SortOptions,
+) -> Option<IdxCa> {
+    if options.limit.is_some() || ca.len() < RADIX_SORT_MIN_LEN {
+        return None;
+    }
+    with_match_radix_key!(T::get_static_dtype(), |K| arg_sort_radix::<T, K>(
+        ca, options
+    ))
+}
+
+fn arg_sort_radix<T: PolarsNumericType, K: RadixKey>(
+    ca: &ChunkedArray<T>,
+    options: SortOptions,
+) -> IdxCa {
+    let mut nulls_idx = Vec::with_capacity(ca.null_count());
+    let mut vals: Vec<(IdxSize, K)> = Vec::with_capacity(ca.len() - ca.null_count());
+    for (i, v) in ca.iter().enumerate() {
+        match v {
+            Some(v) => vals.push((i as IdxSize, bytemuck::cast(v))),
+            None => nulls_idx.push(i as IdxSize),
+        }
+    }
+    radix_sort_by_key(&mut vals, options.descending, options.multithreaded, |v| {
+        v.1
+    });
+
+    let mut idx = Vec::with_capacity(ca.len());
+    if !options.nulls_last {
+        idx.extend_from_slice(&nulls_idx);
+    }
+    idx.extend(vals.iter().map(|v| v.0));
+    if options.nulls_last {
+        idx.extend_from_slice(&nulls_idx);
+    }
+    IdxCa::from_vec(ca.name().clone(), idx)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_radix_sort() {
+        let mut vals = (0..1000i64)
+            .map(|i| (i * 7919) % 1009 - 500)
+            .collect::<Vec<_>>();
+        let mut expected = vals.clone();
+        expected.sort_unstable();
+        radix_sort_by_key(&mut vals, false, false, |v| *v);
+        assert_eq!(vals, expected);
+
+        let mut vals = [3u8, 255, 0, 3, 128];
+        radix_sort_by_key(&mut vals, true, false, |v| *v);
+        assert_eq!(vals, [255, 128, 3, 3, 0]);
+
+        // The sort is stable.
+        let mut vals = [(0, -1i16), (1, 2), (2, -1), (3, i16::MIN)];
+        radix_sort_by_key(&mut vals, false, false, |v| v.1);
+        assert_eq!(vals, [(3, i16::MIN), (0, -1), (2, -1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_radix_sort_multithreaded() {
+        let n = 4 * RADIX_SORT_MIN_LEN_PER_THREAD as u32 + 17;
+        let vals = (0..n)
+            .map(|i| (i, (i.wrapping_mul(2654435761) % 100_003) as i32 - 50_000))
+            .collect::<Vec<_>>();
+        for descending in [false, true] {
+            let mut expected = vals.clone();
+            // A stable comparison sort.
+            match descending {
+                false => expected.sort_by_key(|v| v.1),
+                true => expected.sort_by_key(|v| std::cmp::Reverse(v.1)),
+            }
+            for multithreaded in [false, true] {
+                let mut out = vals.clone();
+                radix_sort_by_key(&mut out, descending, multithreaded, |v| v.1);
+                assert_eq!(out, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_arg_sort_radix() {
+        let ca = Int32Chunked::from_iter(
+            (0..1000).map(|i| (i % 10 != 0).then_some((i * 7919) % 1009 - 500)),
+        );
+        for descending in [false, true] {
+            for nulls_last in [false, true] {
+                let options = SortOptions {
+                    descending,
+                    nulls_last,
+                    maintain_order: true,
+                    ..Default::default()
+                };
+                let radix = try_arg_sort_radix(&ca, options).unwrap();
+                let comparison = arg_sort::arg_sort(
+                    ca.name().clone(),
+                    ca.downcast_iter().map(|arr| arr.iter().map(|v| v.copied())),
+                    options,
+                    ca.null_count(),
+                    ca.len(),
+                    IsSorted::Not,
+                    false,
+                );
+                assert_eq!(Vec::from(&radix), Vec::from(&comparison));
+            }
+        }
+    }
+}
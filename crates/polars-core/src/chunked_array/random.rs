@@ -1,6 +1,7 @@
 use num_traits::{Float, NumCast};
 use polars_error::to_compute_err;
 use rand::distr::Bernoulli;
+use rand::distr::weighted::WeightedIndex;
 use rand::prelude::*;
 use rand::seq::index::IndexVec;
 use rand_distr::{Normal, StandardNormal, StandardUniform, Uniform};
@@ -238,6 +239,97 @@ impl DataFrame {
             None => Ok(self.clear()),
         }
     }
+
+    /// Sample `n` rows where the probability of picking a row is proportional to its weight.
+    ///
+    /// Weights must be non-negative and not null; rows with a zero weight are never sampled.
+    /// Without replacement this uses weighted reservoir sampling (Efraimidis-Spirakis), so it can
+    /// sample at most as many rows as there are positive weights.
+    pub fn sample_n_weighted(
+        &self,
+        n: usize,
+        weights: &Series,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            weights.len() == self.height(),
+            ShapeMismatch: "sample weights have length {} but the DataFrame has height {}",
+            weights.len(), self.height()
+        );
+        polars_ensure!(
+            weights.null_count() == 0,
+            ComputeError: "sample weights must not contain null values"
+        );
+        let weights = weights.cast(&Float64)?;
+        let weights = weights.f64()?;
+        polars_ensure!(
+            weights.into_no_null_iter().all(|w| w.is_finite() && w >= 0.0),
+            ComputeError: "sample weights must be finite and non-negative"
+        );
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+
+        let idx: Vec<IdxSize> = if with_replacement {
+            if n == 0 {
+                vec![]
+            } else {
+                let dist =
+                    WeightedIndex::new(weights.into_no_null_iter()).map_err(to_compute_err)?;
+                (0..n).map(|_| dist.sample(&mut rng) as IdxSize).collect()
+            }
+        } else {
+            let mut keys = weights
+                .into_no_null_iter()
+                .enumerate()
+                .filter(|(_, w)| *w > 0.0)
+                .map(|(i, w)| (rng.random::<f64>().ln() / w, i as IdxSize))
+                .collect::<Vec<_>>();
+            ensure_shape(n, keys.len(), false)?;
+            keys.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+            keys.into_iter().take(n).map(|(_, i)| i).collect()
+        };
+        let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
+    /// Sample a fraction of the rows of every group of the `by` columns (stratified sampling).
+    ///
+    /// Every group keeps `floor(group_len * frac)` rows, so the group proportions are preserved.
+    /// Groups appear in order of first occurrence.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn sample_frac_stratified(
+        &self,
+        by: &[PlSmallStr],
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        let keys = self.select_columns(by.iter().cloned())?;
+        let groups = self.group_by_with_series(keys, true, true)?.take_groups();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+
+        let mut idx = Vec::with_capacity((self.height() as f64 * frac) as usize);
+        for group in groups.iter() {
+            let len = group.len();
+            let n = (len as f64 * frac) as usize;
+            ensure_shape(n, len, with_replacement)?;
+            let group_seed = Some(rng.next_u64());
+            let local = match with_replacement {
+                true => create_rand_index_with_replacement(n, len, group_seed),
+                false => create_rand_index_no_replacement(n, len, group_seed, shuffle),
+            };
+            let local = local.into_no_null_iter();
+            match group {
+                GroupsIndicator::Idx((_, group)) => idx.extend(local.map(|i| group[i as usize])),
+                GroupsIndicator::Slice([first, _]) => idx.extend(local.map(|i| first + i)),
+            }
+        }
+        let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+        // SAFETY: the indices are within bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
 }
 
 impl<T> ChunkedArray<T>
@@ -251,10 +343,11 @@ where
         length: usize,
         mean: f64,
         std_dev: f64,
+        seed: Option<u64>,
     ) -> PolarsResult<Self> {
         let normal = Normal::new(mean, std_dev).map_err(to_compute_err)?;
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::rng();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
         for _ in 0..length {
             let smpl = normal.sample(&mut rng);
             let smpl = NumCast::from(smpl).unwrap();
@@ -264,9 +357,9 @@ where
     }
 
     /// Create [`ChunkedArray`] with samples from a Standard Normal distribution.
-    pub fn rand_standard_normal(name: PlSmallStr, length: usize) -> Self {
+    pub fn rand_standard_normal(name: PlSmallStr, length: usize, seed: Option<u64>) -> Self {
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::rng();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
         for _ in 0..length {
             let smpl: f64 = rng.sample(StandardNormal);
             let smpl = NumCast::from(smpl).unwrap();
@@ -275,25 +368,36 @@ where
         builder.finish()
     }
 
-    /// Create [`ChunkedArray`] with samples from a Uniform distribution.
-    pub fn rand_uniform(name: PlSmallStr, length: usize, low: f64, high: f64) -> Self {
-        let uniform = Uniform::new(low, high).unwrap();
+    /// Create [`ChunkedArray`] with samples from a Uniform distribution on `[low, high)`.
+    pub fn rand_uniform(
+        name: PlSmallStr,
+        length: usize,
+        low: f64,
+        high: f64,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
+        let uniform = Uniform::new(low, high).map_err(to_compute_err)?;
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::rng();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
         for _ in 0..length {
             let smpl = uniform.sample(&mut rng);
             let smpl = NumCast::from(smpl).unwrap();
             builder.append_value(smpl)
         }
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl BooleanChunked {
     /// Create [`ChunkedArray`] with samples from a Bernoulli distribution.
-    pub fn rand_bernoulli(name: PlSmallStr, length: usize, p: f64) -> PolarsResult<Self> {
+    pub fn rand_bernoulli(
+        name: PlSmallStr,
+        length: usize,
+        p: f64,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self> {
         let dist = Bernoulli::new(p).map_err(to_compute_err)?;
-        let mut rng = rand::rng();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
         let mut builder = BooleanChunkedBuilder::new(name, length);
         for _ in 0..length {
             let smpl = dist.sample(&mut rng);
@@ -391,4 +495,60 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    fn test_sample_n_weighted() {
+        let df = df![
+            "foo" => &[1, 2, 3, 4, 5]
+        ]
+        .unwrap();
+        let weights = Series::new(PlSmallStr::from_static("w"), &[0.0, 1.0, 0.0, 2.0, 0.0]);
+
+        let out = df.sample_n_weighted(2, &weights, false, Some(0)).unwrap();
+        let mut values: Vec<_> = out
+            .column("foo")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        values.sort();
+        assert_eq!(values, &[2, 4]);
+
+        // Only two rows have a positive weight.
+        assert!(df.sample_n_weighted(3, &weights, false, Some(0)).is_err());
+
+        let out = df.sample_n_weighted(20, &weights, true, Some(0)).unwrap();
+        assert_eq!(out.height(), 20);
+        let foo = out.column("foo").unwrap().i32().unwrap();
+        assert!(foo.into_no_null_iter().all(|v| v == 2 || v == 4));
+
+        let negative = Series::new(PlSmallStr::from_static("w"), &[1.0, -1.0, 1.0, 1.0, 1.0]);
+        assert!(df.sample_n_weighted(1, &negative, false, Some(0)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_sample_frac_stratified() {
+        let df = df![
+            "g" => &["a", "b", "a", "b", "a", "b", "a", "a"],
+            "v" => &[0, 1, 2, 3, 4, 5, 6, 7]
+        ]
+        .unwrap();
+
+        let out = df
+            .sample_frac_stratified(&[PlSmallStr::from_static("g")], 0.5, false, false, Some(0))
+            .unwrap();
+        let g: Vec<_> = out
+            .column("g")
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(g, &["a", "a", "b"]);
+        let v = out.column("v").unwrap().i32().unwrap();
+        assert!(v.into_no_null_iter().take(2).all(|v| v % 2 == 0));
+        assert_eq!(v.get(2).map(|v| v % 2), Some(1));
+    }
 }
@@ -1,3 +1,9 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -29,10 +35,251 @@ pub(crate) const FMT_TABLE_INLINE_COLUMN_DATA_TYPE: &str =
 pub(crate) const FMT_TABLE_ROUNDED_CORNERS: &str = "POLARS_FMT_TABLE_ROUNDED_CORNERS";
 pub(crate) const FMT_TABLE_CELL_LIST_LEN: &str = "POLARS_FMT_TABLE_CELL_LIST_LEN";
 
+/// Programmatic configuration, taking precedence over the corresponding environment variables.
+///
+/// Settings that are `None` fall back to their environment variable, and then to the default.
+/// A [`Config`] is applied to the current thread with [`Config::apply`], which returns a guard
+/// that removes it again when dropped. It applies to the queries started from that thread,
+/// including their parallel work on the thread pool and in the streaming engine, but not to
+/// queries that other threads run concurrently. This allows e.g. a service to run the queries
+/// of every tenant with their own settings.
+///
+/// ```rust
+/// # use polars_core::config::Config;
+/// {
+///     let _guard = Config {
+///         fmt_max_rows: Some(20),
+///         verbose: Some(true),
+///         ..Config::current()
+///     }
+///     .apply();
+///     // Frames are formatted with up to 20 rows, and logging is enabled.
+/// }
+/// // The previous configuration is restored.
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Maximum number of rows shown when formatting a `DataFrame` (`POLARS_FMT_MAX_ROWS`).
+    pub fmt_max_rows: Option<usize>,
+    /// Maximum number of columns shown when formatting a `DataFrame` (`POLARS_FMT_MAX_COLS`).
+    pub fmt_max_cols: Option<usize>,
+    /// Maximum number of characters shown per string value (`POLARS_FMT_STR_LEN`).
+    pub fmt_str_len: Option<usize>,
+    /// Width of formatted tables, in characters (`POLARS_TABLE_WIDTH`).
+    pub fmt_table_width: Option<u16>,
+    /// Number of decimals shown for floats. Takes precedence over
+    /// [`set_float_precision`](crate::fmt::set_float_precision).
+    pub float_precision: Option<usize>,
+    /// Print logging info to stderr (`POLARS_VERBOSE`).
+    pub verbose: Option<bool>,
+    /// The ideal number of rows per morsel in the streaming engine
+    /// (`POLARS_IDEAL_MORSEL_SIZE`).
+    pub streaming_chunk_size: Option<usize>,
+    /// Base directory for temporary files (`POLARS_TEMP_DIR`). The file cache directory is
+    /// determined once, when the file cache is first used.
+    pub temp_dir: Option<PathBuf>,
+    /// How overflow in integer `+`, `-`, `*` and `sum`, also of the groups of a group-by, is
+    /// handled (`POLARS_INTEGER_OVERFLOW`, one of `wrap`, `raise` or `saturate`).
+    pub integer_overflow: Option<IntegerOverflow>,
+    /// Maximum number of bytes that joins and group-bys of the in-memory engine hold before
+    /// they spill to disk (`POLARS_MEMORY_LIMIT`), see [`crate::memory`].
+    pub memory_limit: Option<usize>,
+}
+
+/// How overflow in integer arithmetic is handled, see [`Config::integer_overflow`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IntegerOverflow {
+    /// Wrap around at the bounds of the dtype, as in two's complement arithmetic.
+    #[default]
+    Wrap,
+    /// Return an error.
+    Raise,
+    /// Clamp the result to the bounds of the dtype.
+    Saturate,
+}
+
+/// The id of the configuration that a thread inherited with [`ConfigScope::enter`], which no
+/// guard removes.
+const INHERITED_ID: u64 = u64::MAX;
+
+thread_local! {
+    /// The configurations applied on this thread with the id of their guard, the last one is
+    /// current.
+    static CONFIG: RefCell<Vec<(u64, Arc<Config>)>> = const { RefCell::new(Vec::new()) };
+}
+
+impl Config {
+    /// A configuration that doesn't override any setting.
+    pub const fn empty() -> Self {
+        Self {
+            fmt_max_rows: None,
+            fmt_max_cols: None,
+            fmt_str_len: None,
+            fmt_table_width: None,
+            float_precision: None,
+            verbose: None,
+            streaming_chunk_size: None,
+            temp_dir: None,
+            integer_overflow: None,
+            memory_limit: None,
+        }
+    }
+
+    /// The configuration that is currently applied on this thread.
+    pub fn current() -> Self {
+        Self::get(|c| Some(c.clone())).unwrap_or_default()
+    }
+
+    /// Read a setting of the configuration that is currently applied on this thread.
+    pub fn get<T>(f: impl FnOnce(&Config) -> Option<T>) -> Option<T> {
+        CONFIG.with_borrow(|configs| configs.last().and_then(|(_, c)| f(c)))
+    }
+
+    /// Apply this configuration to the current thread, until the returned guard is dropped.
+    ///
+    /// The most recently applied configuration that is still alive is current, so guards may
+    /// be dropped in any order.
+    pub fn apply(self) -> ConfigGuard {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        CONFIG.with_borrow_mut(|configs| configs.push((id, Arc::new(self))));
+        ConfigGuard {
+            id,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Removes its [`Config`] from the thread that applied it when dropped.
+#[must_use = "the configuration is reset when the guard is dropped"]
+pub struct ConfigGuard {
+    id: u64,
+    // The configuration is applied to a thread, so it has to be removed on that thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ConfigGuard {
+    fn drop(&mut self) {
+        // The thread local is gone if the guard is dropped while the thread exits.
+        let _ = CONFIG.try_with(|configs| configs.borrow_mut().retain(|(id, _)| *id != self.id));
+    }
+}
+
+/// The configuration of a thread, to carry it into the tasks that it starts on other threads.
+///
+/// The thread pool and the streaming engine capture the scope of the thread that starts a task
+/// and enter it while the task runs, so that the [`Config`] of a query also applies to its
+/// parallel work. Other code that moves work to threads that it spawns itself can do the same.
+///
+/// With the `tracing` feature the scope also carries the current `tracing` subscriber and span,
+/// so that the spans and events of the parallel work are children of the span that started it.
+#[derive(Clone, Debug)]
+pub struct ConfigScope {
+    config: Option<Arc<Config>>,
+    #[cfg(feature = "tracing")]
+    dispatch: tracing::Dispatch,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl ConfigScope {
+    /// The scope of the current thread.
+    pub fn current() -> Self {
+        Self {
+            config: CONFIG.with_borrow(|configs| configs.last().map(|(_, c)| c.clone())),
+            #[cfg(feature = "tracing")]
+            dispatch: tracing::dispatcher::get_default(|dispatch| dispatch.clone()),
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::current(),
+        }
+    }
+
+    /// Run `f` with the configuration of this scope applied to the current thread.
+    pub fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::dispatcher::with_default(&self.dispatch, || {
+                let _span = self.span.enter();
+                self.enter_config(f)
+            })
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            self.enter_config(f)
+        }
+    }
+
+    fn enter_config<R>(&self, f: impl FnOnce() -> R) -> R {
+        let is_current = CONFIG.with_borrow(|configs| match (configs.last(), &self.config) {
+            (None, None) => true,
+            (Some((_, current)), Some(config)) => Arc::ptr_eq(current, config),
+            _ => false,
+        });
+        if is_current {
+            return f();
+        }
+
+        struct Restore(Vec<(u64, Arc<Config>)>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CONFIG.set(std::mem::take(&mut self.0));
+            }
+        }
+
+        let inherited = self
+            .config
+            .iter()
+            .map(|c| (INHERITED_ID, c.clone()))
+            .collect();
+        let _restore = Restore(CONFIG.replace(inherited));
+        f()
+    }
+}
+
 pub fn verbose() -> bool {
+    if let Some(verbose) = Config::get(|c| c.verbose) {
+        return verbose;
+    }
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
 }
 
+/// How overflow in integer arithmetic is handled. `POLARS_INTEGER_OVERFLOW` is read once, as
+/// this is checked by every arithmetic operation.
+pub fn integer_overflow() -> IntegerOverflow {
+    static INTEGER_OVERFLOW: LazyLock<IntegerOverflow> =
+        LazyLock::new(
+            || match std::env::var("POLARS_INTEGER_OVERFLOW").as_deref() {
+                Ok("raise") => IntegerOverflow::Raise,
+                Ok("saturate") => IntegerOverflow::Saturate,
+                _ => IntegerOverflow::Wrap,
+            },
+        );
+    Config::get(|c| c.integer_overflow).unwrap_or(*INTEGER_OVERFLOW)
+}
+
+pub fn memory_limit() -> Option<usize> {
+    if let Some(limit) = Config::get(|c| c.memory_limit) {
+        return Some(limit);
+    }
+    std::env::var("POLARS_MEMORY_LIMIT")
+        .ok()
+        .map(|s| s.parse::<usize>().expect("integer"))
+}
+
+/// Whether the threads of the thread pool are pinned to NUMA nodes, and partitioned operators
+/// place their partitions on the nodes of the threads that build them (`POLARS_NUMA_AWARE=1`).
+/// This is read once, when the thread pool is created, and only applies on machines with
+/// multiple NUMA nodes.
+pub fn numa_aware() -> bool {
+    static NUMA_AWARE: LazyLock<bool> = LazyLock::new(|| {
+        std::env::var("POLARS_NUMA_AWARE").as_deref() == Ok("1")
+            && polars_utils::numa::numa_nodes().len() > 1
+    });
+    *NUMA_AWARE
+}
+
 pub fn get_engine_affinity() -> String {
     std::env::var("POLARS_ENGINE_AFFINITY").unwrap_or_else(|_| "auto".to_string())
 }
@@ -68,3 +315,77 @@ pub fn force_async() -> bool {
         .map(|value| value == "1")
         .unwrap_or_default()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_config_guard() {
+        let before = Config::current();
+        {
+            let _guard = Config {
+                verbose: Some(true),
+                streaming_chunk_size: Some(3),
+                ..before.clone()
+            }
+            .apply();
+            assert!(verbose());
+            assert_eq!(Config::get(|c| c.streaming_chunk_size), Some(3));
+
+            let _inner = Config {
+                verbose: Some(false),
+                ..Config::current()
+            }
+            .apply();
+            assert!(!verbose());
+            assert_eq!(Config::get(|c| c.streaming_chunk_size), Some(3));
+        }
+        assert_eq!(Config::current(), before);
+
+        // Dropping the guards out of order keeps the most recent configuration current.
+        let outer = Config {
+            streaming_chunk_size: Some(5),
+            ..before.clone()
+        }
+        .apply();
+        let inner = Config {
+            streaming_chunk_size: Some(7),
+            ..before.clone()
+        }
+        .apply();
+        drop(outer);
+        assert_eq!(Config::get(|c| c.streaming_chunk_size), Some(7));
+        drop(inner);
+        assert_eq!(Config::current(), before);
+    }
+
+    #[test]
+    fn test_config_scope() {
+        let _guard = Config {
+            streaming_chunk_size: Some(11),
+            ..Config::current()
+        }
+        .apply();
+        let chunk_size = || Config::get(|c| c.streaming_chunk_size);
+
+        // Other threads don't see the configuration, unless they enter its scope.
+        let scope = ConfigScope::current();
+        std::thread::scope(|s| {
+            assert_eq!(s.spawn(chunk_size).join().unwrap(), None);
+            assert_eq!(
+                s.spawn(|| scope.enter(chunk_size)).join().unwrap(),
+                Some(11)
+            );
+        });
+
+        // The parallel work of the thread pool does.
+        assert_eq!(POOL.install(chunk_size), Some(11));
+        assert_eq!(POOL.join(chunk_size, chunk_size), (Some(11), Some(11)));
+        assert!(
+            POOL.map_partitions(4, |_| chunk_size())
+                .iter()
+                .all(|c| *c == Some(11))
+        );
+    }
+}
@@ -1029,11 +1029,10 @@ impl DataType {
             // We don't allow the other way around, only if our current type is
             // null and the schema isn't we allow it.
             (DataType::Null, _) => Ok(true),
+            // Different categories are unified by casting both sides to their supertype, the
+            // global categories.
             #[cfg(feature = "dtype-categorical")]
-            (DataType::Categorical(l, _), DataType::Categorical(r, _)) => {
-                ensure_same_categories(l, r)?;
-                Ok(false)
-            },
+            (DataType::Categorical(l, _), DataType::Categorical(r, _)) => Ok(!Arc::ptr_eq(l, r)),
             #[cfg(feature = "dtype-categorical")]
             (DataType::Enum(l, _), DataType::Enum(r, _)) => {
                 ensure_same_frozen_categories(l, r)?;
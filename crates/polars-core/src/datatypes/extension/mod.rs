@@ -3,7 +3,11 @@ use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
 
-use crate::datatypes::DataType;
+use polars_error::PolarsResult;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::datatypes::{AnyValue, DataType};
+use crate::series::Series;
 
 mod generic;
 mod registry;
@@ -44,6 +48,39 @@ pub trait ExtensionTypeImpl: 'static + Send + Sync + Any {
     /// Should be a more verbose string representation, useful for debugging, in TitleCase,
     /// for example: String, Decimal(10, 2).
     fn dyn_debug(&self) -> Cow<'_, str>;
+
+    /// Format a non-null value of the storage type for display.
+    ///
+    /// Returns `None` to display the storage value as is, which is the default.
+    fn format_value(&self, _value: &AnyValue<'_>) -> Option<String> {
+        None
+    }
+
+    /// Cast a [`Series`], which is not of this extension type, to the storage type, for
+    /// example by parsing strings.
+    ///
+    /// Returns `None` to cast it to the storage type with a regular cast, which is the default.
+    fn cast_from(
+        &self,
+        _s: &Series,
+        _storage: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Cast the storage of a [`Series`] of this extension type to `dtype`, for example by
+    /// formatting it as strings.
+    ///
+    /// Returns `None` to cast the storage with a regular cast, which is the default.
+    fn cast_to(
+        &self,
+        _storage: &Series,
+        _dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
 }
 
 #[repr(transparent)]
@@ -91,3 +128,91 @@ impl ExtensionTypeInstance {
         self.0.serialize_metadata()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+    use crate::prelude::*;
+
+    /// IPv4 addresses, stored as `u32`.
+    struct Ipv4Type;
+
+    impl ExtensionTypeImpl for Ipv4Type {
+        fn name(&self) -> Cow<'_, str> {
+            Cow::Borrowed("test.ipv4")
+        }
+
+        fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+            None
+        }
+
+        fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+            Box::new(Ipv4Type)
+        }
+
+        fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+            (other as &dyn Any).is::<Ipv4Type>()
+        }
+
+        fn dyn_hash(&self) -> u64 {
+            0
+        }
+
+        fn dyn_display(&self) -> Cow<'_, str> {
+            Cow::Borrowed("ipv4")
+        }
+
+        fn dyn_debug(&self) -> Cow<'_, str> {
+            Cow::Borrowed("Ipv4")
+        }
+
+        fn format_value(&self, value: &AnyValue<'_>) -> Option<String> {
+            Some(Ipv4Addr::from(value.extract::<u32>()?).to_string())
+        }
+
+        fn cast_from(
+            &self,
+            s: &Series,
+            _storage: &DataType,
+            _options: CastOptions,
+        ) -> Option<PolarsResult<Series>> {
+            let ca = s.try_str()?;
+            Some(
+                ca.iter()
+                    .map(|opt_s| {
+                        opt_s
+                            .map(|s| s.parse::<Ipv4Addr>().map(u32::from).map_err(to_compute_err))
+                            .transpose()
+                    })
+                    .collect::<PolarsResult<UInt32Chunked>>()
+                    .map(|ca| ca.with_name(s.name().clone()).into_series()),
+            )
+        }
+    }
+
+    #[test]
+    fn test_extension_cast_and_format() -> PolarsResult<()> {
+        let dtype = DataType::Extension(
+            ExtensionTypeInstance(Box::new(Ipv4Type)),
+            Box::new(DataType::UInt32),
+        );
+        let s = Series::new(PlSmallStr::from_static("ip"), [Some("10.0.0.1"), None]);
+        let ip = s.cast(&dtype)?;
+        assert_eq!(ip.dtype(), &dtype);
+        assert_eq!(ip.ext()?.storage().u32()?.get(0), Some(0x0a000001));
+        assert_eq!(ip.str_value(0)?, "10.0.0.1");
+        assert_eq!(ip.str_value(1)?, "null");
+        let formatted = format!("{ip}");
+        assert!(formatted.contains("\t10.0.0.1\n"), "{formatted}");
+        assert!(formatted.contains("\tnull\n"), "{formatted}");
+
+        // Without a custom rule, casts go through the storage type.
+        assert_eq!(ip.cast(&DataType::Int64)?.i64()?.get(0), Some(0x0a000001));
+
+        let bad = Series::new(PlSmallStr::from_static("ip"), ["not an ip"]);
+        assert!(bad.cast(&dtype).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! Structured diagnostics about how queries are planned and executed.
+//!
+//! Diagnostics report decisions that don't change the result of a query but can matter for its
+//! performance, such as falling back from the streaming engine or dropping a sorted flag. By
+//! default they're printed to stderr if `POLARS_VERBOSE=1`. Register a callback with
+//! [`set_diagnostic_callback`], or a channel with [`diagnostic_channel`], to receive them instead.
+use std::sync::mpsc::{Receiver, channel};
+use std::sync::{Arc, RwLock};
+
+use crate::config::verbose;
+
+/// The kind of a [`Diagnostic`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiagnosticKind {
+    /// (Part of) a query doesn't support the streaming engine and runs on the in-memory engine.
+    StreamingFallback,
+    /// A sorted flag was dropped, so that later operations can't use their sorted fast paths.
+    SortednessDropped,
+    /// A fast path is used, e.g. because the data is sorted.
+    FastPath,
+    /// A slower path is used because a fast path doesn't apply.
+    SlowPath,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub message: String,
+}
+
+pub type DiagnosticCallback = Arc<dyn Fn(&Diagnostic) + Send + Sync>;
+
+static DIAGNOSTIC_CALLBACK: RwLock<Option<DiagnosticCallback>> = RwLock::new(None);
+
+/// Set the callback that receives all diagnostics, or restore printing them under
+/// `POLARS_VERBOSE=1` with `None`.
+///
+/// The callback is called on the thread that emits the diagnostic, which can be any thread of
+/// the thread pool.
+pub fn set_diagnostic_callback(callback: Option<DiagnosticCallback>) {
+    *DIAGNOSTIC_CALLBACK.write().unwrap() = callback;
+}
+
+/// Send all diagnostics to the returned channel, replacing the current callback.
+pub fn diagnostic_channel() -> Receiver<Diagnostic> {
+    let (sender, receiver) = channel();
+    set_diagnostic_callback(Some(Arc::new(move |diagnostic: &Diagnostic| {
+        // The receiver may have been dropped, that just means nobody is listening anymore.
+        _ = sender.send(diagnostic.clone());
+    })));
+    receiver
+}
+
+/// Whether emitted diagnostics are received by anyone. Use this to skip expensive work that is
+/// only needed for a diagnostic.
+pub fn diagnostics_enabled() -> bool {
+    DIAGNOSTIC_CALLBACK.read().unwrap().is_some() || verbose()
+}
+
+/// Emit a diagnostic. The message is only created if the diagnostic is received by anyone.
+pub fn emit_diagnostic<F: FnOnce() -> String>(kind: DiagnosticKind, create_message: F) {
+    // Clone the callback, so that it can set a new callback without deadlocking.
+    let callback = DIAGNOSTIC_CALLBACK.read().unwrap().clone();
+    if let Some(callback) = callback {
+        callback(&Diagnostic {
+            kind,
+            message: create_message(),
+        });
+    } else if verbose() {
+        eprintln!("{}", create_message());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_channel() {
+        let receiver = diagnostic_channel();
+        emit_diagnostic(DiagnosticKind::SlowPath, || "slow".to_string());
+        set_diagnostic_callback(None);
+        emit_diagnostic(DiagnosticKind::SlowPath, || "not received".to_string());
+
+        let received = receiver.try_iter().collect::<Vec<_>>();
+        assert!(received.contains(&Diagnostic {
+            kind: DiagnosticKind::SlowPath,
+            message: "slow".to_string(),
+        }));
+        assert!(!received.iter().any(|d| d.message == "not received"));
+    }
+}
@@ -58,7 +58,7 @@ pub fn get_float_fmt() -> FloatFmt {
     }
 }
 pub fn get_float_precision() -> Option<usize> {
-    *FLOAT_PRECISION.read().unwrap()
+    Config::get(|c| c.float_precision).or(*FLOAT_PRECISION.read().unwrap())
 }
 pub fn get_decimal_separator() -> char {
     DECIMAL_SEPARATOR.load() as char
@@ -111,14 +111,17 @@ fn parse_env_var_limit(name: &str, default: usize) -> usize {
 }
 
 fn get_row_limit() -> usize {
-    parse_env_var_limit(FMT_MAX_ROWS, DEFAULT_ROW_LIMIT)
+    Config::get(|c| c.fmt_max_rows)
+        .unwrap_or_else(|| parse_env_var_limit(FMT_MAX_ROWS, DEFAULT_ROW_LIMIT))
 }
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn get_col_limit() -> usize {
-    parse_env_var_limit(FMT_MAX_COLS, DEFAULT_COL_LIMIT)
+    Config::get(|c| c.fmt_max_cols)
+        .unwrap_or_else(|| parse_env_var_limit(FMT_MAX_COLS, DEFAULT_COL_LIMIT))
 }
 fn get_str_len_limit() -> usize {
-    parse_env_var_limit(FMT_STR_LEN, DEFAULT_STR_LEN_LIMIT)
+    Config::get(|c| c.fmt_str_len)
+        .unwrap_or_else(|| parse_env_var_limit(FMT_STR_LEN, DEFAULT_STR_LEN_LIMIT))
 }
 fn get_list_len_limit() -> usize {
     parse_env_var_limit(FMT_TABLE_CELL_LIST_LEN, DEFAULT_LIST_LEN_LIMIT)
@@ -215,6 +218,63 @@ macro_rules! format_array {
     }};
 }
 
+/// The values of an extension type, formatted with
+/// [`ExtensionTypeImpl::format_value`](crate::datatypes::extension::ExtensionTypeImpl::format_value).
+#[cfg(feature = "dtype-extension")]
+struct ExtensionValues<'a>(&'a Series);
+
+#[cfg(feature = "dtype-extension")]
+enum ExtensionValue<'a> {
+    Storage(AnyValue<'a>),
+    /// A value formatted by the extension type; quoted if it is stored as a string, like the
+    /// other string values.
+    Formatted {
+        value: String,
+        quote: bool,
+    },
+}
+
+#[cfg(feature = "dtype-extension")]
+impl<'a> ExtensionValues<'a> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn get_any_value(&self, index: usize) -> PolarsResult<ExtensionValue<'a>> {
+        let DataType::Extension(typ, storage) = self.0.dtype() else {
+            unreachable!()
+        };
+        let av = self.0.get(index)?;
+        if !av.is_null()
+            && let Some(value) = typ.0.format_value(&av)
+        {
+            return Ok(ExtensionValue::Formatted {
+                value,
+                quote: storage.is_string(),
+            });
+        }
+        Ok(ExtensionValue::Storage(av))
+    }
+}
+
+#[cfg(feature = "dtype-extension")]
+impl Display for ExtensionValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Storage(av) => Display::fmt(av, f),
+            Self::Formatted { value, quote: true } => write!(f, "\"{value}\""),
+            Self::Formatted {
+                value,
+                quote: false,
+            } => f.write_str(value),
+        }
+    }
+}
+
 #[cfg(feature = "object")]
 fn format_object_array(
     f: &mut Formatter<'_>,
@@ -450,7 +510,7 @@ impl Debug for Series {
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(_, _) => {
                 let dt = format!("{}", self.dtype());
-                format_array!(f, self.ext().unwrap(), &dt, self.name(), "Series")
+                format_array!(f, ExtensionValues(self), &dt, self.name(), "Series")
             },
             dt => panic!("{dt:?} not impl"),
         }
@@ -468,7 +528,6 @@ impl Debug for DataFrame {
         Display::fmt(self, f)
     }
 }
-#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn make_str_val(v: &str, truncate: usize, ellipsis: &String) -> String {
     let v_trunc = &v[..v
         .char_indices()
@@ -732,19 +791,21 @@ impl Display for DataFrame {
                 table.add_row(dots);
             }
             let tbl_fallback_width = 100;
-            let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
-                .map(|s| {
-                    let n = s
-                        .parse::<i64>()
-                        .expect("could not parse table width argument");
-                    let w = if n < 0 {
-                        u16::MAX
-                    } else {
-                        u16::try_from(n).expect("table width argument does not fit in u16")
-                    };
-                    Some(w)
-                })
-                .unwrap_or(None);
+            let tbl_width = Config::get(|c| c.fmt_table_width).or_else(|| {
+                std::env::var("POLARS_TABLE_WIDTH")
+                    .map(|s| {
+                        let n = s
+                            .parse::<i64>()
+                            .expect("could not parse table width argument");
+                        let w = if n < 0 {
+                            u16::MAX
+                        } else {
+                            u16::try_from(n).expect("table width argument does not fit in u16")
+                        };
+                        Some(w)
+                    })
+                    .unwrap_or(None)
+            });
 
             // column width constraints
             let col_width_exact =
@@ -840,6 +901,72 @@ impl Display for DataFrame {
     }
 }
 
+impl DataFrame {
+    /// Format a transposed preview of this [`DataFrame`]: a line per column with its name,
+    /// dtype and first `max_items_per_column` values.
+    ///
+    /// This is far more readable than [`DataFrame::head`] for wide frames, and is returned as
+    /// a `String` so that it can be printed as well as logged.
+    ///
+    /// ```text
+    /// Rows: 3
+    /// Columns: 2
+    /// $ a <i64> 1, 2, 3
+    /// $ b <str> "x", "y", null
+    /// ```
+    pub fn glimpse(&self, max_items_per_column: usize) -> String {
+        let str_truncate = get_str_len_limit();
+        let ellipsis = get_ellipsis().to_string();
+        let n_items = max_items_per_column.min(self.height());
+
+        let names = self
+            .columns
+            .iter()
+            .map(|c| make_str_val(c.name(), str_truncate, &ellipsis))
+            .collect::<Vec<_>>();
+        let dtypes = self
+            .columns
+            .iter()
+            .map(|c| format!("<{}>", c.dtype()))
+            .collect::<Vec<_>>();
+        let name_width = names.iter().map(|n| estimate_string_width(n)).max();
+        let dtype_width = dtypes.iter().map(|d| d.len()).max();
+
+        let mut out = format!(
+            "Rows: {}\nColumns: {}",
+            fmt_int_string(&self.height().to_string()),
+            fmt_int_string(&self.width().to_string())
+        );
+        for ((column, name), dtype) in self.columns.iter().zip(&names).zip(&dtypes) {
+            let name_pad = name_width.unwrap() - estimate_string_width(name);
+            let dtype_pad = dtype_width.unwrap() - dtype.len();
+            write!(out, "\n$ {name}{:name_pad$} {dtype}{:dtype_pad$}", "", "").unwrap();
+            for i in 0..n_items {
+                let sep = if i == 0 { " " } else { ", " };
+                match column.get(i).unwrap() {
+                    AnyValue::String(v) => {
+                        write!(out, "{sep}\"{}\"", make_str_val(v, str_truncate, &ellipsis))
+                    },
+                    AnyValue::StringOwned(v) => {
+                        write!(
+                            out,
+                            "{sep}\"{}\"",
+                            make_str_val(&v, str_truncate, &ellipsis)
+                        )
+                    },
+                    av => write!(out, "{sep}{av}"),
+                }
+                .unwrap();
+            }
+            if n_items < self.height() {
+                let sep = if n_items == 0 { " " } else { ", " };
+                write!(out, "{sep}{ellipsis}").unwrap();
+            }
+        }
+        out
+    }
+}
+
 fn fmt_int_string_custom(num: &str, group_size: u8, group_separator: &str) -> String {
     if group_size == 0 || num.len() <= 1 {
         num.to_string()
@@ -1538,4 +1665,25 @@ ChunkedArray: 'name' [str]
             format!("{ca:?}")
         );
     }
+
+    #[test]
+    fn test_glimpse() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1i64, 2, 3],
+            "long_name" => [Some("x"), None, Some("z")],
+        }?;
+        assert_eq!(
+            df.glimpse(2),
+            r#"Rows: 3
+Columns: 2
+$ a         <i64> 1, 2, …
+$ long_name <str> "x", null, …"#
+        );
+        assert_eq!(
+            df.glimpse(10).lines().last(),
+            Some(r#"$ long_name <str> "x", null, "z""#)
+        );
+        assert_eq!(df.glimpse(0).lines().last(), Some(r#"$ long_name <str> …"#));
+        Ok(())
+    }
 }
@@ -0,0 +1,124 @@
+use arrow::array::StructArray;
+use arrow::ffi::{ArrowArrayStream, ArrowArrayStreamReader, export_iterator};
+
+use crate::prelude::*;
+
+/// Yields the columns of a [`DataFrame`] with aligned chunks as one [`StructArray`] per chunk.
+struct DataFrameStreamIterator {
+    columns: Vec<Series>,
+    dtype: ArrowDataType,
+    compat_level: CompatLevel,
+    idx: usize,
+    n_chunks: usize,
+}
+
+impl Iterator for DataFrameStreamIterator {
+    type Item = PolarsResult<ArrayRef>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.n_chunks {
+            return None;
+        }
+        let batch_cols = self
+            .columns
+            .iter()
+            .map(|s| s.to_arrow(self.idx, self.compat_level))
+            .collect::<Vec<_>>();
+        self.idx += 1;
+
+        let array = StructArray::new(self.dtype.clone(), batch_cols[0].len(), batch_cols, None);
+        Some(Ok(Box::new(array)))
+    }
+}
+
+impl DataFrame {
+    /// Export this [`DataFrame`] through the [Arrow C stream interface], yielding one record batch
+    /// (a non-nullable struct array) per chunk.
+    ///
+    /// The buffers are shared with the consumer rather than copied, unless the `compat_level`
+    /// requires the data to be converted.
+    ///
+    /// [Arrow C stream interface]: https://arrow.apache.org/docs/format/CStreamInterface.html
+    pub fn to_arrow_c_stream(&self, compat_level: CompatLevel) -> ArrowArrayStream {
+        let mut df = self.clone();
+        df.align_chunks();
+        let fields = df
+            .schema()
+            .to_arrow(compat_level)
+            .into_iter_values()
+            .collect();
+        let dtype = ArrowDataType::Struct(fields);
+        let field = ArrowField::new(PlSmallStr::EMPTY, dtype.clone(), false);
+
+        let iter = DataFrameStreamIterator {
+            n_chunks: df.first_col_n_chunks(),
+            columns: df
+                .take_columns()
+                .into_iter()
+                .map(Column::take_materialized_series)
+                .collect(),
+            dtype,
+            compat_level,
+            idx: 0,
+        };
+        export_iterator(Box::new(iter), field)
+    }
+
+    /// Import a [`DataFrame`] from a stream of record batches produced through the
+    /// [Arrow C stream interface].
+    ///
+    /// Every record batch becomes a chunk of the resulting [`DataFrame`]; the data is imported
+    /// without copying.
+    ///
+    /// # Safety
+    /// The stream must be a valid Arrow C stream (as produced by an Arrow implementation)
+    /// that yields struct arrays.
+    ///
+    /// [Arrow C stream interface]: https://arrow.apache.org/docs/format/CStreamInterface.html
+    pub unsafe fn from_arrow_c_stream(stream: ArrowArrayStream) -> PolarsResult<Self> {
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(Box::new(stream))? };
+        let ArrowDataType::Struct(fields) = reader.field().dtype() else {
+            polars_bail!(
+                ComputeError: "expected an Arrow C stream of struct arrays to import as a DataFrame, got {:?}",
+                reader.field().dtype()
+            );
+        };
+        let mut out = DataFrame::empty_with_arrow_schema(&fields.iter().cloned().collect());
+
+        while let Some(array) = unsafe { reader.next() } {
+            let array = array?;
+            let Some(array) = array.as_any().downcast_ref::<StructArray>() else {
+                polars_bail!(ComputeError: "expected a struct array in Arrow C stream, got {:?}", array.dtype());
+            };
+            let batch = DataFrame::try_from(array.clone())?;
+            out.vstack_mut_owned(batch)?;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_arrow_c_stream_roundtrip() -> PolarsResult<()> {
+        let mut df = df! {
+            "a" => [1i32, 2, 3],
+            "b" => ["x", "y", "z"],
+        }?;
+        df.vstack_mut(&df.clone())?;
+
+        let stream = df.to_arrow_c_stream(CompatLevel::newest());
+        let out = unsafe { DataFrame::from_arrow_c_stream(stream)? };
+        assert!(out.equals(&df));
+        assert_eq!(out.first_col_n_chunks(), 2);
+
+        let empty = df.clear();
+        let stream = empty.to_arrow_c_stream(CompatLevel::oldest());
+        let out = unsafe { DataFrame::from_arrow_c_stream(stream)? };
+        assert_eq!(out.schema(), empty.schema());
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
+}
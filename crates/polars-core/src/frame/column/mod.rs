@@ -762,6 +762,19 @@ impl Column {
         self.agg_with_unit_scalar(groups, |s, g| unsafe { s.agg_mean(g) })
     }
 
+    /// Like [`Column::agg_mean`], but errors if the sum of a group of a Decimal column overflows.
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn try_agg_mean(&self, groups: &GroupsType) -> PolarsResult<Self> {
+        if !self.dtype().is_decimal() {
+            return Ok(unsafe { self.agg_mean(groups) });
+        }
+        unsafe { self.as_materialized_series().try_agg_mean(groups) }.map(Self::from)
+    }
+
     /// # Safety
     ///
     /// Does no bounds checks, groups must be correct.
@@ -771,6 +784,17 @@ impl Column {
         unsafe { self.as_materialized_series().agg_sum(groups) }.into()
     }
 
+    /// Like [`Column::agg_sum`], but handles overflow of integer sums according to
+    /// [`integer_overflow`](crate::config::integer_overflow).
+    ///
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Self> {
+        unsafe { self.as_materialized_series().try_agg_sum(groups) }.map(Self::from)
+    }
+
     /// # Safety
     ///
     /// Does no bounds checks, groups must be correct.
@@ -1485,6 +1509,10 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
+        #[cfg(feature = "dtype-extension")]
+        if self.dtype().is_extension() {
+            return self.as_materialized_series().str_value(index);
+        }
         Ok(self.get(index)?.str_value())
     }
 
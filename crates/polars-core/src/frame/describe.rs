@@ -0,0 +1,168 @@
+use crate::prelude::*;
+
+const DEFAULT_PERCENTILES: &[f64] = &[0.25, 0.5, 0.75];
+
+fn has_min_max(dtype: &DataType) -> bool {
+    !(dtype.is_nested()
+        || dtype.is_categorical()
+        || dtype.is_enum()
+        || dtype.is_null()
+        || dtype.is_object()
+        || dtype.is_binary())
+}
+
+fn percentile_label(p: f64) -> String {
+    // Round, so that e.g. `0.07` is not shown as `7.000000000000001%`.
+    format!("{}%", (p * 1e4).round() / 1e2)
+}
+
+impl DataFrame {
+    /// Summary statistics of all columns.
+    ///
+    /// The result has a single row and a column per statistic: `count`, `null_count`, `mean`,
+    /// `std`, `min`, a column per percentile (`25%`, `50%` and `75%` if `percentiles` is `None`)
+    /// and `max`. Every statistic is a struct with a field per column of this frame, which holds
+    /// the statistic of that column in its own dtype: the counts are `IdxSize`, `min` and `max`
+    /// have the dtype of the column, and `mean`, `std` and the percentiles have the dtype their
+    /// aggregation returns, e.g. `Float64` for integers and `Datetime` for the mean of dates.
+    ///
+    /// Statistics that don't apply to a dtype are a null of dtype `Null`: `mean` and the
+    /// percentiles are only computed for numeric, boolean (`mean` only) and temporal columns,
+    /// `std` only for numeric columns, and `min`/`max` not for nested, categorical, binary and
+    /// object columns. Percentiles use [`QuantileMethod::Nearest`], so they are actual values of
+    /// the column.
+    pub fn describe(&self, percentiles: Option<&[f64]>) -> PolarsResult<DataFrame> {
+        let percentiles = percentiles.unwrap_or(DEFAULT_PERCENTILES);
+        for &p in percentiles {
+            polars_ensure!(
+                (0.0..=1.0).contains(&p),
+                InvalidOperation: "percentiles must be in the range [0, 1], got {}", p
+            );
+        }
+
+        let mut statistics = vec![
+            "count".to_string(),
+            "null_count".to_string(),
+            "mean".to_string(),
+            "std".to_string(),
+            "min".to_string(),
+        ];
+        statistics.extend(percentiles.iter().copied().map(percentile_label));
+        statistics.push("max".to_string());
+
+        // The fields of the struct of every statistic, in the order of the columns.
+        let mut fields = vec![Vec::with_capacity(self.width()); statistics.len()];
+        for column in self.get_columns() {
+            let s = column.as_materialized_series();
+            let name = s.name().clone();
+            let dtype = s.dtype();
+            let is_numeric = dtype.is_numeric();
+            let is_temporal = dtype.is_temporal();
+            let min_max = has_min_max(dtype);
+
+            let reduce = |applies: bool, f: &dyn Fn() -> PolarsResult<Scalar>| {
+                PolarsResult::Ok(if applies {
+                    f()?.into_series(name.clone())
+                } else {
+                    Series::full_null(name.clone(), 1, &DataType::Null)
+                })
+            };
+
+            let mut values = Vec::with_capacity(statistics.len());
+            values.push(
+                Scalar::new_idxsize((s.len() - s.null_count()) as IdxSize)
+                    .into_series(name.clone()),
+            );
+            values.push(Scalar::new_idxsize(s.null_count() as IdxSize).into_series(name.clone()));
+            values.push(reduce(
+                is_numeric || is_temporal || dtype.is_bool(),
+                &|| s.mean_reduce(),
+            )?);
+            values.push(reduce(is_numeric, &|| s.std_reduce(1))?);
+            values.push(reduce(min_max, &|| s.min_reduce())?);
+            for &p in percentiles {
+                values.push(reduce(is_numeric || is_temporal, &|| {
+                    s.quantile_reduce(p, QuantileMethod::Nearest)
+                })?);
+            }
+            values.push(reduce(min_max, &|| s.max_reduce())?);
+
+            for (fields, value) in fields.iter_mut().zip(values) {
+                fields.push(value);
+            }
+        }
+
+        let columns = statistics
+            .into_iter()
+            .zip(fields)
+            .map(|(statistic, fields)| {
+                let ca = StructChunked::from_series(statistic.into(), 1, fields.iter())?;
+                Ok(ca.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_describe() -> PolarsResult<()> {
+        let mut df = df! {
+            "a" => [Some(1i32), Some(2), None, Some(4)],
+            "b" => ["x", "y", "y", "z"],
+            "c" => [Some(true), Some(false), Some(true), None],
+            "d" => [0i32, 10, 20, 30],
+        }?;
+        df.apply("d", |s| s.cast(&DataType::Date).unwrap())?;
+
+        let out = df.describe(Some(&[0.5, 0.07]))?;
+        assert_eq!(out.height(), 1);
+        assert_eq!(
+            out.get_column_names_str(),
+            &[
+                "count",
+                "null_count",
+                "mean",
+                "std",
+                "min",
+                "50%",
+                "7%",
+                "max"
+            ]
+        );
+        let stat = |statistic: &str, column: &str| {
+            out.column(statistic)?
+                .struct_()?
+                .field_by_name(column)?
+                .get(0)
+                .map(|av| av.into_static())
+        };
+
+        assert_eq!(stat("count", "a")?, AnyValue::from(3 as IdxSize));
+        assert_eq!(stat("null_count", "a")?, AnyValue::from(1 as IdxSize));
+        assert_eq!(stat("min", "a")?, AnyValue::Int32(1));
+        assert_eq!(stat("50%", "a")?, AnyValue::Float64(2.0));
+        assert_eq!(stat("max", "a")?, AnyValue::Int32(4));
+
+        assert_eq!(stat("count", "b")?, AnyValue::from(4 as IdxSize));
+        assert_eq!(stat("mean", "b")?, AnyValue::Null);
+        assert_eq!(stat("min", "b")?, AnyValue::StringOwned("x".into()));
+        assert_eq!(stat("max", "b")?, AnyValue::StringOwned("z".into()));
+
+        assert_eq!(stat("mean", "c")?, AnyValue::Float64(2.0 / 3.0));
+        assert_eq!(stat("std", "c")?, AnyValue::Null);
+
+        assert_eq!(stat("std", "d")?, AnyValue::Null);
+        assert_eq!(stat("min", "d")?, AnyValue::Date(0));
+        assert_eq!(stat("max", "d")?, AnyValue::Date(30));
+        let mean_d = out.column("mean")?.struct_()?.field_by_name("d")?;
+        assert!(mean_d.dtype().is_temporal());
+
+        assert!(df.describe(Some(&[1.5])).is_err());
+        Ok(())
+    }
+}
@@ -198,6 +198,82 @@ impl Series {
         .into_series()
     }
 
+    /// The means of the groups of a Decimal series, from the exact sums of the groups.
+    ///
+    /// Returns whether the sum of any group overflowed; the mean of such groups is null.
+    #[cfg(feature = "dtype-decimal")]
+    unsafe fn agg_mean_decimal(&self, groups: &GroupsType) -> (Series, bool) {
+        use polars_compute::decimal::{DEC128_MAX_PREC, dec128_mean, dec128_mean_scale};
+
+        let ca = self.decimal().unwrap();
+        let scale = ca.scale();
+        let phys = ca.physical().rechunk();
+        let mut overflow = false;
+        let mut mean = |values: &mut dyn Iterator<Item = Option<i128>>| {
+            let mut count = 0;
+            let sum = values.flatten().try_fold(0i128, |acc, v| {
+                count += 1;
+                acc.checked_add(v)
+            });
+            let mean = sum
+                .filter(|_| count > 0)
+                .and_then(|sum| dec128_mean(sum, count, scale));
+            overflow |= count > 0 && mean.is_none();
+            mean
+        };
+        let means: Int128Chunked = match groups {
+            GroupsType::Idx(groups) => groups
+                .iter()
+                .map(|(_, idx)| {
+                    mean(
+                        &mut idx
+                            .iter()
+                            .map(|i| unsafe { phys.get_unchecked(*i as usize) }),
+                    )
+                })
+                .collect_ca(self.name().clone()),
+            GroupsType::Slice { groups, .. } => groups
+                .iter()
+                .map(|&[first, len]| {
+                    mean(
+                        &mut (first..first + len)
+                            .map(|i| unsafe { phys.get_unchecked(i as usize) }),
+                    )
+                })
+                .collect_ca(self.name().clone()),
+        };
+        let means = means
+            .into_decimal_unchecked(DEC128_MAX_PREC, dec128_mean_scale(scale))
+            .into_series();
+        (means, overflow)
+    }
+
+    /// Like [`Series::agg_mean`], but errors instead of returning null if the sum of a group of
+    /// a Decimal series overflows.
+    #[doc(hidden)]
+    pub unsafe fn try_agg_mean(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        #[cfg(feature = "dtype-decimal")]
+        if self.dtype().is_decimal() {
+            let (means, overflow) = unsafe { self.agg_mean_decimal(groups) };
+            polars_ensure!(
+                !overflow,
+                ComputeError: "overflow in the Decimal sum of the mean of '{}'", self.name()
+            );
+            return Ok(means);
+        }
+        Ok(unsafe { self.agg_mean(groups) })
+    }
+
+    /// Like `agg_sum`, but handles overflow of integer sums according to
+    /// [`integer_overflow`](crate::config::integer_overflow).
+    #[doc(hidden)]
+    pub unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        match unsafe { crate::series::arithmetic::checked_integer_agg_sum(self, groups) }? {
+            Some(sums) => Ok(sums),
+            None => Ok(unsafe { self.agg_sum(groups) }),
+        }
+    }
+
     #[doc(hidden)]
     pub unsafe fn agg_mean(&self, groups: &GroupsType) -> Series {
         // Prevent a rechunk for every individual group.
@@ -214,7 +290,7 @@ impl Series {
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
             #[cfg(feature = "dtype-decimal")]
-            Decimal(_, _) => self.cast(&Float64).unwrap().agg_mean(groups),
+            Decimal(_, _) => s.agg_mean_decimal(groups).0,
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
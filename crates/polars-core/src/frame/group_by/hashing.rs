@@ -128,40 +128,35 @@ where
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
-    let out = POOL.install(|| {
-        (0..n_partitions)
-            .into_par_iter()
-            .map(|thread_no| {
-                let mut hash_tbl = PlHashMap::with_capacity(init_size);
-
-                let mut offset = 0;
-                for keys in &keys {
-                    let keys = keys.as_ref();
-                    let len = keys.len() as IdxSize;
-
-                    for (key_idx, k) in keys.iter().enumerate_idx() {
-                        let k = k.to_total_ord();
-                        let idx = key_idx + offset;
-
-                        if thread_no == hash_to_partition(k.dirty_hash(), n_partitions) {
-                            match hash_tbl.entry(k) {
-                                Entry::Vacant(entry) => {
-                                    entry.insert((idx, unitvec![idx]));
-                                },
-                                Entry::Occupied(mut entry) => {
-                                    entry.get_mut().1.push(idx);
-                                },
-                            }
-                        }
+    let out = POOL.map_partitions(n_partitions, |thread_no| {
+        let mut hash_tbl = PlHashMap::with_capacity(init_size);
+
+        let mut offset = 0;
+        for keys in &keys {
+            let keys = keys.as_ref();
+            let len = keys.len() as IdxSize;
+
+            for (key_idx, k) in keys.iter().enumerate_idx() {
+                let k = k.to_total_ord();
+                let idx = key_idx + offset;
+
+                if thread_no == hash_to_partition(k.dirty_hash(), n_partitions) {
+                    match hash_tbl.entry(k) {
+                        Entry::Vacant(entry) => {
+                            entry.insert((idx, unitvec![idx]));
+                        },
+                        Entry::Occupied(mut entry) => {
+                            entry.get_mut().1.push(idx);
+                        },
                     }
-                    offset += len;
                 }
-                hash_tbl
-                    .into_iter()
-                    .map(|(_k, v)| v)
-                    .collect_trusted::<Vec<_>>()
-            })
-            .collect::<Vec<_>>()
+            }
+            offset += len;
+        }
+        hash_tbl
+            .into_iter()
+            .map(|(_k, v)| v)
+            .collect_trusted::<Vec<_>>()
     });
     finish_group_order(out, sorted)
 }
@@ -182,50 +177,44 @@ where
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
-    let out = POOL.install(|| {
-        (0..n_partitions)
-            .into_par_iter()
-            .map(|thread_no| {
-                let mut hash_tbl: PlHashMap<T::TotalOrdItem, IdxVec> =
-                    PlHashMap::with_capacity(init_size);
-
-                let mut offset = 0;
-                for keys in keys {
-                    let keys = keys.clone().into_iter();
-                    let len = keys.len() as IdxSize;
-
-                    for (key_idx, k) in keys.into_iter().enumerate_idx() {
-                        let k = k.to_total_ord();
-                        let idx = key_idx + offset;
-
-                        if thread_no == hash_to_partition(k.dirty_hash(), n_partitions) {
-                            match hash_tbl.entry(k) {
-                                Entry::Vacant(entry) => {
-                                    entry.insert(unitvec![idx]);
-                                },
-                                Entry::Occupied(mut entry) => {
-                                    entry.get_mut().push(idx);
-                                },
-                            }
-                        }
+    let out = POOL.map_partitions(n_partitions, |thread_no| {
+        let mut hash_tbl: PlHashMap<T::TotalOrdItem, IdxVec> = PlHashMap::with_capacity(init_size);
+
+        let mut offset = 0;
+        for keys in keys {
+            let keys = keys.clone().into_iter();
+            let len = keys.len() as IdxSize;
+
+            for (key_idx, k) in keys.into_iter().enumerate_idx() {
+                let k = k.to_total_ord();
+                let idx = key_idx + offset;
+
+                if thread_no == hash_to_partition(k.dirty_hash(), n_partitions) {
+                    match hash_tbl.entry(k) {
+                        Entry::Vacant(entry) => {
+                            entry.insert(unitvec![idx]);
+                        },
+                        Entry::Occupied(mut entry) => {
+                            entry.get_mut().push(idx);
+                        },
                     }
-                    offset += len;
                 }
-                // iterating the hash tables locally
-                // was faster than iterating in the materialization phase directly
-                // the proper end vec. I believe this is because the hash-table
-                // currently is local to the thread so in hot cache
-                // So we first collect into a tight vec and then do a second
-                // materialization run
-                // this is also faster than the index-map approach where we
-                // directly locally store to a vec at the cost of an extra
-                // indirection
-                hash_tbl
-                    .into_iter()
-                    .map(|(_k, v)| (unsafe { *v.first().unwrap_unchecked() }, v))
-                    .collect_trusted::<Vec<_>>()
-            })
-            .collect::<Vec<_>>()
+            }
+            offset += len;
+        }
+        // iterating the hash tables locally
+        // was faster than iterating in the materialization phase directly
+        // the proper end vec. I believe this is because the hash-table
+        // currently is local to the thread so in hot cache
+        // So we first collect into a tight vec and then do a second
+        // materialization run
+        // this is also faster than the index-map approach where we
+        // directly locally store to a vec at the cost of an extra
+        // indirection
+        hash_tbl
+            .into_iter()
+            .map(|(_k, v)| (unsafe { *v.first().unwrap_unchecked() }, v))
+            .collect_trusted::<Vec<_>>()
     });
     finish_group_order(out, sorted)
 }
@@ -7,7 +7,7 @@ use polars_utils::total_ord::{ToTotalOrd, TotalHash};
 use super::*;
 use crate::chunked_array::cast::CastOptions;
 use crate::chunked_array::ops::row_encode::_get_rows_encoded_ca_unordered;
-use crate::config::verbose;
+use crate::diagnostics::{DiagnosticKind, emit_diagnostic};
 use crate::series::BitRepr;
 use crate::utils::Container;
 use crate::utils::flatten::flatten_par;
@@ -63,9 +63,9 @@ where
     T::Native: NumCast,
 {
     fn create_groups_from_sorted(&self, multithreaded: bool) -> GroupsSlice {
-        if verbose() {
-            eprintln!("group_by keys are sorted; running sorted key fast path");
-        }
+        emit_diagnostic(DiagnosticKind::FastPath, || {
+            "group_by keys are sorted; running sorted key fast path".to_string()
+        });
         let arr = self.downcast_iter().next().unwrap();
         if arr.is_empty() {
             return GroupsSlice::default();
@@ -228,13 +228,28 @@ impl IntoGroupsType for BinaryChunked {
         mut multithreaded: bool,
         sorted: bool,
     ) -> PolarsResult<GroupsType> {
-        if self.is_sorted_any() && !self.has_nulls() && self.n_chunks() == 1 {
-            let arr = self.downcast_get(0).unwrap();
-            let values = arr.values_iter();
-            let mut out = Vec::with_capacity(values.len() / 30);
-            partition_to_groups_amortized_varsize(values, arr.len() as _, 0, false, 0, &mut out);
+        if self.is_sorted_any() {
+            emit_diagnostic(DiagnosticKind::FastPath, || {
+                "group_by keys are sorted; running sorted key fast path".to_string()
+            });
+            let groups = if !self.has_nulls() && self.n_chunks() == 1 {
+                let arr = self.downcast_get(0).unwrap();
+                let values = arr.values_iter();
+                let mut out = Vec::with_capacity(values.len() / 30);
+                partition_to_groups_amortized_varsize(
+                    values,
+                    arr.len() as _,
+                    0,
+                    false,
+                    0,
+                    &mut out,
+                );
+                out
+            } else {
+                sorted_runs_to_groups(self)
+            };
             return Ok(GroupsType::Slice {
-                groups: out,
+                groups,
                 overlapping: false,
             });
         }
@@ -255,6 +270,33 @@ impl IntoGroupsType for BinaryChunked {
     }
 }
 
+/// The groups of the runs of equal values in `ca`, which must be sorted, so that the nulls form a
+/// single run too.
+fn sorted_runs_to_groups<'a, T>(ca: &'a ChunkedArray<T>) -> GroupsSlice
+where
+    T: PolarsDataType,
+    T::Physical<'a>: PartialEq,
+{
+    let mut groups = Vec::new();
+    let mut values = ca.iter();
+    let Some(mut start_value) = values.next() else {
+        return groups;
+    };
+
+    let mut start_idx = 0;
+    let mut i = 1;
+    for v in values {
+        if v != start_value {
+            groups.push([start_idx, i - start_idx]);
+            start_idx = i;
+            start_value = v;
+        }
+        i += 1;
+    }
+    groups.push([start_idx, i - start_idx]);
+    groups
+}
+
 impl IntoGroupsType for BinaryOffsetChunked {
     #[allow(clippy::needless_lifetimes)]
     fn group_tuples<'a>(
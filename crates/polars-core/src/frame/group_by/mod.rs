@@ -400,7 +400,7 @@ impl<'a> GroupBy<'a> {
 
         for agg_col in agg_cols {
             let new_name = fmt_group_by_column(agg_col.name().as_str(), GroupByMethod::Sum);
-            let mut agg = unsafe { agg_col.agg_sum(&self.groups) };
+            let mut agg = unsafe { agg_col.try_agg_sum(&self.groups) }?;
             agg.rename(new_name);
             cols.push(agg);
         }
@@ -1174,6 +1174,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_group_by_sorted_strings() {
+        use super::IntoGroupsType;
+
+        let mut ca = StringChunked::new("a".into(), [None, None, Some("a")]);
+        ca.append(&StringChunked::new("a".into(), [Some("a"), Some("b")]))
+            .unwrap();
+        ca.set_sorted_flag(IsSorted::Ascending);
+        let groups = ca.group_tuples(true, false).unwrap();
+        assert_eq!(groups.unwrap_slice(), &[[0, 2], [2, 2], [4, 1]]);
+
+        let mut ca = StringChunked::new("a".into(), [Some("b"), Some("a"), None]);
+        ca.set_sorted_flag(IsSorted::Descending);
+        let groups = ca.group_tuples(true, false).unwrap();
+        assert_eq!(groups.unwrap_slice(), &[[0, 1], [1, 1], [2, 1]]);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_group_by_var() -> PolarsResult<()> {
@@ -0,0 +1,260 @@
+//! Rendering a [`DataFrame`] as a Markdown or HTML table.
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::prelude::*;
+
+/// The horizontal alignment of the cells of a column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MarkupAlignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Options for [`DataFrame::to_markdown`] and [`DataFrame::to_html`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MarkupOptions {
+    /// The maximum number of rows to render. If the frame has more rows, the first and last
+    /// rows are rendered with an ellipsis row in between. `None` renders all rows.
+    pub max_rows: Option<usize>,
+    /// The number of decimals of floats. `None` uses the same formatting as the `Display`
+    /// implementation of [`DataFrame`].
+    pub float_precision: Option<usize>,
+    /// The alignment of numeric columns.
+    pub numeric_alignment: MarkupAlignment,
+    /// The alignment of all other columns.
+    pub alignment: MarkupAlignment,
+    /// Whether to render the dtypes below the column names.
+    pub include_dtypes: bool,
+}
+
+impl Default for MarkupOptions {
+    fn default() -> Self {
+        Self {
+            max_rows: None,
+            float_precision: None,
+            numeric_alignment: MarkupAlignment::Right,
+            alignment: MarkupAlignment::Left,
+            include_dtypes: false,
+        }
+    }
+}
+
+impl MarkupOptions {
+    fn column_alignment(&self, dtype: &DataType) -> MarkupAlignment {
+        if dtype.is_numeric() {
+            self.numeric_alignment
+        } else {
+            self.alignment
+        }
+    }
+
+    /// The rows to render, with `None` for the ellipsis row.
+    fn rows(&self, height: usize) -> Vec<Option<usize>> {
+        match self.max_rows {
+            Some(max_rows) if height > max_rows => {
+                let head = max_rows.div_ceil(2);
+                let tail = max_rows / 2;
+                (0..head)
+                    .map(Some)
+                    .chain(std::iter::once(None))
+                    .chain((height - tail..height).map(Some))
+                    .collect()
+            },
+            _ => (0..height).map(Some).collect(),
+        }
+    }
+
+    fn format_value(&self, av: AnyValue) -> String {
+        match (av, self.float_precision) {
+            (AnyValue::Float32(v), Some(precision)) => format!("{v:.precision$}"),
+            (AnyValue::Float64(v), Some(precision)) => format!("{v:.precision$}"),
+            (av, _) => av.str_value().into_owned(),
+        }
+    }
+}
+
+fn escape_markdown(s: &str) -> Cow<'_, str> {
+    if s.contains(['|', '\n', '\\']) {
+        Cow::Owned(
+            s.replace('\\', "\\\\")
+                .replace('|', "\\|")
+                .replace('\n', "<br>"),
+        )
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+fn escape_html(s: &str) -> Cow<'_, str> {
+    if s.contains(['&', '<', '>', '"']) {
+        let mut out = String::with_capacity(s.len() + 8);
+        for c in s.chars() {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                c => out.push(c),
+            }
+        }
+        Cow::Owned(out)
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+impl DataFrame {
+    /// Render this [`DataFrame`] as a (GitHub flavored) Markdown table.
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 2], "b" => ["x", "y"])?;
+    /// assert_eq!(
+    ///     df.to_markdown(&MarkupOptions::default())?,
+    ///     "| a | b |\n| ---: | :--- |\n| 1 | x |\n| 2 | y |\n"
+    /// );
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn to_markdown(&self, options: &MarkupOptions) -> PolarsResult<String> {
+        let mut out = String::new();
+        out.push('|');
+        for c in self.get_columns() {
+            let name = escape_markdown(c.name());
+            if options.include_dtypes {
+                write!(out, " {name}<br>{} |", c.dtype()).unwrap();
+            } else {
+                write!(out, " {name} |").unwrap();
+            }
+        }
+        out.push_str("\n|");
+        for c in self.get_columns() {
+            out.push_str(match options.column_alignment(c.dtype()) {
+                MarkupAlignment::Left => " :--- |",
+                MarkupAlignment::Center => " :---: |",
+                MarkupAlignment::Right => " ---: |",
+            });
+        }
+        out.push('\n');
+
+        for row in options.rows(self.height()) {
+            out.push('|');
+            for c in self.get_columns() {
+                match row {
+                    Some(idx) => {
+                        let value = options.format_value(c.get(idx)?);
+                        write!(out, " {} |", escape_markdown(&value)).unwrap();
+                    },
+                    None => out.push_str(" … |"),
+                }
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Render this [`DataFrame`] as an HTML `<table>`.
+    ///
+    /// The alignment is set with the `style` attribute of the cells, so that the table renders
+    /// the same without a stylesheet.
+    pub fn to_html(&self, options: &MarkupOptions) -> PolarsResult<String> {
+        let styles = self
+            .get_columns()
+            .iter()
+            .map(|c| match options.column_alignment(c.dtype()) {
+                MarkupAlignment::Left => " style=\"text-align: left\"",
+                MarkupAlignment::Center => " style=\"text-align: center\"",
+                MarkupAlignment::Right => " style=\"text-align: right\"",
+            })
+            .collect::<Vec<_>>();
+
+        let mut out = String::from("<table>\n<thead>\n<tr>");
+        for (c, style) in self.get_columns().iter().zip(&styles) {
+            write!(out, "<th{style}>{}</th>", escape_html(c.name())).unwrap();
+        }
+        out.push_str("</tr>\n");
+        if options.include_dtypes {
+            out.push_str("<tr>");
+            for (c, style) in self.get_columns().iter().zip(&styles) {
+                write!(
+                    out,
+                    "<td{style}>{}</td>",
+                    escape_html(&c.dtype().to_string())
+                )
+                .unwrap();
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</thead>\n<tbody>\n");
+
+        for row in options.rows(self.height()) {
+            out.push_str("<tr>");
+            for (c, style) in self.get_columns().iter().zip(&styles) {
+                match row {
+                    Some(idx) => {
+                        let value = options.format_value(c.get(idx)?);
+                        write!(out, "<td{style}>{}</td>", escape_html(&value)).unwrap();
+                    },
+                    None => write!(out, "<td{style}>…</td>").unwrap(),
+                }
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</tbody>\n</table>\n");
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_to_markdown() -> PolarsResult<()> {
+        let df = df! {
+            "a|b" => [1.0f64, 2.5, 3.25, 4.0],
+            "c" => [Some("x"), None, Some("y|z"), Some("w")],
+        }?;
+        let options = MarkupOptions {
+            max_rows: Some(2),
+            float_precision: Some(1),
+            include_dtypes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            df.to_markdown(&options)?,
+            "| a\\|b<br>f64 | c<br>str |\n\
+             | ---: | :--- |\n\
+             | 1.0 | x |\n\
+             | … | … |\n\
+             | 4.0 | w |\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_html() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [1i32, 2],
+            "b" => ["<x>", "y & z"],
+        }?;
+        let options = MarkupOptions {
+            alignment: MarkupAlignment::Center,
+            ..Default::default()
+        };
+        let right = " style=\"text-align: right\"";
+        let center = " style=\"text-align: center\"";
+        assert_eq!(
+            df.to_html(&options)?,
+            format!(
+                "<table>\n<thead>\n<tr><th{right}>a</th><th{center}>b</th></tr>\n</thead>\n<tbody>\n\
+                 <tr><td{right}>1</td><td{center}>&lt;x&gt;</td></tr>\n\
+                 <tr><td{right}>2</td><td{center}>y &amp; z</td></tr>\n\
+                 </tbody>\n</table>\n"
+            )
+        );
+        Ok(())
+    }
+}
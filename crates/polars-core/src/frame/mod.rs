@@ -20,20 +20,25 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
+mod arrow_stream;
 pub mod builder;
 mod chunks;
 pub use chunks::chunk_df_for_writing;
 pub mod column;
+#[cfg(feature = "describe")]
+mod describe;
 pub mod explode;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
 pub(crate) mod horizontal;
+pub mod markup;
 #[cfg(feature = "proptest")]
 pub mod proptest;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
+pub mod typed;
 mod upstream_traits;
 mod validation;
 
@@ -218,6 +223,68 @@ impl DataFrame {
         self.columns.iter().map(Column::estimated_size).sum()
     }
 
+    /// Returns a report of the memory used by every column, with a row per column.
+    ///
+    /// The report has the columns:
+    /// * `column` and `dtype`;
+    /// * `estimated_bytes`: the [`Series::estimated_size`], which includes the validity;
+    /// * `validity_bytes`: the size of the top-level validity bitmaps;
+    /// * `n_chunks`: the number of chunks;
+    /// * `dictionary_len` and `dictionary_bytes`: the number of categories and total size of
+    ///   their strings, for Categorical and Enum columns (null otherwise). Mappings can be
+    ///   shared by many columns, so these are not included in `estimated_bytes`.
+    pub fn memory_usage(&self) -> PolarsResult<DataFrame> {
+        let width = self.width();
+        let mut names = Vec::with_capacity(width);
+        let mut dtypes = Vec::with_capacity(width);
+        let mut estimated_bytes = Vec::with_capacity(width);
+        let mut validity_bytes = Vec::with_capacity(width);
+        let mut n_chunks = Vec::with_capacity(width);
+        let mut dictionary_len: Vec<Option<u64>> = Vec::with_capacity(width);
+        let mut dictionary_bytes: Vec<Option<u64>> = Vec::with_capacity(width);
+
+        for s in self.materialized_column_iter() {
+            names.push(s.name().as_str());
+            dtypes.push(s.dtype().to_string());
+            estimated_bytes.push(s.estimated_size() as u64);
+            validity_bytes.push(
+                s.chunks()
+                    .iter()
+                    .filter_map(|arr| arr.validity())
+                    .map(|validity| validity.as_slice().0.len() as u64)
+                    .sum::<u64>(),
+            );
+            n_chunks.push(s.n_chunks() as u64);
+
+            #[cfg(feature = "dtype-categorical")]
+            if let Ok(mapping) = s.dtype().cat_mapping() {
+                let n = mapping.num_cats_upper_bound();
+                let bytes = (0..n)
+                    .filter_map(|cat| mapping.cat_to_str(cat as CatSize))
+                    .map(|s| s.len() as u64)
+                    .sum::<u64>();
+                dictionary_len.push(Some(n as u64));
+                dictionary_bytes.push(Some(bytes));
+                continue;
+            }
+            dictionary_len.push(None);
+            dictionary_bytes.push(None);
+        }
+
+        DataFrame::new(vec![
+            Column::new(PlSmallStr::from_static("column"), names),
+            Column::new(PlSmallStr::from_static("dtype"), dtypes),
+            Column::new(PlSmallStr::from_static("estimated_bytes"), estimated_bytes),
+            Column::new(PlSmallStr::from_static("validity_bytes"), validity_bytes),
+            Column::new(PlSmallStr::from_static("n_chunks"), n_chunks),
+            Column::new(PlSmallStr::from_static("dictionary_len"), dictionary_len),
+            Column::new(
+                PlSmallStr::from_static("dictionary_bytes"),
+                dictionary_bytes,
+            ),
+        ])
+    }
+
     // Reduce monomorphization.
     fn try_apply_columns(
         &self,
@@ -568,6 +635,10 @@ impl DataFrame {
     }
 
     /// Shrink the capacity of this DataFrame to fit its length.
+    ///
+    /// This copies the data of every column into exactly sized buffers, so that a view created
+    /// with e.g. [`DataFrame::slice`] no longer keeps the memory of the frame it was created
+    /// from alive.
     pub fn shrink_to_fit(&mut self) {
         // Don't parallelize this. Memory overhead
         for s in &mut self.columns {
@@ -1182,6 +1253,8 @@ impl DataFrame {
                 Ok(())
             })?;
         self.height += other.height;
+        // Appending may have unified the categories of a column.
+        self.clear_schema();
         Ok(self)
     }
 
@@ -1210,6 +1283,8 @@ impl DataFrame {
                 Ok(())
             })?;
         self.height += other.height;
+        // Appending may have unified the categories of a column.
+        self.clear_schema();
         Ok(self)
     }
 
@@ -2724,6 +2799,9 @@ impl DataFrame {
 
     /// Slice the [`DataFrame`] along the rows.
     ///
+    /// This is zero-copy: the slice shares the Arrow buffers of this frame, which are only
+    /// copied when either of them is mutated. See [`DataFrame::view`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -2775,6 +2853,21 @@ impl DataFrame {
         unsafe { DataFrame::new_no_checks(height, col) }
     }
 
+    /// Create a zero-copy view of a subset of the columns and rows of this [`DataFrame`].
+    ///
+    /// The view shares the Arrow buffers of this frame, so creating it costs a reference count
+    /// increment per buffer, regardless of the size of the data. The buffers are copy-on-write:
+    /// mutating either frame through e.g. [`DataFrame::apply`] leaves the other one unchanged,
+    /// copying the data only if it is still shared. As long as the view is alive it keeps the
+    /// full buffers alive; use [`DataFrame::shrink_to_fit`] on it to release them.
+    pub fn view<I, S>(&self, columns: I, offset: i64, length: usize) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        Ok(self.select(columns)?.slice(offset, length))
+    }
+
     /// Split [`DataFrame`] at the given `offset`.
     pub fn split_at(&self, offset: i64) -> (Self, Self) {
         let (a, b) = self.columns.iter().map(|s| s.split_at(offset)).unzip();
@@ -3094,6 +3187,21 @@ impl DataFrame {
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<Self> {
         let names = subset.unwrap_or_else(|| self.get_column_names_owned());
+
+        // A single key column only needs a hash set of the seen values instead of the (potentially
+        // huge) group index lists.
+        if keep == UniqueKeepStrategy::Any && names.len() == 1 {
+            let key = self.column(&names[0])?.as_materialized_series();
+            if !key.dtype().is_object() {
+                let mut idx = key.arg_unique()?;
+                if let Some((offset, len)) = slice {
+                    idx = idx.slice(offset, len);
+                }
+                // SAFETY: `arg_unique` returns indices of `key`, which has the height of `self`.
+                return Ok(unsafe { self.take_unchecked(&idx) });
+            }
+        }
+
         let mut df = self.clone();
         // take on multiple chunks is terrible
         df.as_single_chunk_par();
@@ -3159,6 +3267,21 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(height, columns) })
     }
 
+    /// Get the index of the first occurrence of every unique row, in order of appearance.
+    ///
+    /// Only the columns in `subset` are considered if it is given.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn arg_unique_ordered(&self, subset: Option<&[PlSmallStr]>) -> PolarsResult<IdxCa> {
+        let names = subset.map_or_else(|| self.get_column_names_owned(), |s| s.to_vec());
+        if let [key] = names.as_slice() {
+            return self.column(key)?.as_materialized_series().arg_unique();
+        }
+        let name = names.first().cloned().unwrap_or_default();
+        let gb = self.group_by_stable(names)?;
+        let first = gb.get_groups().iter().map(|g| g.first()).collect();
+        Ok(IdxCa::from_vec(name, first))
+    }
+
     /// Get a mask of all the unique rows in the [`DataFrame`].
     ///
     /// # Example
@@ -3774,6 +3897,30 @@ mod test {
         assert!(out.equals(&expected));
     }
 
+    #[test]
+    fn test_unique_keep_any_and_arg_unique_ordered() {
+        let df = df! {
+            "x" => [3, 1, 3, 2, 1],
+            "y" => ["a", "b", "c", "d", "b"]
+        }
+        .unwrap();
+
+        let out = df
+            .unique_stable(Some(&["x".to_string()][..]), UniqueKeepStrategy::Any, None)
+            .unwrap();
+        let expected = df! {
+            "x" => [3, 1, 2],
+            "y" => ["a", "b", "d"]
+        }
+        .unwrap();
+        assert!(out.equals(&expected));
+
+        let idx = df.arg_unique_ordered(Some(&["x".into()])).unwrap();
+        assert_eq!(Vec::from(&idx), &[Some(0), Some(1), Some(3)]);
+        let idx = df.arg_unique_ordered(None).unwrap();
+        assert_eq!(Vec::from(&idx), &[Some(0), Some(1), Some(2), Some(3)]);
+    }
+
     #[test]
     #[cfg(feature = "dtype-i8")]
     fn test_apply_result_schema() {
@@ -3786,4 +3933,65 @@ mod test {
         df.apply("x", |f| f.cast(&DataType::Int8).unwrap()).unwrap();
         assert_ne!(&schema_before, df.schema());
     }
+
+    #[test]
+    fn test_view_shares_buffers() -> PolarsResult<()> {
+        let df = df! {
+            "a" => (0i64..100).collect::<Vec<_>>(),
+            "b" => (0i32..100).collect::<Vec<_>>(),
+        }?;
+        let values_ptr = |df: &DataFrame| {
+            let ca = df.column("a").unwrap().i64().unwrap();
+            ca.downcast_iter().next().unwrap().values().as_ptr()
+        };
+
+        let mut view = df.view(["a"], 10, 5)?;
+        assert_eq!(view.shape(), (5, 1));
+        assert_eq!(values_ptr(&view), unsafe { values_ptr(&df).add(10) });
+
+        view.apply("a", |c| c.as_materialized_series() * 2)?;
+        assert_eq!(df.column("a")?.i64()?.get(10), Some(10));
+        assert_eq!(view.column("a")?.i64()?.get(0), Some(20));
+
+        let mut view = df.view(["a"], 10, 5)?;
+        view.shrink_to_fit();
+        assert_ne!(values_ptr(&view), unsafe { values_ptr(&df).add(10) });
+        assert_eq!(view.column("a")?.i64()?.get(0), Some(10));
+
+        let long = "a string that doesn't fit in a view";
+        let df = df! {
+            "s" => (0..100).map(|i| format!("{long} {i}")).collect::<Vec<_>>(),
+        }?;
+        let mut view = df.view(["s"], 10, 5)?;
+        view.shrink_to_fit();
+        let ca = view.column("s")?.str()?;
+        let arr = ca.downcast_iter().next().unwrap();
+        assert_eq!(arr.total_buffer_len(), arr.total_bytes_len());
+        assert_eq!(ca.get(0), Some(format!("{long} 10").as_str()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_memory_usage() -> PolarsResult<()> {
+        let mut df = df! {
+            "a" => [Some(1i64), None, Some(3)],
+            "b" => ["x", "y", "z"],
+        }?;
+        df.vstack_mut(&df.clone())?;
+
+        let out = df.memory_usage()?;
+        assert_eq!(out.height(), 2);
+        assert_eq!(
+            Vec::from(out.column("column")?.str()?),
+            &[Some("a"), Some("b")]
+        );
+        let estimated_bytes = out.column("estimated_bytes")?.u64()?;
+        assert_eq!(estimated_bytes.get(0), Some(2 * (3 * 8 + 1)));
+        let validity_bytes = out.column("validity_bytes")?.u64()?;
+        assert_eq!(Vec::from(validity_bytes), &[Some(2), Some(0)]);
+        let n_chunks = out.column("n_chunks")?.u64()?;
+        assert_eq!(Vec::from(n_chunks), &[Some(2), Some(2)]);
+        assert_eq!(out.column("dictionary_len")?.null_count(), 2);
+        Ok(())
+    }
 }
@@ -6,6 +6,8 @@ use proptest::prelude::*;
 use crate::prelude::{Column, DataFrame};
 use crate::series::proptest::{SeriesArbitraryOptions, series_strategy};
 
+/// Options for [`dataframe_strategy`], the nulls, chunking and sortedness of the columns are set in
+/// the [`SeriesArbitraryOptions`].
 pub struct DataFrameArbitraryOptions {
     pub series_options: SeriesArbitraryOptions,
     pub num_columns: RangeInclusive<usize>,
@@ -20,6 +22,15 @@ impl Default for DataFrameArbitraryOptions {
     }
 }
 
+impl Arbitrary for DataFrame {
+    type Parameters = DataFrameArbitraryOptions;
+    type Strategy = BoxedStrategy<DataFrame>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        dataframe_strategy(Rc::new(options), 0).boxed()
+    }
+}
+
 pub fn dataframe_strategy(
     options: Rc<DataFrameArbitraryOptions>,
     nesting_level: usize,
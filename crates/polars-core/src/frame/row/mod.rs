@@ -1,6 +1,8 @@
 mod av_buffer;
 mod dataframe;
 mod transpose;
+#[cfg(feature = "rows")]
+mod typed;
 
 use std::borrow::Borrow;
 use std::fmt::Debug;
@@ -13,6 +15,8 @@ use polars_utils::format_pl_smallstr;
 #[cfg(feature = "object")]
 use polars_utils::total_ord::TotalHash;
 use rayon::prelude::*;
+#[cfg(feature = "rows")]
+pub use typed::TypedRow;
 
 use crate::POOL;
 use crate::prelude::*;
@@ -103,6 +103,11 @@ impl DataFrame {
         self.transpose_impl(keep_names_as, new_col_names)
     }
     /// Transpose a DataFrame. This is a very expensive operation.
+    ///
+    /// The new columns get the supertype of all (remaining) columns, so transposing
+    /// numeric columns gives numeric columns. Their names are either taken from
+    /// `new_col_names`, which is a column of this DataFrame or a name per row, or default to
+    /// `column_{i}`.
     pub fn transpose_impl(
         &mut self,
         keep_names_as: Option<&str>,
@@ -118,7 +123,12 @@ impl DataFrame {
                 .collect(),
             Some(cn) => match cn {
                 Either::Left(name) => {
-                    let new_names = self.column(name.as_str()).and_then(|x| x.str())?;
+                    // Non-string header columns, e.g. integer ids, are formatted as strings.
+                    let new_names = self
+                        .column(name.as_str())?
+                        .as_materialized_series()
+                        .strict_cast(&DataType::String)?;
+                    let new_names = new_names.str()?;
                     polars_ensure!(new_names.null_count() == 0, ComputeError: "Column with new names can't have null values");
                     df = Cow::Owned(self.drop(name.as_str())?);
                     new_names
@@ -308,4 +318,22 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_transpose_header_column() -> PolarsResult<()> {
+        let mut df = df![
+            "id" => [1, 2],
+            "a" => [1.5, 2.5],
+            "b" => [3.0, 4.0],
+        ]?;
+
+        let out = df.transpose(Some("column"), Some(Either::Left("id".into())))?;
+        let expected = df![
+            "column" => ["a", "b"],
+            "1" => [1.5, 3.0],
+            "2" => [2.5, 4.0],
+        ]?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
 }
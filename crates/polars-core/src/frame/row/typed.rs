@@ -0,0 +1,121 @@
+use crate::frame::typed::FrameColumn;
+use crate::prelude::*;
+
+type BoxedIter<'a, T> = Box<dyn Iterator<Item = T> + 'a>;
+
+/// A tuple of [`FrameColumn`] values, one per column, as returned by [`DataFrame::rows_typed`].
+pub trait TypedRow<'a>: Sized {
+    /// The number of columns in a row.
+    const WIDTH: usize;
+
+    /// The dtypes the columns must have.
+    fn dtypes() -> Vec<DataType>;
+
+    /// Iterate over the rows of the given columns, which have been checked to have
+    /// [`TypedRow::dtypes`].
+    fn iter(columns: &'a [Column]) -> PolarsResult<BoxedIter<'a, Self>>;
+}
+
+macro_rules! impl_typed_row {
+    ($width:literal; $($T:ident $idx:tt),+) => {
+        impl<'a, $($T: FrameColumn<'a> + 'a),+> TypedRow<'a> for ($($T,)+) {
+            const WIDTH: usize = $width;
+
+            fn dtypes() -> Vec<DataType> {
+                vec![$($T::dtype()),+]
+            }
+
+            #[allow(non_snake_case)]
+            fn iter(columns: &'a [Column]) -> PolarsResult<BoxedIter<'a, Self>> {
+                $(
+                    let s = columns[$idx].as_materialized_series();
+                    let mut $T = $T::iter(s.unpack::<$T::PolarsType>()?)?;
+                )+
+                Ok(Box::new(std::iter::from_fn(move || Some(($($T.next()?,)+)))))
+            }
+        }
+    };
+}
+
+impl_typed_row!(1; A 0);
+impl_typed_row!(2; A 0, B 1);
+impl_typed_row!(3; A 0, B 1, C 2);
+impl_typed_row!(4; A 0, B 1, C 2, D 3);
+impl_typed_row!(5; A 0, B 1, C 2, D 3, E 4);
+impl_typed_row!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_typed_row!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_typed_row!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_typed_row!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_typed_row!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_typed_row!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_typed_row!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+impl DataFrame {
+    /// Iterate over the rows of this [`DataFrame`] as tuples of Rust values.
+    ///
+    /// The dtypes of all columns are checked once, upfront; the values are then read
+    /// directly from the underlying arrays, without materializing [`AnyValue`]s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1i64, 2], "b" => ["x", "y"], "c" => [Some(1.5), None])?;
+    /// let rows = df.rows_typed::<(i64, &str, Option<f64>)>()?.collect::<Vec<_>>();
+    /// assert_eq!(rows, [(1, "x", Some(1.5)), (2, "y", None)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn rows_typed<'a, T: TypedRow<'a>>(&'a self) -> PolarsResult<impl Iterator<Item = T> + 'a> {
+        polars_ensure!(
+            self.width() == T::WIDTH,
+            ShapeMismatch: "cannot read rows of {} columns as tuples of {} values", self.width(), T::WIDTH
+        );
+        for (column, dtype) in self.get_columns().iter().zip(T::dtypes()) {
+            polars_ensure!(
+                column.dtype() == &dtype,
+                SchemaMismatch: "expected column '{}' to have dtype {}, got {}", column.name(), dtype, column.dtype()
+            );
+        }
+        T::iter(self.get_columns())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rows_typed() -> PolarsResult<()> {
+        let mut df = df! {
+            "a" => [1i64, 2, 3],
+            "b" => ["x", "y", "z"],
+            "c" => [Some(1.5), None, Some(3.0)],
+            "d" => [true, false, true],
+        }?;
+        df.vstack_mut(&df.clone())?;
+
+        let rows = df
+            .rows_typed::<(i64, &str, Option<f64>, bool)>()?
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[4], (2, "y", None, false));
+
+        // The same value types as the fields of `#[derive(FrameSchema)]` structs.
+        let names = df
+            .select(["b"])?
+            .rows_typed::<(String,)>()?
+            .map(|(b,)| b)
+            .collect::<Vec<_>>();
+        assert_eq!(names[2], "z");
+        let strs = df.select(["b"])?;
+        assert_eq!(
+            strs.rows_typed::<(Option<&str>,)>()?.nth(1),
+            Some((Some("y"),))
+        );
+
+        assert!(df.rows_typed::<(i64, &str, f64, bool)>().is_err());
+        assert!(df.rows_typed::<(i32, &str, Option<f64>, bool)>().is_err());
+        assert!(df.rows_typed::<(i64, &str)>().is_err());
+        Ok(())
+    }
+}
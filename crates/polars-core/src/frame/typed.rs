@@ -0,0 +1,147 @@
+//! Statically typed frames: the traits behind `#[derive(FrameSchema)]`.
+use crate::prelude::*;
+
+/// A Rust type that can be stored in, and read back from, a column of a [`DataFrame`].
+///
+/// Implemented for the primitive numeric types, `bool`, `String` and `&str`; `Option<T>` maps
+/// to a nullable column, all other types to a column that must not contain nulls. Values are
+/// read from a column that lives for `'a`, which lets `&'a str` borrow from it.
+pub trait FrameColumn<'a>: Sized {
+    /// The physical Polars type of the column.
+    type PolarsType: PolarsPhysicalType;
+
+    /// The [`DataType`] of the column.
+    fn dtype() -> DataType {
+        Self::PolarsType::get_static_dtype()
+    }
+
+    /// Create a column from the values of all rows.
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series;
+
+    /// Iterate over the values of all rows of a column.
+    fn iter(
+        ca: &'a ChunkedArray<Self::PolarsType>,
+    ) -> PolarsResult<Box<dyn Iterator<Item = Self> + 'a>>;
+}
+
+fn ensure_no_nulls<T: PolarsDataType>(ca: &ChunkedArray<T>) -> PolarsResult<()> {
+    polars_ensure!(
+        ca.null_count() == 0,
+        ComputeError: "column '{}' contains nulls; use an `Option` to read it", ca.name()
+    );
+    Ok(())
+}
+
+macro_rules! impl_frame_column {
+    ($($native:ty => $pt:ty),* $(,)?) => {
+        $(
+            impl<'a> FrameColumn<'a> for $native {
+                type PolarsType = $pt;
+
+                fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+                    Series::new(name, values)
+                }
+
+                fn iter(ca: &'a ChunkedArray<$pt>) -> PolarsResult<Box<dyn Iterator<Item = Self> + 'a>> {
+                    ensure_no_nulls(ca)?;
+                    Ok(Box::new(ca.into_no_null_iter().map(Into::into)))
+                }
+            }
+
+            impl<'a> FrameColumn<'a> for Option<$native> {
+                type PolarsType = $pt;
+
+                fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+                    Series::new(name, values)
+                }
+
+                fn iter(ca: &'a ChunkedArray<$pt>) -> PolarsResult<Box<dyn Iterator<Item = Self> + 'a>> {
+                    Ok(Box::new(ca.into_iter().map(|v| v.map(Into::into))))
+                }
+            }
+        )*
+    };
+}
+
+impl_frame_column! {
+    bool => BooleanType,
+    i8 => Int8Type,
+    i16 => Int16Type,
+    i32 => Int32Type,
+    i64 => Int64Type,
+    u8 => UInt8Type,
+    u16 => UInt16Type,
+    u32 => UInt32Type,
+    u64 => UInt64Type,
+    f32 => Float32Type,
+    f64 => Float64Type,
+    String => StringType,
+}
+
+impl<'a> FrameColumn<'a> for &'a str {
+    type PolarsType = StringType;
+
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+        Series::new(name, values)
+    }
+
+    fn iter(ca: &'a StringChunked) -> PolarsResult<Box<dyn Iterator<Item = Self> + 'a>> {
+        ensure_no_nulls(ca)?;
+        Ok(Box::new(ca.into_no_null_iter()))
+    }
+}
+
+impl<'a> FrameColumn<'a> for Option<&'a str> {
+    type PolarsType = StringType;
+
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+        Series::new(name, values)
+    }
+
+    fn iter(ca: &'a StringChunked) -> PolarsResult<Box<dyn Iterator<Item = Self> + 'a>> {
+        Ok(Box::new(ca.into_iter()))
+    }
+}
+
+/// A Rust struct whose fields map to the columns of a [`DataFrame`].
+///
+/// Usually implemented with `#[derive(FrameSchema)]`, which also generates a
+/// `<field>_column(&DataFrame)` getter per field that returns the typed [`ChunkedArray`].
+pub trait FrameSchema: Sized {
+    /// The schema of a frame holding values of this type.
+    fn schema() -> Schema;
+
+    /// Create a [`DataFrame`] with a row per value.
+    fn into_frame(rows: Vec<Self>) -> PolarsResult<DataFrame>;
+
+    /// Read the rows of a [`DataFrame`], which must contain (at least) the columns of
+    /// [`FrameSchema::schema`] with exactly those dtypes.
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>>;
+
+    /// Check that the given [`DataFrame`] contains the columns of [`FrameSchema::schema`].
+    fn validate(df: &DataFrame) -> PolarsResult<()> {
+        for (name, dtype) in Self::schema().iter() {
+            let column = df.column(name)?;
+            polars_ensure!(
+                column.dtype() == dtype,
+                SchemaMismatch: "expected column '{}' to have dtype {}, got {}", name, dtype, column.dtype()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Get a column of a [`DataFrame`] as the [`ChunkedArray`] of a [`FrameColumn`] type.
+///
+/// Used by the getters generated by `#[derive(FrameSchema)]`.
+pub fn typed_column<'a, T: FrameColumn<'a>>(
+    df: &'a DataFrame,
+    name: &str,
+) -> PolarsResult<&'a ChunkedArray<T::PolarsType>> {
+    let column = df.column(name)?;
+    polars_ensure!(
+        column.dtype() == &T::dtype(),
+        SchemaMismatch: "expected column '{}' to have dtype {}, got {}", name, T::dtype(), column.dtype()
+    );
+    column.as_materialized_series().unpack::<T::PolarsType>()
+}
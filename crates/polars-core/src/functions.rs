@@ -3,28 +3,82 @@
 //! Functions that might be useful.
 //!
 pub use crate::frame::horizontal::concat_df_horizontal;
-#[cfg(feature = "diagonal_concat")]
 use crate::prelude::*;
-#[cfg(feature = "diagonal_concat")]
-use crate::utils::concat_df;
+use crate::utils::{concat_df, try_get_supertype};
+
+/// Cast the columns of `df` to the given dtypes, in order.
+fn cast_columns(df: &DataFrame, dtypes: &[DataType]) -> PolarsResult<DataFrame> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .zip(dtypes)
+        .map(|(c, dtype)| {
+            if c.dtype() == dtype {
+                Ok(c.clone())
+            } else {
+                c.cast(dtype)
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // SAFETY: casting doesn't change the names or heights.
+    Ok(unsafe { DataFrame::new_no_checks(df.height(), columns) })
+}
+
+/// Concat [`DataFrame`]s vertically, casting columns with differing dtypes to their supertype.
+///
+/// Unlike [`concat_df`], which requires all frames to have exactly the same schema, this only
+/// requires the same column names, in the same order. E.g. an `Int32` and an `Int64` column
+/// are concatenated to an `Int64` column.
+pub fn concat_df_vertical_relaxed(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+    let first = dfs
+        .first()
+        .ok_or_else(|| polars_err!(NoData: "require at least one DataFrame to concatenate"))?;
+    let mut dtypes = first.dtypes();
+    for df in &dfs[1..] {
+        polars_ensure!(
+            df.width() == first.width(),
+            ShapeMismatch: "unable to concatenate, dataframes have different widths: {} and {}",
+            first.width(), df.width()
+        );
+        for ((dtype, c), first_c) in dtypes
+            .iter_mut()
+            .zip(df.get_columns())
+            .zip(first.get_columns())
+        {
+            polars_ensure!(
+                c.name() == first_c.name(),
+                ShapeMismatch: "unable to concatenate, column names don't match: {:?} and {:?}",
+                first_c.name(), c.name()
+            );
+            *dtype = try_get_supertype(dtype, c.dtype())?;
+        }
+    }
+
+    let dfs = dfs
+        .iter()
+        .map(|df| cast_columns(df, &dtypes))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    concat_df(&dfs)
+}
 
-/// Concat [`DataFrame`]s diagonally.
 #[cfg(feature = "diagonal_concat")]
-/// Concat diagonally thereby combining different schemas.
-pub fn concat_df_diagonal(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+fn concat_df_diagonal_impl(dfs: &[DataFrame], to_supertypes: bool) -> PolarsResult<DataFrame> {
     // TODO! replace with lazy only?
     let upper_bound_width = dfs.iter().map(|df| df.width()).sum();
-    let mut column_names = PlHashSet::with_capacity(upper_bound_width);
-    let mut schema = Vec::with_capacity(upper_bound_width);
+    let mut schema = Schema::with_capacity(upper_bound_width);
 
     for df in dfs {
-        df.get_columns().iter().for_each(|s| {
-            let name = s.name().clone();
-            if column_names.insert(name.clone()) {
-                schema.push((name, s.dtype()))
+        for c in df.get_columns() {
+            match schema.get_mut(c.name()) {
+                None => {
+                    schema.insert(c.name().clone(), c.dtype().clone());
+                },
+                Some(dtype) if to_supertypes => *dtype = try_get_supertype(dtype, c.dtype())?,
+                Some(_) => {},
             }
-        });
+        }
     }
+    let dtypes = schema.iter_values().cloned().collect::<Vec<_>>();
 
     let dfs = dfs
         .iter()
@@ -32,15 +86,72 @@ pub fn concat_df_diagonal(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
             let height = df.height();
             let mut columns = Vec::with_capacity(schema.len());
 
-            for (name, dtype) in &schema {
+            for (name, dtype) in schema.iter() {
                 match df.column(name.as_str()).ok() {
                     Some(s) => columns.push(s.clone()),
                     None => columns.push(Column::full_null(name.clone(), height, dtype)),
                 }
             }
-            unsafe { DataFrame::new_no_checks(height, columns) }
+            let df = unsafe { DataFrame::new_no_checks(height, columns) };
+            if to_supertypes {
+                cast_columns(&df, &dtypes)
+            } else {
+                Ok(df)
+            }
         })
-        .collect::<Vec<_>>();
+        .collect::<PolarsResult<Vec<_>>>()?;
 
     concat_df(&dfs)
 }
+
+/// Concat [`DataFrame`]s diagonally.
+#[cfg(feature = "diagonal_concat")]
+/// Concat diagonally thereby combining different schemas.
+pub fn concat_df_diagonal(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+    concat_df_diagonal_impl(dfs, false)
+}
+
+/// Concat [`DataFrame`]s diagonally, like [`concat_df_diagonal`], but cast columns with
+/// differing dtypes to their supertype.
+#[cfg(feature = "diagonal_concat")]
+pub fn concat_df_diagonal_relaxed(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+    concat_df_diagonal_impl(dfs, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_concat_df_vertical_relaxed() -> PolarsResult<()> {
+        let a = df!("x" => [1i32, 2], "y" => ["a", "b"])?;
+        let b = df!("x" => [3i64], "y" => ["c"])?;
+
+        assert!(concat_df(&[a.clone(), b.clone()]).is_err());
+        let out = concat_df_vertical_relaxed(&[a.clone(), b])?;
+        assert_eq!(out.column("x")?.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.column("x")?.i64()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+
+        let c = df!("y" => ["c"], "x" => [3i64])?;
+        assert!(concat_df_vertical_relaxed(&[a, c]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "diagonal_concat")]
+    fn test_concat_df_diagonal_relaxed() -> PolarsResult<()> {
+        let a = df!("x" => [1i32, 2])?;
+        let b = df!("x" => [3.5f64], "y" => ["c"])?;
+
+        let out = concat_df_diagonal_relaxed(&[a, b])?;
+        assert_eq!(
+            Vec::from(out.column("x")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(3.5)]
+        );
+        assert_eq!(Vec::from(out.column("y")?.str()?), &[None, None, Some("c")]);
+        Ok(())
+    }
+}
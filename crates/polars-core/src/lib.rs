@@ -14,11 +14,13 @@ pub mod utils;
 pub mod chunked_array;
 pub mod config;
 pub mod datatypes;
+pub mod diagnostics;
 pub mod error;
 pub mod fmt;
 pub mod frame;
 pub mod functions;
 pub mod hashing;
+pub mod memory;
 mod named_from;
 pub mod prelude;
 #[cfg(feature = "random")]
@@ -33,9 +35,12 @@ pub mod testing;
 mod tests;
 
 use std::cell::{Cell, RefCell};
-use std::sync::{LazyLock, Mutex};
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+use std::sync::RwLock;
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use config::ConfigScope;
 pub use datatypes::SchemaExtPl;
 pub use hashing::IdBuildHasher;
 use rayon::{ThreadPool, ThreadPoolBuilder};
@@ -75,7 +80,32 @@ impl POOL {
 
         #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
         {
-            self.with(|p| p.install(op))
+            let config = ConfigScope::current();
+            self.with(|p| p.install(|| config.enter(op)))
+        }
+    }
+
+    /// Run `op` on the given thread pool instead of the global one, such that all parallel
+    /// work of Polars started from within `op` also runs on that pool.
+    ///
+    /// This allows limiting the parallelism of a single query, without affecting other queries
+    /// running concurrently (see also [`new_thread_pool`]).
+    pub fn install_pool<OP, R>(&self, pool: &Arc<ThreadPool>, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        #[cfg(not(any(target_os = "emscripten", not(target_family = "wasm"))))]
+        {
+            let _ = pool;
+            op()
+        }
+
+        #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+        {
+            let _guard = InstalledPoolGuard::new(pool);
+            let config = ConfigScope::current();
+            pool.install(|| config.enter(op))
         }
     }
 
@@ -86,7 +116,9 @@ impl POOL {
         RA: Send,
         RB: Send,
     {
-        self.install(|| rayon::join(oper_a, oper_b))
+        // `oper_b` may be stolen by another thread.
+        let config = ConfigScope::current();
+        self.install(|| rayon::join(oper_a, || config.enter(oper_b)))
     }
 
     pub fn scope<'scope, OP, R>(&self, op: OP) -> R
@@ -108,8 +140,9 @@ impl POOL {
 
         #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
         {
+            let config = ConfigScope::current();
             self.with(|p| {
-                p.spawn(op);
+                p.spawn(move || config.enter(op));
                 if p.current_num_threads() == 1 {
                     p.yield_now();
                 }
@@ -128,8 +161,9 @@ impl POOL {
 
         #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
         {
+            let config = ConfigScope::current();
             self.with(|p| {
-                p.spawn_fifo(op);
+                p.spawn_fifo(move || config.enter(op));
                 if p.current_num_threads() == 1 {
                     p.yield_now();
                 }
@@ -137,6 +171,56 @@ impl POOL {
         }
     }
 
+    /// Compute `f(p)` for every partition `p` in `0..n_partitions` in parallel.
+    ///
+    /// If the threads are pinned to NUMA nodes (see [`config::numa_aware`]), partition `p` is
+    /// always computed by thread `p % num_threads`, so that the memory it allocates is local to
+    /// the node of that thread.
+    pub fn map_partitions<F, R>(&self, n_partitions: usize, f: F) -> Vec<R>
+    where
+        F: Fn(usize) -> R + Send + Sync,
+        R: Send,
+    {
+        #[cfg(not(any(target_os = "emscripten", not(target_family = "wasm"))))]
+        {
+            (0..n_partitions).map(f).collect()
+        }
+
+        #[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+        {
+            use rayon::prelude::*;
+
+            let config_scope = ConfigScope::current();
+            self.with(|p| {
+                if !config::numa_aware() || n_partitions < 2 {
+                    return p.install(|| {
+                        (0..n_partitions)
+                            .into_par_iter()
+                            .with_max_len(1)
+                            .map(|partition| config_scope.enter(|| f(partition)))
+                            .collect::<Vec<_>>()
+                    });
+                }
+
+                let num_threads = p.current_num_threads();
+                let mut out = p
+                    .broadcast(|ctx| {
+                        config_scope.enter(|| {
+                            (ctx.index()..n_partitions)
+                                .step_by(num_threads)
+                                .map(|partition| (partition, f(partition)))
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                out.sort_unstable_by_key(|(partition, _)| *partition);
+                out.into_iter().map(|(_, r)| r).collect::<Vec<_>>()
+            })
+        }
+    }
+
     pub fn current_thread_has_pending_tasks(&self) -> Option<bool> {
         #[cfg(not(any(target_os = "emscripten", not(target_family = "wasm"))))]
         {
@@ -179,7 +263,21 @@ impl POOL {
         OP: FnOnce(&ThreadPool) -> R + Send,
         R: Send,
     {
-        if ALLOW_RAYON_THREADS.get() || THREAD_POOL.current_thread_index().is_some() {
+        if THREAD_POOL.current_thread_index().is_some() {
+            return op(&THREAD_POOL);
+        }
+        if rayon::current_thread_index().is_some() {
+            let installed = INSTALLED_POOLS
+                .read()
+                .unwrap()
+                .iter()
+                .find(|(p, _)| p.current_thread_index().is_some())
+                .map(|(p, _)| p.clone());
+            if let Some(pool) = installed {
+                return op(&pool);
+            }
+        }
+        if ALLOW_RAYON_THREADS.get() {
             op(&THREAD_POOL)
         } else {
             NOOP_POOL.with(|v| op(&v.borrow()))
@@ -187,21 +285,81 @@ impl POOL {
     }
 }
 
+/// The thread pools currently installed with [`POOL::install_pool`], with the number of
+/// installations that are active for each of them.
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+static INSTALLED_POOLS: RwLock<Vec<(Arc<ThreadPool>, usize)>> = RwLock::new(Vec::new());
+
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+struct InstalledPoolGuard(Arc<ThreadPool>);
+
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+impl InstalledPoolGuard {
+    fn new(pool: &Arc<ThreadPool>) -> Self {
+        let mut pools = INSTALLED_POOLS.write().unwrap();
+        match pools.iter_mut().find(|(p, _)| Arc::ptr_eq(p, pool)) {
+            Some((_, count)) => *count += 1,
+            None => pools.push((pool.clone(), 1)),
+        }
+        Self(pool.clone())
+    }
+}
+
+#[cfg(any(target_os = "emscripten", not(target_family = "wasm")))]
+impl Drop for InstalledPoolGuard {
+    fn drop(&mut self) {
+        let mut pools = INSTALLED_POOLS.write().unwrap();
+        let idx = pools
+            .iter()
+            .position(|(p, _)| Arc::ptr_eq(p, &self.0))
+            .unwrap();
+        pools[idx].1 -= 1;
+        if pools[idx].1 == 0 {
+            pools.swap_remove(idx);
+        }
+    }
+}
+
+/// Create a thread pool with the given number of threads, to run queries on with
+/// [`POOL::install_pool`].
+pub fn new_thread_pool(num_threads: usize) -> Arc<ThreadPool> {
+    let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(move |i| format!("{thread_name}-{i}"))
+        .start_handler(numa_start_handler(num_threads))
+        .build()
+        .expect("could not spawn threads");
+    Arc::new(pool)
+}
+
+/// Pins thread `i` of a pool with `num_threads` threads to its NUMA node, if
+/// [`config::numa_aware`].
+fn numa_start_handler(num_threads: usize) -> impl Fn(usize) + Send + Sync + 'static {
+    let numa_aware = config::numa_aware();
+    move |i| {
+        if numa_aware {
+            let node = polars_utils::numa::numa_node_of_thread(i, num_threads);
+            polars_utils::numa::pin_current_thread_to_node(node);
+        }
+    }
+}
+
 // this is re-exported in utils for polars child crates
 #[cfg(not(target_family = "wasm"))] // only use this on non wasm targets
 pub static THREAD_POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
     let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
+    let num_threads = std::env::var("POLARS_MAX_THREADS")
+        .map(|s| s.parse::<usize>().expect("integer"))
+        .unwrap_or_else(|_| {
+            std::thread::available_parallelism()
+                .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
+                .get()
+        });
     ThreadPoolBuilder::new()
-        .num_threads(
-            std::env::var("POLARS_MAX_THREADS")
-                .map(|s| s.parse::<usize>().expect("integer"))
-                .unwrap_or_else(|_| {
-                    std::thread::available_parallelism()
-                        .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
-                        .get()
-                }),
-        )
+        .num_threads(num_threads)
         .thread_name(move |i| format!("{thread_name}-{i}"))
+        .start_handler(numa_start_handler(num_threads))
         .build()
         .expect("could not spawn threads")
 });
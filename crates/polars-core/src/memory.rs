@@ -0,0 +1,70 @@
+//! Tracking of the memory held by operators, see [`Config::memory_limit`].
+//!
+//! Operators that hold a lot of memory at once, such as the hash tables of joins and group-bys,
+//! reserve their estimated size with [`try_reserve_memory`] before they build them. If the
+//! reservation fails, they can spill their input to disk and process it in parts instead of
+//! running out of memory. Reservations are shared by all queries of the process, while the limit
+//! is read from the [`Config`] of the query that reserves.
+//!
+//! [`Config`]: crate::config::Config
+//! [`Config::memory_limit`]: crate::config::Config::memory_limit
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::config::memory_limit;
+
+static RESERVED: AtomicUsize = AtomicUsize::new(0);
+
+/// Memory reserved with [`try_reserve_memory`], released when dropped.
+#[must_use = "the memory is released when the reservation is dropped"]
+#[derive(Debug)]
+pub struct MemoryReservation {
+    bytes: usize,
+}
+
+impl MemoryReservation {
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        RESERVED.fetch_sub(self.bytes, Ordering::AcqRel);
+    }
+}
+
+/// The number of bytes that are currently reserved.
+pub fn reserved_memory() -> usize {
+    RESERVED.load(Ordering::Acquire)
+}
+
+/// Reserve `bytes`, unless the reserved memory would exceed the memory limit. This always
+/// succeeds if there is no memory limit.
+pub fn try_reserve_memory(bytes: usize) -> Option<MemoryReservation> {
+    try_reserve_memory_impl(bytes, memory_limit().unwrap_or(usize::MAX))
+}
+
+fn try_reserve_memory_impl(bytes: usize, limit: usize) -> Option<MemoryReservation> {
+    RESERVED
+        .fetch_update(Ordering::AcqRel, Ordering::Acquire, |reserved| {
+            reserved.checked_add(bytes).filter(|r| *r <= limit)
+        })
+        .ok()
+        .map(|_| MemoryReservation { bytes })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memory_reservation() {
+        let limit = usize::MAX / 2;
+        let first = try_reserve_memory_impl(usize::MAX / 4, limit).unwrap();
+        assert_eq!(first.bytes(), usize::MAX / 4);
+        assert!(reserved_memory() >= usize::MAX / 4);
+        assert!(try_reserve_memory_impl(usize::MAX / 3, limit).is_none());
+        drop(first);
+        assert!(try_reserve_memory_impl(usize::MAX / 3, limit).is_some());
+    }
+}
@@ -30,7 +30,7 @@ pub use crate::chunked_array::iterator::PolarsIterator;
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::*;
 #[cfg(feature = "ndarray")]
-pub use crate::chunked_array::ndarray::IndexOrder;
+pub use crate::chunked_array::ndarray::{IndexOrder, NdArrayCastPolicy};
 #[cfg(feature = "object")]
 pub use crate::chunked_array::object::PolarsObject;
 pub use crate::chunked_array::ops::aggregate::*;
@@ -50,6 +50,8 @@ pub use crate::frame::explode::UnpivotArgsIR;
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
+pub use crate::frame::markup::{MarkupAlignment, MarkupOptions};
+pub use crate::frame::typed::{FrameColumn, FrameSchema};
 pub use crate::frame::{DataFrame, UniqueKeepStrategy};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
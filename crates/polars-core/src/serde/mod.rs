@@ -1,5 +1,6 @@
 pub mod chunked_array;
 mod df;
+mod rows;
 pub mod series;
 
 #[cfg(test)]
@@ -124,4 +125,124 @@ mod test {
         let out = serde_json::from_str::<DataFrame>(&df_str).unwrap();
         assert!(df.equals_missing(&out));
     }
+
+    #[test]
+    fn test_serde_rows() -> PolarsResult<()> {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Kind {
+            A,
+            B,
+        }
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Row {
+            id: u32,
+            name: String,
+            score: Option<f64>,
+            tags: Vec<i64>,
+            kind: Kind,
+        }
+
+        let rows = vec![
+            Row {
+                id: 1,
+                name: "a".into(),
+                score: Some(1.5),
+                tags: vec![1, 2],
+                kind: Kind::A,
+            },
+            Row {
+                id: 2,
+                name: "b".into(),
+                score: None,
+                tags: vec![],
+                kind: Kind::B,
+            },
+        ];
+        let df = DataFrame::serialize_from_rows(&rows)?;
+        assert_eq!(
+            df.get_column_names(),
+            &["id", "name", "score", "tags", "kind"]
+        );
+        assert_eq!(df.column("id")?.dtype(), &DataType::UInt32);
+        assert_eq!(
+            df.column("tags")?.dtype(),
+            &DataType::List(Box::new(DataType::Int64))
+        );
+        assert_eq!(df.column("kind")?.dtype(), &DataType::String);
+        assert_eq!(df.column("score")?.null_count(), 1);
+
+        assert_eq!(df.deserialize_rows::<Row>()?, rows);
+        let tuples = df
+            .select(["id", "name"])?
+            .deserialize_rows::<(u32, String)>()?;
+        assert_eq!(tuples, [(1, "a".to_string()), (2, "b".to_string())]);
+
+        assert!(df.deserialize_rows::<(bool,)>().is_err());
+        assert!(DataFrame::serialize_from_rows(&[1, 2]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_rows_mixed_dtypes() -> PolarsResult<()> {
+        let rows = vec![
+            serde_json::json!({"a": 1, "b": "x"}),
+            serde_json::json!({"a": 1.5}),
+            serde_json::json!({"b": null, "c": true}),
+        ];
+        let df = DataFrame::serialize_from_rows(&rows)?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+
+        // The column falls back to the supertype of its values.
+        let a = df.column("a")?.f64()?;
+        assert_eq!(Vec::from(a), &[Some(1.0), Some(1.5), None]);
+        let b = df.column("b")?.str()?;
+        assert_eq!(Vec::from(b), &[Some("x"), None, None]);
+        let c = df.column("c")?.bool()?;
+        assert_eq!(Vec::from(c), &[None, None, Some(true)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_rows_from_columns() -> PolarsResult<()> {
+        use serde::Deserialize;
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row {
+            a: Option<i64>,
+            b: Option<String>,
+            c: i8,
+            d: i32,
+        }
+
+        // Multiple chunks, a column without a typed reader and a temporal column.
+        let mut df = df!(
+            "a" => [Some(1i64), None],
+            "b" => [None, Some("y")],
+            "c" => [1i8, 2],
+            "d" => [10i32, 20],
+        )?;
+        df.vstack_mut(&df.clone())?;
+        #[cfg(feature = "dtype-date")]
+        {
+            let d = df.column("d")?.cast(&DataType::Date)?;
+            df.with_column(d)?;
+        }
+
+        let rows = df.deserialize_rows::<Row>()?;
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[3],
+            Row {
+                a: None,
+                b: Some("y".into()),
+                c: 2,
+                d: 20
+            }
+        );
+        assert!(df.deserialize_rows::<(i64,)>().is_err());
+        Ok(())
+    }
 }
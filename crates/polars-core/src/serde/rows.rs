@@ -0,0 +1,1248 @@
+//! Convert between a [`DataFrame`] and a `Vec` of user types through serde.
+//!
+//! Every row maps to a struct (or map) whose fields are the columns of the frame. Nested
+//! structs map to struct columns and sequences to list columns. Temporal values are
+//! (de)serialized as their physical integer representation.
+use std::fmt::Display;
+
+use arrow::array::{Array, BinaryViewArray, BooleanArray, PrimitiveArray, Utf8ViewArray};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+use serde::{Deserializer, Serializer, forward_to_deserialize_any};
+
+use crate::prelude::*;
+use crate::series::SeriesIter;
+
+#[derive(Debug)]
+struct Error(PolarsError);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(polars_err!(ComputeError: "{}", msg))
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(polars_err!(ComputeError: "{}", msg))
+    }
+}
+
+impl From<PolarsError> for Error {
+    fn from(err: PolarsError) -> Self {
+        Self(err)
+    }
+}
+
+fn unsupported(what: &str) -> Error {
+    Error(polars_err!(ComputeError: "cannot serialize {} into a DataFrame", what))
+}
+
+type Fields = Vec<(PlSmallStr, AnyValue<'static>)>;
+
+impl DataFrame {
+    /// Create a [`DataFrame`] from a slice of values that serialize to structs (or maps).
+    ///
+    /// The fields of the first row determine the column order; fields that are missing in
+    /// some rows are null there. The dtype of every column is inferred from its values.
+    pub fn serialize_from_rows<T: Serialize>(rows: &[T]) -> PolarsResult<Self> {
+        let mut builder = RowsBuilder {
+            columns: PlIndexMap::new(),
+            row: 0,
+            capacity: rows.len(),
+        };
+        for row in rows {
+            row.serialize(RowSerializer(&mut builder))
+                .map_err(|e| e.0)?;
+            let row = builder.row;
+            for (len, column) in builder.columns.values_mut() {
+                if *len == row {
+                    column.push(AnyValue::Null, builder.capacity)?;
+                    *len += 1;
+                }
+            }
+            builder.row += 1;
+        }
+        let columns = builder
+            .columns
+            .into_iter()
+            .map(|(name, (_, column))| column.finish(name).map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
+    /// Deserialize every row of this [`DataFrame`] into a `T`, where the fields of `T` are
+    /// looked up by column name (or by position, for tuples).
+    pub fn deserialize_rows<T: DeserializeOwned>(&self) -> PolarsResult<Vec<T>> {
+        if self.height() == 0 {
+            return Ok(Vec::new());
+        }
+        // Temporal values are read as their physical integers.
+        let columns = self
+            .get_columns()
+            .iter()
+            .map(|c| {
+                let s = c.as_materialized_series();
+                if s.dtype().is_temporal() {
+                    s.to_physical_repr().rechunk()
+                } else {
+                    s.rechunk()
+                }
+            })
+            .collect::<Vec<_>>();
+        let names = columns.iter().map(|s| s.name().clone()).collect::<Vec<_>>();
+        let readers = columns.iter().map(ColumnReader::new).collect::<Vec<_>>();
+        (0..self.height())
+            .map(|idx| {
+                T::deserialize(RowDeserializer {
+                    names: &names,
+                    readers: &readers,
+                    idx,
+                })
+                .map_err(|e| e.0)
+            })
+            .collect()
+    }
+}
+
+/// The columns of the rows serialized so far.
+struct RowsBuilder {
+    /// The builder of every column, with the number of values pushed into it.
+    columns: PlIndexMap<PlSmallStr, (usize, ColumnBuilder)>,
+    /// The index of the row that is being serialized.
+    row: usize,
+    capacity: usize,
+}
+
+impl RowsBuilder {
+    fn push_field<T: ?Sized + Serialize>(
+        &mut self,
+        name: PlSmallStr,
+        value: &T,
+    ) -> Result<(), Error> {
+        let row = self.row;
+        let (len, column) = self
+            .columns
+            .entry(name)
+            .or_insert_with(|| (row, ColumnBuilder::Null(row)));
+        // The first value wins for fields that occur more than once in a row.
+        if *len == row {
+            value.serialize(ColumnSerializer {
+                column,
+                capacity: self.capacity,
+            })?;
+            *len += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Builds a column from the values of a field, with a typed builder for the dtype of its first
+/// non-null value. Nested values, and values that don't match the dtype of the builder, fall back
+/// to collecting [`AnyValue`]s, of which the supertype is taken at the end.
+enum ColumnBuilder {
+    /// Only nulls so far.
+    Null(usize),
+    Boolean(BooleanChunkedBuilder),
+    Int32(PrimitiveChunkedBuilder<Int32Type>),
+    Int64(PrimitiveChunkedBuilder<Int64Type>),
+    UInt32(PrimitiveChunkedBuilder<UInt32Type>),
+    UInt64(PrimitiveChunkedBuilder<UInt64Type>),
+    Float32(PrimitiveChunkedBuilder<Float32Type>),
+    Float64(PrimitiveChunkedBuilder<Float64Type>),
+    String(StringChunkedBuilder),
+    Binary(BinaryChunkedBuilder),
+    AnyValues(Vec<AnyValue<'static>>),
+}
+
+impl ColumnBuilder {
+    fn new(av: &AnyValue<'_>, capacity: usize) -> Self {
+        let name = PlSmallStr::EMPTY;
+        match av {
+            AnyValue::Null => Self::Null(0),
+            AnyValue::Boolean(_) => Self::Boolean(BooleanChunkedBuilder::new(name, capacity)),
+            AnyValue::Int32(_) => Self::Int32(PrimitiveChunkedBuilder::new(name, capacity)),
+            AnyValue::Int64(_) => Self::Int64(PrimitiveChunkedBuilder::new(name, capacity)),
+            AnyValue::UInt32(_) => Self::UInt32(PrimitiveChunkedBuilder::new(name, capacity)),
+            AnyValue::UInt64(_) => Self::UInt64(PrimitiveChunkedBuilder::new(name, capacity)),
+            AnyValue::Float32(_) => Self::Float32(PrimitiveChunkedBuilder::new(name, capacity)),
+            AnyValue::Float64(_) => Self::Float64(PrimitiveChunkedBuilder::new(name, capacity)),
+            AnyValue::StringOwned(_) => Self::String(StringChunkedBuilder::new(name, capacity)),
+            AnyValue::BinaryOwned(_) => Self::Binary(BinaryChunkedBuilder::new(name, capacity)),
+            _ => Self::AnyValues(Vec::with_capacity(capacity)),
+        }
+    }
+
+    fn push(&mut self, av: AnyValue<'static>, capacity: usize) -> PolarsResult<()> {
+        if let Self::Null(n) = *self
+            && !av.is_null()
+        {
+            *self = Self::new(&av, capacity);
+            for _ in 0..n {
+                self.push(AnyValue::Null, capacity)?;
+            }
+        }
+        match (&mut *self, av) {
+            (Self::Null(n), AnyValue::Null) => *n += 1,
+            (Self::AnyValues(values), av) => values.push(av),
+            (Self::Boolean(b), AnyValue::Null) => b.append_null(),
+            (Self::Boolean(b), AnyValue::Boolean(v)) => b.append_value(v),
+            (Self::Int32(b), AnyValue::Null) => b.append_null(),
+            (Self::Int32(b), AnyValue::Int32(v)) => b.append_value(v),
+            (Self::Int64(b), AnyValue::Null) => b.append_null(),
+            (Self::Int64(b), AnyValue::Int64(v)) => b.append_value(v),
+            (Self::UInt32(b), AnyValue::Null) => b.append_null(),
+            (Self::UInt32(b), AnyValue::UInt32(v)) => b.append_value(v),
+            (Self::UInt64(b), AnyValue::Null) => b.append_null(),
+            (Self::UInt64(b), AnyValue::UInt64(v)) => b.append_value(v),
+            (Self::Float32(b), AnyValue::Null) => b.append_null(),
+            (Self::Float32(b), AnyValue::Float32(v)) => b.append_value(v),
+            (Self::Float64(b), AnyValue::Null) => b.append_null(),
+            (Self::Float64(b), AnyValue::Float64(v)) => b.append_value(v),
+            (Self::String(b), AnyValue::Null) => b.append_null(),
+            (Self::String(b), AnyValue::StringOwned(v)) => b.append_value(v.as_str()),
+            (Self::Binary(b), AnyValue::Null) => b.append_null(),
+            (Self::Binary(b), AnyValue::BinaryOwned(v)) => b.append_value(v),
+            (builder, av) => {
+                // A value of another dtype, continue with the values collected so far.
+                let s = std::mem::replace(builder, Self::Null(0)).finish(PlSmallStr::EMPTY)?;
+                let mut values = Vec::with_capacity(capacity);
+                values.extend(s.iter().map(|v| v.into_static()));
+                values.push(av);
+                *builder = Self::AnyValues(values);
+            },
+        }
+        Ok(())
+    }
+
+    fn finish(self, name: PlSmallStr) -> PolarsResult<Series> {
+        let s = match self {
+            Self::Null(n) => Series::full_null(name, n, &DataType::Null),
+            Self::Boolean(b) => b.finish().with_name(name).into_series(),
+            Self::Int32(b) => b.finish().with_name(name).into_series(),
+            Self::Int64(b) => b.finish().with_name(name).into_series(),
+            Self::UInt32(b) => b.finish().with_name(name).into_series(),
+            Self::UInt64(b) => b.finish().with_name(name).into_series(),
+            Self::Float32(b) => b.finish().with_name(name).into_series(),
+            Self::Float64(b) => b.finish().with_name(name).into_series(),
+            Self::String(b) => b.finish().with_name(name).into_series(),
+            Self::Binary(b) => b.finish().with_name(name).into_series(),
+            Self::AnyValues(values) => Series::from_any_values(name, &values, false)?,
+        };
+        Ok(s)
+    }
+}
+
+/// Serializes a single value into an [`AnyValue`].
+struct AnyValueSerializer;
+
+/// Serializes a single value straight into the builder of its column.
+struct ColumnSerializer<'a> {
+    column: &'a mut ColumnBuilder,
+    capacity: usize,
+}
+
+/// Serializes a row into the builders of its fields.
+struct RowSerializer<'a>(&'a mut RowsBuilder);
+
+/// Receives a nested value once it is serialized.
+trait Sink {
+    type Ok;
+
+    fn finish(self, av: AnyValue<'static>) -> Result<Self::Ok, Error>;
+}
+
+impl Sink for AnyValueSerializer {
+    type Ok = AnyValue<'static>;
+
+    fn finish(self, av: AnyValue<'static>) -> Result<Self::Ok, Error> {
+        Ok(av)
+    }
+}
+
+impl Sink for ColumnSerializer<'_> {
+    type Ok = ();
+
+    fn finish(self, av: AnyValue<'static>) -> Result<(), Error> {
+        self.column.push(av, self.capacity)?;
+        Ok(())
+    }
+}
+
+struct SeqSerializer<S> {
+    values: Vec<AnyValue<'static>>,
+    sink: S,
+}
+
+impl<S> SeqSerializer<S> {
+    fn new(len: Option<usize>, sink: S) -> Self {
+        Self {
+            values: Vec::with_capacity(len.unwrap_or(0)),
+            sink,
+        }
+    }
+}
+
+struct StructSerializer<S> {
+    fields: Fields,
+    key: Option<PlSmallStr>,
+    sink: S,
+}
+
+impl<S> StructSerializer<S> {
+    fn new(len: Option<usize>, sink: S) -> Self {
+        Self {
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+            sink,
+        }
+    }
+}
+
+/// Serializes the fields of a row into the builders of their columns.
+struct RowFieldsSerializer<'a> {
+    builder: &'a mut RowsBuilder,
+    key: Option<PlSmallStr>,
+}
+
+fn serialize_key<T: ?Sized + Serialize>(key: &T) -> Result<PlSmallStr, Error> {
+    match key.serialize(AnyValueSerializer)? {
+        AnyValue::StringOwned(key) => Ok(key),
+        _ => Err(unsupported("a map with non-string keys")),
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+fn fields_to_av(fields: Fields) -> Result<AnyValue<'static>, Error> {
+    let (names, values): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+    let fields = names
+        .into_iter()
+        .zip(&values)
+        .map(|(name, av)| Field::new(name, av.dtype()))
+        .collect();
+    Ok(AnyValue::StructOwned(Box::new((values, fields))))
+}
+
+#[cfg(not(feature = "dtype-struct"))]
+fn fields_to_av(_fields: Fields) -> Result<AnyValue<'static>, Error> {
+    Err(unsupported(
+        "a nested struct (requires the 'dtype-struct' feature)",
+    ))
+}
+
+impl Serializer for AnyValueSerializer {
+    type Ok = AnyValue<'static>;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer<Self>;
+    type SerializeTuple = SeqSerializer<Self>;
+    type SerializeTupleStruct = SeqSerializer<Self>;
+    type SerializeTupleVariant = ser::Impossible<Self::Ok, Error>;
+    type SerializeMap = StructSerializer<Self>;
+    type SerializeStruct = StructSerializer<Self>;
+    type SerializeStructVariant = ser::Impossible<Self::Ok, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Boolean(v))
+    }
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Int8(v))
+    }
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Int16(v))
+    }
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Int32(v))
+    }
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Int64(v))
+    }
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Int128(v))
+    }
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::UInt8(v))
+    }
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::UInt16(v))
+    }
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::UInt32(v))
+    }
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::UInt64(v))
+    }
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::UInt128(v))
+    }
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Float32(v))
+    }
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Float64(v))
+    }
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::StringOwned(v.to_string().into()))
+    }
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::StringOwned(v.into()))
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::BinaryOwned(v.to_vec()))
+    }
+    fn serialize_none(self) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Null)
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Null)
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::Null)
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Error> {
+        Ok(AnyValue::StringOwned(variant.into()))
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Error> {
+        Err(unsupported("an enum variant with data"))
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(SeqSerializer::new(len, self))
+    }
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported("an enum variant with data"))
+    }
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(StructSerializer::new(len, self))
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(StructSerializer::new(Some(len), self))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(unsupported("an enum variant with data"))
+    }
+}
+
+/// Appends values of the dtype of the builder directly and hands all others to
+/// [`ColumnBuilder::push`].
+macro_rules! serialize_into_column {
+    ($($method:ident($ty:ty) => $variant:ident, $av:path;)*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<(), Error> {
+                match self.column {
+                    ColumnBuilder::$variant(b) => b.append_value(v),
+                    column => column.push($av(v), self.capacity)?,
+                }
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl Serializer for ColumnSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = SeqSerializer<Self>;
+    type SerializeTuple = SeqSerializer<Self>;
+    type SerializeTupleStruct = SeqSerializer<Self>;
+    type SerializeTupleVariant = ser::Impossible<(), Error>;
+    type SerializeMap = StructSerializer<Self>;
+    type SerializeStruct = StructSerializer<Self>;
+    type SerializeStructVariant = ser::Impossible<(), Error>;
+
+    serialize_into_column! {
+        serialize_bool(bool) => Boolean, AnyValue::Boolean;
+        serialize_i32(i32) => Int32, AnyValue::Int32;
+        serialize_i64(i64) => Int64, AnyValue::Int64;
+        serialize_u32(u32) => UInt32, AnyValue::UInt32;
+        serialize_u64(u64) => UInt64, AnyValue::UInt64;
+        serialize_f32(f32) => Float32, AnyValue::Float32;
+        serialize_f64(f64) => Float64, AnyValue::Float64;
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.finish(AnyValue::Int8(v))
+    }
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.finish(AnyValue::Int16(v))
+    }
+    fn serialize_i128(self, v: i128) -> Result<(), Error> {
+        self.finish(AnyValue::Int128(v))
+    }
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.finish(AnyValue::UInt8(v))
+    }
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.finish(AnyValue::UInt16(v))
+    }
+    fn serialize_u128(self, v: u128) -> Result<(), Error> {
+        self.finish(AnyValue::UInt128(v))
+    }
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        match self.column {
+            ColumnBuilder::String(b) => b.append_value(v),
+            column => column.push(AnyValue::StringOwned(v.into()), self.capacity)?,
+        }
+        Ok(())
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        match self.column {
+            ColumnBuilder::Binary(b) => b.append_value(v),
+            column => column.push(AnyValue::BinaryOwned(v.to_vec()), self.capacity)?,
+        }
+        Ok(())
+    }
+    fn serialize_none(self) -> Result<(), Error> {
+        self.finish(AnyValue::Null)
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.finish(AnyValue::Null)
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        self.finish(AnyValue::Null)
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.serialize_str(variant)
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), Error> {
+        Err(unsupported("an enum variant with data"))
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(SeqSerializer::new(len, self))
+    }
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported("an enum variant with data"))
+    }
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(StructSerializer::new(len, self))
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(StructSerializer::new(Some(len), self))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(unsupported("an enum variant with data"))
+    }
+}
+
+impl<S: Sink> SeqSerializer<S> {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.values.push(value.serialize(AnyValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<S::Ok, Error> {
+        let s = Series::from_any_values(PlSmallStr::EMPTY, &self.values, false)?;
+        self.sink.finish(AnyValue::List(s))
+    }
+}
+
+impl<S: Sink> ser::SerializeSeq for SeqSerializer<S> {
+    type Ok = S::Ok;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<S: Sink> ser::SerializeTuple for SeqSerializer<S> {
+    type Ok = S::Ok;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<S: Sink> ser::SerializeTupleStruct for SeqSerializer<S> {
+    type Ok = S::Ok;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<S: Sink> ser::SerializeMap for StructSerializer<S> {
+    type Ok = S::Ok;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(serialize_key(key)?);
+        Ok(())
+    }
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.key.take().unwrap();
+        self.fields
+            .push((key, value.serialize(AnyValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<S::Ok, Error> {
+        self.sink.finish(fields_to_av(self.fields)?)
+    }
+}
+
+impl<S: Sink> ser::SerializeStruct for StructSerializer<S> {
+    type Ok = S::Ok;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.fields
+            .push((key.into(), value.serialize(AnyValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<S::Ok, Error> {
+        self.sink.finish(fields_to_av(self.fields)?)
+    }
+}
+
+impl ser::SerializeMap for RowFieldsSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(serialize_key(key)?);
+        Ok(())
+    }
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.key.take().unwrap();
+        self.builder.push_field(key, value)
+    }
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for RowFieldsSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.builder.push_field(key.into(), value)
+    }
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+macro_rules! unsupported_row {
+    ($($method:ident($($arg:ty),*) -> $ret:ty;)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$ret, Error> {
+                Err(unsupported("a row that is not a struct or map"))
+            }
+        )*
+    };
+}
+
+impl<'a> Serializer for RowSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = ser::Impossible<(), Error>;
+    type SerializeTuple = ser::Impossible<(), Error>;
+    type SerializeTupleStruct = ser::Impossible<(), Error>;
+    type SerializeTupleVariant = ser::Impossible<(), Error>;
+    type SerializeMap = RowFieldsSerializer<'a>;
+    type SerializeStruct = RowFieldsSerializer<'a>;
+    type SerializeStructVariant = ser::Impossible<(), Error>;
+
+    unsupported_row! {
+        serialize_bool(bool) -> ();
+        serialize_i8(i8) -> ();
+        serialize_i16(i16) -> ();
+        serialize_i32(i32) -> ();
+        serialize_i64(i64) -> ();
+        serialize_u8(u8) -> ();
+        serialize_u16(u16) -> ();
+        serialize_u32(u32) -> ();
+        serialize_u64(u64) -> ();
+        serialize_f32(f32) -> ();
+        serialize_f64(f64) -> ();
+        serialize_char(char) -> ();
+        serialize_str(&str) -> ();
+        serialize_bytes(&[u8]) -> ();
+        serialize_none() -> ();
+        serialize_unit() -> ();
+        serialize_unit_struct(&'static str) -> ();
+        serialize_unit_variant(&'static str, u32, &'static str) -> ();
+        serialize_seq(Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant;
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant;
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), Error> {
+        Err(unsupported("a row that is not a struct or map"))
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(RowFieldsSerializer {
+            builder: self.0,
+            key: None,
+        })
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(RowFieldsSerializer {
+            builder: self.0,
+            key: None,
+        })
+    }
+}
+
+/// Reads the values of a (single chunk) column, without going through [`AnyValue`] for the
+/// dtypes that have a typed builder.
+enum ColumnReader<'a> {
+    Boolean(&'a BooleanArray),
+    Int32(&'a PrimitiveArray<i32>),
+    Int64(&'a PrimitiveArray<i64>),
+    UInt32(&'a PrimitiveArray<u32>),
+    UInt64(&'a PrimitiveArray<u64>),
+    Float32(&'a PrimitiveArray<f32>),
+    Float64(&'a PrimitiveArray<f64>),
+    String(&'a Utf8ViewArray),
+    Binary(&'a BinaryViewArray),
+    Other(&'a Series),
+}
+
+impl<'a> ColumnReader<'a> {
+    fn new(s: &'a Series) -> Self {
+        match s.dtype() {
+            DataType::Boolean => Self::Boolean(s.bool().unwrap().downcast_as_array()),
+            DataType::Int32 => Self::Int32(s.i32().unwrap().downcast_as_array()),
+            DataType::Int64 => Self::Int64(s.i64().unwrap().downcast_as_array()),
+            DataType::UInt32 => Self::UInt32(s.u32().unwrap().downcast_as_array()),
+            DataType::UInt64 => Self::UInt64(s.u64().unwrap().downcast_as_array()),
+            DataType::Float32 => Self::Float32(s.f32().unwrap().downcast_as_array()),
+            DataType::Float64 => Self::Float64(s.f64().unwrap().downcast_as_array()),
+            DataType::String => Self::String(s.str().unwrap().downcast_as_array()),
+            DataType::Binary => Self::Binary(s.binary().unwrap().downcast_as_array()),
+            _ => Self::Other(s),
+        }
+    }
+
+    fn is_null(&self, idx: usize) -> bool {
+        match self {
+            Self::Boolean(arr) => arr.is_null(idx),
+            Self::Int32(arr) => arr.is_null(idx),
+            Self::Int64(arr) => arr.is_null(idx),
+            Self::UInt32(arr) => arr.is_null(idx),
+            Self::UInt64(arr) => arr.is_null(idx),
+            Self::Float32(arr) => arr.is_null(idx),
+            Self::Float64(arr) => arr.is_null(idx),
+            Self::String(arr) => arr.is_null(idx),
+            Self::Binary(arr) => arr.is_null(idx),
+            Self::Other(_) => unreachable!(),
+        }
+    }
+}
+
+/// Deserializes a row, as a map of the column names to their values (or, by position, as a
+/// sequence).
+struct RowDeserializer<'a> {
+    names: &'a [PlSmallStr],
+    readers: &'a [ColumnReader<'a>],
+    idx: usize,
+}
+
+struct RowAccess<'a> {
+    row: RowDeserializer<'a>,
+    column: usize,
+}
+
+impl<'a> RowAccess<'a> {
+    fn value(&self, column: usize) -> ValueDeserializer<'a> {
+        ValueDeserializer {
+            reader: &self.row.readers[column],
+            idx: self.row.idx,
+        }
+    }
+}
+
+impl<'de> de::MapAccess<'de> for RowAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.row.names.get(self.column) {
+            Some(name) => seed
+                .deserialize(name.as_str().into_deserializer())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = seed.deserialize(self.value(self.column));
+        self.column += 1;
+        value
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for RowAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.column == self.row.readers.len() {
+            return Ok(None);
+        }
+        let value = seed.deserialize(self.value(self.column));
+        self.column += 1;
+        value.map(Some)
+    }
+}
+
+impl<'de> Deserializer<'de> for RowDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(RowAccess {
+            row: self,
+            column: 0,
+        })
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(RowAccess {
+            row: self,
+            column: 0,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+/// Deserializes the value of a column in a row straight from its array.
+struct ValueDeserializer<'a> {
+    reader: &'a ColumnReader<'a>,
+    idx: usize,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    fn any_value(&self, series: &'a Series) -> Result<AnyValueDeserializer<'a>, Error> {
+        Ok(AnyValueDeserializer(series.get(self.idx)?))
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let idx = self.idx;
+        match self.reader {
+            ColumnReader::Other(s) => self.any_value(s)?.deserialize_any(visitor),
+            reader if reader.is_null(idx) => visitor.visit_unit(),
+            ColumnReader::Boolean(arr) => visitor.visit_bool(arr.value(idx)),
+            ColumnReader::Int32(arr) => visitor.visit_i32(arr.value(idx)),
+            ColumnReader::Int64(arr) => visitor.visit_i64(arr.value(idx)),
+            ColumnReader::UInt32(arr) => visitor.visit_u32(arr.value(idx)),
+            ColumnReader::UInt64(arr) => visitor.visit_u64(arr.value(idx)),
+            ColumnReader::Float32(arr) => visitor.visit_f32(arr.value(idx)),
+            ColumnReader::Float64(arr) => visitor.visit_f64(arr.value(idx)),
+            ColumnReader::String(arr) => visitor.visit_str(arr.value(idx)),
+            ColumnReader::Binary(arr) => visitor.visit_bytes(arr.value(idx)),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.reader {
+            ColumnReader::Other(s) => self.any_value(s)?.deserialize_option(visitor),
+            reader if reader.is_null(self.idx) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.reader {
+            ColumnReader::Other(s) => self.any_value(s)?.deserialize_enum(name, variants, visitor),
+            ColumnReader::String(arr) if !arr.is_null(self.idx) => {
+                visitor.visit_enum(arr.value(self.idx).into_deserializer())
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Deserializes named fields, as a map (or, by position, as a sequence).
+struct FieldsDeserializer<'a>(Vec<(PlSmallStr, AnyValue<'a>)>);
+
+struct FieldsAccess<'a> {
+    iter: std::vec::IntoIter<(PlSmallStr, AnyValue<'a>)>,
+    value: Option<AnyValue<'a>>,
+}
+
+impl<'de> de::MapAccess<'de> for FieldsAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.iter.next() {
+            Some((name, value)) => {
+                self.value = Some(value);
+                seed.deserialize(name.as_str().into_deserializer())
+                    .map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(AnyValueDeserializer(self.value.take().unwrap()))
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for FieldsAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        self.iter
+            .next()
+            .map(|(_, value)| seed.deserialize(AnyValueDeserializer(value)))
+            .transpose()
+    }
+}
+
+impl<'de> Deserializer<'de> for FieldsDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(FieldsAccess {
+            iter: self.0.into_iter(),
+            value: None,
+        })
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(FieldsAccess {
+            iter: self.0.into_iter(),
+            value: None,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+struct SeriesAccess<'a>(SeriesIter<'a>);
+
+impl<'de> de::SeqAccess<'de> for SeriesAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        self.0
+            .next()
+            .map(|av| seed.deserialize(AnyValueDeserializer(av)))
+            .transpose()
+    }
+}
+
+/// Deserializes a single [`AnyValue`].
+struct AnyValueDeserializer<'a>(AnyValue<'a>);
+
+impl<'de> Deserializer<'de> for AnyValueDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if let Some(s) = self.0.get_str() {
+            return visitor.visit_str(s);
+        }
+        match self.0 {
+            AnyValue::Null => visitor.visit_unit(),
+            AnyValue::Boolean(v) => visitor.visit_bool(v),
+            AnyValue::UInt8(v) => visitor.visit_u8(v),
+            AnyValue::UInt16(v) => visitor.visit_u16(v),
+            AnyValue::UInt32(v) => visitor.visit_u32(v),
+            AnyValue::UInt64(v) => visitor.visit_u64(v),
+            AnyValue::UInt128(v) => visitor.visit_u128(v),
+            AnyValue::Int8(v) => visitor.visit_i8(v),
+            AnyValue::Int16(v) => visitor.visit_i16(v),
+            AnyValue::Int32(v) => visitor.visit_i32(v),
+            AnyValue::Int64(v) => visitor.visit_i64(v),
+            AnyValue::Int128(v) => visitor.visit_i128(v),
+            AnyValue::Float32(v) => visitor.visit_f32(v),
+            AnyValue::Float64(v) => visitor.visit_f64(v),
+            AnyValue::Binary(v) => visitor.visit_bytes(v),
+            AnyValue::BinaryOwned(v) => visitor.visit_byte_buf(v),
+            AnyValue::List(s) => {
+                let s = s.rechunk();
+                visitor.visit_seq(SeriesAccess(s.iter()))
+            },
+            #[cfg(feature = "dtype-array")]
+            AnyValue::Array(s, _) => {
+                let s = s.rechunk();
+                visitor.visit_seq(SeriesAccess(s.iter()))
+            },
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::Struct(idx, arr, flds) => {
+                let av = AnyValue::Struct(idx, arr, flds);
+                let fields = flds
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .zip(av._iter_struct_av())
+                    .collect();
+                FieldsDeserializer(fields).deserialize_any(visitor)
+            },
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::StructOwned(payload) => {
+                let (values, flds) = *payload;
+                let fields = flds.into_iter().map(|f| f.name).zip(values).collect();
+                FieldsDeserializer(fields).deserialize_any(visitor)
+            },
+            av if av.dtype().is_float() => visitor.visit_f64(av.extract().unwrap()),
+            #[cfg(feature = "dtype-decimal")]
+            av @ AnyValue::Decimal(..) => visitor.visit_f64(av.extract().unwrap()),
+            av => match av.extract::<i64>() {
+                // temporal values
+                Some(v) => visitor.visit_i64(v),
+                None => Err(Error(
+                    polars_err!(ComputeError: "cannot deserialize value of dtype {}", av.dtype()),
+                )),
+            },
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            AnyValue::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0.get_str() {
+            Some(s) => visitor.visit_enum(s.into_deserializer()),
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
@@ -516,6 +516,11 @@ impl Add for &Series {
             },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                if let Some(out) =
+                    overflow::checked_integer_arithmetic(&lhs, &rhs, overflow::IntegerOp::Add)?
+                {
+                    return Ok(out);
+                }
                 lhs.add_to(rhs.as_ref())
             },
         }
@@ -541,6 +546,11 @@ impl Sub for &Series {
             },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                if let Some(out) =
+                    overflow::checked_integer_arithmetic(&lhs, &rhs, overflow::IntegerOp::Sub)?
+                {
+                    return Ok(out);
+                }
                 lhs.subtract(rhs.as_ref())
             },
         }
@@ -573,6 +583,9 @@ impl Mul for &Series {
                 let out = rhs.multiply(self)?;
                 Ok(out.with_name(self.name().clone()))
             },
+            // The product takes the sum of the scales, so don't coerce to a common scale.
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(_, _), Decimal(_, _)) => self.multiply(rhs),
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::mul().execute(self, rhs)
             },
@@ -582,6 +595,11 @@ impl Mul for &Series {
             },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                if let Some(out) =
+                    overflow::checked_integer_arithmetic(&lhs, &rhs, overflow::IntegerOp::Mul)?
+                {
+                    return Ok(out);
+                }
                 lhs.multiply(rhs.as_ref())
             },
         }
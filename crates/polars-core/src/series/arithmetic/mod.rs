@@ -1,6 +1,7 @@
 mod bitops;
 mod borrowed;
 mod list;
+mod overflow;
 mod owned;
 
 use std::borrow::Cow;
@@ -11,6 +12,9 @@ pub use borrowed::*;
 pub use fixed_size_list::NumericFixedSizeListOp;
 pub use list::NumericListOp;
 use num_traits::{Num, NumCast};
+#[cfg(feature = "algorithm_group_by")]
+pub(crate) use overflow::checked_integer_agg_sum;
+pub(crate) use overflow::checked_integer_sum;
 #[cfg(feature = "dtype-array")]
 mod fixed_size_list;
 mod list_utils;
@@ -0,0 +1,305 @@
+//! Integer arithmetic that doesn't silently wrap on overflow, see [`IntegerOverflow`].
+use std::fmt::Display;
+
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub, Zero,
+};
+
+use crate::chunked_array::arity::{
+    broadcast_binary_elementwise_values, broadcast_try_binary_elementwise,
+};
+use crate::config::{IntegerOverflow, integer_overflow};
+use crate::prelude::*;
+use crate::with_match_physical_integer_polars_type;
+
+#[derive(Clone, Copy)]
+pub(super) enum IntegerOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl IntegerOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+        }
+    }
+}
+
+trait OverflowNative:
+    Copy
+    + Display
+    + Zero
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + SaturatingAdd
+    + SaturatingSub
+    + SaturatingMul
+{
+    fn checked(self, op: IntegerOp, rhs: Self) -> Option<Self> {
+        match op {
+            IntegerOp::Add => self.checked_add(&rhs),
+            IntegerOp::Sub => self.checked_sub(&rhs),
+            IntegerOp::Mul => self.checked_mul(&rhs),
+        }
+    }
+
+    fn saturating(self, op: IntegerOp, rhs: Self) -> Self {
+        match op {
+            IntegerOp::Add => self.saturating_add(&rhs),
+            IntegerOp::Sub => self.saturating_sub(&rhs),
+            IntegerOp::Mul => self.saturating_mul(&rhs),
+        }
+    }
+}
+
+impl<T> OverflowNative for T where
+    T: Copy
+        + Display
+        + Zero
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + SaturatingAdd
+        + SaturatingSub
+        + SaturatingMul
+{
+}
+
+fn overflow_err(
+    lhs: impl Display,
+    op: IntegerOp,
+    rhs: impl Display,
+    dtype: &DataType,
+) -> PolarsError {
+    polars_err!(
+        ComputeError: "integer overflow: {} {} {} does not fit in {}; cast to a wider integer type before the operation",
+        lhs, op.symbol(), rhs, dtype
+    )
+}
+
+fn arithmetic_impl<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    op: IntegerOp,
+    mode: IntegerOverflow,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    match mode {
+        IntegerOverflow::Wrap => unreachable!(),
+        IntegerOverflow::Saturate => Ok(broadcast_binary_elementwise_values(
+            lhs,
+            rhs,
+            |a: T::Native, b: T::Native| a.saturating(op, b),
+        )),
+        IntegerOverflow::Raise => {
+            broadcast_try_binary_elementwise(lhs, rhs, |a: Option<T::Native>, b| match (a, b) {
+                (Some(a), Some(b)) => a
+                    .checked(op, b)
+                    .map(Some)
+                    .ok_or_else(|| overflow_err(a, op, b, lhs.dtype())),
+                _ => Ok(None),
+            })
+        },
+    }
+}
+
+/// Apply `op` to integer Series of the same dtype, unless integer overflow [wraps].
+///
+/// [wraps]: IntegerOverflow::Wrap
+pub(super) fn checked_integer_arithmetic(
+    lhs: &Series,
+    rhs: &Series,
+    op: IntegerOp,
+) -> PolarsResult<Option<Series>> {
+    if !lhs.dtype().is_integer() || lhs.dtype() != rhs.dtype() {
+        return Ok(None);
+    }
+    let mode = integer_overflow();
+    if mode == IntegerOverflow::Wrap {
+        return Ok(None);
+    }
+    with_match_physical_integer_polars_type!(lhs.dtype(), |$T| {
+        let l: &ChunkedArray<$T> = lhs.as_ref().as_ref();
+        let r: &ChunkedArray<$T> = rhs.as_ref().as_ref();
+        arithmetic_impl(l, r, op, mode).map(|ca| Some(ca.into_series()))
+    })
+}
+
+/// Sum `values`, which are taken from `ca`.
+fn sum_values<T>(
+    ca: &ChunkedArray<T>,
+    values: impl IntoIterator<Item = T::Native>,
+    mode: IntegerOverflow,
+) -> PolarsResult<T::Native>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    let mut acc = T::Native::zero();
+    for v in values {
+        acc = match mode {
+            IntegerOverflow::Wrap => unreachable!(),
+            IntegerOverflow::Saturate => acc.saturating(IntegerOp::Add, v),
+            IntegerOverflow::Raise => acc.checked(IntegerOp::Add, v).ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "integer overflow in the sum of column '{}' of dtype {}",
+                    ca.name(), ca.dtype()
+                )
+            })?,
+        };
+    }
+    Ok(acc)
+}
+
+fn sum_impl<T>(ca: &ChunkedArray<T>, mode: IntegerOverflow) -> PolarsResult<T::Native>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    let values = ca
+        .downcast_iter()
+        .flat_map(|arr| arr.non_null_values_iter());
+    sum_values(ca, values, mode)
+}
+
+/// # Safety
+///
+/// Does no bounds checks, groups must be correct.
+#[cfg(feature = "algorithm_group_by")]
+unsafe fn agg_sum_impl<T>(
+    ca: &ChunkedArray<T>,
+    groups: &GroupsType,
+    mode: IntegerOverflow,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: OverflowNative,
+{
+    let ca = ca.rechunk();
+    let sum = |values: &mut dyn Iterator<Item = Option<T::Native>>| {
+        sum_values(&ca, values.flatten(), mode)
+    };
+    let sums = match groups {
+        GroupsType::Idx(groups) => groups
+            .iter()
+            .map(|(_, idx)| sum(&mut idx.iter().map(|i| unsafe { ca.get_unchecked(*i as usize) })))
+            .collect::<PolarsResult<Vec<_>>>()?,
+        GroupsType::Slice { groups, .. } => groups
+            .iter()
+            .map(|&[first, len]| {
+                sum(&mut (first..first + len).map(|i| unsafe { ca.get_unchecked(i as usize) }))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?,
+    };
+    Ok(ChunkedArray::from_vec(ca.name().clone(), sums))
+}
+
+/// The sum of an integer Series, unless integer overflow [wraps].
+///
+/// [wraps]: IntegerOverflow::Wrap
+pub(crate) fn checked_integer_sum(s: &Series) -> PolarsResult<Option<Scalar>> {
+    if !s.dtype().is_integer() {
+        return Ok(None);
+    }
+    let mode = integer_overflow();
+    if mode == IntegerOverflow::Wrap {
+        return Ok(None);
+    }
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref();
+        let sum = sum_impl(ca, mode)?;
+        Ok(Some(Scalar::new(s.dtype().clone(), sum.into())))
+    })
+}
+
+/// The sums of the groups of an integer Series, unless integer overflow [wraps].
+///
+/// [wraps]: IntegerOverflow::Wrap
+///
+/// # Safety
+///
+/// Does no bounds checks, groups must be correct.
+#[cfg(feature = "algorithm_group_by")]
+pub(crate) unsafe fn checked_integer_agg_sum(
+    s: &Series,
+    groups: &GroupsType,
+) -> PolarsResult<Option<Series>> {
+    use DataType::*;
+
+    if !s.dtype().is_integer() {
+        return Ok(None);
+    }
+    let mode = integer_overflow();
+    if mode == IntegerOverflow::Wrap {
+        return Ok(None);
+    }
+    // Same as `agg_sum`, the small integers are summed as `Int64`.
+    if matches!(s.dtype(), Int8 | UInt8 | Int16 | UInt16) {
+        return unsafe { checked_integer_agg_sum(&s.cast(&Int64)?, groups) };
+    }
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref();
+        unsafe { agg_sum_impl(ca, groups, mode) }.map(|ca| Some(ca.into_series()))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_integer_overflow() -> PolarsResult<()> {
+        let a = Int32Chunked::new("a".into(), [i32::MAX, 1, 2]);
+        let b = Int32Chunked::new("b".into(), [1, 2, i32::MIN]);
+
+        assert!(arithmetic_impl(&a, &b, IntegerOp::Add, IntegerOverflow::Raise).is_err());
+        assert!(arithmetic_impl(&a, &a, IntegerOp::Sub, IntegerOverflow::Raise).is_ok());
+        let out = arithmetic_impl(&a, &b, IntegerOp::Mul, IntegerOverflow::Saturate)?;
+        assert_eq!(Vec::from(&out), &[Some(i32::MAX), Some(2), Some(i32::MIN)]);
+
+        // Null slots and broadcasting.
+        let c = Int32Chunked::new("c".into(), [Some(i32::MAX), None]);
+        let d = Int32Chunked::new("d".into(), [None, Some(1)]);
+        assert!(arithmetic_impl(&c, &d, IntegerOp::Add, IntegerOverflow::Raise).is_ok());
+        let two = Int32Chunked::new("two".into(), [2]);
+        assert!(arithmetic_impl(&c, &two, IntegerOp::Mul, IntegerOverflow::Raise).is_err());
+
+        assert!(sum_impl(&a, IntegerOverflow::Raise).is_err());
+        assert_eq!(sum_impl(&a, IntegerOverflow::Saturate)?, i32::MAX);
+        assert_eq!(sum_impl(&d, IntegerOverflow::Raise)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_integer_overflow_agg_sum() -> PolarsResult<()> {
+        let a = Int32Chunked::new(
+            "a".into(),
+            [Some(i32::MAX), Some(1), None, Some(2), Some(3)],
+        );
+        let overflowing = GroupsType::Slice {
+            groups: vec![[0, 2], [2, 3]],
+            overlapping: false,
+        };
+        let fitting = GroupsType::Idx(GroupsIdx::new(
+            vec![0, 1],
+            vec![vec![0, 2].into(), vec![1, 3, 4].into()],
+            true,
+        ));
+
+        assert!(unsafe { agg_sum_impl(&a, &overflowing, IntegerOverflow::Raise) }.is_err());
+        let out = unsafe { agg_sum_impl(&a, &overflowing, IntegerOverflow::Saturate) }?;
+        assert_eq!(Vec::from(&out), &[Some(i32::MAX), Some(5)]);
+        let out = unsafe { agg_sum_impl(&a, &fitting, IntegerOverflow::Raise) }?;
+        assert_eq!(Vec::from(&out), &[Some(i32::MAX), Some(6)]);
+        Ok(())
+    }
+}
@@ -22,6 +22,9 @@ fn is_eligible(lhs: &DataType, rhs: &DataType) -> bool {
     !lhs.is_logical()
         && lhs.to_physical().is_primitive_numeric()
         && rhs.to_physical().is_primitive_numeric()
+        // The borrowed implementation checks for integer overflow.
+        && (!(lhs.is_integer() || rhs.is_integer())
+            || crate::config::integer_overflow() == crate::config::IntegerOverflow::Wrap)
 }
 
 #[cfg(feature = "performant")]
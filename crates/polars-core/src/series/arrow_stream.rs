@@ -0,0 +1,68 @@
+use arrow::ffi::{ArrowArrayStream, ArrowArrayStreamReader, export_iterator};
+
+use crate::prelude::*;
+
+impl Series {
+    /// Export this [`Series`] through the [Arrow C stream interface], yielding one array per
+    /// chunk.
+    ///
+    /// The buffers are shared with the consumer rather than copied, unless the `compat_level`
+    /// requires the data to be converted.
+    ///
+    /// [Arrow C stream interface]: https://arrow.apache.org/docs/format/CStreamInterface.html
+    pub fn to_arrow_c_stream(&self, compat_level: CompatLevel) -> ArrowArrayStream {
+        let field = self.field().to_arrow(compat_level);
+        let s = self.clone();
+        let iter = (0..s.n_chunks()).map(move |i| Ok(s.to_arrow(i, compat_level)));
+        export_iterator(Box::new(iter), field)
+    }
+
+    /// Import a [`Series`] from a stream of arrays produced through the
+    /// [Arrow C stream interface].
+    ///
+    /// Every array becomes a chunk of the resulting [`Series`]; the data is imported without
+    /// copying.
+    ///
+    /// # Safety
+    /// The stream must be a valid Arrow C stream (as produced by an Arrow implementation).
+    ///
+    /// [Arrow C stream interface]: https://arrow.apache.org/docs/format/CStreamInterface.html
+    pub unsafe fn from_arrow_c_stream(stream: ArrowArrayStream) -> PolarsResult<Self> {
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(Box::new(stream))? };
+        let mut chunks = vec![];
+        while let Some(array) = unsafe { reader.next() } {
+            chunks.push(array?);
+        }
+
+        // `Series::try_from` fails for an empty vec of chunks
+        if chunks.is_empty() {
+            let field = reader.field();
+            let dtype = DataType::from_arrow_field(field);
+            return Ok(Series::new_empty(field.name.clone(), &dtype));
+        }
+        Series::try_from((reader.field(), chunks))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_arrow_c_stream_roundtrip() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), [Some(1i64), None, Some(3)]);
+        s.append(&Series::new("a".into(), [4i64]))?;
+
+        let stream = s.to_arrow_c_stream(CompatLevel::newest());
+        let out = unsafe { Series::from_arrow_c_stream(stream)? };
+        assert!(out.equals_missing(&s));
+        assert_eq!(out.n_chunks(), 2);
+
+        let empty = Series::new_empty("b".into(), &DataType::String);
+        let out =
+            unsafe { Series::from_arrow_c_stream(empty.to_arrow_c_stream(CompatLevel::oldest()))? };
+        assert_eq!(out.name().as_str(), "b");
+        assert_eq!(out.dtype(), &DataType::String);
+        Ok(())
+    }
+}
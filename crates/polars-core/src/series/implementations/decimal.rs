@@ -1,3 +1,4 @@
+use polars_compute::decimal::{DEC128_MAX_PREC, dec128_mean, dec128_mean_scale};
 use polars_compute::rolling::QuantileMethod;
 
 use super::*;
@@ -470,7 +471,22 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             .map(|v| v / self.scale_factor() as f64)
     }
     fn mean_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(Scalar::new(DataType::Float64, self.mean().into()))
+        let ca = self.0.physical();
+        let count = ca.len() - ca.null_count();
+        let overflow = || polars_err!(ComputeError: "overflow in the Decimal sum of the mean of '{}'", self.name());
+        let sum = ca
+            .iter()
+            .flatten()
+            .try_fold(0i128, i128::checked_add)
+            .ok_or_else(overflow)?;
+        let scale = dec128_mean_scale(self.0.scale());
+        let av = if count == 0 {
+            AnyValue::Null
+        } else {
+            let mean = dec128_mean(sum, count, self.0.scale()).ok_or_else(overflow)?;
+            AnyValue::Decimal(mean, DEC128_MAX_PREC, scale)
+        };
+        Ok(Scalar::new(DataType::Decimal(DEC128_MAX_PREC, scale), av))
     }
 
     fn median(&self) -> Option<f64> {
@@ -18,6 +18,7 @@ macro_rules! invalid_operation_panic {
 pub mod amortized_iter;
 mod any_value;
 pub mod arithmetic;
+mod arrow_stream;
 pub mod builder;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical_to_arrow;
@@ -311,6 +312,8 @@ impl Series {
     pub fn append(&mut self, other: &Series) -> PolarsResult<&mut Self> {
         let must_cast = other.dtype().matches_schema_type(self.dtype())?;
         if must_cast {
+            #[cfg(feature = "dtype-categorical")]
+            self.unify_categories(other.dtype())?;
             let other = other.cast(self.dtype())?;
             self.append_owned(other)?;
         } else {
@@ -325,6 +328,8 @@ impl Series {
     pub fn append_owned(&mut self, other: Series) -> PolarsResult<&mut Self> {
         let must_cast = other.dtype().matches_schema_type(self.dtype())?;
         if must_cast {
+            #[cfg(feature = "dtype-categorical")]
+            self.unify_categories(other.dtype())?;
             let other = other.cast(self.dtype())?;
             self._get_inner_mut().append_owned(other)?;
         } else {
@@ -344,6 +349,8 @@ impl Series {
     pub fn extend(&mut self, other: &Series) -> PolarsResult<&mut Self> {
         let must_cast = other.dtype().matches_schema_type(self.dtype())?;
         if must_cast {
+            #[cfg(feature = "dtype-categorical")]
+            self.unify_categories(other.dtype())?;
             let other = other.cast(self.dtype())?;
             self._get_inner_mut().extend(&other)?;
         } else {
@@ -352,6 +359,20 @@ impl Series {
         Ok(self)
     }
 
+    /// Categoricals with different categories are appended in their supertype, the global
+    /// categories, so cast `self` to it before `other` is remapped to it.
+    #[cfg(feature = "dtype-categorical")]
+    fn unify_categories(&mut self, other: &DataType) -> PolarsResult<()> {
+        if self.dtype().contains_categoricals()
+            && other.contains_categoricals()
+            && let Some(dtype) = crate::utils::get_supertype(self.dtype(), other)
+            && &dtype != self.dtype()
+        {
+            *self = self.cast(&dtype)?;
+        }
+        Ok(())
+    }
+
     /// Sort the series with specific options.
     ///
     /// # Example
@@ -462,6 +483,22 @@ impl Series {
             return Ok(Series::full_null(slf.name().clone(), len, dtype));
         }
 
+        #[cfg(feature = "dtype-extension")]
+        if let D::Extension(typ, storage) = dtype {
+            if !slf.dtype().is_extension() {
+                let out = match typ.0.cast_from(&slf, storage, options) {
+                    Some(out) => out?,
+                    None => slf.cast_with_options(storage, options)?,
+                };
+                polars_ensure!(
+                    out.dtype() == storage.as_ref(),
+                    ComputeError: "casting to extension type {} produced {}, expected storage type {}",
+                    typ, out.dtype(), storage
+                );
+                return Ok(out.into_extension(typ.clone()));
+            }
+        }
+
         let new_options = match options {
             // Strictness is handled on this level to improve error messages.
             CastOptions::Strict => CastOptions::NonStrict,
@@ -810,12 +847,16 @@ impl Series {
     /// Returns a Series with a single zeroed entry if self is an empty numeric series.
     ///
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
-    /// first cast to `Int64` to prevent overflow issues.
+    /// first cast to `Int64` to prevent overflow issues. Overflow of other integer sums is handled
+    /// according to [`integer_overflow`](crate::config::integer_overflow).
     pub fn sum_reduce(&self) -> PolarsResult<Scalar> {
         use DataType::*;
         match self.dtype() {
             Int8 | UInt8 | Int16 | UInt16 => self.cast(&Int64).unwrap().sum_reduce(),
-            _ => self.0.sum_reduce(),
+            _ => match arithmetic::checked_integer_sum(self)? {
+                Some(sum) => Ok(sum),
+                None => self.0.sum_reduce(),
+            },
         }
     }
 
@@ -970,7 +1011,14 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
-        Ok(self.0.get(index)?.str_value())
+        let av = self.0.get(index)?;
+        #[cfg(feature = "dtype-extension")]
+        if let DataType::Extension(typ, _) = self.dtype() {
+            if let Some(s) = (!av.is_null()).then(|| typ.0.format_value(&av)).flatten() {
+                return Ok(Cow::Owned(s));
+            }
+        }
+        Ok(av.str_value())
     }
     /// Get the head of the Series.
     pub fn head(&self, length: Option<usize>) -> Series {
@@ -11,7 +11,10 @@ use proptest::prelude::*;
 use crate::chunked_array::builder::AnonymousListBuilder;
 #[cfg(feature = "dtype-categorical")]
 use crate::chunked_array::builder::CategoricalChunkedBuilder;
-use crate::prelude::{Int32Chunked, Int64Chunked, Int128Chunked, NamedFrom, Series, TimeUnit};
+use crate::prelude::{
+    IdxCa, IdxSize, Int32Chunked, Int64Chunked, Int128Chunked, IsSorted, NamedFrom,
+    NewChunkedArray, Series, SortOptions, TimeUnit,
+};
 #[cfg(feature = "dtype-struct")]
 use crate::series::StructChunked;
 use crate::series::from::IntoSeries;
@@ -77,6 +80,13 @@ pub struct SeriesArbitraryOptions {
     pub series_length_range: RangeInclusive<usize>,
     pub categories_range: RangeInclusive<usize>,
     pub struct_fields_range: RangeInclusive<usize>,
+    /// The probability that a value is null, between `0.0` and `1.0`.
+    pub null_probability: f64,
+    /// The maximum number of chunks of a Series, the chunk boundaries are random.
+    pub max_chunks: usize,
+    /// Sort the Series in a random direction, which sets its sorted flag if the dtype supports
+    /// it. Series with a dtype that can't be sorted are left unsorted.
+    pub sorted: bool,
 }
 
 impl Default for SeriesArbitraryOptions {
@@ -87,10 +97,22 @@ impl Default for SeriesArbitraryOptions {
             series_length_range: 0..=5,
             categories_range: 0..=3,
             struct_fields_range: 0..=3,
+            null_probability: 0.0,
+            max_chunks: 1,
+            sorted: false,
         }
     }
 }
 
+impl Arbitrary for Series {
+    type Parameters = SeriesArbitraryOptions;
+    type Strategy = BoxedStrategy<Series>;
+
+    fn arbitrary_with(options: Self::Parameters) -> Self::Strategy {
+        series_strategy(Rc::new(options), 0).boxed()
+    }
+}
+
 pub fn series_strategy(
     options: Rc<SeriesArbitraryOptions>,
     nesting_level: usize,
@@ -106,7 +128,8 @@ pub fn series_strategy(
     let num_possible_types = allowed_dtypes.bits().count_ones();
     assert!(num_possible_types > 0);
 
-    (0..num_possible_types).prop_flat_map(move |i| {
+    let layout_options = options.clone();
+    let series = (0..num_possible_types).prop_flat_map(move |i| {
         let selection =
             S::from_bits_retain(1 << nth_set_bit_u32(options.allowed_dtypes.bits(), i).unwrap());
 
@@ -180,7 +203,53 @@ pub fn series_strategy(
             .boxed(),
             _ => unreachable!(),
         }
-    })
+    });
+    series.prop_flat_map(move |series| series_layout_strategy(series, &layout_options))
+}
+
+/// Add nulls to `series`, sort it and split it into chunks, as configured in `options`.
+fn series_layout_strategy(
+    series: Series,
+    options: &SeriesArbitraryOptions,
+) -> impl Strategy<Value = Series> {
+    let len = series.len();
+    let sorted = options.sorted;
+    (
+        prop::collection::vec(prop::bool::weighted(options.null_probability), len),
+        prop::collection::vec(0..=len, 0..options.max_chunks.max(1)),
+        any::<bool>(),
+    )
+        .prop_map(move |(nulls, mut chunk_offsets, descending)| {
+            let mut series = series.clone();
+            if nulls.contains(&true) {
+                let idx = IdxCa::from_iter_options(
+                    "".into(),
+                    (0..len as IdxSize)
+                        .zip(&nulls)
+                        .map(|(i, null)| (!null).then_some(i)),
+                );
+                series = series.take(&idx).unwrap();
+            }
+            if sorted
+                && let Ok(s) = series.sort(SortOptions::default().with_order_descending(descending))
+            {
+                series = s;
+            }
+            let sorted_flag = series.is_sorted_flag();
+
+            chunk_offsets.push(len);
+            chunk_offsets.sort_unstable();
+            let mut chunked = series.slice(0, chunk_offsets[0]);
+            for bounds in chunk_offsets.windows(2) {
+                chunked
+                    .append_owned(series.slice(bounds[0] as i64, bounds[1] - bounds[0]))
+                    .unwrap();
+            }
+            if sorted_flag != IsSorted::Not {
+                chunked.set_sorted_flag(sorted_flag);
+            }
+            chunked
+        })
 }
 
 fn series_boolean_strategy(
@@ -462,3 +531,42 @@ fn series_struct_strategy(
         })
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_series_layout(
+            series in any_with::<Series>(SeriesArbitraryOptions {
+                allowed_dtypes: SeriesArbitrarySelection::BOOLEAN
+                    | SeriesArbitrarySelection::INT
+                    | SeriesArbitrarySelection::STRING,
+                series_length_range: 0..=20,
+                null_probability: 0.5,
+                max_chunks: 4,
+                sorted: true,
+                ..Default::default()
+            })
+        ) {
+            prop_assert!(series.n_chunks() <= 4);
+            let sorted = SortOptions::default()
+                .with_order_descending(series.is_sorted_flag() == IsSorted::Descending);
+            if series.is_sorted_flag() != IsSorted::Not {
+                prop_assert!(series.equals_missing(&series.sort(sorted).unwrap()));
+            }
+        }
+
+        #[test]
+        fn test_series_all_null(
+            series in any_with::<Series>(SeriesArbitraryOptions {
+                allowed_dtypes: SeriesArbitrarySelection::INT,
+                null_probability: 1.0,
+                ..Default::default()
+            })
+        ) {
+            prop_assert_eq!(series.null_count(), series.len());
+        }
+    }
+}
@@ -15,3 +15,28 @@ fn test_initial_empty_sort() -> PolarsResult<()> {
     series.f64()?.sort(false);
     Ok(())
 }
+
+#[test]
+fn test_install_pool() {
+    use crate::{POOL, new_thread_pool};
+
+    let pool = new_thread_pool(2);
+    let (outer, inner) = POOL.install_pool(&pool, || {
+        let inner = POOL.join(|| POOL.current_num_threads(), || ()).0;
+        (POOL.current_num_threads(), inner)
+    });
+    assert_eq!((outer, inner), (2, 2));
+    assert_eq!(
+        POOL.current_num_threads(),
+        crate::THREAD_POOL.current_num_threads()
+    );
+}
+
+#[test]
+fn test_map_partitions() {
+    use crate::POOL;
+
+    let out = POOL.map_partitions(10, |p| p * 2);
+    assert_eq!(out, (0..10).map(|p| p * 2).collect::<Vec<_>>());
+    assert!(POOL.map_partitions(0, |p| p).is_empty());
+}
@@ -410,6 +410,12 @@ pub fn get_supertype_with_options(
                 let tu = get_time_units(tu_l, tu_r);
                 Some(Datetime(tu, tz_r.clone()))
             }
+            // Categoricals of different origins are unified into the global categories, so that
+            // the supertype doesn't depend on the order of the inputs.
+            #[cfg(feature = "dtype-categorical")]
+            (Categorical(_, _), Categorical(_, _)) => {
+                Some(DataType::from_categories(Categories::global()))
+            },
             (List(inner_left), List(inner_right)) => {
                 let st = get_supertype(inner_left, inner_right)?;
                 Some(List(Box::new(st)))
@@ -0,0 +1,143 @@
+//! Derive macros for the Polars DataFrame library.
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, LitStr, Path, parse_macro_input};
+
+/// Derive `FrameSchema` for a struct with named fields, mapping every field to a column.
+///
+/// Every field type must be an owned type that implements `FrameColumn`. Besides the trait
+/// implementation, a `<field>_column(&DataFrame)` getter is generated for every field, which
+/// returns the column as the `ChunkedArray` matching the field type, after checking its dtype.
+///
+/// Attributes:
+/// * `#[frame_schema(crate = "path")]` on the struct: the path of the crate that exports the
+///   Polars `prelude` and `frame` modules; defaults to `polars`.
+/// * `#[frame_schema(rename = "name")]` on a field: the name of its column; defaults to the
+///   field name.
+#[proc_macro_derive(FrameSchema, attributes(frame_schema))]
+pub fn derive_frame_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut krate: Path = syn::parse_quote!(::polars);
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("frame_schema"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported frame_schema attribute"))
+            }
+        })?;
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "FrameSchema can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "FrameSchema can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut idents = Vec::with_capacity(fields.named.len());
+    let mut names = Vec::with_capacity(fields.named.len());
+    let mut types = Vec::with_capacity(fields.named.len());
+    for field in &fields.named {
+        let ident = field.ident.clone().unwrap();
+        let mut name = LitStr::new(&ident.to_string(), ident.span());
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("frame_schema"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = meta.value()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported frame_schema attribute"))
+                }
+            })?;
+        }
+        idents.push(ident);
+        names.push(name);
+        types.push(field.ty.clone());
+    }
+    // Prefixed, so that fields cannot shadow the arguments of the generated functions.
+    let locals = idents
+        .iter()
+        .map(|ident| format_ident!("__{}", ident))
+        .collect::<Vec<_>>();
+    let getters = idents
+        .iter()
+        .map(|ident| format_ident!("{}_column", ident))
+        .collect::<Vec<_>>();
+
+    let vis = &input.vis;
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let prelude = quote!(#krate::prelude);
+
+    Ok(quote! {
+        impl #impl_generics #prelude::FrameSchema for #ty #ty_generics #where_clause {
+            fn schema() -> #prelude::Schema {
+                #prelude::Schema::from_iter([
+                    #(#prelude::Field::new(
+                        #names.into(),
+                        <#types as #prelude::FrameColumn<'_>>::dtype(),
+                    ),)*
+                ])
+            }
+
+            fn into_frame(rows: ::std::vec::Vec<Self>) -> #prelude::PolarsResult<#prelude::DataFrame> {
+                #(let mut #locals = ::std::vec::Vec::with_capacity(rows.len());)*
+                for row in rows {
+                    #(#locals.push(row.#idents);)*
+                }
+                #prelude::DataFrame::new(::std::vec![
+                    #(#prelude::Column::from(
+                        <#types as #prelude::FrameColumn<'_>>::into_series(#names.into(), #locals),
+                    ),)*
+                ])
+            }
+
+            fn from_frame(df: &#prelude::DataFrame) -> #prelude::PolarsResult<::std::vec::Vec<Self>> {
+                #(
+                    let mut #locals = <#types as #prelude::FrameColumn<'_>>::iter(
+                        #krate::frame::typed::typed_column::<#types>(df, #names)?,
+                    )?;
+                )*
+                Ok((0..df.height())
+                    .map(|_| Self { #(#idents: #locals.next().unwrap(),)* })
+                    .collect())
+            }
+        }
+
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #(
+                #[doc = concat!("Get the `", #names, "` column of a frame of [`", stringify!(#ty), "`] rows.")]
+                #vis fn #getters(
+                    df: &#prelude::DataFrame,
+                ) -> #prelude::PolarsResult<
+                    &#prelude::ChunkedArray<<#types as #prelude::FrameColumn<'_>>::PolarsType>,
+                > {
+                    #krate::frame::typed::typed_column::<#types>(df, #names)
+                }
+            )*
+        }
+    })
+}
@@ -0,0 +1,72 @@
+use polars_core::prelude::*;
+use polars_derive::FrameSchema;
+
+#[derive(Debug, PartialEq, FrameSchema)]
+#[frame_schema(crate = "polars_core")]
+struct Trade {
+    id: u32,
+    #[frame_schema(rename = "sym")]
+    symbol: String,
+    price: f64,
+    volume: Option<i64>,
+}
+
+#[test]
+fn test_frame_schema_roundtrip() -> PolarsResult<()> {
+    let trades = vec![
+        Trade {
+            id: 1,
+            symbol: "A".into(),
+            price: 1.5,
+            volume: Some(10),
+        },
+        Trade {
+            id: 2,
+            symbol: "B".into(),
+            price: 2.5,
+            volume: None,
+        },
+    ];
+    let schema = Trade::schema();
+    assert_eq!(
+        schema.iter_names().map(|n| n.as_str()).collect::<Vec<_>>(),
+        ["id", "sym", "price", "volume"]
+    );
+    assert_eq!(schema.get("volume"), Some(&DataType::Int64));
+
+    let df = Trade::into_frame(trades)?;
+    assert_eq!(df.schema().as_ref(), &schema);
+    Trade::validate(&df)?;
+
+    let prices: &Float64Chunked = Trade::price_column(&df)?;
+    assert_eq!(prices.sum(), Some(4.0));
+    assert_eq!(Trade::symbol_column(&df)?.get(1), Some("B"));
+
+    let out = Trade::from_frame(&df)?;
+    assert_eq!(out[1].volume, None);
+    assert!(Trade::into_frame(out)?.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+fn test_frame_schema_mismatch() -> PolarsResult<()> {
+    let df = df! {
+        "id" => [1i64, 2],
+        "sym" => ["A", "B"],
+        "price" => [1.0, 2.0],
+        "volume" => [Some(1i64), None],
+    }?;
+    assert!(Trade::validate(&df).is_err());
+    assert!(Trade::id_column(&df).is_err());
+    assert!(Trade::from_frame(&df).is_err());
+
+    let df = df! {
+        "id" => [1u32, 2],
+        "sym" => ["A", "B"],
+        "price" => [Some(1.0), None],
+        "volume" => [Some(1i64), None],
+    }?;
+    Trade::validate(&df)?;
+    assert!(Trade::from_frame(&df).is_err());
+    Ok(())
+}
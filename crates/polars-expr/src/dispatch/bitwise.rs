@@ -15,6 +15,9 @@ pub fn function_expr_to_udf(func: IRBitwiseFunction) -> SpecialEq<Arc<dyn Column
         B::LeadingZeros => map!(leading_zeros),
         B::TrailingOnes => map!(trailing_ones),
         B::TrailingZeros => map!(trailing_zeros),
+        B::Not => map!(not),
+        B::ShiftLeft => map_as_slice!(shift_left),
+        B::ShiftRight => map_as_slice!(shift_right),
 
         B::And => map!(reduce_and),
         B::Or => map!(reduce_or),
@@ -46,6 +49,20 @@ fn trailing_zeros(c: &Column) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(polars_ops::series::trailing_zeros)
 }
 
+fn not(c: &Column) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(polars_ops::series::negate_bitwise)
+}
+
+fn shift_left(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::shift_left(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(Column::from)
+}
+
+fn shift_right(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::series::shift_right(s[0].as_materialized_series(), s[1].as_materialized_series())
+        .map(Column::from)
+}
+
 fn reduce_and(c: &Column) -> PolarsResult<Column> {
     c.and_reduce().map(|v| v.into_column(c.name().clone()))
 }
@@ -6,6 +6,11 @@ pub(super) fn cum_count(s: &Column, reverse: bool) -> PolarsResult<Column> {
     polars_ops::prelude::cum_count(s.as_materialized_series(), reverse).map(Column::from)
 }
 
+pub(super) fn cum_n_unique(s: &Column, reverse: bool) -> PolarsResult<Column> {
+    // @scalar-opt
+    polars_ops::prelude::cum_n_unique(s.as_materialized_series(), reverse).map(Column::from)
+}
+
 pub(super) fn cum_sum(s: &Column, reverse: bool) -> PolarsResult<Column> {
     // @scalar-opt
     polars_ops::prelude::cum_sum(s.as_materialized_series(), reverse).map(Column::from)
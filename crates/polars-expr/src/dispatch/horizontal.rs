@@ -125,8 +125,9 @@ pub fn cum_reduce(
         result.push(acc.clone());
     }
 
-    StructChunked::from_series(acc.name().clone(), result[0].len(), result.iter())
-        .map(|ca| ca.into_column())
+    // The initial value may be a scalar that has to be broadcast to the other fields.
+    let length = result.iter().map(Series::len).max().unwrap();
+    StructChunked::from_series(acc.name().clone(), length, result.iter()).map(|ca| ca.into_column())
 }
 
 #[cfg(feature = "dtype-struct")]
@@ -170,6 +171,11 @@ pub fn cum_fold(
         result.push(acc.clone());
     }
 
-    StructChunked::from_series(acc.name().clone(), result[0].len(), result.iter())
-        .map(|ca| ca.into_column())
+    polars_ensure!(
+        !result.is_empty(),
+        ComputeError: "`cum_fold` did not have any expressions to fold"
+    );
+    // The initial value may be a scalar that has to be broadcast to the other fields.
+    let length = result.iter().map(Series::len).max().unwrap();
+    StructChunked::from_series(acc.name().clone(), length, result.iter()).map(|ca| ca.into_column())
 }
@@ -6,7 +6,7 @@ use polars_core::series::ops::NullBehavior;
 use polars_core::series::{IsSorted, Series};
 use polars_core::utils::try_get_supertype;
 #[cfg(feature = "interpolate")]
-use polars_ops::series::InterpolationMethod;
+use polars_ops::series::InterpolateOptions;
 #[cfg(feature = "rank")]
 use polars_ops::series::RankOptions;
 use polars_ops::series::{ArgAgg, NullStrategy, SeriesMethods};
@@ -36,6 +36,30 @@ pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
         .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
 }
 
+#[cfg(feature = "quantile_approx")]
+pub(super) fn quantile_approx(
+    s: &Column,
+    quantile: f64,
+    compression: usize,
+) -> PolarsResult<Column> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() || s.dtype().is_null(),
+        opq = quantile_approx,
+        s.dtype()
+    );
+    let values = s.cast(&DataType::Float64)?;
+    let mut digest = polars_compute::tdigest::TDigest::new(compression);
+    for arr in values.f64()?.downcast_iter() {
+        arr.non_null_values_iter().for_each(|x| digest.insert(x));
+    }
+    let value = digest.quantile(quantile);
+    Ok(Column::new_scalar(
+        s.name().clone(),
+        Scalar::new(DataType::Float64, value.into()),
+        1,
+    ))
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
@@ -54,13 +78,22 @@ pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> PolarsResult<Co
 
 #[cfg(feature = "pct_change")]
 pub(super) fn pct_change(s: &[Column]) -> PolarsResult<Column> {
+    if let Some(fill_value) = s.get(2) {
+        return polars_ops::prelude::pct_change_with_fill(
+            s[0].as_materialized_series(),
+            s[1].as_materialized_series(),
+            fill_value.as_materialized_series(),
+        )
+        .map(Column::from);
+    }
     polars_ops::prelude::pct_change(s[0].as_materialized_series(), s[1].as_materialized_series())
         .map(Column::from)
 }
 
 #[cfg(feature = "interpolate")]
-pub(super) fn interpolate(s: &Column, method: InterpolationMethod) -> PolarsResult<Column> {
-    Ok(polars_ops::prelude::interpolate(s.as_materialized_series(), method).into())
+pub(super) fn interpolate(s: &Column, options: InterpolateOptions) -> PolarsResult<Column> {
+    polars_ops::prelude::interpolate_with_options(s.as_materialized_series(), options)
+        .map(Column::from)
 }
 
 #[cfg(feature = "interpolate_by")]
@@ -259,6 +292,24 @@ pub(super) fn rank(s: &Column, options: RankOptions, seed: Option<u64>) -> Polar
     Ok(s.as_materialized_series().rank(options, seed).into_column())
 }
 
+#[cfg(feature = "rank")]
+pub(super) fn percent_rank(s: &Column, descending: bool) -> PolarsResult<Column> {
+    use polars_ops::series::SeriesRank;
+
+    Ok(s.as_materialized_series()
+        .percent_rank(descending)
+        .into_column())
+}
+
+#[cfg(feature = "rank")]
+pub(super) fn ntile(s: &Column, n: usize, descending: bool) -> PolarsResult<Column> {
+    use polars_ops::series::SeriesRank;
+
+    s.as_materialized_series()
+        .ntile(n, descending)
+        .map(Column::from)
+}
+
 #[cfg(feature = "hist")]
 pub(super) fn hist(
     s: &[Column],
@@ -356,6 +407,17 @@ pub(super) fn row_hash(c: &Column, k0: u64, k1: u64, k2: u64, k3: u64) -> Polars
         .into_column())
 }
 
+#[cfg(feature = "row_hash")]
+pub(super) fn hash_with_algorithm(
+    c: &Column,
+    algorithm: polars_ops::series::HashAlgorithm,
+    seed: u64,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    polars_ops::series::hash_with_algorithm(c.as_materialized_series(), algorithm, seed)
+        .map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "arg_where")]
 pub(super) fn arg_where(s: &mut [Column]) -> PolarsResult<Column> {
     use polars_core::utils::arrow::bitmap::utils::SlicesIterator;
@@ -563,6 +625,11 @@ pub(super) fn coalesce(s: &mut [Column]) -> PolarsResult<Column> {
     polars_ops::series::coalesce_columns(s)
 }
 
+/// Both inputs were evaluated successfully, so the primary input is returned as is.
+pub(super) fn or_else(s: &mut [Column]) -> PolarsResult<Column> {
+    Ok(s[0].clone())
+}
+
 pub(super) fn drop_nans(s: Column) -> PolarsResult<Column> {
     match s.dtype() {
         #[cfg(feature = "dtype-f16")]
@@ -665,6 +732,25 @@ pub(super) fn exp(s: &Column) -> PolarsResult<Column> {
     Ok(s.as_materialized_series().exp().into())
 }
 
+#[cfg(feature = "log")]
+pub(super) fn expm1(s: &Column) -> PolarsResult<Column> {
+    use polars_ops::series::LogSeries;
+
+    Ok(s.as_materialized_series().expm1().into())
+}
+
+pub(super) fn gamma(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::gamma(s.as_materialized_series()).map(Column::from)
+}
+
+pub(super) fn lgamma(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::lgamma(s.as_materialized_series()).map(Column::from)
+}
+
+pub(super) fn erf(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::erf(s.as_materialized_series()).map(Column::from)
+}
+
 pub(super) fn unique(s: &Column, stable: bool) -> PolarsResult<Column> {
     if stable {
         s.unique_stable()
@@ -699,6 +785,25 @@ pub(super) fn concat_expr(s: &[Column], rechunk: bool) -> PolarsResult<Column> {
     Ok(first)
 }
 
+#[cfg(feature = "dtype-struct")]
+pub(super) fn lstsq(s: &[Column], fit_intercept: bool) -> PolarsResult<Column> {
+    let x = s[1..]
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect::<Vec<_>>();
+    polars_ops::series::lstsq(s[0].as_materialized_series(), &x, fit_intercept).map(Column::from)
+}
+
+#[cfg(feature = "dtype-struct")]
+pub(super) fn polyfit(s: &[Column], degree: usize) -> PolarsResult<Column> {
+    polars_ops::series::polyfit(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        degree,
+    )
+    .map(Column::from)
+}
+
 #[cfg(feature = "cov")]
 pub(super) fn corr(s: &[Column], method: IRCorrelationMethod) -> PolarsResult<Column> {
     use polars_plan::plans::IRCorrelationMethod;
@@ -833,6 +938,14 @@ pub(super) fn corr(s: &[Column], method: IRCorrelationMethod) -> PolarsResult<Co
     }
 }
 
+pub(super) fn standardize(s: &Column, ddof: u8) -> PolarsResult<Column> {
+    polars_ops::series::standardize(s.as_materialized_series(), ddof).map(Column::from)
+}
+
+pub(super) fn min_max_scale(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::min_max_scale(s.as_materialized_series()).map(Column::from)
+}
+
 #[cfg(feature = "peaks")]
 pub(super) fn peak_min(s: &Column) -> PolarsResult<Column> {
     polars_ops::prelude::peaks::peak_min_max(s, &AnyValue::Int8(0), &AnyValue::Int8(0), false)
@@ -845,6 +958,11 @@ pub(super) fn peak_max(s: &Column) -> PolarsResult<Column> {
         .map(IntoColumn::into_column)
 }
 
+#[cfg(feature = "peaks")]
+pub(super) fn local_min_max(s: &Column, window: usize, is_max: bool) -> PolarsResult<Column> {
+    polars_ops::prelude::peaks::local_min_max(s, window, is_max).map(IntoColumn::into_column)
+}
+
 #[cfg(feature = "cutqcut")]
 pub(super) fn cut(
     s: &Column,
@@ -863,6 +981,11 @@ pub(super) fn cut(
     .map(Column::from)
 }
 
+#[cfg(feature = "cutqcut")]
+pub(super) fn digitize(s: &Column, breaks: &[f64], left_closed: bool) -> PolarsResult<Column> {
+    polars_ops::prelude::digitize(s.as_materialized_series(), breaks, left_closed).map(Column::from)
+}
+
 #[cfg(feature = "cutqcut")]
 pub(super) fn qcut(
     s: &Column,
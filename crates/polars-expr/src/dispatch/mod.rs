@@ -179,6 +179,10 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Hash(k0, k1, k2, k3) => {
             map!(misc::row_hash, k0, k1, k2, k3)
         },
+        #[cfg(feature = "row_hash")]
+        F::HashWithAlgorithm { algorithm, seed } => {
+            map!(misc::hash_with_algorithm, algorithm, seed)
+        },
         #[cfg(feature = "arg_where")]
         F::ArgWhere => {
             wrap!(misc::arg_where)
@@ -244,6 +248,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
                 },
             }
         },
+        #[cfg(feature = "rolling_window")]
+        F::RollingFrame { agg, frame } => map!(rolling::rolling_frame, agg, frame),
         #[cfg(feature = "rolling_window_by")]
         F::RollingExprBy {
             function_by,
@@ -300,6 +306,10 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Repeat => map_as_slice!(misc::repeat),
         #[cfg(feature = "rank")]
         F::Rank { options, seed } => map!(misc::rank, options, seed),
+        #[cfg(feature = "rank")]
+        F::PercentRank { descending } => map!(misc::percent_rank, descending),
+        #[cfg(feature = "rank")]
+        F::NTile { n, descending } => map!(misc::ntile, n, descending),
         #[cfg(feature = "dtype-struct")]
         F::AsStruct => {
             map_as_slice!(misc::as_struct)
@@ -316,6 +326,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         #[cfg(feature = "cum_agg")]
         F::CumCount { reverse } => map!(cum::cum_count, reverse),
         #[cfg(feature = "cum_agg")]
+        F::CumNUnique { reverse } => map!(cum::cum_n_unique, reverse),
+        #[cfg(feature = "cum_agg")]
         F::CumSum { reverse } => map!(cum::cum_sum, reverse),
         #[cfg(feature = "cum_agg")]
         F::CumProd { reverse } => map!(cum::cum_prod, reverse),
@@ -335,14 +347,20 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Reverse => map!(misc::reverse),
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => map!(misc::approx_n_unique),
+        #[cfg(feature = "quantile_approx")]
+        F::QuantileApprox {
+            quantile,
+            compression,
+        } => map!(misc::quantile_approx, quantile, compression),
         F::Coalesce => map_as_slice!(misc::coalesce),
+        F::OrElse => map_as_slice!(misc::or_else),
         #[cfg(feature = "diff")]
         F::Diff(null_behavior) => map_as_slice!(misc::diff, null_behavior),
         #[cfg(feature = "pct_change")]
         F::PctChange => map_as_slice!(misc::pct_change),
         #[cfg(feature = "interpolate")]
-        F::Interpolate(method) => {
-            map!(misc::interpolate, method)
+        F::Interpolate(options) => {
+            map!(misc::interpolate, options)
         },
         #[cfg(feature = "interpolate_by")]
         F::InterpolateBy => {
@@ -356,6 +374,11 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Log1p => map!(misc::log1p),
         #[cfg(feature = "log")]
         F::Exp => map!(misc::exp),
+        #[cfg(feature = "log")]
+        F::Expm1 => map!(misc::expm1),
+        F::Gamma => map!(misc::gamma),
+        F::LGamma => map!(misc::lgamma),
+        F::Erf => map!(misc::erf),
         F::Unique(stable) => map!(misc::unique, stable),
         #[cfg(feature = "round_series")]
         F::Round { decimals, mode } => map!(round::round, decimals, mode),
@@ -370,10 +393,20 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::ConcatExpr(rechunk) => map_as_slice!(misc::concat_expr, rechunk),
         #[cfg(feature = "cov")]
         F::Correlation { method } => map_as_slice!(misc::corr, method),
+        #[cfg(feature = "dtype-struct")]
+        F::Lstsq { fit_intercept } => map_as_slice!(misc::lstsq, fit_intercept),
+        #[cfg(feature = "dtype-struct")]
+        F::PolyFit { degree } => map_as_slice!(misc::polyfit, degree),
+        F::Standardize { ddof } => map!(misc::standardize, ddof),
+        F::MinMaxScale => map!(misc::min_max_scale),
         #[cfg(feature = "peaks")]
         F::PeakMin => map!(misc::peak_min),
         #[cfg(feature = "peaks")]
         F::PeakMax => map!(misc::peak_max),
+        #[cfg(feature = "peaks")]
+        F::LocalMin { window } => map!(misc::local_min_max, window, false),
+        #[cfg(feature = "peaks")]
+        F::LocalMax { window } => map!(misc::local_min_max, window, true),
         #[cfg(feature = "repeat_by")]
         F::RepeatBy => map_as_slice!(misc::repeat_by),
         #[cfg(feature = "dtype-array")]
@@ -392,6 +425,11 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
             include_breaks
         ),
         #[cfg(feature = "cutqcut")]
+        F::Digitize {
+            breaks,
+            left_closed,
+        } => map!(misc::digitize, &breaks, left_closed),
+        #[cfg(feature = "cutqcut")]
         F::QCut {
             probs,
             labels,
@@ -428,6 +466,9 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
                         map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                     }
                 },
+                Uniform { low, high } => map!(random::uniform, low, high, seed),
+                Normal { mean, std } => map!(random::normal, mean, std, seed),
+                Bernoulli { p } => map!(random::bernoulli, p, seed),
             }
         },
         F::SetSortedFlag(sorted) => map!(misc::set_sorted_flag, sorted),
@@ -1,6 +1,6 @@
 use polars_core::error::{PolarsResult, polars_ensure};
 use polars_core::prelude::DataType::Float64;
-use polars_core::prelude::{Column, IDX_DTYPE};
+use polars_core::prelude::{BooleanChunked, Column, Float64Chunked, IDX_DTYPE, IntoColumn};
 
 pub(super) fn shuffle(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
     Ok(s.shuffle(seed))
@@ -51,3 +51,28 @@ pub(super) fn sample_n(
         None => Ok(Column::new_empty(src.name().clone(), src.dtype())),
     }
 }
+
+fn sample_len(n: &Column) -> PolarsResult<usize> {
+    polars_ensure!(
+        n.len() == 1,
+        ComputeError: "Number of samples must be a single value."
+    );
+    let n = n.strict_cast(&IDX_DTYPE)?;
+    let n = n.idx()?.get(0);
+    Ok(n.unwrap_or(0) as usize)
+}
+
+pub(super) fn uniform(n: &Column, low: f64, high: f64, seed: Option<u64>) -> PolarsResult<Column> {
+    let len = sample_len(n)?;
+    Float64Chunked::rand_uniform(n.name().clone(), len, low, high, seed).map(|ca| ca.into_column())
+}
+
+pub(super) fn normal(n: &Column, mean: f64, std: f64, seed: Option<u64>) -> PolarsResult<Column> {
+    let len = sample_len(n)?;
+    Float64Chunked::rand_normal(n.name().clone(), len, mean, std, seed).map(|ca| ca.into_column())
+}
+
+pub(super) fn bernoulli(n: &Column, p: f64, seed: Option<u64>) -> PolarsResult<Column> {
+    let len = sample_len(n)?;
+    BooleanChunked::rand_bernoulli(n.name().clone(), len, p, seed).map(|ca| ca.into_column())
+}
@@ -20,6 +20,10 @@ pub(super) fn linear_space(s: &[Column], closed: ClosedInterval) -> PolarsResult
 
     let start = start.get(0).unwrap();
     let end = end.get(0).unwrap();
+    polars_ensure!(
+        !start.is_null() && !end.is_null(),
+        ComputeError: "invalid null input for `linear_space`"
+    );
     let num_samples = num_samples.get(0).unwrap();
     let num_samples = num_samples
         .extract::<u64>()
@@ -11,7 +11,7 @@ use polars_core::series::Series;
 #[cfg(feature = "cov")]
 use polars_plan::dsl::RollingCovOptions;
 use polars_plan::prelude::PlanCallback;
-use polars_time::prelude::SeriesOpsTime;
+use polars_time::prelude::{FrameAggregation, SeriesOpsTime, WindowFrame};
 use polars_utils::pl_str::PlSmallStr;
 
 fn roll_with_temporal_conversion<F: FnOnce(&Series) -> PolarsResult<Series>>(
@@ -65,6 +65,21 @@ pub(super) fn rolling_sum(s: &Column, options: RollingOptionsFixedWindow) -> Pol
         .map(Column::from)
 }
 
+pub(super) fn rolling_frame(
+    s: &Column,
+    agg: FrameAggregation,
+    frame: WindowFrame,
+) -> PolarsResult<Column> {
+    match agg {
+        FrameAggregation::Mean => {
+            roll_with_temporal_conversion(s, |s| polars_time::prelude::rolling_frame(s, agg, frame))
+        },
+        // @scalar-opt
+        _ => polars_time::prelude::rolling_frame(s.as_materialized_series(), agg, frame)
+            .map(Column::from),
+    }
+}
+
 pub(super) fn rolling_quantile(
     s: &Column,
     options: RollingOptionsFixedWindow,
@@ -14,34 +14,31 @@ where
     ca.shift_and_fill(n, fill_value)
 }
 
-#[cfg(any(
-    feature = "object",
-    feature = "dtype-struct",
-    feature = "dtype-categorical"
-))]
 fn shift_and_fill_with_mask(s: &Column, n: i64, fill_value: &Column) -> PolarsResult<Column> {
     use arrow::array::BooleanArray;
     use arrow::bitmap::BitmapBuilder;
     use polars_core::prelude::BooleanChunked;
 
+    let len = s.len();
     let mask: BooleanChunked = if n > 0 {
-        let len = s.len();
+        let n = (n as usize).min(len);
         let mut bits = BitmapBuilder::with_capacity(s.len());
-        bits.extend_constant(n as usize, false);
-        bits.extend_constant(len.saturating_sub(n as usize), true);
+        bits.extend_constant(n, false);
+        bits.extend_constant(len - n, true);
         let mask = BooleanArray::from_data_default(bits.freeze(), None);
         mask.into()
     } else {
-        let length = s.len() as i64;
         // n is negative, so subtraction.
-        let tipping_point = std::cmp::max(length + n, 0);
+        let tipping_point = len.saturating_sub(n.unsigned_abs() as usize);
         let mut bits = BitmapBuilder::with_capacity(s.len());
-        bits.extend_constant(tipping_point as usize, true);
-        bits.extend_constant(-n as usize, false);
+        bits.extend_constant(tipping_point, true);
+        bits.extend_constant(len - tipping_point, false);
         let mask = BooleanArray::from_data_default(bits.freeze(), None);
         mask.into()
     };
-    s.shift(n).zip_with_same_type(&mask, fill_value)
+    // The output keeps the dtype of `s`, the fill value may be of another (castable) dtype.
+    let fill_value = fill_value.strict_cast(s.dtype())?;
+    s.shift(n).zip_with_same_type(&mask, &fill_value)
 }
 
 pub(super) fn shift_and_fill(args: &[Column]) -> PolarsResult<Column> {
@@ -53,6 +50,16 @@ pub(super) fn shift_and_fill(args: &[Column]) -> PolarsResult<Column> {
         let logical = s.dtype();
         let physical = s.to_physical_repr();
         let fill_value_s = &args[2];
+
+        // A fill value per row, e.g. from another column.
+        if fill_value_s.len() != 1 {
+            polars_ensure!(
+                fill_value_s.len() == s.len(),
+                ShapeMismatch: "fill value of `shift_and_fill` should have length 1 or {}, got {}",
+                s.len(), fill_value_s.len()
+            );
+            return shift_and_fill_with_mask(s, n, fill_value_s);
+        }
         let fill_value = fill_value_s.get(0).unwrap();
 
         use DataType::*;
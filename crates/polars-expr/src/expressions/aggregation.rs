@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use arrow::legacy::utils::CustomIterTools;
 use polars_compute::rolling::QuantileMethod;
 use polars_core::POOL;
+use polars_core::config::ConfigScope;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{_split_offsets, NoNull};
@@ -204,12 +205,12 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Mean => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_mean(&groups);
+                    let agg_c = c.try_agg_mean(&groups)?;
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Sum => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_sum(&groups);
+                    let agg_c = c.try_agg_sum(&groups)?;
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
@@ -570,12 +571,13 @@ where
     let n_threads = POOL.current_num_threads();
     let splits = _split_offsets(s.len(), n_threads);
 
+    let config = ConfigScope::current();
     let chunks = POOL.install(|| {
         splits
             .into_par_iter()
             .map(|(offset, len)| {
                 let s = s.slice(offset as i64, len);
-                f(s)
+                config.enter(|| f(s))
             })
             .collect::<PolarsResult<Vec<_>>>()
     })?;
@@ -71,7 +71,11 @@ impl ApplyExpr {
         groups: &'a GroupPositions,
         state: &ExecutionState,
     ) -> PolarsResult<Vec<AggregationContext<'a>>> {
-        let f = |e: &Arc<dyn PhysicalExpr>| e.evaluate_on_groups(df, groups, state);
+        let f = |e: &Arc<dyn PhysicalExpr>| {
+            state
+                .config
+                .enter(|| e.evaluate_on_groups(df, groups, state))
+        };
         if self.allow_threading {
             POOL.install(|| self.inputs.par_iter().map(f).collect())
         } else {
@@ -433,7 +437,7 @@ impl PhysicalExpr for ApplyExpr {
     }
 
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
-        let f = |e: &Arc<dyn PhysicalExpr>| e.evaluate(df, state);
+        let f = |e: &Arc<dyn PhysicalExpr>| state.config.enter(|| e.evaluate(df, state));
         let mut inputs = if self.allow_threading && self.inputs.len() > 1 {
             POOL.install(|| {
                 self.inputs
@@ -11,6 +11,7 @@ mod filter;
 mod gather;
 mod group_iter;
 mod literal;
+mod or_else;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
 mod slice;
@@ -37,6 +38,7 @@ pub(crate) use eval::*;
 pub(crate) use filter::*;
 pub(crate) use gather::*;
 pub(crate) use literal::*;
+pub(crate) use or_else::*;
 use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::prelude::*;
@@ -690,6 +692,32 @@ impl<'a> AggregationContext<'a> {
     }
 }
 
+/// Add the expression that failed to evaluate, and the plan node it was evaluated in, to the
+/// message of `err`, so that the culprit can be found among the many expressions of a node.
+///
+/// The variant of the error is kept. Errors that already name the expression they originated
+/// in are returned as is, as are Python exceptions, so that they are re-raised unchanged.
+pub fn add_expr_provenance(err: PolarsError, expr: &dyn PhysicalExpr, node: &str) -> PolarsError {
+    let Some(expr) = expr.as_expression() else {
+        return err;
+    };
+    #[cfg(feature = "python")]
+    if let PolarsError::Python { .. } = err {
+        return err;
+    }
+    if let PolarsError::Context { .. } = err {
+        // `wrap_msg` would drop the context.
+        return err.context(format!("error originated in expression: '{expr}' (in {node})").into());
+    }
+    err.wrap_msg(|msg| {
+        if msg.contains("Error originated in expression") {
+            msg.to_string()
+        } else {
+            format!("{msg}\n\nError originated in expression: '{expr}' (in {node})")
+        }
+    })
+}
+
 /// Take a DataFrame and evaluate the expressions.
 /// Implement this for Column, lt, eq, etc
 pub trait PhysicalExpr: Send + Sync {
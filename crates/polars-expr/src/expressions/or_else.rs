@@ -0,0 +1,90 @@
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::{AggregationContext, PhysicalExpr};
+
+/// Evaluates `fallback` if evaluating `primary` fails with a recoverable error.
+pub struct OrElseExpr {
+    primary: Arc<dyn PhysicalExpr>,
+    fallback: Arc<dyn PhysicalExpr>,
+    expr: Expr,
+    output_field: Field,
+    is_scalar: bool,
+}
+
+impl OrElseExpr {
+    pub fn new(
+        primary: Arc<dyn PhysicalExpr>,
+        fallback: Arc<dyn PhysicalExpr>,
+        expr: Expr,
+        output_field: Field,
+        is_scalar: bool,
+    ) -> Self {
+        Self {
+            primary,
+            fallback,
+            expr,
+            output_field,
+            is_scalar,
+        }
+    }
+}
+
+/// Errors that are raised by the data rather than by the query, e.g. failed casts or parses.
+fn is_recoverable(mut err: &PolarsError) -> bool {
+    while let PolarsError::Context { error, .. } = err {
+        err = error;
+    }
+    matches!(
+        err,
+        PolarsError::ComputeError(_) | PolarsError::InvalidOperation(_)
+    )
+}
+
+impl PhysicalExpr for OrElseExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        match self.primary.evaluate(df, state) {
+            Err(err) if is_recoverable(&err) => {
+                let mut out = self.fallback.evaluate(df, state)?;
+                if out.len() == 1 && !self.is_scalar {
+                    out = out.new_from_index(0, df.height());
+                }
+                Ok(out.with_name(self.output_field.name().clone()))
+            },
+            out => out,
+        }
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        match self.primary.evaluate_on_groups(df, groups, state) {
+            Err(err) if is_recoverable(&err) => {
+                let mut ac = self.fallback.evaluate_on_groups(df, groups, state)?;
+                let c = ac.take().with_name(self.output_field.name().clone());
+                if ac.is_literal() {
+                    ac.with_literal(c);
+                } else {
+                    ac.with_values(c, ac.is_aggregated(), Some(&self.expr))?;
+                }
+                Ok(ac)
+            },
+            ac => ac,
+        }
+    }
+
+    fn to_field(&self, _input_schema: &Schema) -> PolarsResult<Field> {
+        Ok(self.output_field.clone())
+    }
+
+    fn is_scalar(&self) -> bool {
+        self.is_scalar
+    }
+}
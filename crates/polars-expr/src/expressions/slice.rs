@@ -87,7 +87,7 @@ impl PhysicalExpr for SliceExpr {
         let results = POOL.install(|| {
             [&self.offset, &self.length, &self.input]
                 .par_iter()
-                .map(|e| e.evaluate(df, state))
+                .map(|e| state.config.enter(|| e.evaluate(df, state)))
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
         let offset = &results[0];
@@ -107,7 +107,11 @@ impl PhysicalExpr for SliceExpr {
         let mut results = POOL.install(|| {
             [&self.offset, &self.length, &self.input]
                 .par_iter()
-                .map(|e| e.evaluate_on_groups(df, groups, state))
+                .map(|e| {
+                    state
+                        .config
+                        .enter(|| e.evaluate_on_groups(df, groups, state))
+                })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
         let mut ac = results.pop().unwrap();
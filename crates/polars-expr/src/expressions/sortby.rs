@@ -1,5 +1,6 @@
 use polars_core::POOL;
 use polars_core::chunked_array::from_iterator_par::ChunkedCollectParIterExt;
+use polars_core::prelude::sort::arg_sort;
 use polars_core::prelude::*;
 use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
@@ -161,10 +162,7 @@ fn sort_by_groups_multiple_by(
                 limit: None,
             };
 
-            let sorted_idx = groups[0]
-                .as_materialized_series()
-                .arg_sort_multiple(&groups[1..], &options)
-                .unwrap();
+            let sorted_idx = arg_sort(&groups, options).unwrap();
             map_sorted_indices_to_group_idx(&sorted_idx, idx)
         },
         GroupsIndicator::Slice([first, len]) => {
@@ -181,10 +179,7 @@ fn sort_by_groups_multiple_by(
                 maintain_order,
                 limit: None,
             };
-            let sorted_idx = groups[0]
-                .as_materialized_series()
-                .arg_sort_multiple(&groups[1..], &options)
-                .unwrap();
+            let sorted_idx = arg_sort(&groups, options).unwrap();
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
@@ -269,9 +264,7 @@ impl PhysicalExpr for SortByExpr {
                     .with_order_descending_multi(descending)
                     .with_nulls_last_multi(nulls_last);
 
-                s_sort_by[0]
-                    .as_materialized_series()
-                    .arg_sort_multiple(&s_sort_by[1..], &options)
+                arg_sort(&s_sort_by, options)
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         };
@@ -508,6 +508,18 @@ fn create_physical_expr_inner(
                 .get(expression)
                 .to_field(&ToFieldContext::new(expr_arena, schema))?;
             let input = create_physical_expressions_from_irs(input, expr_arena, schema, state)?;
+
+            // Special case: the fallback is only evaluated if the primary expression fails.
+            if let IRFunctionExpr::OrElse = function {
+                return Ok(Arc::new(OrElseExpr::new(
+                    input[0].clone(),
+                    input[1].clone(),
+                    node_to_expr(expression, expr_arena),
+                    output_field,
+                    is_scalar,
+                )));
+            }
+
             let is_fallible = expr_arena.get(expression).is_fallible_top_level(expr_arena);
 
             Ok(Arc::new(ApplyExpr::new(
@@ -16,6 +16,8 @@ use crate::reduce::first_last_nonnull::{new_first_nonnull_reduction, new_last_no
 use crate::reduce::len::LenReduce;
 use crate::reduce::mean::new_mean_reduction;
 use crate::reduce::min_max::{new_max_reduction, new_min_reduction};
+#[cfg(feature = "quantile_approx")]
+use crate::reduce::quantile_approx::new_quantile_approx_reduction;
 use crate::reduce::sum::new_sum_reduction;
 use crate::reduce::var_std::new_var_std_reduction;
 
@@ -115,6 +117,22 @@ pub fn into_reduction(
             (out, input)
         },
 
+        #[cfg(feature = "quantile_approx")]
+        AExpr::Function {
+            input: inner_exprs,
+            function:
+                IRFunctionExpr::QuantileApprox {
+                    quantile,
+                    compression,
+                },
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = new_quantile_approx_reduction(get_dt(input)?, *quantile, *compression)?;
+            (out, input)
+        },
+
         #[cfg(feature = "bitwise")]
         AExpr::Function {
             input: inner_exprs,
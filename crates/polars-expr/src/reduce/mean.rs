@@ -18,7 +18,7 @@ pub fn new_mean_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReduct
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalMeanReducer)),
         Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
             DataType::Null,
         ))),
@@ -50,15 +50,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(*scale as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const US_IN_DAY: f64 = MICROSECONDS_IN_DAY as f64;
@@ -131,6 +122,81 @@ where
     }
 }
 
+/// Keeps the exact sum, so that the mean is computed at the scale given by
+/// [`dec128_mean_scale`](polars_compute::decimal::dec128_mean_scale) instead of going through
+/// `f64`. The sum is `None` once it overflowed, which errors when finishing.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalMeanReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMeanReducer {
+    type Dtype = Int128Type;
+    type Value = (Option<i128>, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (Some(0), 0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 = a.0.zip(b.0).and_then(|(l, r)| l.checked_add(r));
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.0 = a.0.and_then(|a| a.checked_add(b));
+            a.1 += 1;
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        v.0 = ca
+            .iter()
+            .flatten()
+            .try_fold(v.0.unwrap_or(0), i128::checked_add)
+            .filter(|_| v.0.is_some());
+        v.1 += ca.len() - ca.null_count();
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        use polars_compute::decimal::{DEC128_MAX_PREC, dec128_mean, dec128_mean_scale};
+
+        assert!(m.is_none());
+        let DataType::Decimal(_, scale) = dtype else {
+            unreachable!()
+        };
+        let ca: Int128Chunked = v
+            .into_iter()
+            .map(|(s, c)| {
+                if c == 0 {
+                    return Ok(None);
+                }
+                s.and_then(|s| dec128_mean(s, c, *scale))
+                    .map(Some)
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "overflow in the Decimal sum of a mean"),
+                    )
+            })
+            .try_collect_ca(PlSmallStr::EMPTY)?;
+        Ok(ca
+            .into_decimal_unchecked(DEC128_MAX_PREC, dec128_mean_scale(*scale))
+            .into_series())
+    }
+}
+
 #[derive(Clone)]
 struct BoolMeanReducer;
 
@@ -11,6 +11,8 @@ mod first_last_nonnull;
 mod len;
 mod mean;
 mod min_max;
+#[cfg(feature = "quantile_approx")]
+mod quantile_approx;
 mod sum;
 mod var_std;
 
@@ -0,0 +1,77 @@
+use polars_compute::tdigest::TDigest;
+
+use super::*;
+
+pub fn new_quantile_approx_reduction(
+    dtype: DataType,
+    quantile: f64,
+    compression: usize,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use VecGroupedReduction as VGR;
+    Ok(match dtype {
+        _ if dtype.is_primitive_numeric() => Box::new(VGR::new(
+            dtype,
+            QuantileApproxReducer {
+                quantile,
+                compression,
+            },
+        )),
+        DataType::Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
+            DataType::Float64,
+        ))),
+        _ => {
+            polars_bail!(InvalidOperation: "`quantile_approx` operation not supported for dtype `{dtype}`")
+        },
+    })
+}
+
+#[derive(Clone)]
+struct QuantileApproxReducer {
+    quantile: f64,
+    compression: usize,
+}
+
+impl Reducer for QuantileApproxReducer {
+    type Dtype = Float64Type;
+    type Value = TDigest;
+
+    fn init(&self) -> Self::Value {
+        TDigest::new(self.compression)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        Cow::Owned(s.cast(&DataType::Float64).unwrap())
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.merge(b)
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<f64>, _seq_id: u64) {
+        if let Some(x) = b {
+            a.insert(x);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            arr.non_null_values_iter().for_each(|x| v.insert(x));
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let ca: Float64Chunked = v
+            .into_iter()
+            .map(|mut digest| digest.quantile(self.quantile))
+            .collect_ca(PlSmallStr::EMPTY);
+        Ok(ca.into_series())
+    }
+}
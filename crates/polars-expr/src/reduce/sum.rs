@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
 use arrow::array::PrimitiveArray;
-use num_traits::Zero;
-use polars_core::with_match_physical_numeric_polars_type;
+use num_traits::{CheckedAdd, SaturatingAdd, Zero};
+use polars_core::config::{IntegerOverflow, integer_overflow};
+use polars_core::{
+    with_match_physical_integer_polars_type, with_match_physical_numeric_polars_type,
+};
 use polars_utils::float::IsFloat;
 #[cfg(feature = "dtype-f16")]
 use polars_utils::float16::pf16;
@@ -50,8 +53,14 @@ pub fn new_sum_reduction(dtype: DataType) -> PolarsResult<Box<dyn GroupedReducti
     // TODO: Move the error checks up and make this function infallible
     use DataType::*;
     use VecGroupedReduction as VGR;
+    let overflow = integer_overflow();
     Ok(match dtype {
         Boolean => Box::new(VGR::new(dtype, BoolSumReducer)),
+        _ if dtype.is_integer() && overflow != IntegerOverflow::Wrap => {
+            with_match_physical_integer_polars_type!(&dtype, |$T| {
+                Box::new(VGR::new(dtype, CheckedIntSumReducer::<$T>(overflow, PhantomData)))
+            })
+        },
         _ if dtype.is_primitive_numeric() => {
             with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
                 Box::new(VGR::new(dtype, NumSumReducer::<$T>(PhantomData)))
@@ -141,6 +150,89 @@ where
     }
 }
 
+/// Sums integers without wrapping on overflow, see [`IntegerOverflow`]. A sum that overflowed
+/// with [`IntegerOverflow::Raise`] is `None`.
+struct CheckedIntSumReducer<T>(IntegerOverflow, PhantomData<T>);
+impl<T> Clone for CheckedIntSumReducer<T> {
+    fn clone(&self) -> Self {
+        Self(self.0, PhantomData)
+    }
+}
+
+impl<T> CheckedIntSumReducer<T>
+where
+    T: PolarsIntegerType,
+    <T as PolarsNumericType>::Native: SumCast,
+    <T::Native as SumCast>::Sum: CheckedAdd + SaturatingAdd,
+{
+    #[inline(always)]
+    fn add(
+        &self,
+        a: Option<<T::Native as SumCast>::Sum>,
+        b: <T::Native as SumCast>::Sum,
+    ) -> Option<<T::Native as SumCast>::Sum> {
+        match self.0 {
+            IntegerOverflow::Saturate => a.map(|a| a.saturating_add(&b)),
+            _ => a.and_then(|a| a.checked_add(&b)),
+        }
+    }
+}
+
+impl<T> Reducer for CheckedIntSumReducer<T>
+where
+    T: PolarsIntegerType,
+    <T as PolarsNumericType>::Native: SumCast,
+    <T::Native as SumCast>::Sum: CheckedAdd + SaturatingAdd,
+{
+    type Dtype = T;
+    type Value = Option<<T::Native as SumCast>::Sum>;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        Some(Zero::zero())
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        *a = b.and_then(|b| self.add(*a, b));
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<T::Native>, _seq_id: u64) {
+        if let Some(b) = b {
+            *a = self.add(*a, b.into());
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            for x in arr.non_null_values_iter() {
+                *v = self.add(*v, x.into());
+            }
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let Some(v) = v.into_iter().collect::<Option<Vec<_>>>() else {
+            polars_bail!(ComputeError: "integer overflow in the sum of dtype {}", dtype);
+        };
+        let arr = Box::new(PrimitiveArray::from_vec(v));
+        Ok(unsafe {
+            Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr], &out_dtype(dtype))
+        })
+    }
+}
+
 #[derive(Clone)]
 struct BoolSumReducer;
 
@@ -5,7 +5,7 @@ use std::time::Duration;
 
 use arrow::bitmap::Bitmap;
 use bitflags::bitflags;
-use polars_core::config::verbose;
+use polars_core::config::{ConfigScope, verbose};
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 use polars_utils::relaxed_cell::RelaxedCell;
@@ -126,6 +126,8 @@ pub struct ExecutionState {
     pub element: Arc<Option<(Column, Option<Bitmap>)>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<RelaxedCell<bool>>,
+    /// The configuration of the thread that started the query, to enter in its parallel work.
+    pub config: ConfigScope,
 }
 
 impl ExecutionState {
@@ -144,6 +146,7 @@ impl ExecutionState {
             element: Default::default(),
             node_timer: None,
             stop: Arc::new(RelaxedCell::from(false)),
+            config: ConfigScope::current(),
         }
     }
 
@@ -210,6 +213,7 @@ impl ExecutionState {
             element: self.element.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            config: self.config.clone(),
         }
     }
 
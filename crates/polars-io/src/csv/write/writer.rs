@@ -5,9 +5,10 @@ use polars_core::POOL;
 use polars_core::frame::DataFrame;
 use polars_core::schema::Schema;
 use polars_error::PolarsResult;
+use rayon::prelude::*;
 
 use super::write_impl::{write, write_bom, write_header};
-use super::{QuoteStyle, SerializeOptions};
+use super::{CsvWriterOptions, QuoteStyle, SerializeOptions};
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -183,6 +184,49 @@ where
     }
 }
 
+/// Write `df` to one CSV file per writer in `writers`, which are serialized in parallel.
+///
+/// The rows are split into consecutive ranges of about the same number of rows, so the files
+/// hold the rows of `df` in order. Every file gets a header and BOM if `options` include them.
+pub fn write_csv_files<W: Write + Send>(
+    df: &DataFrame,
+    writers: &mut [W],
+    options: &CsvWriterOptions,
+) -> PolarsResult<()> {
+    let n_files = writers.len();
+    let rows_per_file = df.height().div_ceil(n_files.max(1));
+    // The files are already serialized in parallel, so only the remaining threads are used to
+    // serialize every file.
+    let n_threads = POOL.current_num_threads().div_ceil(n_files.max(1));
+    let serialize_options = &options.serialize_options;
+
+    POOL.install(|| {
+        writers
+            .par_iter_mut()
+            .enumerate()
+            .try_for_each(|(i, writer)| {
+                let mut df = df.slice((i * rows_per_file) as i64, rows_per_file);
+                CsvWriter::new(writer)
+                    .include_bom(options.include_bom)
+                    .include_header(options.include_header)
+                    .with_batch_size(options.batch_size)
+                    .with_separator(serialize_options.separator)
+                    .with_line_terminator(serialize_options.line_terminator.clone())
+                    .with_quote_char(serialize_options.quote_char)
+                    .with_datetime_format(serialize_options.datetime_format.clone())
+                    .with_date_format(serialize_options.date_format.clone())
+                    .with_time_format(serialize_options.time_format.clone())
+                    .with_float_scientific(serialize_options.float_scientific)
+                    .with_float_precision(serialize_options.float_precision)
+                    .with_decimal_comma(serialize_options.decimal_comma)
+                    .with_null_value(serialize_options.null.clone())
+                    .with_quote_style(serialize_options.quote_style)
+                    .n_threads(n_threads)
+                    .finish(&mut df)
+            })
+    })
+}
+
 pub struct BatchedWriter<W: Write> {
     writer: CsvWriter<W>,
     has_written_bom: bool,
@@ -0,0 +1,168 @@
+//! Pluggable file access for environments without a (local) file system.
+//!
+//! When a [`FileProvider`] is registered, local paths given to the scan functions are first
+//! looked up in that provider; only paths it does not serve are read from the file system.
+//! This allows running queries on wasm targets (e.g. in the browser), where the file contents
+//! are obtained by other means, such as a `fetch` done by the host application (see
+//! [`FetchFileProvider`]). As these targets have no file system, all local paths must be served
+//! by the registered provider there.
+use std::sync::{Arc, RwLock};
+
+use polars_core::prelude::*;
+use polars_utils::mmap::MemSlice;
+
+/// Provides the contents of files by path.
+pub trait FileProvider: Send + Sync {
+    /// Read the full contents of the file at `path`, or return `None` if this provider does not
+    /// serve that path.
+    fn read(&self, path: &str) -> Option<PolarsResult<MemSlice>>;
+}
+
+/// A [`FileProvider`] serving files registered in memory.
+#[derive(Default)]
+pub struct InMemoryFileProvider {
+    files: RwLock<PlHashMap<PlSmallStr, MemSlice>>,
+}
+
+impl InMemoryFileProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve `contents` as the file at `path`, replacing any previous contents.
+    pub fn insert(&self, path: impl Into<PlSmallStr>, contents: MemSlice) {
+        self.files.write().unwrap().insert(path.into(), contents);
+    }
+
+    /// Stop serving the file at `path`, returning its contents.
+    pub fn remove(&self, path: &str) -> Option<MemSlice> {
+        self.files.write().unwrap().remove(path)
+    }
+}
+
+impl FileProvider for InMemoryFileProvider {
+    fn read(&self, path: &str) -> Option<PolarsResult<MemSlice>> {
+        self.files.read().unwrap().get(path).cloned().map(Ok)
+    }
+}
+
+/// A [`FileProvider`] that obtains the files with a callback, e.g. one forwarding to a `fetch`
+/// by the host application on wasm targets.
+///
+/// The callback returns `Ok(None)` for paths it does not serve. Fetched files are kept in memory,
+/// so that every file is fetched only once.
+pub struct FetchFileProvider<F> {
+    fetch: F,
+    cache: InMemoryFileProvider,
+}
+
+impl<F> FetchFileProvider<F>
+where
+    F: Fn(&str) -> PolarsResult<Option<Vec<u8>>> + Send + Sync,
+{
+    pub fn new(fetch: F) -> Self {
+        Self {
+            fetch,
+            cache: InMemoryFileProvider::new(),
+        }
+    }
+
+    /// Drop the cached contents of the file at `path`, so that it is fetched again.
+    pub fn invalidate(&self, path: &str) {
+        self.cache.remove(path);
+    }
+}
+
+impl<F> FileProvider for FetchFileProvider<F>
+where
+    F: Fn(&str) -> PolarsResult<Option<Vec<u8>>> + Send + Sync,
+{
+    fn read(&self, path: &str) -> Option<PolarsResult<MemSlice>> {
+        if let Some(out) = self.cache.read(path) {
+            return Some(out);
+        }
+        match (self.fetch)(path) {
+            Ok(Some(contents)) => {
+                let contents = MemSlice::from_vec(contents);
+                self.cache.insert(path, contents.clone());
+                Some(Ok(contents))
+            },
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+static FILE_PROVIDER: RwLock<Option<Arc<dyn FileProvider>>> = RwLock::new(None);
+
+/// Register the [`FileProvider`] through which local paths are read, replacing any previously
+/// registered provider.
+pub fn set_file_provider(provider: Arc<dyn FileProvider>) {
+    *FILE_PROVIDER.write().unwrap() = Some(provider);
+}
+
+/// Unregister the current [`FileProvider`], if any.
+pub fn clear_file_provider() {
+    *FILE_PROVIDER.write().unwrap() = None;
+}
+
+/// Read the file at `path` through the registered [`FileProvider`], if it serves that path.
+///
+/// On wasm targets, which have no file system, this errors if the path is not served.
+pub fn read_from_file_provider(path: &str) -> Option<PolarsResult<MemSlice>> {
+    let provider = FILE_PROVIDER.read().unwrap().clone();
+    if let Some(out) = provider.and_then(|provider| provider.read(path)) {
+        return Some(out);
+    }
+    #[cfg(target_family = "wasm")]
+    return Some(Err(polars_err!(
+        ComputeError: "no file provider serves '{}'; local files can only be read through a registered `FileProvider` on wasm targets",
+        path
+    )));
+    #[cfg(not(target_family = "wasm"))]
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_file_provider() {
+        let provider = Arc::new(InMemoryFileProvider::new());
+        provider.insert("mem/a.csv", MemSlice::from_vec(b"a\n1\n".to_vec()));
+        set_file_provider(provider.clone());
+
+        let out = read_from_file_provider("mem/a.csv").unwrap().unwrap();
+        assert_eq!(&*out, b"a\n1\n");
+        assert!(read_from_file_provider("mem/b.csv").is_none());
+
+        provider.remove("mem/a.csv");
+        assert!(read_from_file_provider("mem/a.csv").is_none());
+        clear_file_provider();
+    }
+
+    #[test]
+    fn test_fetch_file_provider() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let fetches = AtomicUsize::new(0);
+        let provider = FetchFileProvider::new(|path: &str| {
+            fetches.fetch_add(1, Ordering::Relaxed);
+            match path {
+                "remote/a.csv" => Ok(Some(b"a\n1\n".to_vec())),
+                "remote/broken.csv" => Err(polars_err!(ComputeError: "fetch failed")),
+                _ => Ok(None),
+            }
+        });
+
+        for _ in 0..2 {
+            let out = provider.read("remote/a.csv").unwrap().unwrap();
+            assert_eq!(&*out, b"a\n1\n");
+        }
+        assert!(provider.read("remote/b.csv").is_none());
+        assert!(provider.read("remote/broken.csv").unwrap().is_err());
+        // The contents of `remote/a.csv` are cached after the first fetch.
+        assert_eq!(fetches.load(Ordering::Relaxed), 3);
+    }
+}
@@ -16,6 +16,8 @@ pub mod cloud;
 pub mod csv;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "file_provider")]
+pub mod file_provider;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
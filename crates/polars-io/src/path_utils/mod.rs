@@ -122,6 +122,14 @@ pub static POLARS_TEMP_DIR_BASE_PATH: LazyLock<Box<Path>> = LazyLock::new(|| {
     .unwrap()
 });
 
+/// The base directory for temporary files.
+///
+/// Unlike [`POLARS_TEMP_DIR_BASE_PATH`], this follows changes of [`config::Config::temp_dir`].
+pub fn temp_dir_base_path() -> PathBuf {
+    config::Config::get(|c| c.temp_dir.clone())
+        .unwrap_or_else(|| POLARS_TEMP_DIR_BASE_PATH.to_path_buf())
+}
+
 /// Replaces a "~" in the Path with the home directory.
 pub fn resolve_homedir(path: &dyn AsRef<Path>) -> PathBuf {
     let path = path.as_ref();
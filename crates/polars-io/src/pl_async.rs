@@ -323,13 +323,15 @@ impl RuntimeManager {
         self.rt.spawn(future)
     }
 
-    // See [`tokio::runtime::Runtime::spawn_blocking`].
+    // See [`tokio::runtime::Runtime::spawn_blocking`]. The task runs with the configuration of
+    // the calling thread.
     pub fn spawn_blocking<F, R>(&self, f: F) -> tokio::task::JoinHandle<R>
     where
         F: FnOnce() -> R + Send + 'static,
         R: Send + 'static,
     {
-        self.rt.spawn_blocking(f)
+        let config = config::ConfigScope::current();
+        self.rt.spawn_blocking(move || config.enter(f))
     }
 
     /// Run a task on the rayon threadpool. To avoid deadlocks, if the current thread is already a
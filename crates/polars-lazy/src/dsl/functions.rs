@@ -40,6 +40,9 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
 #[cfg(feature = "diagonal_concat")]
 /// Concat [LazyFrame]s diagonally.
 /// Calls [`concat`][concat()] internally.
+///
+/// The inputs are padded with null columns to a common schema before the union, so this
+/// runs on the streaming engine like a vertical concat.
 pub fn concat_lf_diagonal<L: AsRef<[LazyFrame]>>(
     inputs: L,
     mut args: UnionArgs,
@@ -49,6 +52,9 @@ pub fn concat_lf_diagonal<L: AsRef<[LazyFrame]>>(
 }
 
 /// Concat [LazyFrame]s horizontally.
+///
+/// On the streaming engine the inputs are zipped morsel by morsel. Unless `args.strict` is
+/// set, shorter inputs are extended with nulls.
 pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
     inputs: L,
     args: UnionArgs,
@@ -133,4 +139,47 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(all(feature = "diagonal_concat", feature = "new_streaming"))]
+    fn test_concat_lf_streaming() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1, 2, 3],
+            "b" => ["a", "b", "c"]
+        ]?;
+        let b = df![
+            "b" => ["d"],
+            "c" => [1i64]
+        ]?;
+
+        let diagonal =
+            concat_lf_diagonal([a.clone().lazy(), b.clone().lazy()], UnionArgs::default())?;
+        let expected = diagonal.clone().collect_with_engine(Engine::InMemory)?;
+        let out = diagonal.collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals_missing(&expected));
+        assert_eq!(out.height(), 4);
+
+        let b = b.lazy().rename(["b"], ["d"], true);
+        let horizontal = concat_lf_horizontal([a.lazy(), b.clone()], UnionArgs::default())?;
+        let out = horizontal.collect_with_engine(Engine::Streaming)?;
+        let expected = df![
+            "a" => [1, 2, 3],
+            "b" => ["a", "b", "c"],
+            "d" => [Some("d"), None, None],
+            "c" => [Some(1i64), None, None]
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let strict = UnionArgs {
+            strict: true,
+            ..Default::default()
+        };
+        let a = df!["a" => [1, 2]]?.lazy();
+        assert!(
+            concat_lf_horizontal([a, b], strict)?
+                .collect_with_engine(Engine::Streaming)
+                .is_err()
+        );
+        Ok(())
+    }
 }
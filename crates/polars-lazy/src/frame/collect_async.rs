@@ -0,0 +1,56 @@
+use std::num::NonZeroUsize;
+
+use polars_core::error::to_compute_err;
+use polars_io::pl_async;
+
+use super::*;
+
+impl LazyFrame {
+    /// Execute all the lazy operations and collect them into a [`DataFrame`], without blocking
+    /// the calling async task.
+    ///
+    /// The query is optimized and executed on a blocking thread of the Polars async runtime
+    /// (its parallel work runs on the Polars thread pool as usual), so the returned future can
+    /// be awaited on any executor, e.g. on a tokio worker thread of a web service.
+    ///
+    /// Dropping the future does not cancel the query; it runs to completion and the result is
+    /// discarded.
+    pub async fn collect_async(self) -> PolarsResult<DataFrame> {
+        match pl_async::get_runtime()
+            .spawn_blocking(move || self.collect())
+            .await
+        {
+            Ok(out) => out,
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => Err(to_compute_err(e)),
+        }
+    }
+
+    /// Async variant of [`LazyFrame::sink`] that also executes the sink, see
+    /// [`LazyFrame::collect_async`].
+    pub async fn sink_async(
+        self,
+        sink_type: SinkDestination,
+        file_format: impl Into<Arc<FileType>>,
+        unified_sink_args: UnifiedSinkArgs,
+    ) -> PolarsResult<()> {
+        self.sink(sink_type, file_format, unified_sink_args)?
+            .collect_async()
+            .await?;
+        Ok(())
+    }
+
+    /// Async variant of [`LazyFrame::sink_batches`] that also executes the sink, see
+    /// [`LazyFrame::collect_async`].
+    pub async fn sink_batches_async(
+        self,
+        function: PlanCallback<DataFrame, bool>,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+    ) -> PolarsResult<()> {
+        self.sink_batches(function, maintain_order, chunk_size)?
+            .collect_async()
+            .await?;
+        Ok(())
+    }
+}
@@ -3,9 +3,13 @@
 mod python;
 
 mod cached_arenas;
+#[cfg(feature = "async")]
+mod collect_async;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod partition;
+mod update;
 
 use std::num::NonZeroUsize;
 use std::sync::{Arc, Mutex};
@@ -20,8 +24,10 @@ pub use file_list_reader::*;
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+pub use partition::PartitionByIter;
 use polars_compute::rolling::QuantileMethod;
 use polars_core::POOL;
+use polars_core::diagnostics::{DiagnosticKind, emit_diagnostic};
 use polars_core::error::feature_gated;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
@@ -31,8 +37,10 @@ use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
+pub use polars_plan::plans::CoercionPolicy;
 use polars_utils::pl_str::PlSmallStr;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+pub use update::{UpdateHow, UpdateStrategy};
 
 use crate::frame::cached_arenas::CachedArena;
 use crate::prelude::*;
@@ -163,6 +171,13 @@ impl LazyFrame {
         self
     }
 
+    /// Override parts of the type coercion rules, e.g. to raise instead of implicitly casting
+    /// integers to floats. Only has an effect if type coercion is enabled.
+    pub fn with_coercion_policy(mut self, policy: CoercionPolicy) -> Self {
+        self.opt_state.set_coercion_policy(policy);
+        self
+    }
+
     /// Toggle expression simplification optimization on or off.
     pub fn with_simplify_expr(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::SIMPLIFY_EXPR, toggle);
@@ -823,6 +838,30 @@ impl LazyFrame {
         self.collect_with_engine(Engine::InMemory)
     }
 
+    /// Execute all the lazy operations and collect them into a [`DataFrame`], running all
+    /// parallel work on the given thread pool instead of the global one.
+    ///
+    /// This allows isolating the parallelism of queries from each other, e.g. to limit every
+    /// tenant of a service to a number of threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     let pool = polars_core::new_thread_pool(2);
+    ///     df.lazy().select([col("foo").sum()]).collect_with_thread_pool(&pool)
+    /// }
+    /// ```
+    pub fn collect_with_thread_pool(
+        self,
+        pool: &Arc<rayon::ThreadPool>,
+    ) -> PolarsResult<DataFrame> {
+        POOL.install_pool(pool, || self.collect())
+    }
+
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     // This version does profiling of the node execution.
     pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
@@ -915,11 +954,10 @@ impl LazyFrame {
                             .map(|s| s.starts_with("not yet implemented"))
                             .unwrap_or(false)
                     {
-                        if polars_core::config::verbose() {
-                            eprintln!(
-                                "caught unimplemented error in new streaming engine, falling back to normal engine"
-                            );
-                        }
+                        emit_diagnostic(DiagnosticKind::StreamingFallback, || {
+                            "caught unimplemented error in new streaming engine, falling back to normal engine"
+                                .to_string()
+                        });
                     } else {
                         std::panic::resume_unwind(e);
                     }
@@ -1535,6 +1573,11 @@ impl LazyFrame {
     }
 
     /// Match or evolve to a certain schema.
+    ///
+    /// The columns are reordered to the order of `schema`, and missing columns, struct fields
+    /// and allowed upcasts are handled according to the policy of each column in `per_column`,
+    /// which must have the same length as `schema`. Any remaining mismatch fails the query
+    /// before it runs, with an error listing all mismatching columns at once.
     pub fn match_to_schema(
         self,
         schema: SchemaRef,
@@ -1793,6 +1836,13 @@ impl LazyFrame {
         self.slice(neg_tail, n)
     }
 
+    /// Pivot the DataFrame from long to wide format.
+    ///
+    /// Creates a column per row of `on_columns`, the distinct values of the `on` columns, and
+    /// value column. `agg` is evaluated per group of the `index` columns on the values with
+    /// [`Expr::Element`]. If there are several `values` columns, the output columns are named
+    /// `{value}{separator}{on_value}`, otherwise `{on_value}`. See [`LazyFrame::pivot_struct`]
+    /// to get a struct column per `on` value instead.
     #[cfg(feature = "pivot")]
     #[expect(clippy::too_many_arguments)]
     pub fn pivot(
@@ -1821,6 +1871,147 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Pivot the DataFrame from long to wide format, like [`LazyFrame::pivot`], but create a
+    /// single struct column per row of `on_columns`, with a field per `values` column.
+    #[cfg(all(feature = "pivot", feature = "dtype-struct"))]
+    pub fn pivot_struct(
+        self,
+        on: Selector,
+        on_columns: Arc<DataFrame>,
+        index: Selector,
+        values: Selector,
+        agg: Expr,
+        maintain_order: bool,
+    ) -> LazyFrame {
+        // Not visible in the output, so this only has to be unlikely to appear in column names.
+        const SEPARATOR: &str = "\u{1f}";
+
+        let callback = move |(mut inputs, schemas): (Vec<DslPlan>, Vec<SchemaRef>)| {
+            let index_names = index.into_columns(&schemas[0], &Default::default())?;
+            let value_names = values.into_columns(&schemas[0], &Default::default())?;
+
+            let titles = if on_columns.width() == 1 {
+                on_columns.get_columns()[0].cast(&DataType::String)?
+            } else {
+                on_columns
+                    .as_ref()
+                    .clone()
+                    .into_struct(PlSmallStr::EMPTY)
+                    .cast(&DataType::String)?
+                    .into_column()
+            };
+
+            let mut exprs = index_names
+                .iter()
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>();
+            for title in titles.str()?.iter() {
+                let title = title.unwrap_or("null");
+                let fields = value_names
+                    .iter()
+                    .map(|value| {
+                        let name = if value_names.len() > 1 {
+                            polars_utils::format_pl_smallstr!("{value}{SEPARATOR}{title}")
+                        } else {
+                            PlSmallStr::from_str(title)
+                        };
+                        col(name).alias(value.clone())
+                    })
+                    .collect();
+                exprs.push(as_struct(fields).alias(title));
+            }
+
+            let input = LazyFrame::from(inputs.pop().unwrap());
+            Ok(input
+                .pivot(
+                    on.clone(),
+                    on_columns.clone(),
+                    index.clone(),
+                    values.clone(),
+                    agg.clone(),
+                    maintain_order,
+                    PlSmallStr::from_static(SEPARATOR),
+                )
+                .select(exprs)
+                .logical_plan)
+        };
+        self.pipe_with_schema(PlanCallback::new(callback))
+    }
+
+    /// Create dummy variables for the selected columns, like `DataFrameOps::to_dummies`.
+    ///
+    /// As the output schema must be known upfront, the categories of the columns are taken
+    /// from their dtype, so only Boolean and Enum columns are supported. Other columns must
+    /// be cast to an Enum first. Unlike the eager version, this creates a column for every
+    /// category, whether it occurs or not, and a `null` column unless `drop_nulls` is set.
+    /// `drop_first` drops the column of the first category.
+    #[cfg(feature = "to_dummies")]
+    pub fn to_dummies(
+        self,
+        columns: Selector,
+        separator: Option<PlSmallStr>,
+        drop_first: bool,
+        drop_nulls: bool,
+        dtype: DummyDtype,
+    ) -> LazyFrame {
+        let callback = move |(mut inputs, schemas): (Vec<DslPlan>, Vec<SchemaRef>)| {
+            let schema = &schemas[0];
+            let selected = columns.into_columns(schema, &Default::default())?;
+            let sep = separator.as_deref().unwrap_or("_");
+
+            let mut exprs = Vec::with_capacity(schema.len());
+            for (name, column_dtype) in schema.iter() {
+                if !selected.contains(name) {
+                    exprs.push(col(name.clone()));
+                    continue;
+                }
+
+                let indicator = |value: &dyn std::fmt::Display, value_expr: Expr| {
+                    (
+                        polars_utils::format_pl_smallstr!("{name}{sep}{value}"),
+                        col(name.clone()).eq_missing(value_expr),
+                    )
+                };
+                let mut dummies = match column_dtype {
+                    DataType::Boolean => [false, true]
+                        .into_iter()
+                        .map(|v| indicator(&v, lit(v)))
+                        .collect::<Vec<_>>(),
+                    #[cfg(feature = "dtype-categorical")]
+                    DataType::Enum(fcats, _) => fcats
+                        .categories()
+                        .values_iter()
+                        .map(|v| indicator(&v, lit(v)))
+                        .collect(),
+                    dt => polars_bail!(
+                        InvalidOperation: "cannot create dummies of column '{}' with dtype {} in a lazy query; cast it to an Enum first",
+                        name, dt
+                    ),
+                };
+                if drop_first && !dummies.is_empty() {
+                    dummies.remove(0);
+                }
+                if !drop_nulls {
+                    dummies.push((
+                        polars_utils::format_pl_smallstr!("{name}{sep}null"),
+                        col(name.clone()).is_null(),
+                    ));
+                }
+                dummies.sort_by(|(a, _), (b, _)| a.cmp(b));
+                exprs.extend(
+                    dummies
+                        .into_iter()
+                        .map(|(dummy_name, e)| e.cast(dtype.dtype()).alias(dummy_name)),
+                );
+            }
+
+            Ok(LazyFrame::from(inputs.pop().unwrap())
+                .select(exprs)
+                .logical_plan)
+        };
+        self.pipe_with_schema(PlanCallback::new(callback))
+    }
+
     /// Unpivot the DataFrame from wide to long format.
     ///
     /// See [`UnpivotArgsIR`] for information on how to unpivot a DataFrame.
@@ -1831,6 +2022,50 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Transpose the DataFrame, turning its rows into columns named `column_names`.
+    ///
+    /// If `keep_names_as` is set, the original column names are kept in a first column with
+    /// that name. The output columns have the supertype of all input columns, so numeric
+    /// columns stay numeric. As the output schema must be known upfront, the names of the new
+    /// columns have to be given, and there must be exactly one per row; use
+    /// [`DataFrame::transpose`] to take them from a column instead.
+    pub fn transpose(
+        self,
+        keep_names_as: Option<PlSmallStr>,
+        column_names: Vec<PlSmallStr>,
+    ) -> LazyFrame {
+        let column_names: Arc<[PlSmallStr]> = column_names.into();
+
+        let schema_names = column_names.clone();
+        let schema_keep_names_as = keep_names_as.clone();
+        let schema = move |input_schema: &Schema| -> PolarsResult<SchemaRef> {
+            let dtype = input_schema
+                .iter_values()
+                .try_fold(DataType::Null, |st, dtype| {
+                    polars_core::utils::try_get_supertype(&st, dtype)
+                })?;
+            let names_field = schema_keep_names_as
+                .iter()
+                .map(|name| Field::new(name.clone(), DataType::String));
+            let fields = schema_names
+                .iter()
+                .map(|name| Field::new(name.clone(), dtype.clone()));
+            Ok(Arc::new(names_field.chain(fields).collect()))
+        };
+
+        self.map(
+            move |mut df| {
+                df.transpose_impl(
+                    keep_names_as.as_deref(),
+                    Some(either::Either::Right(column_names.to_vec())),
+                )
+            },
+            AllowedOptimizations::empty(),
+            Some(Arc::new(schema)),
+            Some("TRANSPOSE"),
+        )
+    }
+
     /// Limit the DataFrame to the first `n` rows.
     pub fn limit(self, n: IdxSize) -> LazyFrame {
         self.slice(0, n)
@@ -1872,6 +2107,28 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Apply a function/closure to batches of rows once the logical plan gets executed.
+    ///
+    /// Unlike [`LazyFrame::map`], the function doesn't have access to the whole DataFrame. It
+    /// must be stateless: its output for a batch may only depend on the rows of that batch, so
+    /// that applying it to every batch and concatenating the outputs is the same as applying it
+    /// to the whole DataFrame. This allows the streaming engine to apply it to every morsel, in
+    /// parallel, instead of materializing its input first.
+    ///
+    /// The warning about the schema of [`LazyFrame::map`] also applies here.
+    pub fn map_batches<F>(
+        self,
+        function: F,
+        schema: Option<Arc<dyn UdfSchema>>,
+        name: Option<&'static str>,
+    ) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        // For map functions, this flag marks the function as streamable.
+        self.map(function, AllowedOptimizations::NEW_STREAMING, schema, name)
+    }
+
     #[cfg(feature = "python")]
     pub fn map_python(
         self,
@@ -1973,6 +2230,66 @@ impl LazyFrame {
         Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
     }
 
+    /// Merge two frames that are both sorted by the `by` columns, in the order given by
+    /// `sort_options`, into a single frame with the same sort order.
+    ///
+    /// Unlike [`LazyFrame::merge_sorted`], this supports multiple keys and descending orders.
+    /// The output is known to be sorted by the keys in downstream operations.
+    #[cfg(feature = "merge_sorted")]
+    pub fn merge_sorted_by(
+        self,
+        other: LazyFrame,
+        by: impl IntoVec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<LazyFrame> {
+        let by = by.into_vec();
+        polars_ensure!(!by.is_empty(), InvalidOperation: "`merge_sorted_by` needs at least one key");
+        let (descending, nulls_last) = per_key_sort_flags(by.len(), &sort_options)?;
+        let sorted = sorted_hint(&by, &descending, &nulls_last);
+
+        if by.len() == 1 && !descending[0] && !nulls_last[0] {
+            return self
+                .merge_sorted(other, by[0].clone())?
+                .hint(HintIR::Sorted(sorted));
+        }
+
+        // Merge on an order-preserving binary encoding of all keys.
+        const MERGE_KEY: &str = "__POLARS_MERGE_KEY";
+        let merge_key = Expr::n_ary(
+            FunctionExpr::RowEncode(RowEncodingVariant::Ordered {
+                descending: Some(descending),
+                nulls_last: Some(nulls_last),
+            }),
+            by.iter().map(|name| col(name.clone())).collect(),
+        )
+        .alias(MERGE_KEY);
+
+        self.with_column(merge_key.clone())
+            .merge_sorted(other.with_column(merge_key), MERGE_KEY)?
+            .drop(by_name([MERGE_KEY], true))
+            .hint(HintIR::Sorted(sorted))
+    }
+
+    /// Mark the frame as sorted by the `by` columns, in the order given by `sort_options`.
+    ///
+    /// Downstream operations use the fast paths for sorted data, e.g. a `group_by` on the keys
+    /// detects runs of equal keys instead of hashing them. The in-memory engine only does so for
+    /// a `group_by` on the first of the `by` columns alone; group-bys on several keys are still
+    /// hashed there. This is not checked: marking a frame that isn't sorted as sorted gives wrong
+    /// results.
+    pub fn set_sorted(
+        self,
+        by: impl IntoVec<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    ) -> PolarsResult<LazyFrame> {
+        let by = by.into_vec();
+        if by.is_empty() {
+            return Ok(self);
+        }
+        let (descending, nulls_last) = per_key_sort_flags(by.len(), &sort_options)?;
+        self.hint(HintIR::Sorted(sorted_hint(&by, &descending, &nulls_last)))
+    }
+
     pub fn hint(self, hint: HintIR) -> PolarsResult<LazyFrame> {
         let lp = DslPlan::MapFunction {
             input: Arc::new(self.logical_plan),
@@ -1982,6 +2299,36 @@ impl LazyFrame {
     }
 }
 
+/// The `descending` and `nulls_last` flags of `sort_options` for each of `n_keys` keys.
+fn per_key_sort_flags(
+    n_keys: usize,
+    sort_options: &SortMultipleOptions,
+) -> PolarsResult<(Vec<bool>, Vec<bool>)> {
+    let per_key = |flags: &[bool]| match flags {
+        [flag] => vec![*flag; n_keys],
+        flags => flags.to_vec(),
+    };
+    let descending = per_key(&sort_options.descending);
+    let nulls_last = per_key(&sort_options.nulls_last);
+    polars_ensure!(
+        descending.len() == n_keys && nulls_last.len() == n_keys,
+        ShapeMismatch: "the number of `descending` and `nulls_last` flags must match the number of keys"
+    );
+    Ok((descending, nulls_last))
+}
+
+fn sorted_hint(by: &[PlSmallStr], descending: &[bool], nulls_last: &[bool]) -> Arc<[Sorted]> {
+    by.iter()
+        .zip(descending)
+        .zip(nulls_last)
+        .map(|((column, &descending), &nulls_last)| Sorted {
+            column: column.clone(),
+            descending: Some(descending),
+            nulls_last: Some(nulls_last),
+        })
+        .collect()
+}
+
 /// Utility struct for lazy group_by operation.
 #[derive(Clone)]
 pub struct LazyGroupBy {
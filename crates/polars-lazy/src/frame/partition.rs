@@ -0,0 +1,96 @@
+use super::*;
+
+impl LazyFrame {
+    /// Partition the result of this query by the `keys` columns, yielding a `(key, DataFrame)`
+    /// pair per group, in the sort order of the keys.
+    ///
+    /// Unlike `DataFrame::partition_by`, the partitions are not materialized upfront. The
+    /// query is sorted by the keys with the given `engine`, for which the streaming engine may
+    /// spill to disk, and every partition is a zero-copy slice of the sorted result.
+    pub fn partition_by_iter(
+        self,
+        keys: impl IntoVec<PlSmallStr>,
+        include_key: bool,
+        engine: Engine,
+    ) -> PolarsResult<PartitionByIter> {
+        let keys = keys.into_vec();
+        polars_ensure!(!keys.is_empty(), InvalidOperation: "`partition_by_iter` needs at least one key");
+
+        let sort_options = SortMultipleOptions::default()
+            .with_maintain_order(true)
+            .with_nulls_last(true);
+        let df = self
+            .sort(keys.clone(), sort_options)
+            .collect_with_engine(engine)?;
+
+        // A group starts at every row where any of the (sorted) keys changes.
+        let mut changed: Option<BooleanChunked> = None;
+        for key in &keys {
+            let s = df.column(key)?.as_materialized_series();
+            let key_changed = s.not_equal_missing(&s.shift(1))?;
+            changed = Some(match changed {
+                None => key_changed,
+                Some(changed) => changed | key_changed,
+            });
+        }
+        let starts = changed
+            .unwrap()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, changed)| (i == 0 || changed == Some(true)).then_some(i))
+            .collect();
+
+        Ok(PartitionByIter {
+            df,
+            keys,
+            include_key,
+            starts,
+            next: 0,
+        })
+    }
+}
+
+/// Iterator over the partitions of a query, created with [`LazyFrame::partition_by_iter`].
+pub struct PartitionByIter {
+    df: DataFrame,
+    keys: Vec<PlSmallStr>,
+    include_key: bool,
+    starts: Vec<usize>,
+    next: usize,
+}
+
+impl Iterator for PartitionByIter {
+    /// The values of the key columns and the rows of the group.
+    type Item = (Vec<AnyValue<'static>>, DataFrame);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = *self.starts.get(self.next)?;
+        let end = self
+            .starts
+            .get(self.next + 1)
+            .copied()
+            .unwrap_or(self.df.height());
+        self.next += 1;
+
+        let part = self.df.slice(start as i64, end - start);
+        // The key columns were checked to exist when sorting.
+        let key = self
+            .keys
+            .iter()
+            .map(|key| part.column(key).unwrap().get(0).unwrap().into_static())
+            .collect();
+        let part = if self.include_key {
+            part
+        } else {
+            part.drop_many(self.keys.iter().cloned())
+        };
+        Some((key, part))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.starts.len() - self.next;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for PartitionByIter {}
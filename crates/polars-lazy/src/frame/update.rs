@@ -0,0 +1,118 @@
+use super::*;
+
+/// Which rows [`LazyFrame::update`] keeps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UpdateHow {
+    /// Keep all rows of the updated frame; rows of `other` without a match are ignored.
+    #[default]
+    Left,
+    /// Only keep the rows that have a match in `other`.
+    Inner,
+    /// Keep all rows of both frames, appending the rows of `other` without a match (upsert).
+    Full,
+}
+
+/// How [`LazyFrame::update`] combines the values of matching rows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UpdateStrategy {
+    /// Overwrite values with the non-null values of `other`.
+    #[default]
+    NonNull,
+    /// Overwrite values with the values of `other`, including nulls.
+    IncludeNulls,
+    /// Only fill the nulls of the updated frame with the values of `other`.
+    FillNull,
+}
+
+impl LazyFrame {
+    /// Update the values of this frame with the values of the matching rows of `other`.
+    ///
+    /// Rows are matched on the `on` columns, and only the columns that exist in both frames
+    /// are updated; the other columns of `other` are ignored. The output has the schema of
+    /// this frame and keeps its row order, with unmatched rows of `other` appended after it
+    /// for [`UpdateHow::Full`].
+    pub fn update(
+        self,
+        other: LazyFrame,
+        on: impl IntoVec<PlSmallStr>,
+        how: UpdateHow,
+        strategy: UpdateStrategy,
+    ) -> LazyFrame {
+        const SUFFIX: &str = "__POLARS_RIGHT";
+        const MATCHED: &str = "__POLARS_MATCHED";
+
+        let on = on.into_vec();
+        let opt_state = self.get_opt_state();
+        let input: Arc<[DslPlan]> = Arc::new([self.logical_plan, other.logical_plan]);
+
+        let callback = move |(mut inputs, schemas): (Vec<DslPlan>, Vec<SchemaRef>)| {
+            let (left_schema, right_schema) = (&schemas[0], &schemas[1]);
+            polars_ensure!(!on.is_empty(), InvalidOperation: "`update` needs at least one key");
+            for key in &on {
+                polars_ensure!(
+                    left_schema.contains(key) && right_schema.contains(key),
+                    ColumnNotFound: "`update` key {:?} not found in both frames", key
+                );
+            }
+
+            let updated = left_schema
+                .iter_names()
+                .filter(|name| !on.contains(*name) && right_schema.contains(name))
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut right_exprs = on
+                .iter()
+                .chain(&updated)
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>();
+            if strategy == UpdateStrategy::IncludeNulls {
+                right_exprs.push(lit(true).alias(MATCHED));
+            }
+
+            let right = LazyFrame::from(inputs.pop().unwrap()).select(right_exprs);
+            let left = LazyFrame::from(inputs.pop().unwrap());
+            let keys = on.iter().map(|name| col(name.clone())).collect::<Vec<_>>();
+            let (join_type, maintain_order) = match how {
+                UpdateHow::Left => (JoinType::Left, MaintainOrderJoin::Left),
+                UpdateHow::Inner => (JoinType::Inner, MaintainOrderJoin::Left),
+                UpdateHow::Full => (JoinType::Full, MaintainOrderJoin::LeftRight),
+            };
+            let joined = left
+                .join_builder()
+                .with(right)
+                .left_on(&keys)
+                .right_on(&keys)
+                .how(join_type)
+                .coalesce(JoinCoalesce::CoalesceColumns)
+                .maintain_order(maintain_order)
+                .suffix(SUFFIX)
+                .finish();
+
+            let exprs = left_schema
+                .iter_names()
+                .map(|name| {
+                    if !updated.contains(name) {
+                        return col(name.clone());
+                    }
+                    let left = col(name.clone());
+                    let right = col(polars_utils::format_pl_smallstr!("{name}{SUFFIX}"));
+                    match strategy {
+                        UpdateStrategy::NonNull => coalesce(&[right, left]),
+                        UpdateStrategy::IncludeNulls => {
+                            when(col(MATCHED).is_not_null()).then(right).otherwise(left)
+                        },
+                        UpdateStrategy::FillNull => coalesce(&[left, right]),
+                    }
+                    .alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            Ok(joined.select(exprs).logical_plan)
+        };
+
+        let lp = DslPlan::PipeWithSchema {
+            input,
+            callback: PlanCallback::new(callback),
+        };
+        Self::from_logical_plan(lp, opt_state)
+    }
+}
@@ -10,6 +10,8 @@ pub use polars_io::parquet::write::ParquetWriteOptions;
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
+#[cfg(feature = "to_dummies")]
+pub use polars_ops::series::DummyDtype;
 #[cfg(feature = "polars_cloud_client")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
@@ -18,6 +20,8 @@ pub(crate) use polars_plan::prelude::*;
 pub use polars_plan::prelude::{PlanCallback, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
+#[cfg(feature = "rolling_window")]
+pub use polars_time::prelude::{FrameAggregation, FrameBound, WindowFrame};
 #[cfg(feature = "dynamic_group_by")]
 pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
 pub(crate) use polars_utils::arena::{Arena, Node};
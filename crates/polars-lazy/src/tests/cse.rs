@@ -345,3 +345,46 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "random")]
+fn test_rand_distributions_not_merged_by_cse() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+    let in_range = |c: &Column, low: f64, high: f64| -> PolarsResult<bool> {
+        Ok(c.f64()?
+            .into_no_null_iter()
+            .all(|v| (low..high).contains(&v)))
+    };
+
+    // Sub-expressions that only differ in the parameters of the distribution.
+    let out = df
+        .clone()
+        .lazy()
+        .with_comm_subexpr_elim(true)
+        .select([
+            (rand_uniform(len(), 0.0, 1.0, Some(0)) + lit(0.0)).alias("a"),
+            (rand_uniform(len(), 10.0, 20.0, Some(0)) + lit(0.0)).alias("b"),
+            (rand_normal(len(), 0.0, 1.0, Some(0)) + lit(0.0)).alias("c"),
+            (rand_normal(len(), 100.0, 1.0, Some(0)) + lit(0.0)).alias("d"),
+        ])
+        .collect()?;
+    assert!(in_range(out.column("a")?, 0.0, 1.0)?);
+    assert!(in_range(out.column("b")?, 10.0, 20.0)?);
+    assert!(!out.column("c")?.equals(out.column("d")?));
+
+    // Sub-plans that only differ in the parameters of the distribution.
+    let plan = |low: f64, high: f64| {
+        df.clone()
+            .lazy()
+            .select([rand_uniform(len(), low, high, Some(0)).alias("u")])
+    };
+    let out = concat(&[plan(0.0, 1.0), plan(10.0, 20.0)], UnionArgs::default())?
+        .with_comm_subplan_elim(true)
+        .collect()?;
+    assert!(in_range(&out.column("u")?.slice(0, 3), 0.0, 1.0)?);
+    assert!(in_range(&out.column("u")?.slice(3, 3), 10.0, 20.0)?);
+
+    Ok(())
+}
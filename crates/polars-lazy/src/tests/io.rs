@@ -383,6 +383,44 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_count_from_metadata() -> PolarsResult<()> {
+    init_files();
+    let glob = "../../examples/datasets/foods*.parquet";
+    let count = |n_rows| -> PolarsResult<(bool, DataFrame)> {
+        let args = ScanArgsParquet {
+            n_rows,
+            ..Default::default()
+        };
+        let q = LazyFrame::scan_parquet(PlPath::new(glob), args)?.select([dsl::len()]);
+        let IRPlan {
+            lp_top, lp_arena, ..
+        } = q.clone().to_alp_optimized()?;
+        let fast_count = lp_arena.iter(lp_top).any(|(_, lp)| {
+            matches!(
+                lp,
+                IR::MapFunction {
+                    function: FunctionIR::FastCount { .. },
+                    ..
+                }
+            )
+        });
+        Ok((fast_count, q.collect()?))
+    };
+
+    let (fast_count, df) = count(None)?;
+    assert!(fast_count);
+    assert_eq!(df.column("len")?.idx()?.get(0), Some(54));
+
+    // The metadata doesn't know the row count after a slice.
+    let (fast_count, df) = count(Some(10))?;
+    assert!(!fast_count);
+    assert_eq!(df.column("len")?.idx()?.get(0), Some(10));
+
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -806,3 +844,28 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "csv", feature = "file_provider"))]
+fn test_scan_csv_from_file_provider() -> PolarsResult<()> {
+    use polars_io::file_provider::{InMemoryFileProvider, clear_file_provider, set_file_provider};
+    use polars_utils::mmap::MemSlice;
+
+    // The file provider is global.
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let provider = Arc::new(InMemoryFileProvider::new());
+    provider.insert(
+        "in-memory-provider/data.csv",
+        MemSlice::from_vec(b"a,b\n1,x\n2,y\n".to_vec()),
+    );
+    set_file_provider(provider);
+
+    let df = LazyCsvReader::new(PlPath::new("in-memory-provider/data.csv"))
+        .finish()
+        .and_then(|lf| lf.filter(col("a").gt(lit(1))).collect());
+    clear_file_provider();
+    let df = df?;
+    let expected = df!["a" => [2i64], "b" => ["y"]]?;
+    assert!(df.equals(&expected));
+    Ok(())
+}
@@ -157,3 +157,66 @@ fn test_lazy_logical_plan_join() {
         let _df = lf.collect().unwrap();
     }
 }
+
+#[test]
+fn test_coercion_policy() -> PolarsResult<()> {
+    let df = df![
+        "int" => [1i64, 2],
+        "float" => [0.5f64, 1.5],
+        "str" => ["1", "2"],
+    ]?;
+    let strict = CoercionPolicy {
+        forbid_int_to_float: true,
+        forbid_string_numeric_comparison: true,
+    };
+
+    // The default policy implicitly casts.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("int") + col("float")])
+        .collect()?;
+    assert_eq!(out.column("int")?.dtype(), &DataType::Float64);
+
+    for expr in [
+        col("int") + col("float"),
+        col("int") * lit(2.5),
+        when(col("int").gt(lit(1)))
+            .then(col("int"))
+            .otherwise(col("float")),
+    ] {
+        let err = df
+            .clone()
+            .lazy()
+            .with_coercion_policy(strict)
+            .select([expr])
+            .collect()
+            .unwrap_err();
+        assert!(err.to_string().contains("implicit cast from i64"));
+    }
+
+    let err = df
+        .clone()
+        .lazy()
+        .with_coercion_policy(strict)
+        .filter(col("str").eq(lit(1)))
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("forbidden by the coercion policy"));
+
+    // Explicit casts and casts that keep integers are still allowed.
+    let out = df
+        .lazy()
+        .with_coercion_policy(strict)
+        .select([
+            col("int").cast(DataType::Float64) + col("float"),
+            (col("float") * lit(2)).alias("float_times_2"),
+            col("str").cast(DataType::Int64).eq(col("int")).alias("eq"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("eq")?.bool()?),
+        &[Some(true), Some(true)]
+    );
+    Ok(())
+}
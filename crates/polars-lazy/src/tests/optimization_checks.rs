@@ -650,3 +650,40 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_sortedness_propagation() -> PolarsResult<()> {
+    use polars_ops::frame::MaintainOrderJoin;
+    use polars_plan::plans::is_sorted;
+
+    let sorted_column = |q: LazyFrame| {
+        let IRPlan {
+            lp_top,
+            lp_arena,
+            expr_arena,
+        } = q.without_optimizations().to_alp().unwrap();
+        is_sorted(lp_top, &lp_arena, &expr_arena).map(|s| s.0[0].column.to_string())
+    };
+
+    let left = df!("a" => [1, 2, 3], "b" => [3, 2, 1])?
+        .lazy()
+        .sort(["a"], Default::default());
+    let right = df!("a" => [1, 2], "c" => [1, 2])?.lazy();
+    let join = |maintain_order| {
+        let mut args = JoinArgs::new(JoinType::Left);
+        args.maintain_order = maintain_order;
+        left.clone()
+            .join(right.clone(), [col("a")], [col("a")], args)
+            .with_row_index("idx", None)
+    };
+    assert_eq!(
+        sorted_column(join(MaintainOrderJoin::Left)).as_deref(),
+        Some("a")
+    );
+    // The left order isn't guaranteed, but the row index is always sorted.
+    assert_eq!(
+        sorted_column(join(MaintainOrderJoin::None)).as_deref(),
+        Some("idx")
+    );
+    Ok(())
+}
@@ -314,3 +314,87 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_predicate_pushdown_unpivot() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 2, 3],
+        "a" => [10, 20, 30],
+        "b" => [40, 50, 60],
+    ]?;
+    let args = UnpivotArgsDSL {
+        on: Some(by_name(["a", "b"], true)),
+        index: by_name(["id"], true),
+        variable_name: None,
+        value_name: None,
+    };
+
+    // Predicates on the index columns are pushed below the unpivot.
+    let q = df
+        .clone()
+        .lazy()
+        .unpivot(args.clone())
+        .filter(col("id").gt(lit(1)));
+    assert!(predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("value")?.i32()?),
+        [Some(20), Some(30), Some(50), Some(60)]
+    );
+
+    // Predicates on the generated columns are not.
+    let q = df.lazy().unpivot(args).filter(col("value").gt(lit(30)));
+    assert!(!predicate_at_scan(q.clone()));
+    assert_eq!(q.collect()?.height(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_filter_fused_into_projection() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4],
+        "b" => ["w", "x", "y", "z"],
+        "c" => [1.0, 2.0, 3.0, 4.0],
+    ]?;
+
+    // The filter only gathers the projected columns, not the predicate-only column `a`.
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(2)))
+        .select([col("b"), (col("c") * lit(2.0)).alias("c2"), len()])
+        .collect()?;
+    let expected = df![
+        "b" => ["y", "z"],
+        "c2" => [6.0, 8.0],
+        "len" => [2 as IdxSize, 2],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // A projection without any columns keeps the height of the filtered frame.
+    let out = df
+        .lazy()
+        .filter(col("a").lt(lit(4)))
+        .select([len()])
+        .collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(3));
+
+    // Columns of a context are not in the filtered frame; they are resolved by the projection.
+    let ctx = df!["d" => [10, 20]]?;
+    let out = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 2, 3],
+    ]?
+    .lazy()
+    .with_context([ctx.lazy()])
+    .filter(col("a").gt(lit(1)))
+    .select([col("b"), col("d").sum()])
+    .collect()?;
+    let expected = df![
+        "b" => [2, 3],
+        "d" => [30, 30],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
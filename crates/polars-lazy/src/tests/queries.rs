@@ -67,6 +67,60 @@ fn test_lazy_unpivot() {
     assert_eq!(out.shape(), (7, 3));
 }
 
+#[test]
+#[cfg(all(feature = "pivot", feature = "dtype-struct"))]
+fn test_lazy_pivot_multiple_values() -> PolarsResult<()> {
+    let df = df! {
+        "key" => [1, 1, 2, 2, 2],
+        "cat" => ["a", "b", "a", "a", "b"],
+        "x" => [1, 2, 3, 4, 5],
+        "y" => [10, 20, 30, 40, 50],
+    }?;
+    let on_columns = Arc::new(df!("cat" => ["a", "b"])?);
+
+    let out = df
+        .clone()
+        .lazy()
+        .pivot(
+            by_name(["cat"], true),
+            on_columns.clone(),
+            by_name(["key"], true),
+            by_name(["x", "y"], true),
+            element().sum(),
+            true,
+            "_".into(),
+        )
+        .collect()?;
+    let expected = df! {
+        "key" => [1, 2],
+        "x_a" => [1, 7],
+        "x_b" => [2, 5],
+        "y_a" => [10, 70],
+        "y_b" => [20, 50],
+    }?;
+    assert!(out.equals(&expected));
+
+    let out = df
+        .lazy()
+        .pivot_struct(
+            by_name(["cat"], true),
+            on_columns,
+            by_name(["key"], true),
+            by_name(["x", "y"], true),
+            element().sum(),
+            true,
+        )
+        .collect()?;
+    assert_eq!(out.get_column_names(), ["key", "a", "b"]);
+    let b = out.column("b")?.struct_()?.clone().unnest();
+    let expected = df! {
+        "x" => [2, 5],
+        "y" => [20, 50],
+    }?;
+    assert!(b.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_drop_nulls() {
     let df = df! {
@@ -614,6 +668,100 @@ fn test_lazy_wildcard() {
     assert_eq!(new.shape(), (3, 5)); // Should exclude b from wildcard aggregations.
 }
 
+#[test]
+fn test_name_namespace_in_aggregations_and_windows() -> PolarsResult<()> {
+    let df = df! {
+        "g" => ["x", "x", "y"],
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("g"),
+            cols(["a", "b"])
+                .as_expr()
+                .sum()
+                .over([col("g")])
+                .name()
+                .suffix("_sum"),
+            (col("a") * col("b")).max().over([col("g")]).name().keep(),
+            cols(["a", "b"])
+                .as_expr()
+                .first()
+                .over([col("g")])
+                .name()
+                .map(PlanCallback::new(|name: PlSmallStr| {
+                    Ok(polars_utils::format_pl_smallstr!(
+                        "first_{}",
+                        name.to_uppercase()
+                    ))
+                })),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["g", "a_sum", "b_sum", "a", "first_A", "first_B"]
+    );
+    assert_eq!(
+        Vec::from(out.column("a_sum")?.i32()?),
+        &[Some(3), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(10), Some(10), Some(18)]
+    );
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            all()
+                .exclude_cols(["g"])
+                .as_expr()
+                .mean()
+                .name()
+                .prefix("mean_"),
+            col("a").alias("foo").max().name().to_uppercase(),
+        ])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["g", "mean_a", "mean_b", "FOO"]);
+    Ok(())
+}
+
+#[test]
+fn test_error_names_failing_expression() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [1, 2, 3],
+        "b" => ["1", "x", "3"],
+    }?;
+
+    let err = df
+        .clone()
+        .lazy()
+        .select([
+            col("a") + lit(1),
+            col("b").strict_cast(DataType::Int64).alias("b_int"),
+            col("a").sum(),
+        ])
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    let msg = err.to_string();
+    assert!(msg.contains("Error originated in expression: 'col(\"b\").strict_cast"));
+    assert!(msg.contains("(in select)"));
+
+    let err = df
+        .lazy()
+        .with_column(col("b").strict_cast(DataType::Int64))
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("(in with_columns)"));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_reverse() {
     let df = load_df();
@@ -1203,6 +1351,67 @@ fn test_fill_forward() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_fill_limit_over() -> PolarsResult<()> {
+    let df = df![
+        "id" => ["a", "a", "a", "a", "b", "b", "b"],
+        "x" => [Some(1), None, None, None, None, Some(7), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("x").forward_fill(Some(2)).over([col("id")]).alias("fw"),
+            col("x")
+                .backward_fill(Some(1))
+                .over([col("id")])
+                .alias("bw"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("fw")?.i32()?),
+        &[Some(1), Some(1), Some(1), None, None, Some(7), Some(7)]
+    );
+    assert_eq!(
+        Vec::from(out.column("bw")?.i32()?),
+        &[Some(1), None, None, None, Some(7), Some(7), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "round_series")]
+fn test_clip_with_group_bounds() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "x" => [1.0, 5.0, 10.0, 100.0, 200.0, 300.0]
+    ]?;
+
+    let bound = |q: f64| {
+        col("x")
+            .quantile(lit(q), QuantileMethod::Lower)
+            .over([col("g")])
+    };
+    let out = df
+        .lazy()
+        .select([col("x").clip(bound(0.5), bound(1.0))])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("x")?.f64()?),
+        &[
+            Some(5.0),
+            Some(5.0),
+            Some(10.0),
+            Some(200.0),
+            Some(200.0),
+            Some(300.0)
+        ]
+    );
+    Ok(())
+}
+
 #[cfg(feature = "cross_join")]
 #[test]
 fn test_cross_join() -> PolarsResult<()> {
@@ -1221,6 +1430,160 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[cfg(all(feature = "cross_join", not(feature = "bigidx")))]
+#[test]
+fn test_cross_join_overflow() -> PolarsResult<()> {
+    use polars_ops::frame::{CrossJoin, MaintainOrderJoin};
+
+    // 2^16 * 2^16 rows is one more than the largest index that fits into `IdxSize`.
+    let df1 = df!["a" => vec![0i32; 1 << 16]]?;
+    let df2 = df!["b" => vec![0i32; 1 << 16]]?;
+    let err = df1
+        .cross_join(&df2, None, None, MaintainOrderJoin::None)
+        .unwrap_err();
+    assert!(err.to_string().contains("bigidx"));
+
+    // Slicing does not lift the limit on the total number of rows.
+    assert!(
+        df1.cross_join(&df2, None, Some((0, 10)), MaintainOrderJoin::None)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "merge_sorted")]
+fn test_merge_sorted_by() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 1, 2, 3],
+        "b" => [9, 2, 5, 1],
+    ]?;
+    let right = df![
+        "a" => [1, 2, 2, 4],
+        "b" => [4, 8, 3, 0],
+    ]?;
+    let options = SortMultipleOptions::default().with_order_descending_multi([false, true]);
+
+    let out = left
+        .lazy()
+        .merge_sorted_by(right.lazy(), ["a", "b"], options)?
+        .collect()?;
+    let expected = df![
+        "a" => [1, 1, 1, 2, 2, 2, 3, 4],
+        "b" => [9, 4, 2, 8, 5, 3, 1, 0],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_update() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 2, 3],
+        "a" => [Some(10), None, Some(30)],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let other = df![
+        "id" => [3, 2, 4],
+        "a" => [None, Some(200), Some(400)],
+        "c" => [true, false, true],
+    ]?;
+    let update = |how, strategy| {
+        df.clone()
+            .lazy()
+            .update(other.clone().lazy(), ["id"], how, strategy)
+            .collect()
+    };
+
+    let out = update(UpdateHow::Left, UpdateStrategy::NonNull)?;
+    let expected = df![
+        "id" => [1, 2, 3],
+        "a" => [Some(10), Some(200), Some(30)],
+        "b" => ["x", "y", "z"],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = update(UpdateHow::Left, UpdateStrategy::IncludeNulls)?;
+    let expected = df![
+        "id" => [1, 2, 3],
+        "a" => [Some(10), Some(200), None],
+        "b" => ["x", "y", "z"],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = update(UpdateHow::Full, UpdateStrategy::FillNull)?;
+    let expected = df![
+        "id" => [1, 2, 3, 4],
+        "a" => [Some(10), Some(200), Some(30), Some(400)],
+        "b" => [Some("x"), Some("y"), Some("z"), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = update(UpdateHow::Inner, UpdateStrategy::NonNull)?;
+    assert_eq!(out.column("id")?.i32()?.to_vec(), [Some(2), Some(3)]);
+    Ok(())
+}
+
+#[test]
+fn test_partition_by_iter() -> PolarsResult<()> {
+    let df = df![
+        "k" => [Some(2), Some(1), None, Some(2), Some(1)],
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+
+    let parts = df
+        .lazy()
+        .partition_by_iter(["k"], false, Engine::InMemory)?
+        .collect::<Vec<_>>();
+    assert_eq!(parts.len(), 3);
+    let (keys, values): (Vec<_>, Vec<_>) = parts
+        .into_iter()
+        .map(|(key, part)| {
+            (
+                key[0].clone(),
+                Vec::from(part.column("v").unwrap().i32().unwrap()),
+            )
+        })
+        .unzip();
+    assert_eq!(
+        keys,
+        [AnyValue::Int32(1), AnyValue::Int32(2), AnyValue::Null]
+    );
+    assert_eq!(
+        values,
+        [
+            vec![Some(2), Some(5)],
+            vec![Some(1), Some(4)],
+            vec![Some(3)]
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lazy_transpose() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i64, 2],
+        "b" => [3i32, 4],
+    ]?;
+
+    let lf = df
+        .lazy()
+        .transpose(Some("column".into()), vec!["x".into(), "y".into()]);
+    let schema = lf.clone().collect_schema()?;
+    assert_eq!(schema.get("column"), Some(&DataType::String));
+    assert_eq!(schema.get("x"), Some(&DataType::Int64));
+
+    let out = lf.collect()?;
+    let expected = df![
+        "column" => ["a", "b"],
+        "x" => [1i64, 3],
+        "y" => [2i64, 4],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -2044,3 +2407,788 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_or_else() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["1", "x", "3"],
+        "b" => [10, 20, 30],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .strict_cast(DataType::Int32)
+                .or_else(col("b"))
+                .alias("fallback"),
+            col("a")
+                .strict_cast(DataType::Int32)
+                .or_else(lit(NULL))
+                .alias("null"),
+            col("b").cast(DataType::Int32).or_else(lit(0)).alias("ok"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("fallback")?.i32()?),
+        &[Some(10), Some(20), Some(30)]
+    );
+    assert_eq!(out.column("null")?.null_count(), 3);
+    assert_eq!(
+        Vec::from(out.column("ok")?.i32()?),
+        &[Some(10), Some(20), Some(30)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_or_else_common_subexpr() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["1", "x", "3"],
+        "b" => [10, 20, 30],
+    ]?;
+
+    // The same fallible input occurs twice, it must not be hoisted out of `or_else`.
+    let primary = || col("a").strict_cast(DataType::Int32);
+    let out = df
+        .lazy()
+        .with_comm_subexpr_elim(true)
+        .filter(primary().or_else(lit(1)).gt(lit(0)))
+        .select([
+            primary().or_else(col("b")).alias("x"),
+            (primary() + lit(1)).or_else(lit(0)).alias("y"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(10), Some(20), Some(30)]
+    );
+    assert_eq!(
+        Vec::from(out.column("y")?.i32()?),
+        &[Some(0), Some(0), Some(0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_lstsq_group_by() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "x" => [1.0, 2.0, 3.0, 1.0, 2.0, 3.0],
+        "y" => [3.0, 5.0, 7.0, 1.0, 0.0, -1.0],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            lstsq(col("y"), [col("x")], true).alias("ols"),
+            polyfit(col("x"), col("y"), 1).alias("poly"),
+        ])
+        .select([
+            col("ols").struct_().field_by_name("intercept"),
+            col("ols").struct_().field_by_name("x"),
+            col("poly").struct_().field_by_name("c1"),
+        ])
+        .collect()?;
+
+    let intercept = out.column("intercept")?.f64()?;
+    let slope = out.column("x")?.f64()?;
+    let c1 = out.column("c1")?.f64()?;
+    for (i, (b, m)) in [(1.0, 2.0), (2.0, -1.0)].into_iter().enumerate() {
+        assert!((intercept.get(i).unwrap() - b).abs() < 1e-10);
+        assert!((slope.get(i).unwrap() - m).abs() < 1e-10);
+        assert!((c1.get(i).unwrap() - m).abs() < 1e-10);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pct_change")]
+fn test_pct_change_with_fill_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [1.0, 2.0, 3.0, 10.0, 5.0],
+        "n" => [1i64, 1, 1, 1, 1],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("v")
+            .pct_change_with_fill(col("n").first(), lit(0.0))
+            .over([col("g")])])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("v")?.f64()?),
+        &[Some(0.0), Some(1.0), Some(0.5), Some(0.0), Some(-0.5)]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "diff")]
+fn test_diff_order() -> PolarsResult<()> {
+    let df = df![
+        "v" => [1i64, 4, 9, 16, 25, 36],
+        "lag" => [2i64, 2, 2, 2, 2, 2],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("v")
+                .diff_order(lit(1), 2, NullBehavior::Ignore)
+                .alias("second"),
+            col("v")
+                .diff_order(col("lag").first(), 1, NullBehavior::Ignore)
+                .alias("lagged"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("second")?.i64()?),
+        &[None, None, Some(2), Some(2), Some(2), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("lagged")?.i64()?),
+        &[None, None, Some(8), Some(12), Some(16), Some(20)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_rand_distributions() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b", "b"],
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_columns([
+            rand_uniform(len(), 1.0, 2.0, Some(0)).alias("u"),
+            rand_uniform(len(), 1.0, 2.0, Some(0)).alias("u2"),
+            rand_normal(len(), 0.0, 1.0, Some(1)).alias("n"),
+            rand_bernoulli(len(), 1.0, None).over([col("g")]).alias("b"),
+        ])
+        .collect()?;
+
+    let u = out.column("u")?.f64()?;
+    assert_eq!(u.len(), 5);
+    assert!(u.into_no_null_iter().all(|v| (1.0..2.0).contains(&v)));
+    assert!(out.column("u")?.equals(out.column("u2")?));
+    assert_eq!(out.column("n")?.dtype(), &DataType::Float64);
+    assert!(out.column("b")?.bool()?.all());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "bitwise")]
+fn test_bitwise_flags() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "flags" => [0b0101u8, 0b0011, 0b1000],
+        "n" => [1u32, 2, 3],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("flags").bitwise_shift_left(col("n")).alias("shl"),
+            col("flags").bitwise_shift_right(lit(3)).alias("shr"),
+            col("flags").bitwise_not().alias("not"),
+        ])
+        .collect()?;
+    let shl: Vec<_> = out.column("shl")?.u8()?.into_no_null_iter().collect();
+    assert_eq!(shl, &[0b1010, 0b1100, 0b0100_0000]);
+    let shr: Vec<_> = out.column("shr")?.u8()?.into_no_null_iter().collect();
+    assert_eq!(shr, &[0, 0, 1]);
+    let not: Vec<_> = out.column("not")?.u8()?.into_no_null_iter().collect();
+    assert_eq!(not, &[!0b0101u8, !0b0011, !0b1000]);
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("flags").bitwise_or().alias("or"),
+            col("flags").bitwise_and().alias("and"),
+        ])
+        .collect()?;
+    let or: Vec<_> = out.column("or")?.u8()?.into_no_null_iter().collect();
+    assert_eq!(or, &[0b0111, 0b1000]);
+    let and: Vec<_> = out.column("and")?.u8()?.into_no_null_iter().collect();
+    assert_eq!(and, &[0b0001, 0b1000]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "arg_where", feature = "index_of"))]
+fn test_arg_where_and_index_of_group_by() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "x" => [1, 5, 7, 2, 3],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            arg_where(col("x").gt(lit(2))).alias("all"),
+            arg_where_first(col("x").gt(lit(4))).alias("first"),
+            col("x").index_of(lit(3)).alias("index_of"),
+        ])
+        .collect()?;
+
+    let all = out.column("all")?.list()?;
+    let all: Vec<Vec<IdxSize>> = all
+        .into_no_null_iter()
+        .map(|s| s.idx().unwrap().into_no_null_iter().collect())
+        .collect();
+    assert_eq!(all, [vec![1, 2], vec![1]]);
+
+    let first: Vec<_> = out.column("first")?.idx()?.iter().collect();
+    assert_eq!(first, &[Some(1), None]);
+
+    let index_of: Vec<_> = out.column("index_of")?.idx()?.iter().collect();
+    assert_eq!(index_of, &[None, Some(1)]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rolling_window")]
+fn test_rolling_frame_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "a", "b"],
+        "x" => [1, 2, 10, 3, 20],
+    ]?;
+
+    let frame = WindowFrame::new(FrameBound::Preceding(1), FrameBound::Following(1));
+    let out = df
+        .lazy()
+        .select([
+            col("x")
+                .rolling_frame(FrameAggregation::Sum, frame)
+                .over([col("g")])
+                .alias("sum"),
+            col("x")
+                .rolling_frame(
+                    FrameAggregation::Max,
+                    WindowFrame::new(FrameBound::UnboundedPreceding, FrameBound::CurrentRow),
+                )
+                .over([col("g")])
+                .alias("running_max"),
+        ])
+        .collect()?;
+
+    let sum: Vec<_> = out.column("sum")?.i32()?.into_no_null_iter().collect();
+    assert_eq!(sum, &[3, 6, 30, 5, 30]);
+    let running_max: Vec<_> = out
+        .column("running_max")?
+        .i32()?
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(running_max, &[1, 2, 10, 3, 20]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rank")]
+fn test_percent_rank_and_ntile_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "a", "b", "a"],
+        "x" => [4, 1, 7, 2, 5, 3],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("x").percent_rank(false).alias("pr"),
+            col("x").percent_rank(false).over([col("g")]).alias("pr_g"),
+            col("x").ntile(2, false).over([col("g")]).alias("ntile_g"),
+        ])
+        .collect()?;
+
+    let pr: Vec<_> = out.column("pr")?.f64()?.into_no_null_iter().collect();
+    assert_eq!(pr, &[0.6, 0.0, 1.0, 0.2, 0.8, 0.4]);
+    let pr_g: Vec<_> = out.column("pr_g")?.f64()?.into_no_null_iter().collect();
+    assert_eq!(pr_g, &[1.0, 0.0, 1.0, 1.0 / 3.0, 0.0, 2.0 / 3.0]);
+    let ntile_g: Vec<_> = out.column("ntile_g")?.idx()?.into_no_null_iter().collect();
+    assert_eq!(ntile_g, &[2, 1, 2, 1, 1, 2]);
+
+    Ok(())
+}
+
+#[test]
+fn test_lead_lag_with_expression_default() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "a", "b"],
+        "x" => [1, 2, 10, 3, 20],
+        "y" => [-1, -2, -3, -4, -5],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("x").lag(1, Some(col("y"))).alias("lag_y"),
+            col("x").lead(2, None)?.alias("lead"),
+            col("x")
+                .lag(1, Some(col("x").first()))
+                .over([col("g")])
+                .alias("lag_first"),
+            col("x")
+                .lead(1, Some(col("y")))?
+                .over([col("g")])
+                .alias("lead_y"),
+        ])
+        .collect()?;
+
+    let lag_y: Vec<_> = out.column("lag_y")?.i32()?.iter().collect();
+    assert_eq!(lag_y, &[Some(-1), Some(1), Some(2), Some(10), Some(3)]);
+    let lead: Vec<_> = out.column("lead")?.i32()?.iter().collect();
+    assert_eq!(lead, &[Some(10), Some(3), Some(20), None, None]);
+    let lag_first: Vec<_> = out.column("lag_first")?.i32()?.iter().collect();
+    assert_eq!(lag_first, &[Some(1), Some(1), Some(10), Some(2), Some(10)]);
+    let lead_y: Vec<_> = out.column("lead_y")?.i32()?.iter().collect();
+    assert_eq!(lead_y, &[Some(2), Some(3), Some(20), Some(-4), Some(-5)]);
+
+    // The fill values are cast to the dtype of the shifted column.
+    let df = df![
+        "x" => [1i32, 2, 3],
+        "y" => [-1i64, -2, -3],
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("x").lag(1, Some(col("y"))).alias("lag_y")])
+        .collect()?;
+    let lag_y: Vec<_> = out.column("lag_y")?.i32()?.iter().collect();
+    assert_eq!(lag_y, &[Some(-1), Some(1), Some(2)]);
+
+    assert!(col("x").lead(i64::MIN, None).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cum_agg")]
+fn test_cum_n_unique_over() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "a", "b", "b"],
+        "x" => [Some(1), Some(1), Some(2), Some(1), None, Some(3)],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("x").cum_n_unique(false).alias("global"),
+            col("x")
+                .cum_n_unique(false)
+                .over([col("g")])
+                .alias("per_group"),
+            col("x")
+                .cum_n_unique(true)
+                .over([col("g")])
+                .alias("per_group_rev"),
+        ])
+        .collect()?;
+
+    assert_eq!(out.column("global")?.dtype(), &IDX_DTYPE);
+    let global: Vec<_> = out.column("global")?.idx()?.into_no_null_iter().collect();
+    assert_eq!(global, &[1, 1, 2, 2, 3, 4]);
+    let per_group: Vec<_> = out
+        .column("per_group")?
+        .idx()?
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(per_group, &[1, 1, 2, 2, 2, 3]);
+    let rev: Vec<_> = out
+        .column("per_group_rev")?
+        .idx()?
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(rev, &[2, 3, 2, 1, 2, 1]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn test_collect_async() -> PolarsResult<()> {
+    let lf = get_df().lazy().select([col("sepal_length").sum()]);
+    let expected = lf.clone().collect()?;
+    let out = polars_io::pl_async::get_runtime().block_on(lf.collect_async())?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_execution_provider() -> PolarsResult<()> {
+    use polars_expr::state::ExecutionState;
+    use polars_mem_engine::{
+        ExecutionProvider, Executor, register_execution_provider, unregister_execution_provider,
+    };
+    use polars_plan::plans::{AExpr, IR};
+    use polars_utils::arena::{Arena, Node};
+
+    struct ConstExec(DataFrame);
+
+    impl Executor for ConstExec {
+        fn execute(&mut self, _state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+            Ok(self.0.clone())
+        }
+    }
+
+    struct FailingExec;
+
+    impl Executor for FailingExec {
+        fn execute(&mut self, _state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+            polars_bail!(InvalidOperation: "not supported on this device")
+        }
+    }
+
+    struct TestProvider;
+
+    impl ExecutionProvider for TestProvider {
+        fn name(&self) -> &str {
+            "test-provider"
+        }
+
+        fn claim(
+            &self,
+            root: Node,
+            lp_arena: &Arena<IR>,
+            _expr_arena: &Arena<AExpr>,
+        ) -> Option<PolarsResult<Box<dyn Executor>>> {
+            let IR::DataFrameScan { schema, .. } = lp_arena.get(root) else {
+                return None;
+            };
+            if schema.contains("__claimed") {
+                let df = df!["__claimed" => [42i32]].unwrap();
+                Some(Ok(Box::new(ConstExec(df))))
+            } else if schema.contains("__failing") {
+                Some(Ok(Box::new(FailingExec)))
+            } else {
+                None
+            }
+        }
+    }
+
+    register_execution_provider(Arc::new(TestProvider));
+
+    let out = df!["__claimed" => [1i32, 2]]?
+        .lazy()
+        .with_column(col("__claimed") * lit(2))
+        .collect()?;
+    assert!(out.equals(&df!["__claimed" => [84i32]]?));
+
+    let df = df!["__failing" => [1i32, 2]]?;
+    let out = df.clone().lazy().collect()?;
+    assert!(out.equals(&df));
+
+    unregister_execution_provider("test-provider");
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "to_dummies")]
+fn test_lazy_to_dummies() -> PolarsResult<()> {
+    use polars_ops::frame::DataFrameOps;
+
+    let df = df! {
+        "id" => [1, 2, 3],
+        "flag" => [Some(true), None, Some(false)],
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .to_dummies(
+            by_name(["flag"], true),
+            Some(":".into()),
+            true,
+            false,
+            DummyDtype::Boolean,
+        )
+        .collect()?;
+    let expected = df! {
+        "id" => [1, 2, 3],
+        "flag:null" => [false, true, false],
+        "flag:true" => [true, false, false],
+    }?;
+    assert!(out.equals(&expected));
+
+    let eager = df.columns_to_dummies(vec!["flag"], Some(":"), false, true, DummyDtype::Integer)?;
+    assert_eq!(eager.get_column_names(), &["id", "flag:false", "flag:true"]);
+    assert_eq!(eager.column("flag:true")?.dtype(), &DataType::UInt8);
+
+    assert!(
+        df.lazy()
+            .to_dummies(all(), None, false, false, DummyDtype::Integer)
+            .collect()
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_map_batches_streaming() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let n = 300_000i64;
+    let df = df!["a" => (0..n).collect::<Vec<_>>()]?;
+    let q = df.lazy().map_batches(
+        |df| {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            let a = df.column("a")?.as_materialized_series() * 2;
+            DataFrame::new(vec![a.into_column()])
+        },
+        None,
+        Some("double"),
+    );
+
+    let out = q.clone().collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("a")?.i64()?.sum(), Some(n * (n - 1)));
+    // The function is applied to every morsel instead of the materialized input.
+    assert!(CALLS.load(Ordering::Relaxed) > 1);
+
+    assert!(out.equals(&q.collect()?));
+    Ok(())
+}
+
+#[test]
+fn test_group_by_set_sorted() -> PolarsResult<()> {
+    let df = df![
+        "k" => [Some("a"), Some("a"), Some("b"), Some("c"), Some("c"), None],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    let q = df
+        .lazy()
+        .set_sorted(["k"], SortMultipleOptions::default().with_nulls_last(true))?
+        .group_by([col("k")])
+        .agg([col("v").sum()]);
+
+    let expected = df![
+        "k" => [Some("a"), Some("b"), Some("c"), None],
+        "v" => [3, 3, 9, 6],
+    ]?;
+    let out = q.clone().collect()?;
+    assert!(
+        out.sort(["k"], Default::default())?
+            .equals_missing(&expected.sort(["k"], Default::default())?)
+    );
+    #[cfg(feature = "new_streaming")]
+    {
+        let out = q.collect_with_engine(Engine::Streaming)?;
+        assert!(
+            out.sort(["k"], Default::default())?
+                .equals_missing(&expected.sort(["k"], Default::default())?)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_group_by_sum_integer_overflow() -> PolarsResult<()> {
+    use polars_core::config::{Config, IntegerOverflow};
+
+    let df = df![
+        "k" => [1, 1, 2],
+        "v" => [i64::MAX, 1, 2],
+    ]?;
+    let sums = |key: Expr, mode, engine| -> PolarsResult<Vec<Option<i64>>> {
+        let _guard = Config {
+            integer_overflow: Some(mode),
+            ..Config::current()
+        }
+        .apply();
+        let out = df
+            .clone()
+            .lazy()
+            .group_by([key.alias("k")])
+            .agg([col("v").sum()])
+            .sort(["k"], Default::default())
+            .collect_with_engine(engine)?;
+        Ok(out.column("v")?.i64()?.to_vec())
+    };
+
+    // A key expression isn't partitionable, so the in-memory engine aggregates the groups itself
+    // instead of with the streaming reducers.
+    let mut cases = vec![(col("k") * lit(2), Engine::InMemory)];
+    #[cfg(feature = "new_streaming")]
+    cases.extend([(col("k"), Engine::InMemory), (col("k"), Engine::Streaming)]);
+    for (key, engine) in cases {
+        assert!(sums(key.clone(), IntegerOverflow::Raise, engine).is_err());
+        assert_eq!(
+            sums(key.clone(), IntegerOverflow::Saturate, engine)?,
+            [Some(i64::MAX), Some(2)]
+        );
+        assert_eq!(
+            sums(key, IntegerOverflow::Wrap, engine)?,
+            [Some(i64::MIN), Some(2)]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_config_per_thread() -> PolarsResult<()> {
+    use polars_core::config::{Config, IntegerOverflow};
+
+    let df = df!["v" => [i64::MAX]]?;
+    let add = |mode| {
+        let _guard = Config {
+            integer_overflow: Some(mode),
+            ..Config::current()
+        }
+        .apply();
+        df.clone().lazy().select([col("v") + lit(1i64)]).collect()
+    };
+    // Queries that run concurrently on different threads don't see each other's configuration.
+    std::thread::scope(|s| {
+        let raise = s.spawn(|| (0..20).all(|_| add(IntegerOverflow::Raise).is_err()));
+        let wrap = s.spawn(|| (0..20).all(|_| add(IntegerOverflow::Wrap).is_ok()));
+        assert!(raise.join().unwrap());
+        assert!(wrap.join().unwrap());
+    });
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "spill", feature = "new_streaming"))]
+fn test_partitionable_group_by_spill() -> PolarsResult<()> {
+    use polars_core::config::Config;
+    use polars_core::diagnostics::{diagnostic_channel, set_diagnostic_callback};
+
+    // The diagnostic callback is global.
+    let _lock = polars_core::SINGLE_LOCK.lock().unwrap();
+    let n = 10_000i64;
+    let df = df![
+        "k" => (0..n).map(|i| i % 100).collect::<Vec<_>>(),
+        "v" => (0..n).collect::<Vec<_>>(),
+    ]?;
+    // Only column keys and aggregations that can be pre-aggregated, so the group-by is
+    // partitionable.
+    let q = df
+        .lazy()
+        .group_by([col("k")])
+        .agg([col("v").sum(), col("v").max().alias("max")])
+        .sort(["k"], Default::default());
+    let expected = q.clone().collect_with_engine(Engine::InMemory)?;
+
+    let diagnostics = diagnostic_channel();
+    let out = {
+        let _guard = Config {
+            memory_limit: Some(1),
+            ..Config::current()
+        }
+        .apply();
+        q.collect_with_engine(Engine::InMemory)
+    };
+    set_diagnostic_callback(None);
+    assert!(out?.equals(&expected));
+    assert!(
+        diagnostics
+            .try_iter()
+            .any(|d| d.message.starts_with("streaming_group_by: the input of"))
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "spill")]
+fn test_join_spill() -> PolarsResult<()> {
+    use polars_core::config::Config;
+    use polars_core::diagnostics::{diagnostic_channel, set_diagnostic_callback};
+
+    // The diagnostic callback is global.
+    let _lock = polars_core::SINGLE_LOCK.lock().unwrap();
+    let n = 1_000i64;
+    let left = df![
+        "k" => (0..n).map(|i| i % 50).collect::<Vec<_>>(),
+        "a" => (0..n).collect::<Vec<_>>(),
+    ]?;
+    let right = df![
+        "k" => (0..100i64).collect::<Vec<_>>(),
+        "b" => (0..100i64).map(|i| -i).collect::<Vec<_>>(),
+    ]?;
+
+    for how in [
+        JoinType::Inner,
+        JoinType::Left,
+        JoinType::Right,
+        JoinType::Full,
+    ] {
+        let q = left
+            .clone()
+            .lazy()
+            .join(
+                right.clone().lazy(),
+                [col("k")],
+                [col("k")],
+                JoinArgs::new(how.clone()),
+            )
+            .sort(["a", "b"], Default::default());
+        let expected = q.clone().collect_with_engine(Engine::InMemory)?;
+
+        let diagnostics = diagnostic_channel();
+        let out = {
+            let _guard = Config {
+                memory_limit: Some(1),
+                ..Config::current()
+            }
+            .apply();
+            q.collect_with_engine(Engine::InMemory)
+        };
+        set_diagnostic_callback(None);
+        assert!(out?.equals_missing(&expected), "{how:?}");
+        assert!(
+            diagnostics
+                .try_iter()
+                .any(|d| d.message.starts_with("join: the input of")),
+            "{how:?}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_group_by_set_sorted_multiple_keys() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 1, 1, 2, 2, 3],
+        "b" => ["x", "y", "y", "x", "x", "z"],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    let q = df
+        .lazy()
+        .set_sorted(["a", "b"], Default::default())?
+        .group_by([col("a"), col("b")])
+        .agg([col("v").sum()]);
+
+    let expected = df![
+        "a" => [1, 1, 2, 3],
+        "b" => ["x", "y", "x", "z"],
+        "v" => [1, 5, 9, 6],
+    ]?;
+    let out = q.clone().collect()?;
+    assert!(out.sort(["a", "b"], Default::default())?.equals(&expected));
+    #[cfg(feature = "new_streaming")]
+    {
+        let out = q.collect_with_engine(Engine::Streaming)?;
+        assert!(out.sort(["a", "b"], Default::default())?.equals(&expected));
+    }
+    Ok(())
+}
@@ -46,3 +46,51 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_match_to_schema_reports_all_mismatches() -> PolarsResult<()> {
+    let df = df![
+        "b" => [1i32, 2],
+        "a" => [1i64, 2],
+        "c" => ["x", "y"],
+    ]?;
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::Int64),
+        Field::new("d".into(), DataType::Float64),
+    ]));
+    let per_column: Arc<[_]> = vec![MatchToSchemaPerColumn::default(); 3].into();
+
+    let err = df
+        .clone()
+        .lazy()
+        .match_to_schema(schema.clone(), per_column, ExtraColumnsPolicy::Raise)
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+    let msg = err.to_string();
+    assert!(msg.contains("missing columns in `match_to_schema`: \"d\"\n"));
+    assert!(msg.contains("data type mismatch for column b"));
+    assert!(msg.contains("extra columns in `match_to_schema`: \"c\"\n"));
+    assert!(msg.ends_with("- b: i32\n+ b: i64\n+ d: f64\n- c: str"));
+
+    let per_column: Arc<[_]> = vec![
+        MatchToSchemaPerColumn::default(),
+        MatchToSchemaPerColumn {
+            integer_cast: UpcastOrForbid::Upcast,
+            ..Default::default()
+        },
+        MatchToSchemaPerColumn {
+            missing_columns: MissingColumnsPolicyOrExpr::Insert,
+            ..Default::default()
+        },
+    ]
+    .into();
+    let out = df
+        .lazy()
+        .match_to_schema(schema.clone(), per_column, ExtraColumnsPolicy::Ignore)
+        .collect()?;
+    assert_eq!(out.schema(), &schema);
+    assert_eq!(out.column("d")?.null_count(), 2);
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+/// Executes a subtree claimed by an [`ExecutionProvider`], falling back to the plan of the
+/// default engine if the provider fails.
+///
+/// [`ExecutionProvider`]: crate::ExecutionProvider
+pub(crate) struct ExecutionProviderExec {
+    pub(crate) provider: PlSmallStr,
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) fallback: Box<dyn Executor>,
+}
+
+impl Executor for ExecutionProviderExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        match self.input.execute(state) {
+            Ok(df) => Ok(df),
+            Err(err) => {
+                if state.verbose() {
+                    eprintln!(
+                        "execution provider '{}' failed, falling back to default engine: {err}",
+                        self.provider
+                    )
+                }
+                self.fallback.execute(state)
+            },
+        }
+    }
+}
@@ -8,6 +8,9 @@ pub struct FilterExec {
     // if the predicate contains a window function
     has_window: bool,
     streamable: bool,
+    /// The columns that are used after the filter. Columns that are only used by the predicate
+    /// are dropped before filtering, so that they are never gathered.
+    pub(crate) projection: Option<Arc<[PlSmallStr]>>,
 }
 
 fn column_to_mask(c: &Column) -> PolarsResult<&BooleanChunked> {
@@ -18,6 +21,18 @@ fn column_to_mask(c: &Column) -> PolarsResult<&BooleanChunked> {
     })
 }
 
+/// The columns of `df` that are in `projection`. Columns of the projection that are not in
+/// `df`, such as those of a `with_context` frame, are resolved elsewhere by the projection.
+fn select_projected(df: &DataFrame, projection: &[PlSmallStr]) -> DataFrame {
+    let schema = df.schema();
+    let columns = projection
+        .iter()
+        .filter_map(|name| Some(df.get_columns()[schema.index_of(name)?].clone()))
+        .collect();
+    // SAFETY: the columns are taken from `df` and the projection has unique names.
+    unsafe { DataFrame::new_no_checks(df.height(), columns) }
+}
+
 impl FilterExec {
     pub fn new(
         predicate: Arc<dyn PhysicalExpr>,
@@ -30,6 +45,15 @@ impl FilterExec {
             input,
             has_window,
             streamable,
+            projection: None,
+        }
+    }
+
+    fn filter(&self, df: &DataFrame, predicate: &Column) -> PolarsResult<DataFrame> {
+        let mask = column_to_mask(predicate)?;
+        match &self.projection {
+            Some(projection) => select_projected(df, projection).filter(mask),
+            None => df.filter(mask),
         }
     }
 
@@ -41,14 +65,17 @@ impl FilterExec {
         if self.has_window {
             state.insert_has_window_function_flag()
         }
-        let c = self.predicate.evaluate(&df, state)?;
+        let c = self
+            .predicate
+            .evaluate(&df, state)
+            .map_err(|err| add_expr_provenance(err, self.predicate.as_ref(), "filter"))?;
         if self.has_window {
             state.clear_window_expr_cache()
         }
 
         // @scalar-opt
         // @partition-opt
-        df.filter(column_to_mask(&c)?)
+        self.filter(&df, &c)
     }
 
     fn execute_chunks(
@@ -57,11 +84,14 @@ impl FilterExec {
         state: &ExecutionState,
     ) -> PolarsResult<DataFrame> {
         let iter = chunks.into_par_iter().map(|df| {
-            let c = self.predicate.evaluate(&df, state)?;
+            let c = state
+                .config
+                .enter(|| self.predicate.evaluate(&df, state))
+                .map_err(|err| add_expr_provenance(err, self.predicate.as_ref(), "filter"))?;
 
             // @scalar-opt
             // @partition-opt
-            df.filter(column_to_mask(&c)?)
+            self.filter(&df, &c)
         });
         let df = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
         Ok(accumulate_dataframes_vertical_unchecked(df))
@@ -119,3 +149,29 @@ impl Executor for FilterExec {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_select_projected() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"],
+            "c" => [1.0, 2.0, 3.0],
+        ]?;
+        // The predicate-only column `a` is not gathered, and `ctx` is not in the frame.
+        let projection = ["c", "ctx", "b"].map(PlSmallStr::from_static);
+        let out = select_projected(&df, &projection);
+        assert_eq!(out.get_column_names_str(), &["c", "b"]);
+        assert_eq!(out.height(), 3);
+
+        let out = select_projected(&df, &[]);
+        assert_eq!(out.width(), 0);
+        assert_eq!(out.height(), 3);
+        Ok(())
+    }
+}
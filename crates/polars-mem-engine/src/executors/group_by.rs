@@ -1,5 +1,9 @@
+#[cfg(feature = "spill")]
+use polars_core::memory::try_reserve_memory;
 use rayon::prelude::*;
 
+#[cfg(feature = "spill")]
+use super::spill::*;
 use super::*;
 
 pub(super) fn evaluate_aggs(
@@ -7,11 +11,16 @@ pub(super) fn evaluate_aggs(
     aggs: &[Arc<dyn PhysicalExpr>],
     groups: &GroupPositions,
     state: &ExecutionState,
+    node: &str,
 ) -> PolarsResult<Vec<Column>> {
     POOL.install(|| {
         aggs.par_iter()
             .map(|expr| {
-                let agg = expr.evaluate_on_groups(df, groups, state)?.finalize();
+                let agg = state
+                    .config
+                    .enter(|| expr.evaluate_on_groups(df, groups, state))
+                    .map_err(|err| add_expr_provenance(err, expr.as_ref(), node))?
+                    .finalize();
                 polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
                 Ok(agg)
             })
@@ -84,7 +93,7 @@ pub(super) fn group_by_helper(
     let (mut columns, agg_columns) = POOL.install(|| {
         let get_columns = || gb.keys_sliced(slice);
 
-        let get_agg = || evaluate_aggs(&df, aggs, groups, state);
+        let get_agg = || evaluate_aggs(&df, aggs, groups, state, "group_by");
 
         rayon::join(get_columns, get_agg)
     });
@@ -98,8 +107,27 @@ impl GroupByExec {
         let keys = self
             .keys
             .iter()
-            .map(|e| e.evaluate(&df, state))
-            .collect::<PolarsResult<_>>()?;
+            .map(|e| {
+                e.evaluate(&df, state)
+                    .map_err(|err| add_expr_provenance(err, e.as_ref(), "group_by"))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        #[cfg(feature = "spill")]
+        let _reservation = {
+            let size = estimated_memory(&df);
+            let reservation = try_reserve_memory(size);
+            if reservation.is_none()
+                && self.apply.is_none()
+                && !self.maintain_order
+                && self.slice.is_none()
+                && can_spill(&df, &keys)
+            {
+                return self.execute_spilled(state, df, keys, size);
+            }
+            reservation
+        };
+
         group_by_helper(
             df,
             keys,
@@ -110,6 +138,24 @@ impl GroupByExec {
             self.slice,
         )
     }
+
+    /// Group every partition of the spilled input separately, which is possible because all the
+    /// rows of a group are in the same partition.
+    #[cfg(feature = "spill")]
+    fn execute_spilled(
+        &self,
+        state: &ExecutionState,
+        df: DataFrame,
+        keys: Vec<Column>,
+        size: usize,
+    ) -> PolarsResult<DataFrame> {
+        let n_partitions = n_spill_partitions("group_by", size);
+        let partitions = spill_partitions(df, keys, n_partitions)?;
+        collect_spilled(partitions.iter().map(|partition| {
+            let (df, keys) = partition.load()?;
+            group_by_helper(df, keys, &self.aggs, None, state, false, None)
+        }))
+    }
 }
 
 impl Executor for GroupByExec {
@@ -73,7 +73,7 @@ impl GroupByDynamicExec {
             }
         }
 
-        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
+        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state, "group_by_dynamic")?;
 
         let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
         columns.extend_from_slice(&keys);
@@ -18,6 +18,16 @@ pub(super) fn sort_and_groups(
     df: &mut DataFrame,
     keys: &mut Vec<Column>,
 ) -> PolarsResult<Vec<[IdxSize; 2]>> {
+    // The runs of an ascending key already are the groups, so it doesn't have to be encoded and
+    // sorted. A descending key still is sorted, so the groups come out in ascending order.
+    if let [key] = keys.as_slice()
+        && key.is_sorted_flag() == polars_core::series::IsSorted::Ascending
+        && let GroupsType::Slice { groups, .. } =
+            key.as_materialized_series().group_tuples(true, false)?
+    {
+        return Ok(groups);
+    }
+
     let encoded = row_encode::encode_rows_vertical_par_unordered(keys)?;
     let encoded = encoded.rechunk().into_owned();
     let encoded = encoded.with_name(unique_column_name());
@@ -106,7 +116,7 @@ impl GroupByRollingExec {
             }
         }
 
-        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
+        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state, "rolling")?;
 
         let mut columns = Vec::with_capacity(agg_columns.len() + 1 + keys.len());
         columns.extend_from_slice(&keys);
@@ -153,3 +163,45 @@ impl Executor for GroupByRollingExec {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use polars_core::df;
+    use polars_core::series::IsSorted;
+
+    use super::*;
+
+    #[test]
+    fn test_sort_and_groups_sorted_key() -> PolarsResult<()> {
+        let df = df![
+            "k" => [1, 1, 2, 3, 3],
+            "v" => [0, 1, 2, 3, 4],
+        ]?;
+        let groups_of = |df: &DataFrame, flag: Option<IsSorted>| {
+            let mut df = df.clone();
+            let mut key = df.column("k").unwrap().clone();
+            if let Some(flag) = flag {
+                key.set_sorted_flag(flag);
+            }
+            let mut keys = vec![key];
+            let groups = sort_and_groups(&mut df, &mut keys).unwrap();
+            (df, keys, groups)
+        };
+
+        // The runs of an ascending key are reused and the frame is left as is.
+        let (out, keys, groups) = groups_of(&df, Some(IsSorted::Ascending));
+        assert_eq!(groups, [[0, 2], [2, 1], [3, 2]]);
+        assert!(out.equals(&df));
+        assert!(keys[0].equals(df.column("k")?));
+
+        // A descending key is sorted like an unflagged one.
+        let df = df.reverse();
+        let (out, keys, groups) = groups_of(&df, Some(IsSorted::Descending));
+        let (expected, expected_keys, expected_groups) = groups_of(&df, None);
+        assert_eq!(groups, expected_groups);
+        assert!(out.equals(&expected));
+        assert!(keys[0].equals(&expected_keys[0]));
+        assert_eq!(groups, [[0, 2], [2, 1], [3, 2]]);
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@ use std::borrow::Cow;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
+#[cfg(feature = "spill")]
+use polars_core::memory::try_reserve_memory;
 #[cfg(feature = "dtype-categorical")]
 use polars_core::prelude::DataType;
 use polars_core::prelude::{Column, GroupsType};
@@ -13,6 +15,8 @@ use polars_expr::state::ExecutionState;
 use polars_plan::plans::{AExpr, IR, IRPlan};
 use polars_utils::arena::{Arena, Node};
 
+#[cfg(feature = "spill")]
+use super::spill::*;
 use super::{Executor, check_expand_literals, group_by_helper};
 use crate::StreamingExecutorBuilder;
 
@@ -96,6 +100,65 @@ impl GroupByStreamingExec {
     fn keys(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Vec<Column>> {
         compute_keys(&self.phys_keys, df, state)
     }
+
+    fn execute_impl(
+        &mut self,
+        state: &mut ExecutionState,
+        input_df: DataFrame,
+        keys: Vec<Column>,
+        profile_name: Cow<'static, str>,
+    ) -> PolarsResult<DataFrame> {
+        if !can_run_partitioned(&keys, &input_df, state, self.from_partitioned_ds)? {
+            return group_by_helper(
+                input_df,
+                keys,
+                &self.phys_aggs,
+                None,
+                state,
+                self.maintain_order,
+                self.slice,
+            );
+        }
+
+        // Insert the input DataFrame into our DataFrame scan node
+        if let IR::DataFrameScan { df, schema, .. } =
+            self.plan.lp_arena.get_mut(self.input_scan_node)
+        {
+            *schema = input_df.schema().clone();
+            *df = Arc::new(input_df);
+        } else {
+            unreachable!();
+        }
+
+        let mut streaming_exec = (self.builder)(
+            self.plan.lp_top,
+            &mut self.plan.lp_arena,
+            &mut self.plan.expr_arena,
+        )?;
+
+        state
+            .clone()
+            .record(|| streaming_exec.execute(state), profile_name)
+    }
+
+    /// Group every partition of the spilled input separately, which is possible because all the
+    /// rows of a group are in the same partition.
+    #[cfg(feature = "spill")]
+    fn execute_spilled(
+        &mut self,
+        state: &mut ExecutionState,
+        input_df: DataFrame,
+        keys: Vec<Column>,
+        size: usize,
+        profile_name: Cow<'static, str>,
+    ) -> PolarsResult<DataFrame> {
+        let n_partitions = n_spill_partitions("streaming_group_by", size);
+        let partitions = spill_partitions(input_df, keys, n_partitions)?;
+        collect_spilled(partitions.iter().map(|partition| {
+            let (df, keys) = partition.load()?;
+            self.execute_impl(state, df, keys, profile_name.clone())
+        }))
+    }
 }
 
 fn compute_keys(
@@ -263,36 +326,20 @@ impl Executor for GroupByStreamingExec {
 
         let keys = self.keys(&input_df, state)?;
 
-        if !can_run_partitioned(&keys, &input_df, state, self.from_partitioned_ds)? {
-            return group_by_helper(
-                input_df,
-                keys,
-                &self.phys_aggs,
-                None,
-                state,
-                self.maintain_order,
-                self.slice,
-            );
-        }
-
-        // Insert the input DataFrame into our DataFrame scan node
-        if let IR::DataFrameScan { df, schema, .. } =
-            self.plan.lp_arena.get_mut(self.input_scan_node)
-        {
-            *schema = input_df.schema().clone();
-            *df = Arc::new(input_df);
-        } else {
-            unreachable!();
-        }
-
-        let mut streaming_exec = (self.builder)(
-            self.plan.lp_top,
-            &mut self.plan.lp_arena,
-            &mut self.plan.expr_arena,
-        )?;
+        #[cfg(feature = "spill")]
+        let _reservation = {
+            let size = estimated_memory(&input_df);
+            let reservation = try_reserve_memory(size);
+            if reservation.is_none()
+                && !self.maintain_order
+                && self.slice.is_none()
+                && can_spill(&input_df, &keys)
+            {
+                return self.execute_spilled(state, input_df, keys, size, profile_name);
+            }
+            reservation
+        };
 
-        state
-            .clone()
-            .record(|| streaming_exec.execute(state), profile_name)
+        self.execute_impl(state, input_df, keys, profile_name)
     }
 }
@@ -49,7 +49,8 @@ impl Executor for HConcatExec {
                                 let mut input = std::mem::take(input);
                                 let mut state = state.split();
                                 state.branch_idx += idx;
-                                input.execute(&mut state)
+                                let config = state.config.clone();
+                                config.enter(|| input.execute(&mut state))
                             })
                             .collect::<PolarsResult<Vec<_>>>()
                     })
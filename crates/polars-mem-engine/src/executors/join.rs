@@ -1,5 +1,11 @@
+#[cfg(feature = "spill")]
+use polars_core::config::memory_limit;
+#[cfg(feature = "spill")]
+use polars_core::memory::try_reserve_memory;
 use polars_ops::frame::DataFrameJoinOps;
 
+#[cfg(feature = "spill")]
+use super::spill::*;
 use super::*;
 
 pub struct JoinExec {
@@ -33,6 +39,159 @@ impl JoinExec {
             options,
         }
     }
+
+    fn join(
+        &self,
+        state: &ExecutionState,
+        df_left: &DataFrame,
+        df_right: &DataFrame,
+        left_on: Vec<Column>,
+        right_on: Vec<Column>,
+    ) -> PolarsResult<DataFrame> {
+        let df = df_left._join_impl(
+            df_right,
+            left_on
+                .into_iter()
+                .map(|c| c.take_materialized_series())
+                .collect(),
+            right_on
+                .into_iter()
+                .map(|c| c.take_materialized_series())
+                .collect(),
+            self.args.clone(),
+            self.options.clone(),
+            true,
+            state.verbose(),
+        );
+
+        if state.verbose() {
+            eprintln!("{:?} join dataframes finished", self.args.how);
+        };
+        df
+    }
+
+    fn profile_name(
+        &self,
+        state: &ExecutionState,
+        df_left: &DataFrame,
+    ) -> PolarsResult<Cow<'static, str>> {
+        Ok(if state.has_node_timer() {
+            let by = self
+                .left_on
+                .iter()
+                .map(|s| Ok(s.to_field(df_left.schema())?.name))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let name = comma_delimited("join".to_string(), &by);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        })
+    }
+
+    /// Whether the join can be computed per partition of the keys, and the partitioned results
+    /// can be concatenated without changing the result.
+    #[cfg(feature = "spill")]
+    fn can_spill(&self) -> bool {
+        matches!(
+            self.args.how,
+            JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full
+        ) && self.args.slice.is_none()
+            && self.args.maintain_order == MaintainOrderJoin::None
+            && self.options.is_none()
+    }
+
+    /// Execute the inputs one after the other and reserve the memory of each input before the
+    /// next one is materialized. An input that doesn't fit is spilled to disk right away, and
+    /// then the other input is spilled into the same partitions.
+    #[cfg(feature = "spill")]
+    fn execute_reserved(
+        &self,
+        state: &mut ExecutionState,
+        mut input_left: Box<dyn Executor>,
+        mut input_right: Box<dyn Executor>,
+    ) -> PolarsResult<DataFrame> {
+        enum Input {
+            InMemory(DataFrame, Vec<Column>),
+            Spilled(Vec<SpilledPartition>),
+        }
+
+        let df_left = input_left.execute(state)?;
+        let left_on = evaluate_keys(&self.left_on, &df_left, state)?;
+        let left_dtypes = left_on
+            .iter()
+            .map(|c| c.dtype().clone())
+            .collect::<Vec<_>>();
+        let left_size = estimated_memory(&df_left);
+        let left_reservation = try_reserve_memory(left_size);
+        let left = if left_reservation.is_none() && can_spill(&df_left, &left_on) {
+            let n_partitions = n_spill_partitions("join", left_size);
+            Input::Spilled(spill_partitions(df_left, left_on, n_partitions)?)
+        } else {
+            Input::InMemory(df_left, left_on)
+        };
+
+        let df_right = input_right.execute(state)?;
+        let right_on = evaluate_keys(&self.right_on, &df_right, state)?;
+        let right_size = estimated_memory(&df_right);
+        let right_reservation = try_reserve_memory(right_size);
+        let spill = can_spill(&df_right, &right_on)
+            // Keys of different dtypes are cast by the join, so they may not hash the same.
+            && right_on.iter().map(|c| c.dtype()).eq(left_dtypes.iter())
+            && match &left {
+                Input::InMemory(df_left, left_on) => {
+                    right_reservation.is_none() && can_spill(df_left, left_on)
+                },
+                Input::Spilled(_) => true,
+            };
+
+        if spill {
+            drop((left_reservation, right_reservation));
+            let left = match left {
+                Input::InMemory(df_left, left_on) => {
+                    let n_partitions = n_spill_partitions("join", left_size + right_size);
+                    spill_partitions(df_left, left_on, n_partitions)?
+                },
+                Input::Spilled(left) => left,
+            };
+            let right = spill_partitions(df_right, right_on, left.len())?;
+            return self.join_partitions(state, &left, &right);
+        }
+
+        let (df_left, left_on) = match left {
+            Input::InMemory(df_left, left_on) => (df_left, left_on),
+            // The right input can't be spilled, so the left one has to be joined in memory.
+            Input::Spilled(left) => load_partitions(&left)?,
+        };
+        let profile_name = self.profile_name(state, &df_left)?;
+        state.record(
+            || self.join(state, &df_left, &df_right, left_on, right_on),
+            profile_name,
+        )
+    }
+
+    /// Join every pair of partitions of the spilled inputs separately, which is possible
+    /// because rows with equal keys are in the same partition on both sides.
+    #[cfg(feature = "spill")]
+    fn join_partitions(
+        &self,
+        state: &ExecutionState,
+        left: &[SpilledPartition],
+        right: &[SpilledPartition],
+    ) -> PolarsResult<DataFrame> {
+        collect_spilled(left.iter().zip(right).map(|(left, right)| {
+            let (df_left, left_on) = left.load()?;
+            let (df_right, right_on) = right.load()?;
+            self.join(state, &df_left, &df_right, left_on, right_on)
+        }))
+    }
+}
+
+fn evaluate_keys(
+    on: &[Arc<dyn PhysicalExpr>],
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Column>> {
+    on.iter().map(|e| e.evaluate(df, state)).collect()
 }
 
 impl Executor for JoinExec {
@@ -50,6 +209,11 @@ impl Executor for JoinExec {
         let mut input_left = self.input_left.take().unwrap();
         let mut input_right = self.input_right.take().unwrap();
 
+        #[cfg(feature = "spill")]
+        if memory_limit().is_some() && self.can_spill() {
+            return self.execute_reserved(state, input_left, input_right);
+        }
+
         let (df_left, df_right) = if self.parallel {
             let mut state_right = state.split();
             let mut state_left = state.split();
@@ -66,52 +230,12 @@ impl Executor for JoinExec {
         let df_left = df_left?;
         let df_right = df_right?;
 
-        let profile_name = if state.has_node_timer() {
-            let by = self
-                .left_on
-                .iter()
-                .map(|s| Ok(s.to_field(df_left.schema())?.name))
-                .collect::<PolarsResult<Vec<_>>>()?;
-            let name = comma_delimited("join".to_string(), &by);
-            Cow::Owned(name)
-        } else {
-            Cow::Borrowed("")
-        };
-
+        let profile_name = self.profile_name(state, &df_left)?;
         state.record(
             || {
-                let left_on_series = self
-                    .left_on
-                    .iter()
-                    .map(|e| e.evaluate(&df_left, state))
-                    .collect::<PolarsResult<Vec<_>>>()?;
-
-                let right_on_series = self
-                    .right_on
-                    .iter()
-                    .map(|e| e.evaluate(&df_right, state))
-                    .collect::<PolarsResult<Vec<_>>>()?;
-
-                let df = df_left._join_impl(
-                    &df_right,
-                    left_on_series
-                        .into_iter()
-                        .map(|c| c.take_materialized_series())
-                        .collect(),
-                    right_on_series
-                        .into_iter()
-                        .map(|c| c.take_materialized_series())
-                        .collect(),
-                    self.args.clone(),
-                    self.options.clone(),
-                    true,
-                    state.verbose(),
-                );
-
-                if state.verbose() {
-                    eprintln!("{:?} join dataframes finished", self.args.how);
-                };
-                df
+                let left_on = evaluate_keys(&self.left_on, &df_left, state)?;
+                let right_on = evaluate_keys(&self.right_on, &df_right, state)?;
+                self.join(state, &df_left, &df_right, left_on, right_on)
             },
             profile_name,
         )
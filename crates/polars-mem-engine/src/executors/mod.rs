@@ -1,4 +1,5 @@
 mod cache;
+mod execution_provider;
 mod executor;
 mod ext_context;
 mod filter;
@@ -17,7 +18,11 @@ mod scan;
 mod sink;
 mod slice;
 mod sort;
+#[cfg(feature = "spill")]
+mod spill;
 mod stack;
+#[cfg(feature = "tracing")]
+mod traced;
 mod udf;
 mod union;
 mod unique;
@@ -31,6 +36,7 @@ use projection_utils::*;
 use rayon::prelude::*;
 
 pub(super) use self::cache::*;
+pub(super) use self::execution_provider::*;
 pub(super) use self::ext_context::*;
 pub(super) use self::filter::*;
 pub(super) use self::group_by::*;
@@ -50,6 +56,8 @@ pub(super) use self::sink::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
 pub(super) use self::stack::*;
+#[cfg(feature = "tracing")]
+pub(super) use self::traced::*;
 pub(super) use self::udf::*;
 pub(super) use self::union::*;
 pub(super) use self::unique::*;
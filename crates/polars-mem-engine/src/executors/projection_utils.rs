@@ -15,11 +15,26 @@ pub(super) fn profile_name(
 
 type IdAndExpression = (u32, Arc<dyn PhysicalExpr>);
 
+fn evaluate_expr(
+    expr: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+    node: &str,
+) -> PolarsResult<Column> {
+    // The expressions are evaluated in parallel, on threads that don't have the configuration
+    // of the query.
+    state
+        .config
+        .enter(|| expr.evaluate(df, state))
+        .map_err(|err| add_expr_provenance(err, expr, node))
+}
+
 #[cfg(feature = "dynamic_group_by")]
 fn rolling_evaluate(
     df: &DataFrame,
     state: &ExecutionState,
     rolling: PlHashMap<RollingGroupOptions, Vec<IdAndExpression>>,
+    node: &str,
 ) -> PolarsResult<Vec<Vec<(u32, Column)>>> {
     POOL.install(|| {
         rolling
@@ -37,7 +52,9 @@ fn rolling_evaluate(
                 state.window_cache.insert_groups(groups_key, groups);
                 partition
                     .par_iter()
-                    .map(|(idx, expr)| expr.evaluate(df, &state).map(|s| (*idx, s)))
+                    .map(|(idx, expr)| {
+                        evaluate_expr(expr.as_ref(), df, &state, node).map(|s| (*idx, s))
+                    })
                     .collect::<PolarsResult<Vec<_>>>()
             })
             .collect()
@@ -48,6 +65,7 @@ fn window_evaluate(
     df: &DataFrame,
     state: &ExecutionState,
     window: PlHashMap<String, Vec<IdAndExpression>>,
+    node: &str,
 ) -> PolarsResult<Vec<Vec<(u32, Column)>>> {
     if window.is_empty() {
         return Ok(vec![]);
@@ -94,15 +112,16 @@ fn window_evaluate(
         //  rayon threads should not be blocked.
         if cache {
             let first = &partition[0];
-            let c = first.1.evaluate(df, &state)?;
+            let c = evaluate_expr(first.1.as_ref(), df, &state, node)?;
             first_result = Some((first.0, c));
             state.insert_cache_window_flag();
         } else {
             state.remove_cache_window_flag();
         }
 
-        let apply =
-            |index: &u32, e: &Arc<dyn PhysicalExpr>| e.evaluate(df, &state).map(|c| (*index, c));
+        let apply = |index: &u32, e: &Arc<dyn PhysicalExpr>| {
+            evaluate_expr(e.as_ref(), df, &state, node).map(|c| (*index, c))
+        };
 
         let slice = &partition[first_result.is_some() as usize..];
         let mut results = if par_horizontal {
@@ -135,6 +154,7 @@ fn execute_projection_cached_window_fns(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    node: &str,
 ) -> PolarsResult<Vec<Column>> {
     // We partition by normal expression and window expression
     // - the normal expressions can run in parallel
@@ -214,7 +234,7 @@ fn execute_projection_cached_window_fns(
     let mut selected_columns = POOL.install(|| {
         other
             .par_iter()
-            .map(|(idx, expr)| expr.evaluate(df, state).map(|s| (*idx, s)))
+            .map(|(idx, expr)| evaluate_expr(*expr, df, state, node).map(|s| (*idx, s)))
             .collect::<PolarsResult<Vec<_>>>()
     })?;
 
@@ -224,8 +244,8 @@ fn execute_projection_cached_window_fns(
     #[cfg(feature = "dynamic_group_by")]
     {
         let (a, b) = POOL.join(
-            || rolling_evaluate(df, state, rolling),
-            || window_evaluate(df, state, windows),
+            || rolling_evaluate(df, state, rolling, node),
+            || window_evaluate(df, state, windows, node),
         );
 
         let partitions = a?;
@@ -239,7 +259,7 @@ fn execute_projection_cached_window_fns(
     }
     #[cfg(not(feature = "dynamic_group_by"))]
     {
-        let partitions = window_evaluate(df, state, windows)?;
+        let partitions = window_evaluate(df, state, windows, node)?;
         for part in partitions {
             selected_columns.extend_from_slice(&part)
         }
@@ -254,11 +274,12 @@ fn run_exprs_par(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    node: &str,
 ) -> PolarsResult<Vec<Column>> {
     POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
+            .map(|expr| evaluate_expr(expr.as_ref(), df, state, node))
             .collect()
     })
 }
@@ -267,16 +288,23 @@ fn run_exprs_seq(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    node: &str,
 ) -> PolarsResult<Vec<Column>> {
-    exprs.iter().map(|expr| expr.evaluate(df, state)).collect()
+    exprs
+        .iter()
+        .map(|expr| evaluate_expr(expr.as_ref(), df, state, node))
+        .collect()
 }
 
+/// Evaluate the expressions of the plan node `node`, e.g. `select`. An error names the
+/// expression it originated in.
 pub(super) fn evaluate_physical_expressions(
     df: &mut DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
     has_windows: bool,
     run_parallel: bool,
+    node: &str,
 ) -> PolarsResult<Vec<Column>> {
     let expr_runner = if has_windows {
         execute_projection_cached_window_fns
@@ -286,7 +314,7 @@ pub(super) fn evaluate_physical_expressions(
         run_exprs_seq
     };
 
-    let selected_columns = expr_runner(df, exprs, state)?;
+    let selected_columns = expr_runner(df, exprs, state, node)?;
 
     if has_windows {
         state.clear_window_expr_cache();
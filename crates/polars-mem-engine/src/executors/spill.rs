@@ -0,0 +1,263 @@
+//! Spilling the inputs of joins and group-bys to disk when they exceed the memory limit, see
+//! [`polars_core::memory`].
+//!
+//! The input is split into partitions by the hashes of the keys, so that every group, or every
+//! set of rows that can match in a join, is in a single partition. The partitions are written to
+//! temporary files and processed one at a time, so only a single partition is held in memory.
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_core::config::memory_limit;
+use polars_core::diagnostics::{DiagnosticKind, emit_diagnostic};
+use polars_core::memory::reserved_memory;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::path_utils::temp_dir_base_path;
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
+use polars_utils::format_pl_smallstr;
+use polars_utils::hashing::HashPartitioner;
+
+use super::*;
+
+const MAX_SPILL_PARTITIONS: usize = 256;
+
+/// A [`DataFrame`] that is written to a temporary file, which is deleted when this is dropped.
+struct SpilledFrame {
+    path: PathBuf,
+}
+
+impl SpilledFrame {
+    fn new(mut df: DataFrame) -> PolarsResult<Self> {
+        static SPILL_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let dir = temp_dir_base_path().join("spill");
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!(
+            "{}-{}.arrow",
+            std::process::id(),
+            SPILL_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        // Create the frame before writing, so that the file is also deleted if writing fails.
+        let spilled = Self { path };
+        df.serialize_into_writer(&mut writer)?;
+        writer.flush()?;
+        Ok(spilled)
+    }
+
+    fn load(&self) -> PolarsResult<DataFrame> {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        DataFrame::deserialize_from_reader(&mut reader)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn size(&self) -> u64 {
+        std::fs::metadata(&self.path).map_or(0, |m| m.len())
+    }
+}
+
+impl Drop for SpilledFrame {
+    fn drop(&mut self) {
+        // The file is in the temporary directory, so it is eventually removed anyway.
+        _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// A partition of a [`DataFrame`] and the evaluated key columns of its rows.
+pub(super) struct SpilledPartition {
+    df: SpilledFrame,
+    keys: SpilledFrame,
+    key_names: Arc<[PlSmallStr]>,
+}
+
+impl SpilledPartition {
+    pub(super) fn load(&self) -> PolarsResult<(DataFrame, Vec<Column>)> {
+        let keys = self
+            .keys
+            .load()?
+            .take_columns()
+            .into_iter()
+            .zip(self.key_names.iter())
+            .map(|(key, name)| key.with_name(name.clone()))
+            .collect();
+        Ok((self.df.load()?, keys))
+    }
+}
+
+/// Load all `partitions` back into a single [`DataFrame`] and its key columns. The rows are in
+/// the order of the partitions, not in the order of the frame that was spilled.
+pub(super) fn load_partitions(
+    partitions: &[SpilledPartition],
+) -> PolarsResult<(DataFrame, Vec<Column>)> {
+    let mut partitions = partitions.iter().map(SpilledPartition::load);
+    let (mut df, mut keys) = partitions.next().unwrap()?;
+    for partition in partitions {
+        let (part, part_keys) = partition?;
+        df.vstack_mut_owned(part)?;
+        for (key, part_key) in keys.iter_mut().zip(part_keys) {
+            key.append_owned(part_key)?;
+        }
+    }
+    Ok((df, keys))
+}
+
+/// Concatenate the `outputs` of the partitions of a spilled operator. Every output is spilled
+/// to disk as soon as it is computed, so that only a single partition and its output are in
+/// memory at a time, and the outputs are only loaded once all the partitions are processed.
+pub(super) fn collect_spilled(
+    outputs: impl IntoIterator<Item = PolarsResult<DataFrame>>,
+) -> PolarsResult<DataFrame> {
+    let spilled = outputs
+        .into_iter()
+        .map(|df| SpilledFrame::new(df?))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let dfs = spilled
+        .into_iter()
+        .map(|frame| frame.load())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    accumulate_dataframes_vertical(dfs)
+}
+
+/// The estimated memory that a join or group-by holds for its input `df`: the input itself,
+/// the partition and group indices of its rows, and an output of about the size of the input.
+pub(super) fn estimated_memory(df: &DataFrame) -> usize {
+    2 * df.estimated_size() + df.height() * size_of::<IdxSize>()
+}
+
+/// Whether `df` can be spilled with `keys`.
+pub(super) fn can_spill(df: &DataFrame, keys: &[Column]) -> bool {
+    !keys.is_empty()
+        && keys.iter().all(|k| k.len() == df.height())
+        && !df
+            .get_columns()
+            .iter()
+            .chain(keys)
+            .any(|c| c.dtype().is_object())
+}
+
+/// The number of partitions to spill `size` bytes into, such that every partition fits in the
+/// memory that is still available.
+pub(super) fn n_spill_partitions(node: &str, size: usize) -> usize {
+    let available = memory_limit()
+        .unwrap_or(usize::MAX)
+        .saturating_sub(reserved_memory())
+        .max(1);
+    let n_partitions = size.div_ceil(available).clamp(2, MAX_SPILL_PARTITIONS);
+    emit_diagnostic(DiagnosticKind::SlowPath, || {
+        format!(
+            "{node}: the input of {size} bytes exceeds the memory limit, spilling it to disk in {n_partitions} partitions"
+        )
+    });
+    n_partitions
+}
+
+/// Split `df` into `n_partitions` by the hashes of `keys` and spill the partitions to disk.
+///
+/// Rows with equal keys are in the same partition, also if they are in different frames that
+/// are partitioned into the same number of partitions. The order of the rows in a partition is
+/// their order in `df`.
+pub(super) fn spill_partitions(
+    df: DataFrame,
+    keys: Vec<Column>,
+    n_partitions: usize,
+) -> PolarsResult<Vec<SpilledPartition>> {
+    let build_hasher = PlSeedableRandomStateQuality::seed_from_u64(0);
+    let mut hashes = Vec::with_capacity(df.height());
+    keys[0].vec_hash(build_hasher, &mut hashes)?;
+    for key in &keys[1..] {
+        key.vec_hash_combine(build_hasher, &mut hashes)?;
+    }
+
+    let partitioner = HashPartitioner::new(n_partitions, 0);
+    let mut partition_idxs = vec![Vec::new(); n_partitions];
+    for (i, h) in hashes.into_iter().enumerate() {
+        partition_idxs[partitioner.hash_to_partition(h)].push(i as IdxSize);
+    }
+
+    // Keys can have the same name, so they're spilled under their position.
+    let key_names: Arc<[PlSmallStr]> = keys.iter().map(|k| k.name().clone()).collect();
+    let keys = keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, k)| k.with_name(format_pl_smallstr!("{i}")))
+        .collect();
+    // SAFETY: the keys have the height of `df` and unique names.
+    let keys = unsafe { DataFrame::new_no_checks(df.height(), keys) };
+
+    let partitions = partition_idxs
+        .into_iter()
+        .map(|idx| {
+            // SAFETY: the indices are in bounds of `df` and `keys`.
+            let (df, keys) = unsafe {
+                (
+                    df.take_slice_unchecked(&idx),
+                    keys.take_slice_unchecked(&idx),
+                )
+            };
+            Ok(SpilledPartition {
+                df: SpilledFrame::new(df)?,
+                keys: SpilledFrame::new(keys)?,
+                key_names: key_names.clone(),
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    // This is emitted in the span of the spilling node.
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        target: "polars",
+        spill_bytes = partitions
+            .iter()
+            .map(|p| p.df.size() + p.keys.size())
+            .sum::<u64>(),
+        partitions = partitions.len(),
+        "spilled to disk"
+    );
+    Ok(partitions)
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_spill_partitions() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..100).map(|i| i % 7).collect::<Vec<i32>>(),
+            "b" => (0..100).collect::<Vec<i32>>(),
+        ]?;
+        let other = df![
+            "a" => (0..7).rev().collect::<Vec<i32>>(),
+        ]?;
+        let keys = |df: &DataFrame| vec![df.column("a").unwrap().clone().with_name("key".into())];
+
+        let partitions = spill_partitions(df.clone(), keys(&df), 4)?;
+        let other_partitions = spill_partitions(other.clone(), keys(&other), 4)?;
+        assert_eq!(partitions.len(), 4);
+
+        let mut dfs = Vec::new();
+        for (partition, other_partition) in partitions.iter().zip(&other_partitions) {
+            let (part, part_keys) = partition.load()?;
+            let (other_part, _) = other_partition.load()?;
+            assert_eq!(part_keys[0].name(), "key");
+            assert!(part_keys[0].equals(part.column("a")?));
+            // Equal keys are in the same partition of both frames.
+            assert_eq!(part.column("a")?.n_unique()?, other_part.height());
+            dfs.push(part);
+        }
+
+        let out = accumulate_dataframes_vertical(dfs)?.sort(["b"], Default::default())?;
+        assert!(out.equals(&df));
+
+        let (loaded, loaded_keys) = load_partitions(&partitions)?;
+        assert!(loaded_keys[0].equals(loaded.column("a")?));
+        assert!(loaded.sort(["b"], Default::default())?.equals(&df));
+
+        let out = collect_spilled(partitions.iter().map(|p| Ok(p.load()?.0)))?;
+        assert!(out.equals(&loaded));
+        Ok(())
+    }
+}
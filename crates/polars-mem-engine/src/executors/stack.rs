@@ -36,6 +36,7 @@ impl StackExec {
                     state,
                     self.has_windows,
                     self.options.run_parallel,
+                    "with_columns",
                 )?;
                 // We don't have to do a broadcast check as cse is not allowed to hit this.
                 df._add_columns(res.into_iter().collect(), schema)?;
@@ -53,6 +54,7 @@ impl StackExec {
                 state,
                 self.has_windows,
                 self.options.run_parallel,
+                "with_columns",
             )?;
             if !self.options.should_broadcast {
                 debug_assert!(
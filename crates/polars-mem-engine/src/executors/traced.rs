@@ -0,0 +1,200 @@
+use std::time::Instant;
+
+use polars_core::config::ConfigScope;
+use tracing::field;
+
+use super::*;
+
+/// Runs the wrapped executor inside a `tracing` span named after its IR node.
+///
+/// The span is a child of the spans of the executors that consume this executor's output, so
+/// the number of rows a node receives are the `rows_out` of its child spans.
+pub struct TracedExec {
+    pub name: &'static str,
+    pub input: Box<dyn Executor>,
+}
+
+impl Executor for TracedExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let span = tracing::info_span!(
+            target: "polars",
+            "polars.node",
+            engine = "in-memory",
+            node = self.name,
+            rows_out = field::Empty,
+            duration_ns = field::Empty,
+            error = field::Empty,
+        );
+        let _guard = span.enter();
+        // The parallel work of the input enters the scope of the state, which then carries this
+        // span to the threads of the pool.
+        let scope = std::mem::replace(&mut state.config, ConfigScope::current());
+
+        let start = Instant::now();
+        let out = self.input.execute(state);
+        state.config = scope;
+        span.record("duration_ns", start.elapsed().as_nanos() as u64);
+        match &out {
+            Ok(df) => span.record("rows_out", df.height() as u64),
+            Err(err) => span.record("error", field::display(err)),
+        };
+        out
+    }
+
+    fn is_cache_prefiller(&self) -> bool {
+        self.input.is_cache_prefiller()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::sync::{Arc, Mutex};
+    use std::thread::ThreadId;
+
+    use polars_core::df;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Current, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct CapturedSpan {
+        metadata: Option<&'static Metadata<'static>>,
+        node: Option<String>,
+        parent: Option<u64>,
+        rows_out: Option<u64>,
+        thread: Option<ThreadId>,
+    }
+
+    impl Visit for CapturedSpan {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "node" {
+                self.node = Some(value.to_string());
+            }
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            if field.name() == "rows_out" {
+                self.rows_out = Some(value);
+            }
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+    }
+
+    thread_local! {
+        static ENTERED: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Captures the spans of the `polars` target, with the thread they are first entered on.
+    struct Capture(Arc<Mutex<Vec<CapturedSpan>>>);
+
+    impl Subscriber for Capture {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "polars"
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut span = CapturedSpan {
+                parent: attrs.parent().map(Id::into_u64).or_else(|| {
+                    attrs
+                        .is_contextual()
+                        .then(|| ENTERED.with_borrow(|e| e.last().copied()))
+                        .flatten()
+                }),
+                metadata: Some(attrs.metadata()),
+                ..Default::default()
+            };
+            attrs.record(&mut span);
+            let mut spans = self.0.lock().unwrap();
+            spans.push(span);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            values.record(&mut self.0.lock().unwrap()[span.into_u64() as usize - 1]);
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            ENTERED.with_borrow_mut(|e| e.push(span.into_u64()));
+            self.0.lock().unwrap()[span.into_u64() as usize - 1]
+                .thread
+                .get_or_insert_with(|| std::thread::current().id());
+        }
+
+        fn exit(&self, _span: &Id) {
+            ENTERED.with_borrow_mut(|e| e.pop());
+        }
+
+        fn current_span(&self) -> Current {
+            match ENTERED.with_borrow(|e| e.last().copied()) {
+                Some(id) => {
+                    let metadata = self.0.lock().unwrap()[id as usize - 1].metadata.unwrap();
+                    Current::new(Id::from_u64(id), metadata)
+                },
+                None => Current::none(),
+            }
+        }
+    }
+
+    struct Scan(DataFrame);
+
+    impl Executor for Scan {
+        fn execute(&mut self, _state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+            Ok(self.0.clone())
+        }
+    }
+
+    /// Executes its input on a thread of the thread pool.
+    struct OnPool(Option<Box<dyn Executor>>);
+
+    impl Executor for OnPool {
+        fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+            let mut input = self.0.take().unwrap();
+            let mut state = state.split();
+            let (tx, rx) = std::sync::mpsc::channel();
+            POOL.spawn(move || {
+                _ = tx.send(input.execute(&mut state));
+            });
+            rx.recv().unwrap()
+        }
+    }
+
+    #[test]
+    fn test_traced_exec_spans() -> PolarsResult<()> {
+        let df = df!["a" => [1, 2, 3]]?;
+        let mut exec = TracedExec {
+            name: "join",
+            input: Box::new(OnPool(Some(Box::new(TracedExec {
+                name: "df",
+                input: Box::new(Scan(df)),
+            })))),
+        };
+
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        let out = tracing::subscriber::with_default(Capture(spans.clone()), || {
+            exec.execute(&mut ExecutionState::new())
+        })?;
+        assert_eq!(out.height(), 3);
+
+        let spans = spans.lock().unwrap();
+        let [outer, inner] = &spans[..] else {
+            panic!("expected two spans, got {spans:?}");
+        };
+        assert_eq!(outer.node.as_deref(), Some("join"));
+        assert_eq!(outer.parent, None);
+        assert_eq!(outer.rows_out, Some(3));
+        // The span of the input is a child of the outer span, also on the thread pool.
+        assert_eq!(inner.node.as_deref(), Some("df"));
+        assert_eq!(inner.parent, Some(1));
+        assert_eq!(inner.rows_out, Some(3));
+        assert_ne!(inner.thread, outer.thread);
+        Ok(())
+    }
+}
@@ -92,7 +92,8 @@ impl Executor for UnionExec {
                                 let mut input = std::mem::take(input);
                                 let mut state = state.split();
                                 state.branch_idx += idx;
-                                input.execute(&mut state)
+                                let config = state.config.clone();
+                                config.enter(|| input.execute(&mut state))
                             })
                             .collect::<PolarsResult<Vec<_>>>()
                     })
@@ -10,4 +10,7 @@ pub mod scan_predicate;
 pub use executors::Executor;
 #[cfg(feature = "python")]
 pub use planner::python_scan_predicate;
-pub use planner::{StreamingExecutorBuilder, create_multiple_physical_plans, create_physical_plan};
+pub use planner::{
+    ExecutionProvider, StreamingExecutorBuilder, create_multiple_physical_plans,
+    create_physical_plan, register_execution_provider, unregister_execution_provider,
+};
@@ -0,0 +1,64 @@
+use std::sync::{Arc, RwLock};
+
+use polars_core::prelude::*;
+use polars_plan::prelude::*;
+use polars_utils::arena::{Arena, Node};
+
+use crate::executors::Executor;
+
+/// Allows an external crate to execute subtrees of a physical plan itself, e.g. on a GPU.
+///
+/// Registered providers (see [`register_execution_provider`]) are offered every node of the
+/// plan, from the root down, when the physical plan is created. A provider claims a node by
+/// returning an [`Executor`] for the full subtree rooted at it; unclaimed nodes are executed
+/// by the default engine as usual.
+///
+/// The default engine also plans every claimed subtree, and falls back to that plan if the
+/// provider's executor fails, so providers can claim nodes optimistically.
+pub trait ExecutionProvider: Send + Sync {
+    /// The name of the provider, as shown in profiles and verbose output.
+    fn name(&self) -> &str;
+
+    /// Claim the subtree rooted at `root`, returning the executor that replaces it, or `None`
+    /// to leave it to the default engine.
+    ///
+    /// Returning an error aborts the creation of the physical plan.
+    fn claim(
+        &self,
+        root: Node,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<PolarsResult<Box<dyn Executor>>>;
+}
+
+static EXECUTION_PROVIDERS: RwLock<Vec<Arc<dyn ExecutionProvider>>> = RwLock::new(Vec::new());
+
+/// Register an [`ExecutionProvider`]; providers are asked to claim nodes in order of
+/// registration.
+pub fn register_execution_provider(provider: Arc<dyn ExecutionProvider>) {
+    EXECUTION_PROVIDERS.write().unwrap().push(provider);
+}
+
+/// Unregister all [`ExecutionProvider`]s with the given name.
+pub fn unregister_execution_provider(name: &str) {
+    EXECUTION_PROVIDERS
+        .write()
+        .unwrap()
+        .retain(|p| p.name() != name);
+}
+
+/// Offer the subtree rooted at `root` to the registered providers, returning the name of the
+/// provider that claimed it and its executor.
+pub(super) fn claim_by_execution_provider(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Option<(PlSmallStr, Box<dyn Executor>)>> {
+    let providers = EXECUTION_PROVIDERS.read().unwrap();
+    for provider in providers.iter() {
+        if let Some(executor) = provider.claim(root, lp_arena, expr_arena) {
+            return Ok(Some((provider.name().into(), executor?)));
+        }
+    }
+    Ok(None)
+}
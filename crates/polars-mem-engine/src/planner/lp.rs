@@ -55,6 +55,10 @@ fn partitionable_gb(
 struct ConversionState {
     has_cache_child: bool,
     has_cache_parent: bool,
+    /// Whether we are planning the fallback of a subtree claimed by an execution provider.
+    in_claimed_subtree: bool,
+    /// The columns the parent of a filter uses, see [`executors::FilterExec::projection`].
+    filter_projection: Option<Arc<[PlSmallStr]>>,
 }
 
 impl ConversionState {
@@ -62,6 +66,8 @@ impl ConversionState {
         Ok(ConversionState {
             has_cache_child: false,
             has_cache_parent: false,
+            in_claimed_subtree: false,
+            filter_projection: None,
         })
     }
 
@@ -202,7 +208,6 @@ pub fn python_scan_predicate(
     Ok((predicate, predicate_serialized))
 }
 
-#[recursive]
 fn create_physical_plan_impl(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -211,6 +216,34 @@ fn create_physical_plan_impl(
     // Cache nodes in order of discovery
     cache_nodes: &mut PlIndexMap<UniqueId, executors::CachePrefill>,
     build_streaming_executor: Option<StreamingExecutorBuilder>,
+) -> PolarsResult<Box<dyn Executor>> {
+    #[cfg(feature = "tracing")]
+    let name = lp_arena.get(root).name();
+    let executor = create_physical_plan_node(
+        root,
+        lp_arena,
+        expr_arena,
+        state,
+        cache_nodes,
+        build_streaming_executor,
+    )?;
+    #[cfg(feature = "tracing")]
+    let executor: Box<dyn Executor> = Box::new(executors::TracedExec {
+        name,
+        input: executor,
+    });
+    Ok(executor)
+}
+
+#[recursive]
+fn create_physical_plan_node(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    state: &mut ConversionState,
+    // Cache nodes in order of discovery
+    cache_nodes: &mut PlIndexMap<UniqueId, executors::CachePrefill>,
+    build_streaming_executor: Option<StreamingExecutorBuilder>,
 ) -> PolarsResult<Box<dyn Executor>> {
     use IR::*;
 
@@ -227,6 +260,21 @@ fn create_physical_plan_impl(
         };
     }
 
+    if !state.in_claimed_subtree {
+        if let Some((provider, input)) =
+            execution_provider::claim_by_execution_provider(root, lp_arena, expr_arena)?
+        {
+            state.in_claimed_subtree = true;
+            let fallback = recurse!(root, state);
+            state.in_claimed_subtree = false;
+            return Ok(Box::new(executors::ExecutionProviderExec {
+                provider,
+                input,
+                fallback: fallback?,
+            }));
+        }
+    }
+
     let logical_plan = if state.has_cache_parent
         || matches!(
             lp_arena.get(root),
@@ -422,18 +470,17 @@ fn create_physical_plan_impl(
             Ok(Box::new(executors::SliceExec { input, offset, len }))
         },
         Filter { input, predicate } => {
+            let projection = state.filter_projection.take();
             let streamable = is_elementwise_rec(predicate.node(), expr_arena);
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = recurse!(input, state)?;
             let mut state = ExpressionConversionState::new(true);
             let predicate =
                 create_physical_expr(&predicate, expr_arena, &input_schema, &mut state)?;
-            Ok(Box::new(executors::FilterExec::new(
-                predicate,
-                input,
-                state.has_windows,
-                streamable,
-            )))
+            let mut exec =
+                executors::FilterExec::new(predicate, input, state.has_windows, streamable);
+            exec.projection = projection;
+            Ok(Box::new(exec))
         },
         #[allow(unused_variables)]
         Scan {
@@ -518,7 +565,20 @@ fn create_physical_plan_impl(
             ..
         } => {
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+            // Fuse the projection into a filter input, so that the filter only gathers the
+            // columns that are projected.
+            if matches!(lp_arena.get(input), Filter { .. }) {
+                let live_columns = expr
+                    .iter()
+                    .flat_map(|e| aexpr_to_leaf_names_iter(e.node(), expr_arena))
+                    .cloned()
+                    .collect::<PlIndexSet<_>>();
+                if live_columns.len() < input_schema.len() {
+                    state.filter_projection = Some(live_columns.into_iter().collect());
+                }
+            }
             let input = recurse!(input, state)?;
+            state.filter_projection = None;
             let mut state = ExpressionConversionState::new(POOL.current_num_threads() > expr.len());
             let phys_expr =
                 create_physical_expressions_from_irs(&expr, expr_arena, &input_schema, &mut state)?;
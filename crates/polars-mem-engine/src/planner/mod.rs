@@ -1,4 +1,8 @@
+mod execution_provider;
 mod lp;
+pub use execution_provider::{
+    ExecutionProvider, register_execution_provider, unregister_execution_provider,
+};
 pub use lp::*;
 pub(crate) use polars_expr::planner::*;
 use polars_plan::prelude::*;
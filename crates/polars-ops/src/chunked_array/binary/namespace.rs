@@ -10,16 +10,16 @@ use base64::engine::general_purpose;
 use memchr::memmem::find;
 use polars_compute::cast::{binview_to_fixed_size_list_dyn, binview_to_primitive_dyn};
 use polars_compute::size::binary_size_bytes;
-use polars_core::prelude::arity::{broadcast_binary_elementwise_values, unary_elementwise_values};
+use polars_core::prelude::arity::broadcast_binary_elementwise_values;
 
+use super::predicates::{contains_kernel, ends_with_kernel, starts_with_kernel};
 use super::*;
 
 pub trait BinaryNameSpaceImpl: AsBinary {
     /// Check if binary contains given literal
     fn contains(&self, lit: &[u8]) -> BooleanChunked {
         let ca = self.as_binary();
-        let f = |s: &[u8]| find(s, lit).is_some();
-        unary_elementwise_values(ca, f)
+        ca.apply_kernel_cast(&|arr| contains_kernel(arr, lit))
     }
 
     fn contains_chunked(&self, lit: &BinaryChunked) -> PolarsResult<BooleanChunked> {
@@ -44,15 +44,13 @@ pub trait BinaryNameSpaceImpl: AsBinary {
     /// Check if strings ends with a substring
     fn ends_with(&self, sub: &[u8]) -> BooleanChunked {
         let ca = self.as_binary();
-        let f = |s: &[u8]| s.ends_with(sub);
-        ca.apply_nonnull_values_generic(DataType::Boolean, f)
+        ca.apply_kernel_cast(&|arr| ends_with_kernel(arr, sub))
     }
 
     /// Check if strings starts with a substring
     fn starts_with(&self, sub: &[u8]) -> BooleanChunked {
         let ca = self.as_binary();
-        let f = |s: &[u8]| s.starts_with(sub);
-        ca.apply_nonnull_values_generic(DataType::Boolean, f)
+        ca.apply_kernel_cast(&|arr| starts_with_kernel(arr, sub))
     }
 
     fn starts_with_chunked(&self, prefix: &BinaryChunked) -> PolarsResult<BooleanChunked> {
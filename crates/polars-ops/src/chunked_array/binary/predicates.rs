@@ -0,0 +1,108 @@
+//! Substring predicates on the views of a [`BinaryViewArray`].
+//!
+//! The length and the first 4 bytes of every value are stored inline in its view, so most values
+//! that don't match are rejected with a packed compare of the view, without loading the data
+//! buffers.
+use arrow::array::{Array, ArrayRef, BinaryViewArray, BooleanArray, View};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+use memchr::memmem::Finder;
+
+fn predicate_kernel(arr: &BinaryViewArray, f: impl Fn(&View) -> bool) -> ArrayRef {
+    let values = Bitmap::from_trusted_len_iter(arr.views().iter().map(f));
+    let out = BooleanArray::new(ArrowDataType::Boolean, values, arr.validity().cloned());
+    Box::new(out)
+}
+
+/// Loads (up to) the first 4 bytes of `s` as little-endian, padded with zeros, together with the
+/// mask of the loaded bytes.
+fn load_prefix(s: &[u8]) -> (u32, u32) {
+    let n = s.len().min(4);
+    let mut tmp = [0u8; 4];
+    tmp[..n].copy_from_slice(&s[..n]);
+    let mask = u32::MAX.checked_shr(32 - 8 * n as u32).unwrap_or(0);
+    (u32::from_le_bytes(tmp), mask)
+}
+
+pub(super) fn starts_with_kernel(arr: &BinaryViewArray, prefix: &[u8]) -> ArrayRef {
+    let (inline_prefix, mask) = load_prefix(prefix);
+    let buffers = arr.data_buffers().as_ref();
+    predicate_kernel(arr, |v| {
+        v.length as usize >= prefix.len()
+            && v.prefix & mask == inline_prefix
+            // SAFETY: the views of an array point into its buffers.
+            && (prefix.len() <= 4 || unsafe { v.get_slice_unchecked(buffers) }.starts_with(prefix))
+    })
+}
+
+pub(super) fn ends_with_kernel(arr: &BinaryViewArray, suffix: &[u8]) -> ArrayRef {
+    let buffers = arr.data_buffers().as_ref();
+    predicate_kernel(arr, |v| {
+        // SAFETY: the views of an array point into its buffers.
+        v.length as usize >= suffix.len()
+            && unsafe { v.get_slice_unchecked(buffers) }.ends_with(suffix)
+    })
+}
+
+pub(super) fn contains_kernel(arr: &BinaryViewArray, needle: &[u8]) -> ArrayRef {
+    // Build the searcher once for the whole array, `memmem` then uses SIMD to find candidates.
+    let finder = Finder::new(needle);
+    let buffers = arr.data_buffers().as_ref();
+    predicate_kernel(arr, |v| {
+        v.length as usize >= needle.len()
+            // SAFETY: the views of an array point into its buffers.
+            && finder.find(unsafe { v.get_slice_unchecked(buffers) }).is_some()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn eval(
+        kernel: impl Fn(&BinaryViewArray) -> ArrayRef,
+        arr: &BinaryViewArray,
+    ) -> Vec<Option<bool>> {
+        let out = kernel(arr);
+        let out = out.as_any().downcast_ref::<BooleanArray>().unwrap();
+        out.iter().collect()
+    }
+
+    #[test]
+    fn test_view_predicates() {
+        let values: [Option<&[u8]>; 7] = [
+            Some(b"foo"),
+            Some(b"foobar"),
+            Some(b"a long value that is not inlined, foobar"),
+            Some(b"foo bar baz ..."),
+            None,
+            Some(b""),
+            Some(b"bar"),
+        ];
+        let arr = BinaryViewArray::from_slice(values);
+
+        let check = |kernel: &dyn Fn(&BinaryViewArray) -> ArrayRef, f: &dyn Fn(&[u8]) -> bool| {
+            let expected = values.iter().map(|v| v.map(f)).collect::<Vec<_>>();
+            assert_eq!(eval(kernel, &arr), expected);
+            // Sliced arrays.
+            assert_eq!(eval(kernel, &arr.clone().sliced(2, 4)), expected[2..6]);
+        };
+
+        for pat in [
+            &b""[..],
+            b"f",
+            b"foo",
+            b"foob",
+            b"foo bar",
+            b"a long value",
+            b"bar",
+            b"xyz",
+        ] {
+            check(&|arr| starts_with_kernel(arr, pat), &|v| v.starts_with(pat));
+            check(&|arr| ends_with_kernel(arr, pat), &|v| v.ends_with(pat));
+            check(&|arr| contains_kernel(arr, pat), &|v| {
+                memchr::memmem::find(v, pat).is_some()
+            });
+        }
+    }
+}
@@ -259,3 +259,26 @@ pub fn hist_series(
     });
     Ok(out)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hist_uniform_and_custom_bins() {
+        let s = Series::new("a".into(), &[1i32, 2, 2, 3, 4, 5]);
+
+        let out = hist_series(&s, Some(4), None, false, true).unwrap();
+        let out = out.struct_().unwrap().fields_as_series();
+        let count = out[1].idx().unwrap();
+        assert_eq!(count.into_no_null_iter().collect::<Vec<_>>(), &[3, 1, 1, 1]);
+
+        let bins = Series::new("bins".into(), &[0.0f64, 2.0, 10.0]);
+        let out = hist_series(&s, None, Some(bins), false, false).unwrap();
+        let count = out.idx().unwrap();
+        assert_eq!(count.into_no_null_iter().collect::<Vec<_>>(), &[3, 3]);
+
+        let bins = Series::new("bins".into(), &[2.0f64, 1.0]);
+        assert!(hist_series(&s, None, Some(bins), false, false).is_err());
+    }
+}
@@ -1,5 +1,8 @@
+use std::collections::VecDeque;
+
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
 
 pub fn peak_min_max(
     column: &Column,
@@ -59,3 +62,134 @@ where
     let shift_right = ca.shift_and_fill(-1, end);
     ChunkedArray::gt(&shift_left, ca) & ChunkedArray::gt(&shift_right, ca)
 }
+
+/// Get a boolean mask of the values that are strictly greater (`is_max`) or smaller than all
+/// non-null values within `window` rows before and after them.
+///
+/// Rows beyond the bounds of the data are ignored. Null values result in a null mask value.
+pub fn local_min_max(column: &Column, window: usize, is_max: bool) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(window > 0, InvalidOperation: "`window` of local extrema must be at least 1");
+    let name = column.name().clone();
+    let column = column.to_physical_repr();
+    let column = column.as_materialized_series();
+    match column.dtype() {
+        dt if dt.is_bool() => {
+            let series = column.cast(&DataType::Int8)?;
+            local_min_max(&series.into_column(), window, is_max)
+        },
+        dt if dt.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = column.as_ref().as_ref().as_ref();
+                Ok(local_min_max_ca(ca, window, is_max).with_name(name))
+            })
+        },
+        dt => polars_bail!(opq = local_max, dt),
+    }
+}
+
+fn local_min_max_ca<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    window: usize,
+    is_max: bool,
+) -> BooleanChunked {
+    // Whether `a` is strictly more extreme than `b`.
+    let beats = |a: T::Native, b: T::Native| {
+        if is_max { a.tot_gt(&b) } else { a.tot_lt(&b) }
+    };
+
+    let values: Vec<Option<T::Native>> = ca.iter().collect();
+    let n = values.len();
+    let before = preceding_extremes(values.iter().copied(), window, beats);
+    let after = preceding_extremes(values.iter().rev().copied(), window, beats);
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            v.map(|v| {
+                before[i].is_none_or(|b| beats(v, b))
+                    && after[n - 1 - i].is_none_or(|a| beats(v, a))
+            })
+        })
+        .collect_ca(PlSmallStr::EMPTY)
+}
+
+/// For every position, the most extreme non-null value among the `window` preceding values.
+///
+/// Uses a monotonic deque, so this runs in linear time regardless of the window size.
+fn preceding_extremes<T: Copy>(
+    values: impl Iterator<Item = Option<T>>,
+    window: usize,
+    beats: impl Fn(T, T) -> bool,
+) -> Vec<Option<T>> {
+    let mut deque: VecDeque<(usize, T)> = VecDeque::with_capacity(window);
+    let mut out = Vec::with_capacity(values.size_hint().0);
+    for (i, v) in values.enumerate() {
+        while deque.front().is_some_and(|(j, _)| j + window < i) {
+            deque.pop_front();
+        }
+        out.push(deque.front().map(|(_, x)| *x));
+        if let Some(v) = v {
+            while deque.back().is_some_and(|(_, x)| !beats(*x, v)) {
+                deque.pop_back();
+            }
+            deque.push_back((i, v));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_local_min_max() {
+        let c = Column::new(
+            "a".into(),
+            &[
+                Some(1i32),
+                Some(3),
+                Some(2),
+                Some(4),
+                None,
+                Some(1),
+                Some(1),
+            ],
+        );
+
+        let out = local_min_max(&c, 1, true).unwrap();
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            &[
+                Some(false),
+                Some(true),
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                Some(false)
+            ]
+        );
+
+        let out = local_min_max(&c, 2, true).unwrap();
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            &[
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                Some(false)
+            ]
+        );
+
+        let out = local_min_max(&c, 2, false).unwrap();
+        assert_eq!(out.get(0), Some(true));
+        assert_eq!(out.get(2), Some(false));
+
+        assert!(local_min_max(&c, 0, true).is_err());
+    }
+}
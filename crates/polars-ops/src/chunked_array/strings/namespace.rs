@@ -330,10 +330,8 @@ pub trait StringNameSpaceImpl: AsString {
 
     /// Check if strings contain a given literal
     fn contains_literal(&self, lit: &str) -> PolarsResult<BooleanChunked> {
-        // note: benchmarking shows that the regex engine is actually
-        // faster at finding literal matches than str::contains.
-        // ref: https://github.com/pola-rs/polars/pull/6811
-        self.contains(regex::escape(lit).as_str(), true)
+        // A `memmem` searcher that is built once finds literals faster than the regex engine.
+        Ok(self.as_string().as_binary().contains(lit.as_bytes()))
     }
 
     /// Return the index position of a literal substring in the target string.
@@ -82,11 +82,16 @@ fn cross_join_dfs<'a>(
 
     let n_rows_left = df_self.height() as IdxSize;
     let n_rows_right = other.height() as IdxSize;
-    let Some(total_rows) = n_rows_left.checked_mul(n_rows_right) else {
-        polars_bail!(
-            ComputeError: "cross joins would produce more rows than fits into 2^32; \
-            consider compiling with polars-big-idx feature, or set 'streaming'"
-        );
+    // `IdxSize::MAX` itself is reserved to indicate `NULL` in indexing.
+    let Some(total_rows) = n_rows_left
+        .checked_mul(n_rows_right)
+        .filter(|n| *n < IdxSize::MAX)
+    else {
+        let size = df_self.height() as u128 * other.height() as u128;
+        #[cfg(not(feature = "big_idx"))]
+        polars_bail!(bigidx, ctx = "cross join", size = size);
+        #[cfg(feature = "big_idx")]
+        polars_bail!(ComputeError: "cross join produces {} rows, which does not fit into u64", size);
     };
 
     // the left side has the Nth row combined with every row from right.
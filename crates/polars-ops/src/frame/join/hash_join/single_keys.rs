@@ -121,48 +121,44 @@ where
         }
 
         // Build tables.
-        (0..n_partitions)
-            .into_par_iter()
-            .with_max_len(1)
-            .map(|p| {
-                // Resizing the hash map is very, very expensive. That's why we
-                // adopt a hybrid strategy: we assume an initially small hash
-                // map, which would satisfy a highly skewed relation. If this
-                // fills up we immediately reserve enough for a full cardinality
-                // data set.
-                let partition_range = partition_offsets[p]..partition_offsets[p + 1];
-                let full_size = partition_range.len();
-                let mut conservative_size = _HASHMAP_INIT_SIZE.max(full_size / 64);
-                let mut hm: PlHashMap<T::TotalOrdItem, IdxVec> =
-                    PlHashMap::with_capacity(conservative_size);
+        POOL.map_partitions(n_partitions, |p| {
+            // Resizing the hash map is very, very expensive. That's why we
+            // adopt a hybrid strategy: we assume an initially small hash
+            // map, which would satisfy a highly skewed relation. If this
+            // fills up we immediately reserve enough for a full cardinality
+            // data set.
+            let partition_range = partition_offsets[p]..partition_offsets[p + 1];
+            let full_size = partition_range.len();
+            let mut conservative_size = _HASHMAP_INIT_SIZE.max(full_size / 64);
+            let mut hm: PlHashMap<T::TotalOrdItem, IdxVec> =
+                PlHashMap::with_capacity(conservative_size);
 
-                unsafe {
-                    for i in partition_range {
-                        if hm.len() == conservative_size {
-                            hm.reserve(full_size - conservative_size);
-                            conservative_size = 0; // Hack to ensure we never hit this branch again.
-                        }
+            unsafe {
+                for i in partition_range {
+                    if hm.len() == conservative_size {
+                        hm.reserve(full_size - conservative_size);
+                        conservative_size = 0; // Hack to ensure we never hit this branch again.
+                    }
 
-                        let key = *scatter_keys.get_unchecked(i);
+                    let key = *scatter_keys.get_unchecked(i);
 
-                        if !key.is_null() || nulls_equal {
-                            let idx = *scatter_idxs.get_unchecked(i);
-                            match hm.entry(key) {
-                                Entry::Occupied(mut o) => {
-                                    o.get_mut().push(idx as IdxSize);
-                                },
-                                Entry::Vacant(v) => {
-                                    let iv = unitvec![idx as IdxSize];
-                                    v.insert(iv);
-                                },
-                            };
-                        }
+                    if !key.is_null() || nulls_equal {
+                        let idx = *scatter_idxs.get_unchecked(i);
+                        match hm.entry(key) {
+                            Entry::Occupied(mut o) => {
+                                o.get_mut().push(idx as IdxSize);
+                            },
+                            Entry::Vacant(v) => {
+                                let iv = unitvec![idx as IdxSize];
+                                v.insert(iv);
+                            },
+                        };
                     }
                 }
+            }
 
-                hm
-            })
-            .collect()
+            hm
+        })
     })
 }
 
@@ -73,6 +73,12 @@ fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> PolarsR
             let rhs = rhs.binary().unwrap();
             merge_ca(lhs, rhs, merge_indicator).into_series()
         },
+        // Row-encoded keys.
+        BinaryOffset => {
+            let lhs = lhs.binary_offset().unwrap();
+            let rhs = rhs.binary_offset().unwrap();
+            merge_ca(lhs, rhs, merge_indicator).into_series()
+        },
         #[cfg(feature = "dtype-extension")]
         Extension(typ, _) => {
             let lhs = lhs.ext().unwrap();
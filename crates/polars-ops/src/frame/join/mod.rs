@@ -211,6 +211,20 @@ pub trait DataFrameJoinOps: IntoDf {
             }
         }
 
+        // Categorical keys with different categories are joined in their supertype, the global
+        // categories.
+        #[cfg(feature = "dtype-categorical")]
+        for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+            if l.dtype().is_categorical()
+                && r.dtype().is_categorical()
+                && l.dtype() != r.dtype()
+                && let Some(dtype) = polars_core::utils::get_supertype(l.dtype(), r.dtype())
+            {
+                *l = l.cast(&dtype)?;
+                *r = r.cast(&dtype)?;
+            }
+        }
+
         if let Some((l, r)) = selected_left
             .iter()
             .zip(&selected_right)
@@ -11,6 +11,9 @@ use polars_core::utils::accumulate_dataframes_horizontal;
 #[cfg(feature = "to_dummies")]
 use rayon::prelude::*;
 
+#[cfg(feature = "to_dummies")]
+use crate::series::DummyDtype;
+
 pub trait IntoDf {
     fn to_df(&self) -> &DataFrame;
 }
@@ -41,7 +44,7 @@ pub trait DataFrameOps: IntoDf {
     ///       "code" => &["X1", "X2", "X3", "X3", "X2", "X2", "X1", "X1"]
     ///   }.unwrap();
     ///
-    ///   let dummies = df.to_dummies(None, false, false).unwrap();
+    ///   let dummies = df.to_dummies(None, false, false, DummyDtype::default()).unwrap();
     ///   println!("{}", dummies);
     /// # }
     /// ```
@@ -75,8 +78,9 @@ pub trait DataFrameOps: IntoDf {
         separator: Option<&str>,
         drop_first: bool,
         drop_nulls: bool,
+        dtype: DummyDtype,
     ) -> PolarsResult<DataFrame> {
-        self._to_dummies(None, separator, drop_first, drop_nulls)
+        self._to_dummies(None, separator, drop_first, drop_nulls, dtype)
     }
 
     #[cfg(feature = "to_dummies")]
@@ -86,8 +90,9 @@ pub trait DataFrameOps: IntoDf {
         separator: Option<&str>,
         drop_first: bool,
         drop_nulls: bool,
+        dtype: DummyDtype,
     ) -> PolarsResult<DataFrame> {
-        self._to_dummies(Some(columns), separator, drop_first, drop_nulls)
+        self._to_dummies(Some(columns), separator, drop_first, drop_nulls, dtype)
     }
 
     #[cfg(feature = "to_dummies")]
@@ -97,6 +102,7 @@ pub trait DataFrameOps: IntoDf {
         separator: Option<&str>,
         drop_first: bool,
         drop_nulls: bool,
+        dtype: DummyDtype,
     ) -> PolarsResult<DataFrame> {
         use crate::series::ToDummies;
 
@@ -114,7 +120,7 @@ pub trait DataFrameOps: IntoDf {
                 .map(|s| match set.contains(s.name().as_str()) {
                     true => s
                         .as_materialized_series()
-                        .to_dummies(separator, drop_first, drop_nulls),
+                        .to_dummies(separator, drop_first, drop_nulls, dtype),
                     false => Ok(s.clone().into_frame()),
                 })
                 .collect::<PolarsResult<Vec<_>>>()
@@ -1,9 +1,10 @@
+use num_traits::PrimInt;
 use polars_core::chunked_array::ChunkedArray;
-use polars_core::chunked_array::ops::arity::unary_mut_values;
+use polars_core::chunked_array::ops::arity::{binary_elementwise_values, unary_mut_values};
 use polars_core::prelude::DataType;
 use polars_core::series::Series;
 use polars_core::{with_match_physical_float_polars_type, with_match_physical_integer_polars_type};
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 
 use super::*;
 
@@ -55,3 +56,103 @@ apply_bitwise_op! {
     trailing_ones,
     trailing_zeros,
 }
+
+fn shift_ca<T>(ca: &ChunkedArray<T>, n: &UInt32Chunked, left: bool) -> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: PrimInt,
+{
+    let bits = (size_of::<T::Native>() * 8) as u32;
+    let zero = T::Native::zero();
+    let f = move |v: T::Native, n: u32| match (left, n < bits) {
+        (true, true) => v << n as usize,
+        (false, true) => v >> n as usize,
+        (true, false) => zero,
+        // Shifting out all bits keeps only the sign for signed integers.
+        (false, false) if v < zero => !zero,
+        (false, false) => zero,
+    };
+
+    if n.len() == 1 {
+        match n.get(0) {
+            Some(n) => ca.apply_values(|v| f(v, n)),
+            None => ChunkedArray::full_null(ca.name().clone(), ca.len()),
+        }
+    } else {
+        binary_elementwise_values(ca, n, f)
+    }
+}
+
+fn shift(s: &Series, n: &Series, left: bool, op: &str) -> PolarsResult<Series> {
+    let dt = s.dtype();
+    polars_ensure!(
+        dt.is_integer(),
+        InvalidOperation: "dtype {:?} not supported in '{}' operation", dt, op
+    );
+    let s = match (s.len(), n.len()) {
+        (1, len) if len != 1 => s.new_from_index(0, len),
+        (a, b) => {
+            polars_ensure!(
+                a == b || b == 1,
+                ShapeMismatch: "'{}' expected a shift amount of length 1 or {}, got {}", op, a, b
+            );
+            s.clone()
+        },
+    };
+    let n = n.strict_cast(&DataType::UInt32)?;
+    let n = n.u32().unwrap();
+
+    with_match_physical_integer_polars_type!(dt, |$T| {
+        let ca: &ChunkedArray<$T> = s.as_any().downcast_ref().unwrap();
+        Ok(shift_ca(ca, n, left).into_series())
+    })
+}
+
+/// Shift the bits of every integer value to the left by `n`, filling with zeros.
+///
+/// Shifting by at least the bit width of the type results in zero. Negative shift amounts are an
+/// error.
+pub fn shift_left(s: &Series, n: &Series) -> PolarsResult<Series> {
+    shift(s, n, true, "shift_left")
+}
+
+/// Shift the bits of every integer value to the right by `n`.
+///
+/// This is an arithmetic shift, so signed integers keep their sign. Shifting by at least the bit
+/// width of the type results in zero, or `-1` for a negative signed value.
+pub fn shift_right(s: &Series, n: &Series) -> PolarsResult<Series> {
+    shift(s, n, false, "shift_right")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shift_left_right() {
+        let s = Series::new("a".into(), &[Some(1i8), Some(-8), None, Some(3)]);
+        let n = Series::new("n".into(), &[Some(1u8), Some(2), Some(1), None]);
+
+        let out = shift_left(&s, &n).unwrap();
+        assert_eq!(out.dtype(), &DataType::Int8);
+        let out: Vec<_> = out.i8().unwrap().iter().collect();
+        assert_eq!(out, &[Some(2), Some(-32), None, None]);
+
+        let out = shift_right(&s, &n).unwrap();
+        let out: Vec<_> = out.i8().unwrap().iter().collect();
+        assert_eq!(out, &[Some(0), Some(-2), None, None]);
+
+        let n = Series::new("n".into(), &[8u32]);
+        let out = shift_left(&s, &n).unwrap();
+        let out: Vec<_> = out.i8().unwrap().iter().collect();
+        assert_eq!(out, &[Some(0), Some(0), None, Some(0)]);
+        let out = shift_right(&s, &n).unwrap();
+        let out: Vec<_> = out.i8().unwrap().iter().collect();
+        assert_eq!(out, &[Some(0), Some(-1), None, Some(0)]);
+
+        let n = Series::new("n".into(), &[-1i32]);
+        assert!(shift_left(&s, &n).is_err());
+        let s = Series::new("a".into(), &[1.0f64]);
+        assert!(shift_right(&s, &Series::new("n".into(), &[1u32])).is_err());
+    }
+}
@@ -121,6 +121,15 @@ pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
     })
 }
 
+/// Clamp `v` to `[min, max]`.
+///
+/// Unlike `num_traits::clamp` this does not panic if `min > max`, which can happen when the
+/// bounds come from other columns. The upper bound takes precedence in that case.
+#[inline]
+fn clamp_both<T: PartialOrd>(v: T, min: T, max: T) -> T {
+    num_traits::clamp_max(num_traits::clamp_min(v, min), max)
+}
+
 fn clip_helper_both_bounds<T>(
     ca: &ChunkedArray<T>,
     min: &ChunkedArray<T>,
@@ -132,17 +141,17 @@ where
 {
     match (min.len(), max.len()) {
         (1, 1) => match (min.get(0), max.get(0)) {
-            (Some(min), Some(max)) => clip_unary(ca, |v| num_traits::clamp(v, min, max)),
+            (Some(min), Some(max)) => clip_unary(ca, |v| clamp_both(v, min, max)),
             (Some(min), None) => clip_unary(ca, |v| num_traits::clamp_min(v, min)),
             (None, Some(max)) => clip_unary(ca, |v| num_traits::clamp_max(v, max)),
             (None, None) => ca.clone(),
         },
         (1, _) => match min.get(0) {
-            Some(min) => clip_binary(ca, max, |v, b| num_traits::clamp(v, min, b)),
+            Some(min) => clip_binary(ca, max, |v, b| clamp_both(v, min, b)),
             None => clip_binary(ca, max, num_traits::clamp_max),
         },
         (_, 1) => match max.get(0) {
-            Some(max) => clip_binary(ca, min, |v, b| num_traits::clamp(v, b, max)),
+            Some(max) => clip_binary(ca, min, |v, b| clamp_both(v, b, max)),
             None => clip_binary(ca, min, num_traits::clamp_min),
         },
         _ => clip_ternary(ca, min, max),
@@ -200,7 +209,7 @@ where
 {
     ternary_elementwise(ca, min, max, |opt_v, opt_min, opt_max| {
         match (opt_v, opt_min, opt_max) {
-            (Some(v), Some(min), Some(max)) => Some(num_traits::clamp(v, min, max)),
+            (Some(v), Some(min), Some(max)) => Some(clamp_both(v, min, max)),
             (Some(v), Some(min), None) => Some(num_traits::clamp_min(v, min)),
             (Some(v), None, Some(max)) => Some(num_traits::clamp_max(v, max)),
             (Some(v), None, None) => Some(v),
@@ -208,3 +217,37 @@ where
         }
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clip_expression_bounds() {
+        let s = Series::new("a".into(), &[Some(1i32), Some(5), None, Some(10), Some(7)]);
+        let min = Series::new("min".into(), &[Some(2i32), Some(2), Some(2), None, Some(8)]);
+        let max = Series::new(
+            "max".into(),
+            &[Some(3i32), Some(8), Some(3), Some(9), Some(6)],
+        );
+
+        let out = clip(&s, &min, &max).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(2), Some(5), None, Some(9), Some(6)]
+        );
+
+        let min = Series::new("min".into(), &[4i32]);
+        let out = clip(&s, &min, &max).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(3), Some(5), None, Some(9), Some(6)]
+        );
+
+        let out = clip_min(&s, &Series::new("min".into(), &[6.0f64])).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(6), Some(6), None, Some(10), Some(7)]
+        );
+    }
+}
@@ -417,6 +417,28 @@ pub fn cum_max(s: &Series, reverse: bool) -> PolarsResult<Series> {
     cum_max_with_init(s, reverse, &AnyValue::Null)
 }
 
+/// Get the number of distinct values up to and including every row. Like `n_unique`, null is
+/// counted as a distinct value.
+pub fn cum_n_unique(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    let s = if reverse { s.reverse() } else { s.clone() };
+    let mut is_first = vec![false; s.len()];
+    for i in s.arg_unique()?.into_no_null_iter() {
+        is_first[i as usize] = true;
+    }
+
+    let mut count = 0;
+    let out: NoNull<IdxCa> = is_first
+        .into_iter()
+        .map(|is_first| {
+            count += is_first as IdxSize;
+            count
+        })
+        .collect_trusted();
+    let out = out.into_inner().with_name(s.name().clone());
+    let out = if reverse { out.reverse() } else { out };
+    Ok(out.into_series())
+}
+
 pub fn cum_count(s: &Series, reverse: bool) -> PolarsResult<Series> {
     cum_count_with_init(s, reverse, 0)
 }
@@ -467,3 +489,29 @@ fn cum_count_no_nulls(name: PlSmallStr, len: usize, reverse: bool, init: IdxSize
     ca.rename(name);
     ca.into_series()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cum_n_unique() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some("x"), Some("y"), None, Some("x"), None, Some("z")],
+        );
+
+        let out = cum_n_unique(&s, false)?;
+        assert_eq!(out.name().as_str(), "a");
+        let out: Vec<_> = out.idx()?.into_no_null_iter().collect();
+        assert_eq!(out, &[1, 2, 3, 3, 3, 4]);
+
+        let out = cum_n_unique(&s, true)?;
+        let out: Vec<_> = out.idx()?.into_no_null_iter().collect();
+        assert_eq!(out, &[4, 4, 3, 3, 2, 1]);
+
+        let empty = Series::new_empty("a".into(), &DataType::Int32);
+        assert!(cum_n_unique(&empty, false)?.is_empty());
+        Ok(())
+    }
+}
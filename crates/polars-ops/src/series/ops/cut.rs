@@ -111,6 +111,41 @@ pub fn cut(
     map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
 }
 
+/// Map every value to the index of the bucket it falls in, given strictly increasing `breaks`.
+///
+/// Bucket `i` is `[breaks[i - 1], breaks[i])` if `left_closed`, else `(breaks[i - 1], breaks[i]]`,
+/// so values range from `0` (below the first break) to `breaks.len()` (above the last break).
+/// This is the position of the category [`cut`] assigns with the same breaks. Nulls and NaN
+/// values map to null.
+pub fn digitize(s: &Series, breaks: &[f64], left_closed: bool) -> PolarsResult<IdxCa> {
+    polars_ensure!(!breaks.iter().any(|x| x.is_nan()), ComputeError: "breaks cannot be NaN");
+    polars_ensure!(
+        breaks.windows(2).all(|x| x[0] < x[1]),
+        ComputeError: "breaks must be strictly increasing"
+    );
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() || s.dtype().is_decimal() || s.dtype().is_null(),
+        opq = digitize,
+        s.dtype()
+    );
+
+    let s2 = s.cast(&DataType::Float64)?;
+    let op = if left_closed {
+        PartialOrd::ge
+    } else {
+        PartialOrd::gt
+    };
+    let out: IdxCa = s2
+        .f64()?
+        .into_iter()
+        .map(|opt| {
+            opt.filter(|x| !x.is_nan())
+                .map(|x| breaks.partition_point(|v| op(&x, v)) as IdxSize)
+        })
+        .collect();
+    Ok(out.with_name(s.name().clone()))
+}
+
 pub fn qcut(
     s: &Series,
     probs: Vec<f64>,
@@ -152,15 +187,62 @@ pub fn qcut(
     map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
 }
 
+#[cfg(test)]
 mod test {
+    use polars_core::prelude::*;
+
+    use super::{compute_labels, cut};
+
+    #[test]
+    fn test_compute_labels() {
+        let labels = compute_labels(&[1.0, 2.0], false).unwrap();
+        assert_eq!(
+            labels.iter().map(|l| l.as_str()).collect::<Vec<_>>(),
+            ["(-inf, 1]", "(1, 2]", "(2, inf]"]
+        );
+
+        let labels = compute_labels(&[1.0, 2.0], true).unwrap();
+        assert_eq!(
+            labels.iter().map(|l| l.as_str()).collect::<Vec<_>>(),
+            ["[-inf, 1)", "[1, 2)", "[2, inf)"]
+        );
+    }
+
+    #[test]
+    fn test_cut_validates_breaks() {
+        let s = Series::new("x".into(), &[1.0f64, 2.0, 3.0]);
+
+        assert!(cut(&s, vec![1.0, 1.0], None, false, false).is_err());
+        assert!(cut(&s, vec![f64::NAN], None, false, false).is_err());
+        assert!(cut(&s, vec![f64::INFINITY], None, false, false).is_err());
+        let labels = Some(vec![PlSmallStr::from_static("a")]);
+        assert!(cut(&s, vec![1.0], labels, false, false).is_err());
+    }
+
+    #[test]
+    fn test_digitize() {
+        let s = Series::new(
+            "x".into(),
+            &[Some(0.5f64), Some(1.0), Some(1.5), None, Some(3.0)],
+        );
+        let breaks = [1.0, 2.0];
+
+        let out = digitize(&s, &breaks, false).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(0), Some(0), Some(1), None, Some(2)]);
+        let out = digitize(&s, &breaks, true).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(0), Some(1), Some(1), None, Some(2)]);
+
+        assert!(digitize(&s, &[2.0, 1.0], false).is_err());
+        let s = Series::new("x".into(), &["a"]);
+        assert!(digitize(&s, &breaks, false).is_err());
+    }
+
     // This need metadata in fields
     #[ignore]
     #[test]
     fn test_map_cats_fast_unique() {
         // This test is here to check the fast unique flag is set when it can be
         // as it is not visible to Python.
-        use polars_core::prelude::*;
-
         use super::map_cats;
 
         let s = Series::new("x".into(), &[1, 2, 3, 4, 5]);
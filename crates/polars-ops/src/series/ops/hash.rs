@@ -0,0 +1,314 @@
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hash algorithms with a fixed, documented output so that hashes can be reproduced outside of
+/// Polars (e.g. for bucketing or sharding).
+///
+/// Values are hashed over their little-endian physical representation; strings and binary
+/// values are hashed over their raw bytes. Categoricals are hashed over their string values.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HashAlgorithm {
+    /// 64-bit xxHash (XXH64).
+    #[default]
+    XxHash64,
+    /// wyhash, final version 4.2, with the default secret.
+    WyHash,
+    /// 32-bit MurmurHash3 (x86_32). Only the lower 32 bits of the seed are used.
+    Murmur3,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::XxHash64 => "xxhash64",
+            Self::WyHash => "wyhash",
+            Self::Murmur3 => "murmur3",
+        }
+    }
+
+    pub fn hash_bytes(self, bytes: &[u8], seed: u64) -> u64 {
+        match self {
+            Self::XxHash64 => xxhash64(bytes, seed),
+            Self::WyHash => wyhash(bytes, seed),
+            Self::Murmur3 => murmur3_32(bytes, seed as u32) as u64,
+        }
+    }
+}
+
+/// Hash every value of `s` with the given algorithm and seed. Null values hash to null.
+pub fn hash_with_algorithm(
+    s: &Series,
+    algorithm: HashAlgorithm,
+    seed: u64,
+) -> PolarsResult<UInt64Chunked> {
+    let f = |bytes: &[u8]| algorithm.hash_bytes(bytes, seed);
+
+    let s = match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.cast(&DataType::String)?,
+        _ => s.to_physical_repr().into_owned(),
+    };
+    let out: UInt64Chunked = match s.dtype() {
+        DataType::Boolean => s
+            .bool()?
+            .apply_nonnull_values_generic(DataType::UInt64, |v| f(&[v as u8])),
+        DataType::String => s
+            .str()?
+            .apply_nonnull_values_generic(DataType::UInt64, |v| f(v.as_bytes())),
+        DataType::Binary => s
+            .binary()?
+            .apply_nonnull_values_generic(DataType::UInt64, f),
+        DataType::Null => UInt64Chunked::full_null(s.name().clone(), s.len()),
+        dt if dt.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                ca.apply_nonnull_values_generic(DataType::UInt64, |v| f(v.to_le_bytes().as_ref()))
+            })
+        },
+        dt => polars_bail!(opq = hash, dt),
+    };
+    Ok(out.with_name(s.name().clone()))
+}
+
+#[inline(always)]
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+const XXH_PRIME64_1: u64 = 0x9E3779B185EBCA87;
+const XXH_PRIME64_2: u64 = 0xC2B2AE3D27D4EB4F;
+const XXH_PRIME64_3: u64 = 0x165667B19E3779F9;
+const XXH_PRIME64_4: u64 = 0x85EBCA77C2B2AE63;
+const XXH_PRIME64_5: u64 = 0x27D4EB2F165667C5;
+
+#[inline(always)]
+fn xxh64_round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(XXH_PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(XXH_PRIME64_1)
+}
+
+#[inline(always)]
+fn xxh64_merge_round(acc: u64, val: u64) -> u64 {
+    (acc ^ xxh64_round(0, val))
+        .wrapping_mul(XXH_PRIME64_1)
+        .wrapping_add(XXH_PRIME64_4)
+}
+
+fn xxhash64(bytes: &[u8], seed: u64) -> u64 {
+    let len = bytes.len();
+    let mut offset = 0;
+
+    let mut h = if len >= 32 {
+        let mut v1 = seed.wrapping_add(XXH_PRIME64_1).wrapping_add(XXH_PRIME64_2);
+        let mut v2 = seed.wrapping_add(XXH_PRIME64_2);
+        let mut v3 = seed;
+        let mut v4 = seed.wrapping_sub(XXH_PRIME64_1);
+        while offset + 32 <= len {
+            v1 = xxh64_round(v1, read_u64(bytes, offset));
+            v2 = xxh64_round(v2, read_u64(bytes, offset + 8));
+            v3 = xxh64_round(v3, read_u64(bytes, offset + 16));
+            v4 = xxh64_round(v4, read_u64(bytes, offset + 24));
+            offset += 32;
+        }
+        let h = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+        [v1, v2, v3, v4]
+            .into_iter()
+            .fold(h, |h, v| xxh64_merge_round(h, v))
+    } else {
+        seed.wrapping_add(XXH_PRIME64_5)
+    };
+    h = h.wrapping_add(len as u64);
+
+    while offset + 8 <= len {
+        h ^= xxh64_round(0, read_u64(bytes, offset));
+        h = h
+            .rotate_left(27)
+            .wrapping_mul(XXH_PRIME64_1)
+            .wrapping_add(XXH_PRIME64_4);
+        offset += 8;
+    }
+    if offset + 4 <= len {
+        h ^= (read_u32(bytes, offset) as u64).wrapping_mul(XXH_PRIME64_1);
+        h = h
+            .rotate_left(23)
+            .wrapping_mul(XXH_PRIME64_2)
+            .wrapping_add(XXH_PRIME64_3);
+        offset += 4;
+    }
+    for &b in &bytes[offset..] {
+        h ^= (b as u64).wrapping_mul(XXH_PRIME64_5);
+        h = h.rotate_left(11).wrapping_mul(XXH_PRIME64_1);
+    }
+
+    h ^= h >> 33;
+    h = h.wrapping_mul(XXH_PRIME64_2);
+    h ^= h >> 29;
+    h = h.wrapping_mul(XXH_PRIME64_3);
+    h ^ (h >> 32)
+}
+
+const WY_SECRET: [u64; 4] = [
+    0x2d358dccaa6c78a5,
+    0x8bb84b93962eacc9,
+    0x4b33a62ed433d4a3,
+    0x4d5a2da51de1aa47,
+];
+
+#[inline(always)]
+fn wymum(a: u64, b: u64) -> (u64, u64) {
+    let r = (a as u128) * (b as u128);
+    (r as u64, (r >> 64) as u64)
+}
+
+#[inline(always)]
+fn wymix(a: u64, b: u64) -> u64 {
+    let (a, b) = wymum(a, b);
+    a ^ b
+}
+
+#[inline(always)]
+fn wyr3(bytes: &[u8], k: usize) -> u64 {
+    ((bytes[0] as u64) << 16) | ((bytes[k >> 1] as u64) << 8) | bytes[k - 1] as u64
+}
+
+fn wyhash(bytes: &[u8], mut seed: u64) -> u64 {
+    let s = &WY_SECRET;
+    let len = bytes.len();
+    seed ^= wymix(seed ^ s[0], s[1]);
+
+    let (a, b) = if len <= 16 {
+        if len >= 4 {
+            let mid = (len >> 3) << 2;
+            (
+                ((read_u32(bytes, 0) as u64) << 32) | read_u32(bytes, mid) as u64,
+                ((read_u32(bytes, len - 4) as u64) << 32) | read_u32(bytes, len - 4 - mid) as u64,
+            )
+        } else if len > 0 {
+            (wyr3(bytes, len), 0)
+        } else {
+            (0, 0)
+        }
+    } else {
+        let mut i = len;
+        let mut offset = 0;
+        if i >= 48 {
+            let mut see1 = seed;
+            let mut see2 = seed;
+            while i >= 48 {
+                seed = wymix(
+                    read_u64(bytes, offset) ^ s[1],
+                    read_u64(bytes, offset + 8) ^ seed,
+                );
+                see1 = wymix(
+                    read_u64(bytes, offset + 16) ^ s[2],
+                    read_u64(bytes, offset + 24) ^ see1,
+                );
+                see2 = wymix(
+                    read_u64(bytes, offset + 32) ^ s[3],
+                    read_u64(bytes, offset + 40) ^ see2,
+                );
+                offset += 48;
+                i -= 48;
+            }
+            seed ^= see1 ^ see2;
+        }
+        while i > 16 {
+            seed = wymix(
+                read_u64(bytes, offset) ^ s[1],
+                read_u64(bytes, offset + 8) ^ seed,
+            );
+            i -= 16;
+            offset += 16;
+        }
+        (
+            read_u64(bytes, offset + i - 16),
+            read_u64(bytes, offset + i - 8),
+        )
+    };
+
+    let (a, b) = wymum(a ^ s[1], b ^ seed);
+    wymix(a ^ s[0] ^ len as u64, b ^ s[1])
+}
+
+fn murmur3_32(bytes: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e2d51;
+    const C2: u32 = 0x1b873593;
+
+    #[inline(always)]
+    fn scramble(k: u32) -> u32 {
+        k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+    }
+
+    let mut h = seed;
+    let mut blocks = bytes.chunks_exact(4);
+    for block in &mut blocks {
+        h ^= scramble(u32::from_le_bytes(block.try_into().unwrap()));
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+    }
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        let k = tail.iter().rev().fold(0u32, |k, &b| (k << 8) | b as u32);
+        h ^= scramble(k);
+    }
+
+    h ^= bytes.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^ (h >> 16)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_xxhash64_reference() {
+        assert_eq!(xxhash64(b"", 0), 0xef46db3751d8e999);
+        assert_eq!(xxhash64(b"abc", 0), 0x44bc2cf5ad770999);
+        assert_eq!(
+            xxhash64(b"Nobody inspects the spammish repetition", 0),
+            0xfbcea83c8a378bf1
+        );
+    }
+
+    #[test]
+    fn test_wyhash_reference() {
+        assert_eq!(wyhash(b"", 0), 0x93228a4de0eec5a2);
+        assert_eq!(wyhash(b"a", 1), 0xc5bac3db178713c4);
+        assert_eq!(wyhash(b"abc", 2), 0xa97f2f7b1d9b3314);
+        assert_eq!(wyhash(b"message digest", 3), 0x786d1f1df3801df4);
+    }
+
+    #[test]
+    fn test_murmur3_reference() {
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"", 1), 0x514e28b7);
+        assert_eq!(murmur3_32(b"\0\0\0\0", 0), 0x2362f9de);
+        assert_eq!(murmur3_32(b"Hello, world!", 0x9747b28c), 0x24884cba);
+    }
+
+    #[test]
+    fn test_hash_with_algorithm_nulls() {
+        let s = Series::new("a".into(), &[Some("abc"), None]);
+        let out = hash_with_algorithm(&s, HashAlgorithm::XxHash64, 0).unwrap();
+        assert_eq!(out.get(0), Some(0x44bc2cf5ad770999));
+        assert_eq!(out.get(1), None);
+    }
+}
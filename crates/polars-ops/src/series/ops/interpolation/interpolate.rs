@@ -202,18 +202,237 @@ fn linear_interp_signed<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Series {
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
+/// Positions of the known (non-null) values of `ca` together with those values.
+fn knots(ca: &Float64Chunked) -> (Vec<f64>, Vec<f64>) {
+    ca.iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (i as f64, v)))
+        .unzip()
+}
+
+/// Fill every null that lies between two known values with `eval(k, x)`, where `k` is the index
+/// of the knot directly before position `x`.
+fn fill_interior<F>(ca: &Float64Chunked, n_knots: usize, eval: F) -> Float64Chunked
+where
+    F: Fn(usize, f64) -> f64,
+{
+    let mut seen = 0;
+    ca.iter()
+        .enumerate()
+        .map(|(i, v)| match v {
+            Some(v) => {
+                seen += 1;
+                Some(v)
+            },
+            None if seen > 0 && seen < n_knots => Some(eval(seen - 1, i as f64)),
+            None => None,
+        })
+        .collect_ca(ca.name().clone())
+}
+
+/// Natural cubic spline through all known values.
+fn spline_interp(ca: &Float64Chunked) -> Float64Chunked {
+    let (xs, ys) = knots(ca);
+    let n = xs.len();
+    if n < 2 || !ca.has_nulls() {
+        return ca.clone();
+    }
+
+    // Solve the tridiagonal system for the second derivatives with the Thomas algorithm. The
+    // natural boundary conditions fix the second derivatives at both ends to zero.
+    let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut m = vec![0.0; n];
+    if n > 2 {
+        let mut c_prime = vec![0.0; n];
+        let mut d_prime = vec![0.0; n];
+        for i in 1..n - 1 {
+            let a = h[i - 1];
+            let b = 2.0 * (h[i - 1] + h[i]);
+            let d = 6.0 * ((ys[i + 1] - ys[i]) / h[i] - (ys[i] - ys[i - 1]) / h[i - 1]);
+            let denom = b - a * c_prime[i - 1];
+            c_prime[i] = h[i] / denom;
+            d_prime[i] = (d - a * d_prime[i - 1]) / denom;
+        }
+        for i in (1..n - 1).rev() {
+            m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+        }
+    }
+
+    fill_interior(ca, n, |k, x| {
+        let h = h[k];
+        let (dl, dr) = (x - xs[k], xs[k + 1] - x);
+        m[k] * dr.powi(3) / (6.0 * h)
+            + m[k + 1] * dl.powi(3) / (6.0 * h)
+            + (ys[k] / h - m[k] * h / 6.0) * dr
+            + (ys[k + 1] / h - m[k + 1] * h / 6.0) * dl
+    })
+}
+
+/// Interpolating polynomial of the given order through the known values closest to each gap.
+fn polynomial_interp(ca: &Float64Chunked, order: u8) -> Float64Chunked {
+    let (xs, ys) = knots(ca);
+    let n = xs.len();
+    if n < 2 || !ca.has_nulls() {
+        return ca.clone();
+    }
+    let n_points = (order as usize + 1).min(n);
+
+    fill_interior(ca, n, |k, x| {
+        // Center the points around the gap, shifting them inwards at the boundaries.
+        let start = (k + 1)
+            .saturating_sub(n_points.div_ceil(2))
+            .min(n - n_points);
+        let (xs, ys) = (&xs[start..start + n_points], &ys[start..start + n_points]);
+
+        // Lagrange form.
+        let mut out = 0.0;
+        for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+            let mut weight = 1.0;
+            for (j, &xj) in xs.iter().enumerate() {
+                if i != j {
+                    weight *= (x - xj) / (xi - xj);
+                }
+            }
+            out += yi * weight;
+        }
+        out
+    })
+}
+
+/// Apply a float interpolation kernel to numeric and temporal data.
+fn interpolate_float<F>(s: &Series, kernel: F) -> PolarsResult<Series>
+where
+    F: Fn(&Float64Chunked) -> Float64Chunked,
+{
+    let logical = s.dtype();
+    if logical.is_primitive_numeric() {
+        let out = kernel(s.cast(&DataType::Float64)?.f64()?).into_series();
+        return if logical.is_float() {
+            out.cast(logical)
+        } else {
+            Ok(out)
+        };
+    }
+    if matches!(
+        logical,
+        DataType::Date | DataType::Datetime(_, _) | DataType::Duration(_) | DataType::Time
+    ) {
+        let physical = s.to_physical_repr();
+        let out = kernel(physical.cast(&DataType::Float64)?.f64()?).apply_values(f64::round);
+        return out.into_series().cast(physical.dtype())?.cast(logical);
+    }
+    Ok(s.clone())
+}
+
+/// Set the values that were filled beyond `limit` back to null.
+fn apply_limit(
+    original: &Series,
+    out: Series,
+    limit: usize,
+    direction: LimitDirection,
+) -> PolarsResult<Series> {
+    let is_null: Vec<bool> = original.is_null().into_no_null_iter().collect();
+    let mut keep = Vec::with_capacity(is_null.len());
+    let mut i = 0;
+    while i < is_null.len() {
+        if !is_null[i] {
+            keep.push(true);
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < is_null.len() && is_null[i] {
+            i += 1;
+        }
+        let run = i - start;
+        keep.extend((0..run).map(|k| {
+            let from_end = run - 1 - k;
+            match direction {
+                LimitDirection::Forward => k < limit,
+                LimitDirection::Backward => from_end < limit,
+                LimitDirection::Both => k < limit || from_end < limit,
+            }
+        }));
+    }
+    let mask = BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep);
+    let nulls = Series::full_null(out.name().clone(), out.len(), out.dtype());
+    out.zip_with(&mask, &nulls)
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum InterpolationMethod {
     Linear,
     Nearest,
+    /// Natural cubic spline through all non-null values.
+    Spline,
+    /// Polynomial of the given order through the non-null values closest to each gap.
+    Polynomial(u8),
+}
+
+/// Which side of a gap is filled when a `limit` is set.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum LimitDirection {
+    /// Fill the first `limit` nulls of a gap.
+    #[default]
+    Forward,
+    /// Fill the last `limit` nulls of a gap.
+    Backward,
+    /// Fill up to `limit` nulls from both ends of a gap.
+    Both,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct InterpolateOptions {
+    pub method: InterpolationMethod,
+    /// Maximum number of consecutive nulls to fill. `None` fills whole gaps.
+    pub limit: Option<IdxSize>,
+    pub limit_direction: LimitDirection,
+}
+
+impl Default for InterpolateOptions {
+    fn default() -> Self {
+        Self {
+            method: InterpolationMethod::Linear,
+            limit: None,
+            limit_direction: LimitDirection::default(),
+        }
+    }
+}
+
+impl From<InterpolationMethod> for InterpolateOptions {
+    fn from(method: InterpolationMethod) -> Self {
+        Self {
+            method,
+            ..Default::default()
+        }
+    }
 }
 
-pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
+pub fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResult<Series> {
     match method {
-        InterpolationMethod::Linear => interpolate_linear(s),
-        InterpolationMethod::Nearest => interpolate_nearest(s),
+        InterpolationMethod::Linear => Ok(interpolate_linear(s)),
+        InterpolationMethod::Nearest => Ok(interpolate_nearest(s)),
+        InterpolationMethod::Spline => interpolate_float(s, spline_interp),
+        InterpolationMethod::Polynomial(order) => {
+            interpolate_float(s, |ca| polynomial_interp(ca, order))
+        },
+    }
+}
+
+pub fn interpolate_with_options(s: &Series, options: InterpolateOptions) -> PolarsResult<Series> {
+    if let InterpolationMethod::Polynomial(order) = options.method {
+        polars_ensure!(order > 0, InvalidOperation: "polynomial interpolation requires an `order` of at least 1");
+    }
+    let out = interpolate(s, options.method)?;
+    match options.limit {
+        Some(limit) => apply_limit(s, out, limit as usize, options.limit_direction),
+        None => Ok(out),
     }
 }
 
@@ -224,7 +443,7 @@ mod test {
     #[test]
     fn test_interpolate() {
         let ca = UInt32Chunked::new("".into(), &[Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -232,7 +451,7 @@ mod test {
         );
 
         let ca = UInt32Chunked::new("".into(), &[None, Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -243,7 +462,7 @@ mod test {
             "".into(),
             &[None, Some(1), None, None, Some(4), Some(5), None],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -261,7 +480,7 @@ mod test {
             "".into(),
             &[None, Some(1), None, None, Some(4), Some(5), None],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest).unwrap();
         let out = out.u32().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -269,10 +488,117 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_spline_and_polynomial() {
+        // Samples of x^2, which both a natural spline and a quadratic reproduce closely.
+        let ca = Float64Chunked::new(
+            "".into(),
+            &[
+                None,
+                Some(0.0),
+                None,
+                Some(4.0),
+                None,
+                Some(16.0),
+                Some(25.0),
+                None,
+            ],
+        );
+        let s = ca.into_series();
+
+        let out = interpolate(&s, InterpolationMethod::Polynomial(2)).unwrap();
+        let out: Vec<_> = out.f64().unwrap().iter().collect();
+        assert_eq!(
+            out,
+            &[
+                None,
+                Some(0.0),
+                Some(1.0),
+                Some(4.0),
+                Some(9.0),
+                Some(16.0),
+                Some(25.0),
+                None
+            ]
+        );
+
+        let out = interpolate(&s, InterpolationMethod::Spline).unwrap();
+        let out = out.f64().unwrap();
+        assert_eq!(out.get(0), None);
+        assert_eq!(out.get(7), None);
+        assert!((out.get(4).unwrap() - 9.0).abs() < 0.5);
+
+        let two_points = Float32Chunked::new("".into(), &[Some(1.0), None, Some(3.0)]);
+        let out = interpolate(&two_points.into_series(), InterpolationMethod::Spline).unwrap();
+        assert_eq!(
+            Vec::from(out.f32().unwrap()),
+            &[Some(1.0), Some(2.0), Some(3.0)]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_limit() {
+        let ca = Int32Chunked::new(
+            "".into(),
+            &[Some(0), None, None, None, Some(4), None, Some(6)],
+        );
+        let s = ca.into_series();
+        let options = |limit_direction| InterpolateOptions {
+            method: InterpolationMethod::Linear,
+            limit: Some(1),
+            limit_direction,
+        };
+
+        let out = interpolate_with_options(&s, options(LimitDirection::Forward)).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                Some(0.0),
+                Some(1.0),
+                None,
+                None,
+                Some(4.0),
+                Some(5.0),
+                Some(6.0)
+            ]
+        );
+
+        let out = interpolate_with_options(&s, options(LimitDirection::Backward)).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                Some(0.0),
+                None,
+                None,
+                Some(3.0),
+                Some(4.0),
+                Some(5.0),
+                Some(6.0)
+            ]
+        );
+
+        let out = interpolate_with_options(&s, options(LimitDirection::Both)).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                Some(0.0),
+                Some(1.0),
+                None,
+                Some(3.0),
+                Some(4.0),
+                Some(5.0),
+                Some(6.0)
+            ]
+        );
+
+        let options = InterpolateOptions::from(InterpolationMethod::Polynomial(0));
+        assert!(interpolate_with_options(&s, options).is_err());
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("".into(), &[Some(4), None, None, Some(1)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f64().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -300,7 +626,7 @@ mod test {
                 Some(4660f32),
             ],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f32().unwrap();
 
         assert_eq!(
@@ -101,3 +101,36 @@ pub fn new_linear_space_f64(
     ca.set_sorted_flag(is_sorted);
     Ok(ca)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn values(closed: ClosedInterval, n: u64) -> Vec<f64> {
+        new_linear_space_f64(0.0, 1.0, n, closed, PlSmallStr::EMPTY)
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_linear_space_closed() {
+        assert_eq!(
+            values(ClosedInterval::Both, 5),
+            &[0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(values(ClosedInterval::Left, 4), &[0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(values(ClosedInterval::Right, 4), &[0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(values(ClosedInterval::None, 3), &[0.25, 0.5, 0.75]);
+        assert_eq!(values(ClosedInterval::None, 1), &[0.5]);
+        assert!(values(ClosedInterval::Both, 0).is_empty());
+
+        let ca =
+            new_linear_space_f32(1.0, -1.0, 3, ClosedInterval::Both, PlSmallStr::EMPTY).unwrap();
+        assert_eq!(ca.is_sorted_flag(), IsSorted::Descending);
+        assert_eq!(
+            ca.into_no_null_iter().collect::<Vec<_>>(),
+            &[1.0, 0.0, -1.0]
+        );
+    }
+}
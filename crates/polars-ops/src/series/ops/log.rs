@@ -10,6 +10,10 @@ fn log1p<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
     ca.cast_and_apply_in_place(|v: f64| v.ln_1p())
 }
 
+fn expm1<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
+    ca.cast_and_apply_in_place(|v: f64| v.exp_m1())
+}
+
 fn exp<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
     ca.cast_and_apply_in_place(|v: f64| v.exp())
 }
@@ -90,6 +94,32 @@ pub trait LogSeries: SeriesSealed {
         }
     }
 
+    /// Calculate `exp(x) - 1` of all elements in the input array, accurate for small `x`.
+    fn expm1(&self) -> Series {
+        let s = self.as_series();
+        if s.dtype().is_decimal() {
+            return s.cast(&DataType::Float64).unwrap().expm1();
+        }
+
+        let s = s.to_physical_repr();
+        let s = s.as_ref();
+
+        use DataType::*;
+        match s.dtype() {
+            dt if dt.is_integer() => {
+                with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                    expm1(ca).into_series()
+                })
+            },
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s.f16().unwrap().apply_values(|v| v.exp_m1()).into_series(),
+            Float32 => s.f32().unwrap().apply_values(|v| v.exp_m1()).into_series(),
+            Float64 => s.f64().unwrap().apply_values(|v| v.exp_m1()).into_series(),
+            _ => s.cast(&DataType::Float64).unwrap().expm1(),
+        }
+    }
+
     /// Compute the entropy as `-sum(pk * log(pk))`.
     /// where `pk` are discrete probabilities.
     fn entropy(&self, base: f64, normalize: bool) -> PolarsResult<f64> {
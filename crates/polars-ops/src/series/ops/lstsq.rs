@@ -0,0 +1,229 @@
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+fn to_f64(s: &Series, op: &str) -> PolarsResult<Vec<Option<f64>>> {
+    let dt = s.dtype();
+    polars_ensure!(
+        dt.is_primitive_numeric() || dt.is_decimal() || dt.is_bool() || dt.is_null(),
+        InvalidOperation: "{} operation not supported for dtype `{}`", op, dt
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64().unwrap().iter().collect())
+}
+
+/// Solve `min ||A x - b||` with Householder QR.
+///
+/// `a` is an `n x k` matrix in column-major order. Returns `None` if the system is underdetermined
+/// or `A` is (numerically) rank deficient.
+fn solve_least_squares(mut a: Vec<f64>, mut b: Vec<f64>, n: usize, k: usize) -> Option<Vec<f64>> {
+    if k == 0 || n < k {
+        return None;
+    }
+    let mut v = vec![0.0; n];
+    for j in 0..k {
+        let col = &a[j * n..(j + 1) * n];
+        let norm = col[j..].iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return None;
+        }
+        let alpha = if col[j] > 0.0 { -norm } else { norm };
+        let v = &mut v[j..];
+        v.copy_from_slice(&col[j..]);
+        v[0] -= alpha;
+        let v_norm2 = v.iter().map(|x| x * x).sum::<f64>();
+        if v_norm2 == 0.0 {
+            continue;
+        }
+
+        let reflect = |x: &mut [f64]| {
+            let dot = v.iter().zip(x.iter()).map(|(v, x)| v * x).sum::<f64>();
+            let f = 2.0 * dot / v_norm2;
+            x.iter_mut().zip(v.iter()).for_each(|(x, v)| *x -= f * v);
+        };
+        for c in j..k {
+            reflect(&mut a[c * n + j..(c + 1) * n]);
+        }
+        reflect(&mut b[j..]);
+    }
+
+    let r = |row: usize, col: usize| a[col * n + row];
+    let max_diag = (0..k).map(|j| r(j, j).abs()).fold(0.0, f64::max);
+    let tol = max_diag * f64::EPSILON * n as f64;
+    if (0..k).any(|j| r(j, j).abs() <= tol) {
+        return None;
+    }
+
+    let mut x = vec![0.0; k];
+    for j in (0..k).rev() {
+        let dot = (j + 1..k).map(|c| r(j, c) * x[c]).sum::<f64>();
+        x[j] = (b[j] - dot) / r(j, j);
+    }
+    Some(x)
+}
+
+/// Fit `y` on the given design columns, skipping rows where any value is null.
+fn fit(
+    name: PlSmallStr,
+    y: &[Option<f64>],
+    columns: &[Vec<Option<f64>>],
+    field_names: Vec<PlSmallStr>,
+) -> PolarsResult<Series> {
+    let k = columns.len();
+    let valid = (0..y.len())
+        .filter(|&i| y[i].is_some() && columns.iter().all(|c| c[i].is_some()))
+        .collect::<Vec<_>>();
+    let n = valid.len();
+
+    let mut a = Vec::with_capacity(n * k);
+    for c in columns {
+        a.extend(valid.iter().map(|&i| c[i].unwrap()));
+    }
+    let b = valid.iter().map(|&i| y[i].unwrap()).collect();
+    let coefficients = solve_least_squares(a, b, n, k);
+
+    let fields = field_names
+        .into_iter()
+        .enumerate()
+        .map(|(j, name)| Series::new(name, [coefficients.as_ref().map(|c| c[j])]))
+        .collect::<Vec<_>>();
+    Ok(StructChunked::from_series(name, 1, fields.iter())?.into_series())
+}
+
+/// Names of the coefficients returned by [`lstsq`].
+///
+/// Errors if two coefficients would get the same name, e.g. if a regressor is named `intercept`.
+pub fn lstsq_field_names(x: &[Field], fit_intercept: bool) -> PolarsResult<Vec<PlSmallStr>> {
+    let intercept = fit_intercept.then(|| PlSmallStr::from_static("intercept"));
+    let names = intercept
+        .into_iter()
+        .chain(x.iter().map(|f| f.name().clone()))
+        .collect::<Vec<_>>();
+    let mut seen = PlHashSet::with_capacity(names.len());
+    for name in &names {
+        polars_ensure!(
+            seen.insert(name),
+            Duplicate: "`lstsq` coefficient name '{}' is not unique; rename the regressors", name
+        );
+    }
+    Ok(names)
+}
+
+/// Names of the coefficients returned by [`polyfit`], from the constant term up.
+pub fn polyfit_field_names(degree: usize) -> Vec<PlSmallStr> {
+    (0..=degree).map(|p| format_pl_smallstr!("c{p}")).collect()
+}
+
+/// Ordinary least squares fit of `y` on the columns in `x`.
+///
+/// Returns a single struct row with a `Float64` coefficient per column in `x`, named after that
+/// column and preceded by an `intercept` field if `fit_intercept` is set. Rows with a null value
+/// in any input are ignored. The coefficients are null if there are fewer rows than coefficients
+/// or if the columns are linearly dependent.
+pub fn lstsq(y: &Series, x: &[Series], fit_intercept: bool) -> PolarsResult<Series> {
+    polars_ensure!(!x.is_empty(), ComputeError: "`lstsq` needs at least one regressor");
+    for s in x {
+        polars_ensure!(
+            s.len() == y.len(),
+            ShapeMismatch: "`lstsq` expected all inputs to have length {}, got {}", y.len(), s.len()
+        );
+    }
+    let yv = to_f64(y, "`lstsq`")?;
+    let mut columns = Vec::with_capacity(x.len() + fit_intercept as usize);
+    if fit_intercept {
+        columns.push(vec![Some(1.0); y.len()]);
+    }
+    for s in x {
+        columns.push(to_f64(s, "`lstsq`")?);
+    }
+
+    let fields = x.iter().map(|s| s.field().into_owned()).collect::<Vec<_>>();
+    fit(
+        y.name().clone(),
+        &yv,
+        &columns,
+        lstsq_field_names(&fields, fit_intercept)?,
+    )
+}
+
+/// Least squares fit of a polynomial of the given degree through the points `(x, y)`.
+///
+/// Returns a single struct row with the coefficients `c0` (the constant term) up to `c{degree}`.
+pub fn polyfit(y: &Series, x: &Series, degree: usize) -> PolarsResult<Series> {
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "`polyfit` expected inputs of equal length, got {} and {}", y.len(), x.len()
+    );
+    let yv = to_f64(y, "`polyfit`")?;
+    let xv = to_f64(x, "`polyfit`")?;
+    let columns = (0..=degree as i32)
+        .map(|p| xv.iter().map(|x| x.map(|x| x.powi(p))).collect())
+        .collect::<Vec<_>>();
+    fit(y.name().clone(), &yv, &columns, polyfit_field_names(degree))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn coefficients(s: &Series) -> Vec<Option<f64>> {
+        s.struct_()
+            .unwrap()
+            .fields_as_series()
+            .iter()
+            .map(|s| s.f64().unwrap().get(0))
+            .collect()
+    }
+
+    #[test]
+    fn test_lstsq() {
+        let x1 = Series::new("x1".into(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let x2 = Series::new("x2".into(), &[Some(2i32), Some(1), None, Some(3), Some(7)]);
+        // y = 1 + 2 * x1 - 3 * x2
+        let y = Series::new("y".into(), &[-3.0, 2.0, 100.0, 0.0, -10.0]);
+
+        let out = lstsq(&y, &[x1.clone(), x2], true).unwrap();
+        assert_eq!(out.name().as_str(), "y");
+        let names = out
+            .struct_()
+            .unwrap()
+            .struct_fields()
+            .iter()
+            .map(|f| f.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["intercept", "x1", "x2"]);
+        let expected = [1.0, 2.0, -3.0];
+        for (c, e) in coefficients(&out).into_iter().zip(expected) {
+            assert!((c.unwrap() - e).abs() < 1e-10);
+        }
+
+        // Linearly dependent regressors.
+        let x3 = (&x1 * 2.0).with_name("x3".into());
+        let out = lstsq(&y, &[x1.clone(), x3], false).unwrap();
+        assert_eq!(coefficients(&out), &[None, None]);
+
+        // The coefficient names must be unique.
+        let intercept = x1.clone().with_name("intercept".into());
+        assert!(lstsq(&y, &[intercept.clone()], true).is_err());
+        assert!(lstsq(&y, &[intercept], false).is_ok());
+        assert!(lstsq(&y, &[x1.clone(), x1], false).is_err());
+    }
+
+    #[test]
+    fn test_polyfit() {
+        let x = Series::new("x".into(), &[-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]);
+        let y = x
+            .f64()
+            .unwrap()
+            .apply_values(|x| 0.5 * x * x - x + 4.0)
+            .into_series();
+
+        let out = polyfit(&y, &x, 2).unwrap();
+        let expected = [4.0, -1.0, 0.5];
+        for (c, e) in coefficients(&out).into_iter().zip(expected) {
+            assert!((c.unwrap() - e).abs() < 1e-10);
+        }
+
+        let out = polyfit(&y.head(Some(2)), &x.head(Some(2)), 2).unwrap();
+        assert_eq!(coefficients(&out), &[None, None, None]);
+    }
+}
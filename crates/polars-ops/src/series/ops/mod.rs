@@ -20,6 +20,8 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "hash")]
+mod hash;
 mod horizontal;
 mod index;
 #[cfg(feature = "index_of")]
@@ -42,6 +44,8 @@ mod is_unique;
 mod linear_space;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-struct")]
+mod lstsq;
 #[cfg(feature = "moment")]
 mod moment;
 mod negate;
@@ -59,8 +63,10 @@ mod rle;
 mod rolling;
 #[cfg(feature = "round_series")]
 pub mod round;
+mod scale;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+mod special;
 mod strings;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
@@ -90,6 +96,8 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "hash")]
+pub use hash::*;
 pub use horizontal::*;
 pub use index::*;
 #[cfg(feature = "index_of")]
@@ -116,6 +124,8 @@ pub use is_unique::*;
 pub use linear_space::*;
 #[cfg(feature = "log")]
 pub use log::*;
+#[cfg(feature = "dtype-struct")]
+pub use lstsq::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
 pub use negate::*;
@@ -135,8 +145,10 @@ pub use rle::*;
 pub use rolling::*;
 #[cfg(feature = "round_series")]
 pub use round::*;
+pub use scale::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+pub use special::*;
 pub use strings::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
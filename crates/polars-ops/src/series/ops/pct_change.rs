@@ -23,3 +23,64 @@ pub fn pct_change(s: &Series, n: &Series) -> PolarsResult<Series> {
         Ok(Series::full_null(s.name().clone(), s.len(), s.dtype()))
     }
 }
+
+/// Computes the percentage change between values like [`pct_change`], replacing the `n` values
+/// that have no predecessor (leading for positive `n`, trailing for negative `n`) by
+/// `fill_value`. Null values within the data are not filled.
+pub fn pct_change_with_fill(s: &Series, n: &Series, fill_value: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        fill_value.len() == 1,
+        ComputeError: "fill value must be a single value."
+    );
+    let out = pct_change(s, n)?;
+    let Some(n) = n.cast(&DataType::Int64)?.i64()?.get(0) else {
+        return Ok(out);
+    };
+
+    let len = out.len();
+    let k = (n.unsigned_abs() as usize).min(len);
+    if k == 0 {
+        return Ok(out);
+    }
+    let fill = fill_value.cast(out.dtype())?.new_from_index(0, k);
+    let mut filled = if n > 0 {
+        let mut filled = fill;
+        filled.append(&out.slice(k as i64, len - k))?;
+        filled
+    } else {
+        let mut filled = out.slice(0, len - k);
+        filled.append(&fill)?;
+        filled
+    };
+    filled.rename(out.name().clone());
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pct_change_with_fill() {
+        let s = Series::new("a".into(), &[Some(1i32), Some(2), None, Some(4)]);
+        let zero = Series::new("".into(), &[0.0f64]);
+
+        let n = Series::new("".into(), &[1i64]);
+        let out = pct_change_with_fill(&s, &n, &zero).unwrap();
+        assert_eq!(out.name().as_str(), "a");
+        let out: Vec<_> = out.f64().unwrap().iter().collect();
+        assert_eq!(out, &[Some(0.0), Some(1.0), None, None]);
+
+        let n = Series::new("".into(), &[-2i64]);
+        let out = pct_change_with_fill(&s, &n, &zero).unwrap();
+        let out: Vec<_> = out.f64().unwrap().iter().collect();
+        assert_eq!(out, &[None, Some(-0.5), Some(0.0), Some(0.0)]);
+
+        let n = Series::new("".into(), &[10i64]);
+        let out = pct_change_with_fill(&s, &n, &zero).unwrap();
+        assert_eq!(
+            out.f64().unwrap().iter().collect::<Vec<_>>(),
+            &[Some(0.0); 4]
+        );
+    }
+}
@@ -193,10 +193,57 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
     }
 }
 
+fn percent_rank(s: &Series, descending: bool) -> Series {
+    let ranks = rank(s, RankMethod::Min, descending, None);
+    let valid = s.len() - s.null_count();
+    let denominator = valid.saturating_sub(1).max(1) as f64;
+    let out: Float64Chunked = ranks
+        .idx()
+        .unwrap()
+        .apply_nonnull_values_generic(DataType::Float64, |r| (r - 1) as f64 / denominator);
+    out.with_name(s.name().clone()).into_series()
+}
+
+fn ntile(s: &Series, n: usize, descending: bool) -> PolarsResult<Series> {
+    polars_ensure!(n > 0, InvalidOperation: "`ntile` expects a positive number of buckets");
+    let ranks = rank(s, RankMethod::Ordinal, descending, None);
+    let valid = (s.len() - s.null_count()) as IdxSize;
+    let n = n as IdxSize;
+
+    // The first `valid % n` buckets get one value more than the others.
+    let size = valid / n;
+    let larger = valid % n;
+    let in_larger = larger * (size + 1);
+    let out: IdxCa = ranks
+        .idx()
+        .unwrap()
+        .apply_nonnull_values_generic(IDX_DTYPE, |r| {
+            let i = r - 1;
+            if i < in_larger {
+                i / (size + 1) + 1
+            } else {
+                larger + (i - in_larger) / size + 1
+            }
+        });
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self.as_series(), options.method, options.descending, seed)
     }
+
+    /// The relative rank `(rank - 1) / (count - 1)` of every value, where ties get the lowest
+    /// rank and nulls are not counted.
+    fn percent_rank(&self, descending: bool) -> Series {
+        percent_rank(self.as_series(), descending)
+    }
+
+    /// Divide the values in rank order into `n` buckets of (nearly) equal size and return the
+    /// 1-based bucket of every value. Earlier buckets get the remainder, as in SQL's `NTILE`.
+    fn ntile(&self, n: usize, descending: bool) -> PolarsResult<Series> {
+        ntile(self.as_series(), n, descending)
+    }
 }
 
 impl SeriesRank for Series {}
@@ -310,6 +357,38 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_percent_rank_and_ntile() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[Some(3), None, Some(1), Some(3), Some(2)]);
+
+        let out = percent_rank(&s, false);
+        let out = out.f64()?.iter().collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(2.0 / 3.0),
+                None,
+                Some(0.0),
+                Some(2.0 / 3.0),
+                Some(1.0 / 3.0)
+            ]
+        );
+
+        let s = Series::new("a".into(), &[Some(4), None, Some(1), Some(3), Some(2)]);
+        let out = ntile(&s, 3, false)?;
+        let out = out.idx()?.iter().collect::<Vec<_>>();
+        assert_eq!(out, &[Some(3), None, Some(1), Some(2), Some(1)]);
+
+        let out = ntile(&s, 10, true)?;
+        let out = out.idx()?.iter().collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1), None, Some(4), Some(2), Some(3)]);
+
+        let single = Series::new("a".into(), &[5]);
+        assert_eq!(percent_rank(&single, false).f64()?.get(0), Some(0.0));
+        assert!(ntile(&s, 0, false).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("".into(), &[None, None, None]).into_series();
@@ -150,26 +150,52 @@ pub fn rle(s: &Column) -> PolarsResult<Column> {
 
 /// Similar to `rle`, but maps values to run IDs.
 pub fn rle_id(s: &Column) -> PolarsResult<Column> {
-    if s.is_empty() {
-        return Ok(Column::new_empty(s.name().clone(), &IDX_DTYPE));
-    }
-
-    let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
-    let s_neq = s1
-        .as_materialized_series()
-        .not_equal_missing(s2.as_materialized_series())?;
+    let mut lengths = Vec::new();
+    rle_lengths(s, &mut lengths)?;
 
+    // Run numbers start at zero.
     let mut out = Vec::<IdxSize>::with_capacity(s.len());
-    let mut last = 0;
-    out.push(last); // Run numbers start at zero
-    assert_eq!(s_neq.null_count(), 0);
-    for a in s_neq.downcast_iter() {
-        for aa in a.values_iter() {
-            last += aa as IdxSize;
-            out.push(last);
-        }
+    for (run_id, length) in lengths.iter().enumerate() {
+        out.extend(std::iter::repeat_n(run_id as IdxSize, *length as usize));
     }
     Ok(IdxCa::from_vec(s.name().clone(), out)
         .with_sorted_flag(IsSorted::Ascending)
         .into_column())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rle_id_with_nulls() {
+        let c = Column::new(
+            "a".into(),
+            &[Some(1i32), Some(1), None, None, Some(2), Some(1)],
+        );
+        let out = rle_id(&c).unwrap();
+        let out = out.idx().unwrap();
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(
+            out.into_no_null_iter().collect::<Vec<_>>(),
+            &[0, 0, 1, 1, 2, 3]
+        );
+
+        let mut lengths = Vec::new();
+        rle_lengths(&c, &mut lengths).unwrap();
+        assert_eq!(lengths, &[2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_rle_id_scalar_and_empty() {
+        let c = Column::new_scalar("a".into(), Scalar::from(1i32), 3);
+        let out = rle_id(&c).unwrap();
+        assert_eq!(
+            out.idx().unwrap().into_no_null_iter().collect::<Vec<_>>(),
+            &[0, 0, 0]
+        );
+
+        let c = Column::new_empty("a".into(), &DataType::Int32);
+        assert_eq!(rle_id(&c).unwrap().len(), 0);
+    }
+}
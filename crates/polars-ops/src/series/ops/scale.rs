@@ -0,0 +1,90 @@
+use num_traits::{Float, NumCast};
+use polars_core::prelude::*;
+
+fn float_dtype(s: &Series, op: &str) -> PolarsResult<DataType> {
+    match s.dtype() {
+        DataType::Float32 => Ok(DataType::Float32),
+        dt if dt.is_primitive_numeric() || dt.is_bool() => Ok(DataType::Float64),
+        dt => polars_bail!(op = op, dt),
+    }
+}
+
+fn standardize_ca<T>(ca: &ChunkedArray<T>, ddof: u8) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    match (ca.mean(), ca.std(ddof)) {
+        (Some(mean), Some(std)) => {
+            let mean: T::Native = NumCast::from(mean).unwrap();
+            let std: T::Native = NumCast::from(std).unwrap();
+            ca.apply_values(|v| (v - mean) / std)
+        },
+        _ => ChunkedArray::full_null(ca.name().clone(), ca.len()),
+    }
+}
+
+fn min_max_scale_ca<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    match (ca.min(), ca.max()) {
+        (Some(min), Some(max)) => {
+            let range = max - min;
+            ca.apply_values(|v| (v - min) / range)
+        },
+        _ => ChunkedArray::full_null(ca.name().clone(), ca.len()),
+    }
+}
+
+/// Compute the z-score `(x - mean) / std` of every value.
+///
+/// The output is null if the standard deviation is undefined for the given `ddof`.
+pub fn standardize(s: &Series, ddof: u8) -> PolarsResult<Series> {
+    let s = s.cast(&float_dtype(s, "`standardize`")?)?;
+    let out = match s.dtype() {
+        DataType::Float32 => standardize_ca(s.f32().unwrap(), ddof).into_series(),
+        _ => standardize_ca(s.f64().unwrap(), ddof).into_series(),
+    };
+    Ok(out)
+}
+
+/// Rescale the values linearly to the range `[0, 1]` using `(x - min) / (max - min)`.
+///
+/// A constant input has no range and results in NaN values.
+pub fn min_max_scale(s: &Series) -> PolarsResult<Series> {
+    let s = s.cast(&float_dtype(s, "`min_max_scale`")?)?;
+    let out = match s.dtype() {
+        DataType::Float32 => min_max_scale_ca(s.f32().unwrap()).into_series(),
+        _ => min_max_scale_ca(s.f64().unwrap()).into_series(),
+    };
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_standardize_and_min_max_scale() {
+        let s = Series::new("a".into(), &[Some(1i32), Some(2), None, Some(3)]);
+
+        let out = standardize(&s, 1).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float64);
+        let out: Vec<_> = out.f64().unwrap().iter().collect();
+        assert_eq!(out, &[Some(-1.0), Some(0.0), None, Some(1.0)]);
+
+        let out = min_max_scale(&s).unwrap();
+        let out: Vec<_> = out.f64().unwrap().iter().collect();
+        assert_eq!(out, &[Some(0.0), Some(0.5), None, Some(1.0)]);
+
+        let single = Series::new("a".into(), &[1.0f32]);
+        let out = standardize(&single, 1).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float32);
+        assert_eq!(out.null_count(), 1);
+
+        let s = Series::new("a".into(), &["x"]);
+        assert!(min_max_scale(&s).is_err());
+    }
+}
@@ -112,3 +112,98 @@ pub fn search_sorted(
         _ => polars_bail!(opq = search_sorted, original_dtype),
     }
 }
+
+/// Find the indices where the rows of `search_values` should be inserted into the rows of `by` to
+/// maintain order, where `by` is sorted lexicographically over its columns.
+///
+/// Every column can be sorted in a different direction given by `descending` and `nulls_last`.
+pub fn search_sorted_multiple(
+    by: &[Column],
+    search_values: &[Column],
+    side: SearchSortedSide,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        !by.is_empty() && by.len() == search_values.len(),
+        ComputeError: "`search_sorted` expected the same number of key and search columns, got {} and {}",
+        by.len(), search_values.len()
+    );
+    polars_ensure!(
+        descending.len() == by.len() && nulls_last.len() == by.len(),
+        ComputeError: "`search_sorted` expected a `descending` and `nulls_last` flag for every key column"
+    );
+    for (s, v) in by.iter().zip(search_values) {
+        polars_ensure!(
+            s.dtype() == v.dtype(),
+            op = "search_sorted",
+            s.dtype(),
+            v.dtype()
+        );
+    }
+
+    let ca = _get_rows_encoded_ca(PlSmallStr::EMPTY, by, descending, nulls_last)?;
+    let search_values =
+        _get_rows_encoded_ca(PlSmallStr::EMPTY, search_values, descending, nulls_last)?;
+    let idx = binary_search_ca(&ca, search_values.iter(), side, false);
+    Ok(IdxCa::new_vec(by[0].name().clone(), idx))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_sorted_multiple() {
+        // Sorted by `a` ascending, then `b` descending.
+        let by = [
+            Column::new("a".into(), &[1, 1, 2, 2, 3]),
+            Column::new("b".into(), &["z", "x", "y", "a", "m"]),
+        ];
+        let search_values = [
+            Column::new("a".into(), &[1, 2, 2, 0, 4]),
+            Column::new("b".into(), &["y", "y", "b", "a", "a"]),
+        ];
+
+        let out = search_sorted_multiple(
+            &by,
+            &search_values,
+            SearchSortedSide::Left,
+            &[false, true],
+            &[false, false],
+        )
+        .unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(2), Some(3), Some(0), Some(5)]
+        );
+
+        let out = search_sorted_multiple(
+            &by,
+            &search_values,
+            SearchSortedSide::Right,
+            &[false, true],
+            &[false, false],
+        )
+        .unwrap();
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(1), Some(3), Some(3), Some(0), Some(5)]
+        );
+
+        assert!(
+            search_sorted_multiple(&by, &by[..1], SearchSortedSide::Left, &[false], &[false])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_search_sorted_descending() {
+        let s = Series::new("a".into(), &[5, 4, 4, 1]);
+        let v = Series::new("a".into(), &[4, 6, 0]);
+        let out = search_sorted(&s, &v, SearchSortedSide::Left, true).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(1), Some(0), Some(4)]);
+        let out = search_sorted(&s, &v, SearchSortedSide::Right, true).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(3), Some(0), Some(4)]);
+    }
+}
@@ -0,0 +1,70 @@
+use polars_core::prelude::*;
+
+fn apply_float(
+    s: &Series,
+    op: &str,
+    f32_fn: fn(f32) -> f32,
+    f64_fn: fn(f64) -> f64,
+) -> PolarsResult<Series> {
+    let out = match s.dtype() {
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let s = s.cast(&DataType::Float32)?;
+            let out = s.f32().unwrap().apply_values(f32_fn).into_series();
+            out.cast(&DataType::Float16)?
+        },
+        DataType::Float32 => s.f32().unwrap().apply_values(f32_fn).into_series(),
+        DataType::Float64 => s.f64().unwrap().apply_values(f64_fn).into_series(),
+        dt if dt.is_primitive_numeric() || dt.is_bool() || dt.is_decimal() => {
+            let s = s.cast(&DataType::Float64)?;
+            s.f64().unwrap().apply_values(f64_fn).into_series()
+        },
+        dt => polars_bail!(op = op, dt),
+    };
+    Ok(out)
+}
+
+/// Compute the gamma function of every value.
+pub fn gamma(s: &Series) -> PolarsResult<Series> {
+    apply_float(s, "`gamma`", libm::tgammaf, libm::tgamma)
+}
+
+/// Compute the natural logarithm of the absolute value of the gamma function of every value.
+pub fn lgamma(s: &Series) -> PolarsResult<Series> {
+    apply_float(s, "`lgamma`", libm::lgammaf, libm::lgamma)
+}
+
+/// Compute the Gauss error function of every value.
+pub fn erf(s: &Series) -> PolarsResult<Series> {
+    apply_float(s, "`erf`", libm::erff, libm::erf)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_special_functions() {
+        let s = Series::new("a".into(), &[Some(1i32), Some(5), None]);
+
+        let out = gamma(&s).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float64);
+        let out: Vec<_> = out.f64().unwrap().iter().collect();
+        assert_eq!(out[2], None);
+        assert!((out[0].unwrap() - 1.0).abs() < 1e-12);
+        assert!((out[1].unwrap() - 24.0).abs() < 1e-9);
+
+        let out = lgamma(&s).unwrap();
+        let out = out.f64().unwrap();
+        assert!((out.get(1).unwrap() - 24.0f64.ln()).abs() < 1e-12);
+
+        let s = Series::new("a".into(), &[0.0f32, 10.0]);
+        let out = erf(&s).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float32);
+        let out: Vec<_> = out.f32().unwrap().iter().collect();
+        assert_eq!(out, &[Some(0.0), Some(1.0)]);
+
+        let s = Series::new("a".into(), &["x"]);
+        assert!(erf(&s).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use arrow::bitmap::MutableBitmap;
 use polars_utils::format_pl_smallstr;
 
 use super::*;
@@ -6,11 +7,34 @@ use super::*;
 type DummyType = u8;
 #[cfg(feature = "dtype-u8")]
 type DummyCa = UInt8Chunked;
+#[cfg(feature = "dtype-u8")]
+const DUMMY_DTYPE: DataType = DataType::UInt8;
 
 #[cfg(not(feature = "dtype-u8"))]
 type DummyType = i32;
 #[cfg(not(feature = "dtype-u8"))]
 type DummyCa = Int32Chunked;
+#[cfg(not(feature = "dtype-u8"))]
+const DUMMY_DTYPE: DataType = DataType::Int32;
+
+/// The dtype of the indicator columns created by [`ToDummies::to_dummies`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DummyDtype {
+    /// `1` and `0`, as `UInt8` (or `Int32` without the `dtype-u8` feature).
+    #[default]
+    Integer,
+    /// `true` and `false`.
+    Boolean,
+}
+
+impl DummyDtype {
+    pub fn dtype(self) -> DataType {
+        match self {
+            Self::Integer => DUMMY_DTYPE,
+            Self::Boolean => DataType::Boolean,
+        }
+    }
+}
 
 pub trait ToDummies {
     fn to_dummies(
@@ -18,6 +42,7 @@ pub trait ToDummies {
         separator: Option<&str>,
         drop_first: bool,
         drop_nulls: bool,
+        dtype: DummyDtype,
     ) -> PolarsResult<DataFrame>;
 }
 
@@ -27,6 +52,7 @@ impl ToDummies for Series {
         separator: Option<&str>,
         drop_first: bool,
         drop_nulls: bool,
+        dtype: DummyDtype,
     ) -> PolarsResult<DataFrame> {
         let sep = separator.unwrap_or("_");
         let col_name = self.name();
@@ -50,13 +76,15 @@ impl ToDummies for Series {
                     return None;
                 }
 
-                let ca = match group {
-                    GroupsIndicator::Idx((_, group)) => dummies_helper_idx(group, self.len(), name),
+                let column = match group {
+                    GroupsIndicator::Idx((_, group)) => {
+                        dummies_helper(group.iter().copied(), self.len(), name, dtype)
+                    },
                     GroupsIndicator::Slice([offset, len]) => {
-                        dummies_helper_slice(offset, len, self.len(), name)
+                        dummies_helper(offset..(offset + len), self.len(), name, dtype)
                     },
                 };
-                Some(ca.into_column())
+                Some(column)
             })
             .collect::<Vec<_>>();
 
@@ -64,31 +92,29 @@ impl ToDummies for Series {
     }
 }
 
-fn dummies_helper_idx(groups: &[IdxSize], len: usize, name: PlSmallStr) -> DummyCa {
-    let mut av = vec![0 as DummyType; len];
-
-    for &idx in groups {
-        let elem = unsafe { av.get_unchecked_mut(idx as usize) };
-        *elem = 1;
-    }
-
-    ChunkedArray::from_vec(name, av)
-}
-
-fn dummies_helper_slice(
-    group_offset: IdxSize,
-    group_len: IdxSize,
+fn dummies_helper(
+    group: impl Iterator<Item = IdxSize>,
     len: usize,
     name: PlSmallStr,
-) -> DummyCa {
-    let mut av = vec![0 as DummyType; len];
-
-    for idx in group_offset..(group_offset + group_len) {
-        let elem = unsafe { av.get_unchecked_mut(idx as usize) };
-        *elem = 1;
+    dtype: DummyDtype,
+) -> Column {
+    match dtype {
+        DummyDtype::Integer => {
+            let mut av = vec![0 as DummyType; len];
+            for idx in group {
+                let elem = unsafe { av.get_unchecked_mut(idx as usize) };
+                *elem = 1;
+            }
+            DummyCa::from_vec(name, av).into_column()
+        },
+        DummyDtype::Boolean => {
+            let mut bitmap = MutableBitmap::from_len_zeroed(len);
+            for idx in group {
+                unsafe { bitmap.set_unchecked(idx as usize, true) };
+            }
+            BooleanChunked::from_bitmap(name, bitmap.freeze()).into_column()
+        },
     }
-
-    ChunkedArray::from_vec(name, av)
 }
 
 fn sort_columns(mut columns: Vec<Column>) -> Vec<Column> {
@@ -66,6 +66,27 @@ pub trait SeriesMethods: SeriesSealed {
         UInt64Chunked::from_vec(s.name().clone(), h)
     }
 
+    /// Compute the histogram of this [`Series`].
+    ///
+    /// Either `bin_count` equal-width bins are derived from the data, or the given `bins` are
+    /// used as bin edges. If neither is given, 10 equal-width bins are used.
+    #[cfg(feature = "hist")]
+    fn hist(
+        &self,
+        bin_count: Option<usize>,
+        bins: Option<Series>,
+        include_category: bool,
+        include_breakpoint: bool,
+    ) -> PolarsResult<Series> {
+        crate::chunked_array::hist_series(
+            self.as_series(),
+            bin_count,
+            bins,
+            include_category,
+            include_breakpoint,
+        )
+    }
+
     fn ensure_sorted_arg(&self, operation: &str) -> PolarsResult<()> {
         polars_ensure!(self.is_sorted(Default::default())?, InvalidOperation: "argument in operation '{}' is not sorted, please sort the 'expr/series/column' first", operation);
         Ok(())
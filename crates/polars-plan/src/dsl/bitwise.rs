@@ -31,6 +31,21 @@ impl Expr {
         self.map_unary(FunctionExpr::Bitwise(BitwiseFunction::TrailingZeros))
     }
 
+    /// Flip every bit of an integer value, or negate a boolean.
+    pub fn bitwise_not(self) -> Self {
+        self.map_unary(FunctionExpr::Bitwise(BitwiseFunction::Not))
+    }
+
+    /// Shift the bits to the left by `n`, filling with zeros.
+    pub fn bitwise_shift_left<E: Into<Expr>>(self, n: E) -> Self {
+        self.map_binary(FunctionExpr::Bitwise(BitwiseFunction::ShiftLeft), n.into())
+    }
+
+    /// Shift the bits to the right by `n`, keeping the sign of signed integers.
+    pub fn bitwise_shift_right<E: Into<Expr>>(self, n: E) -> Self {
+        self.map_binary(FunctionExpr::Bitwise(BitwiseFunction::ShiftRight), n.into())
+    }
+
     /// Perform an aggregation of bitwise ANDs
     pub fn bitwise_and(self) -> Self {
         self.map_unary(FunctionExpr::Bitwise(BitwiseFunction::And))
@@ -0,0 +1,234 @@
+//! Column selectors.
+//!
+//! Constructors for [`Selector`]s, mirroring the `polars.selectors` module of the Python API.
+//! Selectors can be combined with the set operators `|` (union), `&` (intersection), `-`
+//! (difference), `^` (symmetric difference) and `!` (complement), and can be used anywhere an
+//! [`Expr`] is accepted through [`Selector::as_expr`] or `.into()`.
+//!
+//! ```ignore
+//! use polars::prelude::*;
+//!
+//! // All numeric columns, except for the ones whose name starts with `id`.
+//! lf.select([(cs::numeric() - cs::starts_with("id")).as_expr()])
+//! ```
+use super::*;
+
+/// Escape all characters with a special meaning in a regular expression.
+fn escape_regex(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\.+*?()|[]{}^$#&-~".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Select all columns.
+pub fn all() -> Selector {
+    Selector::Wildcard
+}
+
+/// Select no columns.
+pub fn empty() -> Selector {
+    Selector::Empty
+}
+
+/// Select columns by name, in the given order.
+///
+/// If `strict`, selecting a column that does not exist is an error.
+pub fn by_name<S: Into<PlSmallStr>, I: IntoIterator<Item = S>>(names: I, strict: bool) -> Selector {
+    Selector::ByName {
+        names: names.into_iter().map(Into::into).collect(),
+        strict,
+    }
+}
+
+/// Select columns by index, in the given order. Negative indices count from the end.
+///
+/// If `strict`, selecting a column that does not exist is an error.
+pub fn by_index<N: AsRef<[i64]>>(indices: N, strict: bool) -> Selector {
+    Selector::ByIndex {
+        indices: indices.as_ref().into(),
+        strict,
+    }
+}
+
+/// Select the first column.
+pub fn first() -> Selector {
+    by_index([0], true)
+}
+
+/// Select the last column.
+pub fn last() -> Selector {
+    by_index([-1], true)
+}
+
+/// Select the columns whose name matches the regular expression `pattern`.
+///
+/// The pattern is not anchored, use `^` and `$` to match the full name.
+pub fn matches(pattern: impl Into<PlSmallStr>) -> Selector {
+    Selector::Matches(pattern.into())
+}
+
+/// Select the columns whose name starts with `prefix`.
+pub fn starts_with(prefix: &str) -> Selector {
+    matches(format!("^{}", escape_regex(prefix)))
+}
+
+/// Select the columns whose name ends with `suffix`.
+pub fn ends_with(suffix: &str) -> Selector {
+    matches(format!("{}$", escape_regex(suffix)))
+}
+
+/// Select the columns whose name contains `substring`.
+pub fn contains(substring: &str) -> Selector {
+    matches(escape_regex(substring))
+}
+
+/// Select the columns of any of the given dtypes.
+pub fn by_dtype<DT: AsRef<[DataType]>>(dtypes: DT) -> Selector {
+    DataTypeSelector::AnyOf(dtypes.as_ref().into()).as_selector()
+}
+
+/// Select all numeric columns: integers, floats and decimals.
+pub fn numeric() -> Selector {
+    DataTypeSelector::Numeric.as_selector()
+}
+
+/// Select all integer columns.
+pub fn integer() -> Selector {
+    DataTypeSelector::Integer.as_selector()
+}
+
+/// Select all signed integer columns.
+pub fn signed_integer() -> Selector {
+    DataTypeSelector::SignedInteger.as_selector()
+}
+
+/// Select all unsigned integer columns.
+pub fn unsigned_integer() -> Selector {
+    DataTypeSelector::UnsignedInteger.as_selector()
+}
+
+/// Select all float columns.
+pub fn float() -> Selector {
+    DataTypeSelector::Float.as_selector()
+}
+
+/// Select all decimal columns.
+pub fn decimal() -> Selector {
+    DataTypeSelector::Decimal.as_selector()
+}
+
+/// Select all boolean columns.
+pub fn boolean() -> Selector {
+    by_dtype([DataType::Boolean])
+}
+
+/// Select all string columns.
+pub fn string() -> Selector {
+    by_dtype([DataType::String])
+}
+
+/// Select all binary columns.
+pub fn binary() -> Selector {
+    by_dtype([DataType::Binary])
+}
+
+/// Select all temporal columns: dates, datetimes, durations and times.
+pub fn temporal() -> Selector {
+    DataTypeSelector::Temporal.as_selector()
+}
+
+/// Select all date columns.
+#[cfg(feature = "dtype-date")]
+pub fn date() -> Selector {
+    by_dtype([DataType::Date])
+}
+
+/// Select all time columns.
+#[cfg(feature = "dtype-time")]
+pub fn time() -> Selector {
+    by_dtype([DataType::Time])
+}
+
+/// Select all datetime columns, regardless of their time unit and time zone.
+#[cfg(feature = "dtype-datetime")]
+pub fn datetime() -> Selector {
+    DataTypeSelector::Datetime(TimeUnitSet::all(), TimeZoneSet::Any).as_selector()
+}
+
+/// Select all duration columns, regardless of their time unit.
+#[cfg(feature = "dtype-duration")]
+pub fn duration() -> Selector {
+    DataTypeSelector::Duration(TimeUnitSet::all()).as_selector()
+}
+
+/// Select all categorical columns.
+pub fn categorical() -> Selector {
+    DataTypeSelector::Categorical.as_selector()
+}
+
+/// Select all enum columns.
+pub fn enum_() -> Selector {
+    DataTypeSelector::Enum.as_selector()
+}
+
+/// Select all nested columns: lists, arrays and structs.
+pub fn nested() -> Selector {
+    DataTypeSelector::Nested.as_selector()
+}
+
+/// Select all list columns.
+pub fn list() -> Selector {
+    DataTypeSelector::List(None).as_selector()
+}
+
+/// Select all array columns.
+pub fn array() -> Selector {
+    DataTypeSelector::Array(None, None).as_selector()
+}
+
+/// Select all struct columns.
+pub fn struct_() -> Selector {
+    DataTypeSelector::Struct.as_selector()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select(selector: Selector, schema: &Schema) -> Vec<String> {
+        selector
+            .into_columns(schema, &Default::default())
+            .unwrap()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_selectors() {
+        let schema = Schema::from_iter([
+            Field::new("id".into(), DataType::UInt32),
+            Field::new("a.x".into(), DataType::Float64),
+            Field::new("ax".into(), DataType::String),
+            Field::new("b".into(), DataType::Int8),
+            Field::new("c".into(), DataType::Boolean),
+        ]);
+
+        assert_eq!(select(numeric(), &schema), ["id", "a.x", "b"]);
+        assert_eq!(select(numeric() - starts_with("i"), &schema), ["a.x", "b"]);
+        assert_eq!(select(starts_with("a."), &schema), ["a.x"]);
+        assert_eq!(select(contains("x") & !float(), &schema), ["ax"]);
+        assert_eq!(select(signed_integer() | boolean(), &schema), ["b", "c"]);
+        assert_eq!(
+            select(by_dtype([DataType::String]) ^ matches("^a"), &schema),
+            ["a.x"]
+        );
+        assert_eq!(select(first() | last(), &schema), ["id", "c"]);
+        assert!(select(empty(), &schema).is_empty());
+    }
+}
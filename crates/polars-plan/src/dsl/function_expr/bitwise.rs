@@ -16,6 +16,13 @@ pub enum BitwiseFunction {
     TrailingOnes,
     TrailingZeros,
 
+    /// Element-wise bitwise negation.
+    Not,
+    /// Element-wise left shift by the second input.
+    ShiftLeft,
+    /// Element-wise arithmetic right shift by the second input.
+    ShiftRight,
+
     // Bitwise Aggregations
     And,
     Or,
@@ -33,6 +40,9 @@ impl fmt::Display for BitwiseFunction {
             B::LeadingZeros => "leading_zeros",
             B::TrailingOnes => "trailing_ones",
             B::TrailingZeros => "trailing_zeros",
+            B::Not => "not",
+            B::ShiftLeft => "shift_left",
+            B::ShiftRight => "shift_right",
 
             B::And => "and",
             B::Or => "or",
@@ -112,6 +112,11 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "row_hash")]
+    HashWithAlgorithm {
+        algorithm: HashAlgorithm,
+        seed: u64,
+    },
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -136,6 +141,11 @@ pub enum FunctionExpr {
         function: RollingFunction,
         options: RollingOptionsFixedWindow,
     },
+    #[cfg(feature = "rolling_window")]
+    RollingFrame {
+        agg: FrameAggregation,
+        frame: WindowFrame,
+    },
     #[cfg(feature = "rolling_window_by")]
     RollingExprBy {
         function_by: RollingFunctionBy,
@@ -174,6 +184,15 @@ pub enum FunctionExpr {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    PercentRank {
+        descending: bool,
+    },
+    #[cfg(feature = "rank")]
+    NTile {
+        n: usize,
+        descending: bool,
+    },
     Repeat,
     #[cfg(feature = "round_series")]
     Clip {
@@ -195,6 +214,10 @@ pub enum FunctionExpr {
         reverse: bool,
     },
     #[cfg(feature = "cum_agg")]
+    CumNUnique {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
     CumSum {
         reverse: bool,
     },
@@ -222,13 +245,19 @@ pub enum FunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "quantile_approx")]
+    QuantileApprox {
+        quantile: f64,
+        compression: usize,
+    },
     Coalesce,
+    OrElse,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
     #[cfg(feature = "pct_change")]
     PctChange,
     #[cfg(feature = "interpolate")]
-    Interpolate(InterpolationMethod),
+    Interpolate(InterpolateOptions),
     #[cfg(feature = "interpolate_by")]
     InterpolateBy,
     #[cfg(feature = "log")]
@@ -242,6 +271,11 @@ pub enum FunctionExpr {
     Log1p,
     #[cfg(feature = "log")]
     Exp,
+    #[cfg(feature = "log")]
+    Expm1,
+    Gamma,
+    LGamma,
+    Erf,
     Unique(bool),
     #[cfg(feature = "round_series")]
     Round {
@@ -263,10 +297,30 @@ pub enum FunctionExpr {
     Correlation {
         method: correlation::CorrelationMethod,
     },
+    #[cfg(feature = "dtype-struct")]
+    Lstsq {
+        fit_intercept: bool,
+    },
+    #[cfg(feature = "dtype-struct")]
+    PolyFit {
+        degree: usize,
+    },
+    Standardize {
+        ddof: u8,
+    },
+    MinMaxScale,
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
     PeakMax,
+    #[cfg(feature = "peaks")]
+    LocalMin {
+        window: usize,
+    },
+    #[cfg(feature = "peaks")]
+    LocalMax {
+        window: usize,
+    },
     #[cfg(feature = "cutqcut")]
     Cut {
         breaks: Vec<f64>,
@@ -275,6 +329,11 @@ pub enum FunctionExpr {
         include_breaks: bool,
     },
     #[cfg(feature = "cutqcut")]
+    Digitize {
+        breaks: Vec<f64>,
+        left_closed: bool,
+    },
+    #[cfg(feature = "cutqcut")]
     QCut {
         probs: Vec<f64>,
         labels: Option<Vec<PlSmallStr>>,
@@ -409,9 +468,16 @@ impl Hash for FunctionExpr {
                 descending.hash(state);
             },
             #[cfg(feature = "random")]
-            Random { method, .. } => method.hash(state),
+            Random { method, seed } => {
+                method.hash(state);
+                seed.hash(state);
+            },
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "dtype-struct")]
+            Lstsq { fit_intercept } => fit_intercept.hash(state),
+            #[cfg(feature = "dtype-struct")]
+            PolyFit { degree } => degree.hash(state),
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
@@ -499,12 +565,19 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            HashWithAlgorithm { algorithm, seed } => (algorithm, seed).hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
                 function.hash(state);
                 options.hash(state);
             },
+            #[cfg(feature = "rolling_window")]
+            RollingFrame { agg, frame } => {
+                agg.hash(state);
+                frame.hash(state);
+            },
             #[cfg(feature = "rolling_window_by")]
             RollingExprBy {
                 function_by,
@@ -526,6 +599,13 @@ impl Hash for FunctionExpr {
                 options.hash(state);
                 seed.hash(state);
             },
+            #[cfg(feature = "rank")]
+            PercentRank { descending } => descending.hash(state),
+            #[cfg(feature = "rank")]
+            NTile { n, descending } => {
+                n.hash(state);
+                descending.hash(state);
+            },
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 has_min.hash(state);
@@ -536,6 +616,8 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
+            CumNUnique { reverse } => reverse.hash(state),
+            #[cfg(feature = "cum_agg")]
             CumSum { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
             CumProd { reverse } => reverse.hash(state),
@@ -559,7 +641,16 @@ impl Hash for FunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "quantile_approx")]
+            QuantileApprox {
+                quantile,
+                compression,
+            } => {
+                quantile.to_bits().hash(state);
+                compression.hash(state);
+            },
             Coalesce => {},
+            OrElse => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
             #[cfg(feature = "log")]
@@ -573,6 +664,9 @@ impl Hash for FunctionExpr {
             Log1p => {},
             #[cfg(feature = "log")]
             Exp => {},
+            #[cfg(feature = "log")]
+            Expm1 => {},
+            Gamma | LGamma | Erf => {},
             Unique(a) => a.hash(state),
             #[cfg(feature = "round_series")]
             Round { decimals, mode } => {
@@ -588,10 +682,14 @@ impl Hash for FunctionExpr {
             UpperBound => {},
             LowerBound => {},
             ConcatExpr(a) => a.hash(state),
+            Standardize { ddof } => ddof.hash(state),
+            MinMaxScale => {},
             #[cfg(feature = "peaks")]
             PeakMin => {},
             #[cfg(feature = "peaks")]
             PeakMax => {},
+            #[cfg(feature = "peaks")]
+            LocalMin { window } | LocalMax { window } => window.hash(state),
             #[cfg(feature = "cutqcut")]
             Cut {
                 breaks,
@@ -610,6 +708,15 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "repeat_by")]
             RepeatBy => {},
             #[cfg(feature = "cutqcut")]
+            Digitize {
+                breaks,
+                left_closed,
+            } => {
+                let slice = bytemuck::cast_slice::<_, u64>(breaks);
+                slice.hash(state);
+                left_closed.hash(state);
+            },
+            #[cfg(feature = "cutqcut")]
             QCut {
                 probs,
                 labels,
@@ -703,6 +810,8 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            HashWithAlgorithm { .. } => "hash",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -720,6 +829,8 @@ impl Display for FunctionExpr {
             FillNull => "fill_null",
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, .. } => return write!(f, "{function}"),
+            #[cfg(feature = "rolling_window")]
+            RollingFrame { agg, .. } => return write!(f, "rolling_frame_{}", agg.as_str()),
             #[cfg(feature = "rolling_window_by")]
             RollingExprBy { function_by, .. } => return write!(f, "{function_by}"),
             Rechunk => "rechunk",
@@ -747,6 +858,10 @@ impl Display for FunctionExpr {
             Repeat => "repeat",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            PercentRank { .. } => "percent_rank",
+            #[cfg(feature = "rank")]
+            NTile { .. } => "ntile",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -770,6 +885,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => "cum_count",
             #[cfg(feature = "cum_agg")]
+            CumNUnique { .. } => "cum_n_unique",
+            #[cfg(feature = "cum_agg")]
             CumSum { .. } => "cum_sum",
             #[cfg(feature = "cum_agg")]
             CumProd { .. } => "cum_prod",
@@ -784,7 +901,10 @@ impl Display for FunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "quantile_approx")]
+            QuantileApprox { .. } => "quantile_approx",
             Coalesce => "coalesce",
+            OrElse => "or_else",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
             #[cfg(feature = "pct_change")]
@@ -801,6 +921,11 @@ impl Display for FunctionExpr {
             Log1p => "log1p",
             #[cfg(feature = "log")]
             Exp => "exp",
+            #[cfg(feature = "log")]
+            Expm1 => "expm1",
+            Gamma => "gamma",
+            LGamma => "lgamma",
+            Erf => "erf",
             Unique(stable) => {
                 if *stable {
                     "unique_stable"
@@ -821,13 +946,25 @@ impl Display for FunctionExpr {
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "dtype-struct")]
+            Lstsq { .. } => "lstsq",
+            #[cfg(feature = "dtype-struct")]
+            PolyFit { .. } => "polyfit",
+            Standardize { .. } => "standardize",
+            MinMaxScale => "min_max_scale",
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
             PeakMax => "peak_max",
+            #[cfg(feature = "peaks")]
+            LocalMin { .. } => "local_min",
+            #[cfg(feature = "peaks")]
+            LocalMax { .. } => "local_max",
             #[cfg(feature = "cutqcut")]
             Cut { .. } => "cut",
             #[cfg(feature = "cutqcut")]
+            Digitize { .. } => "digitize",
+            #[cfg(feature = "cutqcut")]
             QCut { .. } => "qcut",
             #[cfg(feature = "dtype-array")]
             Reshape(_) => "reshape",
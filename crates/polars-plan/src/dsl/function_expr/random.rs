@@ -15,10 +15,45 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    /// Sample `n` values from a uniform distribution on `[low, high)`.
+    Uniform {
+        low: f64,
+        high: f64,
+    },
+    /// Sample `n` values from a normal distribution.
+    Normal {
+        mean: f64,
+        std: f64,
+    },
+    /// Sample `n` booleans that are `true` with probability `p`.
+    Bernoulli {
+        p: f64,
+    },
 }
 
 impl Hash for RandomMethod {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        std::mem::discriminant(self).hash(state)
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Shuffle => {},
+            Self::Sample {
+                is_fraction,
+                with_replacement,
+                shuffle,
+            } => {
+                is_fraction.hash(state);
+                with_replacement.hash(state);
+                shuffle.hash(state);
+            },
+            Self::Uniform { low, high } => {
+                low.to_bits().hash(state);
+                high.to_bits().hash(state);
+            },
+            Self::Normal { mean, std } => {
+                mean.to_bits().hash(state);
+                std.to_bits().hash(state);
+            },
+            Self::Bernoulli { p } => p.to_bits().hash(state),
+        }
     }
 }
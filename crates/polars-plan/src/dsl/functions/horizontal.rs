@@ -106,6 +106,9 @@ where
 
 /// Create a new column with the bitwise-and of the elements in each row.
 ///
+/// Nulls follow Kleene logic: a row is `false` if any value is `false`, otherwise it is null
+/// if any value is null.
+///
 /// The name of the resulting column will be "all"; use [`alias`](Expr::alias) to choose a different name.
 pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
@@ -119,6 +122,9 @@ pub fn all_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 
 /// Create a new column with the bitwise-or of the elements in each row.
 ///
+/// Nulls follow Kleene logic: a row is `true` if any value is `true`, otherwise it is null
+/// if any value is null.
+///
 /// The name of the resulting column will be "any"; use [`alias`](Expr::alias) to choose a different name.
 pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
@@ -130,7 +136,7 @@ pub fn any_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     ))
 }
 
-/// Create a new column with the maximum value per row.
+/// Create a new column with the maximum value per row. Null values are ignored.
 ///
 /// The name of the resulting column will be `"max"`; use [`alias`](Expr::alias) to choose a different name.
 pub fn max_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
@@ -139,7 +145,7 @@ pub fn max_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
     Ok(Expr::n_ary(FunctionExpr::MaxHorizontal, exprs))
 }
 
-/// Create a new column with the minimum value per row.
+/// Create a new column with the minimum value per row. Null values are ignored.
 ///
 /// The name of the resulting column will be `"min"`; use [`alias`](Expr::alias) to choose a different name.
 pub fn min_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
@@ -149,6 +155,8 @@ pub fn min_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
 }
 
 /// Sum all values horizontally across columns.
+///
+/// If `ignore_nulls` is `false`, any null value in a row makes the sum of that row null.
 pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
@@ -159,6 +167,8 @@ pub fn sum_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsR
 }
 
 /// Compute the mean of all values horizontally across columns.
+///
+/// If `ignore_nulls` is `false`, any null value in a row makes the mean of that row null.
 pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
     let exprs = exprs.as_ref().to_vec();
     polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
@@ -19,3 +19,9 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
     condition.into().map_unary(FunctionExpr::ArgWhere)
 }
+
+#[cfg(feature = "arg_where")]
+/// Get the first index where `condition` evaluates `true`, or null if it never does.
+pub fn arg_where_first<E: Into<Expr>>(condition: E) -> Expr {
+    arg_where(condition).first()
+}
@@ -0,0 +1,18 @@
+use super::*;
+
+/// Ordinary least squares fit of `y` on the regressors `x`.
+///
+/// This is an aggregation returning a struct with a `Float64` coefficient per regressor, named
+/// after it and preceded by an `intercept` field if `fit_intercept` is set. Rows with a null in
+/// any input are skipped; the coefficients are null if the fit is underdetermined.
+pub fn lstsq<E: AsRef<[Expr]>>(y: Expr, x: E, fit_intercept: bool) -> Expr {
+    y.map_n_ary(FunctionExpr::Lstsq { fit_intercept }, x.as_ref().to_vec())
+}
+
+/// Least squares fit of a polynomial of the given degree through the points `(x, y)`.
+///
+/// This is an aggregation returning a struct with the coefficients `c0` (the constant term) up to
+/// `c{degree}`.
+pub fn polyfit(x: Expr, y: Expr, degree: usize) -> Expr {
+    y.map_binary(FunctionExpr::PolyFit { degree }, x)
+}
@@ -11,6 +11,10 @@ mod correlation;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "dtype-struct")]
+mod lstsq;
+#[cfg(feature = "ffi_plugin")]
+mod plugin;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -30,6 +34,10 @@ pub use correlation::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+#[cfg(feature = "dtype-struct")]
+pub use lstsq::*;
+#[cfg(feature = "ffi_plugin")]
+pub use plugin::*;
 #[cfg(all(
     feature = "range",
     any(feature = "dtype-date", feature = "dtype-datetime")
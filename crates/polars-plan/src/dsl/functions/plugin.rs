@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use super::*;
+
+/// Create an expression that calls the function `symbol` of the expression plugin at `lib`.
+///
+/// `lib` is the path to a shared library following the `polars-ffi` plugin ABI. It is loaded
+/// once and never unloaded. The library has to export `_polars_plugin_get_version`, the
+/// kernel `_polars_plugin_{symbol}` and the schema function `_polars_plugin_field_{symbol}`.
+/// `kwargs` are passed verbatim to both functions.
+///
+/// `options` describe how the function behaves (e.g. whether it is elementwise) and must match
+/// what the kernel does, as the optimizer relies on them.
+///
+/// # Safety
+/// The library is trusted to implement the ABI correctly. Calling into it runs arbitrary code.
+pub unsafe fn register_plugin_function<E: AsRef<[Expr]>>(
+    lib: impl Into<PlSmallStr>,
+    symbol: impl Into<PlSmallStr>,
+    args: E,
+    kwargs: impl Into<Arc<[u8]>>,
+    options: FunctionOptions,
+) -> Expr {
+    Expr::Function {
+        input: args.as_ref().to_vec(),
+        function: FunctionExpr::FfiPlugin {
+            flags: options,
+            lib: lib.into(),
+            symbol: symbol.into(),
+            kwargs: kwargs.into(),
+        },
+    }
+}
@@ -29,3 +29,16 @@ pub struct MatchToSchemaPerColumn {
     pub integer_cast: UpcastOrForbid,
     pub float_cast: UpcastOrForbid,
 }
+
+impl Default for MatchToSchemaPerColumn {
+    /// Raise on any mismatch.
+    fn default() -> Self {
+        Self {
+            missing_columns: MissingColumnsPolicyOrExpr::Raise,
+            missing_struct_fields: MissingColumnsPolicy::Raise,
+            extra_struct_fields: ExtraColumnsPolicy::Raise,
+            integer_cast: UpcastOrForbid::Forbid,
+            float_cast: UpcastOrForbid::Forbid,
+        }
+    }
+}
@@ -16,6 +16,7 @@ pub mod binary;
 mod bitwise;
 mod builder_dsl;
 pub use builder_dsl::*;
+pub mod cs;
 mod datatype_expr;
 #[cfg(feature = "temporal")]
 pub mod dt;
@@ -76,12 +77,15 @@ use polars_core::series::IsSorted;
 use polars_core::series::ops::NullBehavior;
 #[cfg(feature = "is_close")]
 use polars_utils::total_ord::TotalOrdWrap;
+#[cfg(feature = "random")]
+pub use random::{rand_bernoulli, rand_normal, rand_uniform};
 pub use selector::{DataTypeSelector, Selector, TimeUnitSet, TimeZoneSet};
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
 mod file_scan;
 pub use file_scan::*;
+pub(crate) use scan_sources::read_from_file_provider;
 pub use scan_sources::{ScanSource, ScanSourceIter, ScanSourceRef, ScanSources};
 
 pub use crate::plans::lit;
@@ -646,10 +650,33 @@ impl Expr {
     }
 
     /// Shift the values in the array by some period and fill the resulting empty values.
+    ///
+    /// The fill value can be a scalar, such as `col("a").first()`, or an expression with a value
+    /// for every row.
     pub fn shift_and_fill<E: Into<Expr>, IE: Into<Expr>>(self, n: E, fill_value: IE) -> Self {
         self.map_ternary(FunctionExpr::ShiftAndFill, n.into(), fill_value.into())
     }
 
+    /// Get the value `n` rows before the current row, like SQL's `LAG`.
+    ///
+    /// Rows without such a value are filled with `default`, or null if it is not given.
+    pub fn lag(self, n: i64, default: Option<Expr>) -> Self {
+        match default {
+            Some(default) => self.shift_and_fill(lit(n), default),
+            None => self.shift(lit(n)),
+        }
+    }
+
+    /// Get the value `n` rows after the current row, like SQL's `LEAD`.
+    ///
+    /// Rows without such a value are filled with `default`, or null if it is not given.
+    pub fn lead(self, n: i64, default: Option<Expr>) -> PolarsResult<Self> {
+        let n = n
+            .checked_neg()
+            .ok_or_else(|| polars_err!(InvalidOperation: "`lead` by {} overflows", n))?;
+        Ok(self.lag(n, default))
+    }
+
     /// Cumulatively count values from 0 to len.
     #[cfg(feature = "cum_agg")]
     pub fn cumulative_eval(self, evaluation: Expr, min_samples: usize) -> Self {
@@ -666,6 +693,12 @@ impl Expr {
         self.map_unary(FunctionExpr::CumCount { reverse })
     }
 
+    /// Get the number of distinct values seen up to and including every element.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_n_unique(self, reverse: bool) -> Self {
+        self.map_unary(FunctionExpr::CumNUnique { reverse })
+    }
+
     /// Get an array with the cumulative sum computed at every element.
     #[cfg(feature = "cum_agg")]
     pub fn cum_sum(self, reverse: bool) -> Self {
@@ -823,6 +856,37 @@ impl Expr {
             .expect("We explicitly passed `partition_by`")
     }
 
+    /// Apply a window function over `partition_by`, evaluating every partition in the order given
+    /// by `order_by` instead of the order of the rows.
+    ///
+    /// The results are mapped back to the original rows, so the frame does not have to be sorted
+    /// beforehand.
+    pub fn over_ordered<P, O, IP, IO>(
+        self,
+        partition_by: P,
+        order_by: O,
+        options: SortOptions,
+    ) -> Self
+    where
+        P: AsRef<[IP]>,
+        O: AsRef<[IO]>,
+        IP: Into<Expr> + Clone,
+        IO: Into<Expr> + Clone,
+    {
+        let into_exprs = |e: &[IO]| e.iter().map(|e| e.clone().into()).collect::<Vec<Expr>>();
+        let partition_by = partition_by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        self.over_with_options(
+            Some(partition_by),
+            Some((into_exprs(order_by.as_ref()), options)),
+            Default::default(),
+        )
+        .expect("We explicitly passed `partition_by`")
+    }
+
     pub fn over_with_options<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
         self,
         partition_by: Option<E>,
@@ -887,10 +951,30 @@ impl Expr {
         self.fill_null_impl(fill_value.into())
     }
 
+    /// Evaluate `fallback` if evaluating this expression fails with a compute error, e.g. a
+    /// strict cast or parse of invalid data. Pass `lit(NULL)` to yield nulls instead.
+    ///
+    /// The error is caught per evaluated batch (or per `group_by` context), not per row.
+    pub fn or_else<E: Into<Expr>>(self, fallback: E) -> Self {
+        self.map_binary(FunctionExpr::OrElse, fallback.into())
+    }
+
     pub fn fill_null_with_strategy(self, strategy: FillNullStrategy) -> Self {
         self.map_unary(FunctionExpr::FillNullWithStrategy(strategy))
     }
 
+    /// Fill null values with the previous non-null value, filling at most `limit` consecutive
+    /// nulls. Within `over()` the fill does not cross group boundaries.
+    pub fn forward_fill(self, limit: FillNullLimit) -> Self {
+        self.fill_null_with_strategy(FillNullStrategy::Forward(limit))
+    }
+
+    /// Fill null values with the next non-null value, filling at most `limit` consecutive
+    /// nulls. Within `over()` the fill does not cross group boundaries.
+    pub fn backward_fill(self, limit: FillNullLimit) -> Self {
+        self.fill_null_with_strategy(FillNullStrategy::Backward(limit))
+    }
+
     /// Replace the floating point `NaN` values by a value.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> Self {
         // we take the not branch so that self is truthy value of `when -> then -> otherwise`
@@ -969,6 +1053,19 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Get an approximate quantile using a t-digest with bounded memory.
+    ///
+    /// A higher `compression` (precision) gives more accurate estimates at the cost of memory;
+    /// [`DEFAULT_COMPRESSION`](polars_compute::tdigest::DEFAULT_COMPRESSION) is a good default.
+    /// As digests can be merged, this is also supported in group-bys and the streaming engine.
+    #[cfg(feature = "quantile_approx")]
+    pub fn quantile_approx(self, quantile: f64, compression: usize) -> Self {
+        self.map_unary(FunctionExpr::QuantileApprox {
+            quantile,
+            compression,
+        })
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1097,7 +1194,14 @@ impl Expr {
     /// Interpolate intermediate values.
     /// Nulls at the beginning and end of the series remain null.
     pub fn interpolate(self, method: InterpolationMethod) -> Expr {
-        self.map_unary(FunctionExpr::Interpolate(method))
+        self.map_unary(FunctionExpr::Interpolate(method.into()))
+    }
+
+    #[cfg(feature = "interpolate")]
+    /// Interpolate intermediate values, filling at most `options.limit` consecutive nulls per gap.
+    /// Nulls at the beginning and end of the series remain null.
+    pub fn interpolate_with_options(self, options: InterpolateOptions) -> Expr {
+        self.map_unary(FunctionExpr::Interpolate(options))
     }
 
     #[cfg(feature = "rolling_window_by")]
@@ -1138,6 +1242,15 @@ impl Expr {
         })
     }
 
+    /// Compute `agg` over a frame of rows around every row, like the SQL frame clause
+    /// `ROWS BETWEEN <start> AND <end>`.
+    ///
+    /// Combine this with [`Expr::over`] to compute the frames within every partition.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_frame(self, agg: FrameAggregation, frame: WindowFrame) -> Expr {
+        self.map_unary(FunctionExpr::RollingFrame { agg, frame })
+    }
+
     /// Apply a rolling minimum based on another column.
     #[cfg(feature = "rolling_window_by")]
     pub fn rolling_min_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
@@ -1305,6 +1418,21 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Map(f))
     }
 
+    /// Standardize the values to their z-score `(x - mean) / std`.
+    ///
+    /// The input is evaluated once and, within `over()` or a group-by aggregation, the
+    /// statistics are computed per group.
+    pub fn standardize(self, ddof: u8) -> Expr {
+        self.map_unary(FunctionExpr::Standardize { ddof })
+    }
+
+    /// Rescale the values linearly to the range `[0, 1]` using `(x - min) / (max - min)`.
+    ///
+    /// Within `over()` or a group-by aggregation, the minimum and maximum are taken per group.
+    pub fn min_max_scale(self) -> Expr {
+        self.map_unary(FunctionExpr::MinMaxScale)
+    }
+
     #[cfg(feature = "peaks")]
     pub fn peak_min(self) -> Expr {
         self.map_unary(FunctionExpr::PeakMin)
@@ -1315,12 +1443,41 @@ impl Expr {
         self.map_unary(FunctionExpr::PeakMax)
     }
 
+    /// Get a boolean mask of the values that are strictly smaller than every non-null value
+    /// within `window` rows before and after them.
+    #[cfg(feature = "peaks")]
+    pub fn local_min(self, window: usize) -> Expr {
+        self.map_unary(FunctionExpr::LocalMin { window })
+    }
+
+    /// Get a boolean mask of the values that are strictly greater than every non-null value
+    /// within `window` rows before and after them.
+    #[cfg(feature = "peaks")]
+    pub fn local_max(self, window: usize) -> Expr {
+        self.map_unary(FunctionExpr::LocalMax { window })
+    }
+
     #[cfg(feature = "rank")]
     /// Assign ranks to data, dealing with ties appropriately.
     pub fn rank(self, options: RankOptions, seed: Option<u64>) -> Expr {
         self.map_unary(FunctionExpr::Rank { options, seed })
     }
 
+    #[cfg(feature = "rank")]
+    /// Get the relative rank `(rank - 1) / (count - 1)` of every value, in `[0, 1]`.
+    ///
+    /// Ties get the lowest rank and nulls are not counted, as in SQL's `PERCENT_RANK`.
+    pub fn percent_rank(self, descending: bool) -> Expr {
+        self.map_unary(FunctionExpr::PercentRank { descending })
+    }
+
+    #[cfg(feature = "rank")]
+    /// Divide the values in rank order into `n` buckets of (nearly) equal size and get the 1-based
+    /// bucket of every value, as in SQL's `NTILE`.
+    pub fn ntile(self, n: usize, descending: bool) -> Expr {
+        self.map_unary(FunctionExpr::NTile { n, descending })
+    }
+
     #[cfg(feature = "replace")]
     /// Replace the given values with other values.
     pub fn replace<E: Into<Expr>>(self, old: E, new: E) -> Expr {
@@ -1367,6 +1524,17 @@ impl Expr {
         })
     }
 
+    #[cfg(feature = "cutqcut")]
+    /// Map continuous values to the index of their bucket given strictly increasing breaks.
+    ///
+    /// The index equals the position of the category [`Expr::cut`] assigns with the same breaks.
+    pub fn digitize(self, breaks: Vec<f64>, left_closed: bool) -> Expr {
+        self.map_unary(FunctionExpr::Digitize {
+            breaks,
+            left_closed,
+        })
+    }
+
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories based on their quantiles.
     pub fn qcut(
@@ -1424,12 +1592,26 @@ impl Expr {
         self.map_binary(FunctionExpr::Diff(null_behavior), n)
     }
 
+    #[cfg(feature = "diff")]
+    /// Calculate the `order`-th repeated difference with lag `n`, e.g. `order = 2` computes
+    /// `diff(diff(x))`. An `order` of zero returns the expression unchanged.
+    pub fn diff_order(self, n: Expr, order: usize, null_behavior: NullBehavior) -> Expr {
+        (0..order).fold(self, |expr, _| expr.diff(n.clone(), null_behavior))
+    }
+
     #[cfg(feature = "pct_change")]
     /// Computes percentage change between values.
     pub fn pct_change(self, n: Expr) -> Expr {
         self.map_binary(FunctionExpr::PctChange, n)
     }
 
+    #[cfg(feature = "pct_change")]
+    /// Computes percentage change between values, filling the `n` values without a predecessor
+    /// with `fill_value` instead of null.
+    pub fn pct_change_with_fill(self, n: Expr, fill_value: Expr) -> Expr {
+        self.map_ternary(FunctionExpr::PctChange, n, fill_value)
+    }
+
     #[cfg(feature = "moment")]
     /// Compute the sample skewness of a data set.
     ///
@@ -1560,6 +1742,27 @@ impl Expr {
         self.map_unary(FunctionExpr::Exp)
     }
 
+    #[cfg(feature = "log")]
+    /// Calculate `exp(x) - 1` of all elements in the input array, accurate for small `x`.
+    pub fn expm1(self) -> Self {
+        self.map_unary(FunctionExpr::Expm1)
+    }
+
+    /// Compute the gamma function of all elements in the input array.
+    pub fn gamma(self) -> Self {
+        self.map_unary(FunctionExpr::Gamma)
+    }
+
+    /// Compute the natural logarithm of the absolute value of the gamma function.
+    pub fn lgamma(self) -> Self {
+        self.map_unary(FunctionExpr::LGamma)
+    }
+
+    /// Compute the Gauss error function of all elements in the input array.
+    pub fn erf(self) -> Self {
+        self.map_unary(FunctionExpr::Erf)
+    }
+
     #[cfg(feature = "log")]
     /// Compute the entropy as `-sum(pk * log(pk))`.
     /// where `pk` are discrete probabilities.
@@ -1587,6 +1790,15 @@ impl Expr {
         self.map_unary(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "row_hash")]
+    /// Compute the hash of every element with a stable, well-known hash algorithm.
+    ///
+    /// Unlike [`Expr::hash`], the output is guaranteed not to change between Polars versions and
+    /// can be reproduced by other systems implementing the same algorithm. Nulls hash to null.
+    pub fn hash_with_algorithm(self, seed: u64, algorithm: HashAlgorithm) -> Expr {
+        self.map_unary(FunctionExpr::HashWithAlgorithm { algorithm, seed })
+    }
+
     pub fn to_physical(self) -> Expr {
         self.map_unary(FunctionExpr::ToPhysical)
     }
@@ -1690,6 +1902,32 @@ where
     }
 }
 
+/// Apply a function/closure over multiple columns that returns a column of `output_dtype`.
+///
+/// This is [`map_multiple`] with the output type declared up front, so the schema is known
+/// when the plan is resolved. The output is named after the first input, and it is an error
+/// if the function returns a column of any other dtype.
+pub fn map_multiple_with_dtype<F, E>(function: F, expr: E, output_dtype: DataType) -> Expr
+where
+    F: Fn(&mut [Column]) -> PolarsResult<Column> + 'static + Send + Sync,
+    E: AsRef<[Expr]>,
+{
+    let expected = output_dtype.clone();
+    let function = move |c: &mut [Column]| {
+        let out = function(c)?;
+        polars_ensure!(
+            out.dtype() == &expected,
+            SchemaMismatch: "`map_multiple` function returned dtype {}, expected {}",
+            out.dtype(), expected
+        );
+        Ok(out)
+    };
+    map_multiple(function, expr, move |_, fields| {
+        let name = fields.first().map(|f| f.name().clone()).unwrap_or_default();
+        Ok(Field::new(name, output_dtype.clone()))
+    })
+}
+
 /// Apply a function/closure over the groups of multiple columns. This should only be used in a group_by aggregation.
 ///
 /// It is the responsibility of the caller that the schema is correct by giving
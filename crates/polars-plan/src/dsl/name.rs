@@ -25,6 +25,19 @@ impl ExprNameNameSpace {
     }
 
     /// Define an alias by mapping a function over the original root column name.
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_plan::prelude::*;
+    /// // Named `A` and `B`.
+    /// let sums = cols(["a", "b"])
+    ///     .as_expr()
+    ///     .sum()
+    ///     .name()
+    ///     .map(PlanCallback::new(|name: PlSmallStr| {
+    ///         Ok(name.to_uppercase().into())
+    ///     }));
+    /// ```
     pub fn map(self, function: PlanCallback<PlSmallStr, PlSmallStr>) -> Expr {
         Expr::RenameAlias {
             expr: Arc::new(self.0),
@@ -32,7 +45,7 @@ impl ExprNameNameSpace {
         }
     }
 
-    /// Add a prefix to the root column name.
+    /// Add a prefix to the output name of the expression.
     pub fn prefix(self, prefix: &str) -> Expr {
         Expr::RenameAlias {
             expr: Arc::new(self.0),
@@ -40,7 +53,11 @@ impl ExprNameNameSpace {
         }
     }
 
-    /// Add a suffix to the root column name.
+    /// Add a suffix to the output name of the expression.
+    ///
+    /// Aliases are respected, and the name is kept through aggregations and window functions,
+    /// so e.g. `col("a").sum().over([col("g")]).name().suffix("_sum")` is named `a_sum`, and
+    /// `col("a").alias("b").sum().name().suffix("_sum")` is named `b_sum`.
     pub fn suffix(self, suffix: &str) -> Expr {
         Expr::RenameAlias {
             expr: Arc::new(self.0),
@@ -48,3 +48,28 @@ impl Expr {
         )
     }
 }
+
+fn rand(n: Expr, method: RandomMethod, seed: Option<u64>) -> Expr {
+    n.map_unary(FunctionExpr::Random { method, seed })
+}
+
+/// Generate `n` samples from a uniform distribution on `[low, high)`.
+///
+/// `n` must evaluate to a single non-negative integer, e.g. [`len`] to get a value per row.
+pub fn rand_uniform(n: Expr, low: f64, high: f64, seed: Option<u64>) -> Expr {
+    rand(n, RandomMethod::Uniform { low, high }, seed)
+}
+
+/// Generate `n` samples from a normal distribution with the given mean and standard deviation.
+///
+/// `n` must evaluate to a single non-negative integer, e.g. [`len`] to get a value per row.
+pub fn rand_normal(n: Expr, mean: f64, std: f64, seed: Option<u64>) -> Expr {
+    rand(n, RandomMethod::Normal { mean, std }, seed)
+}
+
+/// Generate `n` booleans that are `true` with probability `p`.
+///
+/// `n` must evaluate to a single non-negative integer, e.g. [`len`] to get a value per row.
+pub fn rand_bernoulli(n: Expr, p: f64, seed: Option<u64>) -> Expr {
+    rand(n, RandomMethod::Bernoulli { p }, seed)
+}
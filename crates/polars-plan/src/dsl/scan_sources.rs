@@ -375,6 +375,9 @@ impl ScanSourceRef<'_> {
     ) -> PolarsResult<MemSlice> {
         match self {
             ScanSourceRef::Path(path) => {
+                if let Some(out) = read_from_file_provider(*path) {
+                    return out;
+                }
                 let file = if run_async {
                     open_cache_entry(polars_io::file_cache::FILE_CACHE.get_entry(*path).unwrap())?
                 } else {
@@ -403,6 +406,9 @@ impl ScanSourceRef<'_> {
     fn to_memslice_async(&self, run_async: bool) -> PolarsResult<MemSlice> {
         match self {
             ScanSourceRef::Path(path) => {
+                if let Some(out) = read_from_file_provider(*path) {
+                    return out;
+                }
                 let file = polars_utils::open_file(path.as_local_path().unwrap())?;
                 MemSlice::from_file(&file)
             },
@@ -432,6 +438,9 @@ impl ScanSourceRef<'_> {
     ) -> PolarsResult<MemSlice> {
         match self {
             Self::Path(path) => {
+                if let Some(out) = read_from_file_provider(*path) {
+                    return out;
+                }
                 let file = if run_async {
                     feature_gated!("cloud", {
                         cache_entries.unwrap()[index].try_open_check_latest()?
@@ -454,7 +463,10 @@ impl ScanSourceRef<'_> {
         cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<DynByteSource> {
         match self {
-            Self::Path(path) => builder.try_build_from_path(*path, cloud_options).await,
+            Self::Path(path) => match read_from_file_provider(*path) {
+                Some(out) => Ok(DynByteSource::from(out?)),
+                None => builder.try_build_from_path(*path, cloud_options).await,
+            },
             Self::File(file) => Ok(DynByteSource::from(MemSlice::from_file(file)?)),
             Self::Buffer(buff) => Ok(DynByteSource::from((*buff).clone())),
         }
@@ -465,6 +477,23 @@ impl ScanSourceRef<'_> {
     }
 }
 
+/// Read a local path through the registered `FileProvider` of the `file_provider` feature, if it
+/// serves that path.
+pub(crate) fn read_from_file_provider(path: PlPathRef<'_>) -> Option<PolarsResult<MemSlice>> {
+    #[cfg(feature = "file_provider")]
+    {
+        if path.is_cloud_url() {
+            return None;
+        }
+        polars_io::file_provider::read_from_file_provider(path.to_str())
+    }
+    #[cfg(not(feature = "file_provider"))]
+    {
+        _ = path;
+        None
+    }
+}
+
 impl<'a> Iterator for ScanSourceIter<'a> {
     type Item = ScanSourceRef<'a>;
 
@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 
+use crate::plans::optimizer::CoercionPolicy;
+
 bitflags! {
 #[derive(Copy, Clone, Debug)]
     /// Allowed optimizations.
@@ -27,6 +29,8 @@ bitflags! {
 
         // const STREAMING = 1 << 10; // Legacy flag for removed old streaming engine.
 
+        /// Run the query on the streaming engine. In the optimizations of a map function, this
+        /// marks the function as streamable, i.e. it can be applied to batches of rows.
         const NEW_STREAMING = 1 << 11;
         /// Run every node eagerly. This turns off multi-node optimizations.
         const EAGER = 1 << 12;
@@ -37,6 +41,10 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 15;
+        /// Raise instead of implicitly casting integers to floats during type coercion.
+        const FORBID_INT_TO_FLOAT_COERCION = 1 << 16;
+        /// Raise on comparisons between strings and numbers during type coercion.
+        const FORBID_STRING_NUMERIC_COMPARISON = 1 << 17;
     }
 }
 
@@ -72,11 +80,32 @@ impl OptFlags {
     pub fn fast_projection(&self) -> bool {
         self.contains(OptFlags::FAST_PROJECTION)
     }
+    pub fn coercion_policy(&self) -> CoercionPolicy {
+        CoercionPolicy {
+            forbid_int_to_float: self.contains(OptFlags::FORBID_INT_TO_FLOAT_COERCION),
+            forbid_string_numeric_comparison: self
+                .contains(OptFlags::FORBID_STRING_NUMERIC_COMPARISON),
+        }
+    }
+    pub fn set_coercion_policy(&mut self, policy: CoercionPolicy) {
+        self.set(
+            OptFlags::FORBID_INT_TO_FLOAT_COERCION,
+            policy.forbid_int_to_float,
+        );
+        self.set(
+            OptFlags::FORBID_STRING_NUMERIC_COMPARISON,
+            policy.forbid_string_numeric_comparison,
+        );
+    }
 }
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::FORBID_INT_TO_FLOAT_COERCION
+            & !Self::FORBID_STRING_NUMERIC_COMPARISON
     }
 }
 
@@ -19,6 +19,13 @@ pub enum IRBitwiseFunction {
     TrailingOnes,
     TrailingZeros,
 
+    /// Element-wise bitwise negation.
+    Not,
+    /// Element-wise left shift by the second input.
+    ShiftLeft,
+    /// Element-wise arithmetic right shift by the second input.
+    ShiftRight,
+
     // Bitwise Aggregations
     And,
     Or,
@@ -40,6 +47,10 @@ impl IRBitwiseFunction {
                 Self::LeadingZeros |
                 Self::TrailingOnes |
                 Self::TrailingZeros => Ok(DataType::UInt32),
+                Self::ShiftLeft | Self::ShiftRight if !dtype.is_integer() => {
+                    polars_bail!(InvalidOperation: "dtype {} not supported in '{}' operation", dtype, self)
+                },
+                Self::Not | Self::ShiftLeft | Self::ShiftRight => Ok(dtype.clone()),
                 Self::And |
                 Self::Or |
                 Self::Xor => Ok(dtype.clone()),
@@ -55,7 +66,10 @@ impl IRBitwiseFunction {
             | B::LeadingOnes
             | B::LeadingZeros
             | B::TrailingOnes
-            | B::TrailingZeros => FunctionOptions::elementwise(),
+            | B::TrailingZeros
+            | B::Not
+            | B::ShiftLeft
+            | B::ShiftRight => FunctionOptions::elementwise(),
             B::And | B::Or | B::Xor => FunctionOptions::aggregation()
                 .with_flags(|f| f | FunctionFlags::NON_ORDER_OBSERVING),
         }
@@ -73,6 +87,9 @@ impl fmt::Display for IRBitwiseFunction {
             B::LeadingZeros => "leading_zeros",
             B::TrailingOnes => "trailing_ones",
             B::TrailingZeros => "trailing_zeros",
+            B::Not => "not",
+            B::ShiftLeft => "shift_left",
+            B::ShiftRight => "shift_right",
 
             B::And => "and",
             B::Or => "or",
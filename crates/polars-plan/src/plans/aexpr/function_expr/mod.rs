@@ -126,6 +126,11 @@ pub enum IRFunctionExpr {
     Pow(IRPowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "row_hash")]
+    HashWithAlgorithm {
+        algorithm: HashAlgorithm,
+        seed: u64,
+    },
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -150,6 +155,11 @@ pub enum IRFunctionExpr {
         function: IRRollingFunction,
         options: RollingOptionsFixedWindow,
     },
+    #[cfg(feature = "rolling_window")]
+    RollingFrame {
+        agg: FrameAggregation,
+        frame: WindowFrame,
+    },
     #[cfg(feature = "rolling_window_by")]
     RollingExprBy {
         function_by: IRRollingFunctionBy,
@@ -188,6 +198,15 @@ pub enum IRFunctionExpr {
         options: RankOptions,
         seed: Option<u64>,
     },
+    #[cfg(feature = "rank")]
+    PercentRank {
+        descending: bool,
+    },
+    #[cfg(feature = "rank")]
+    NTile {
+        n: usize,
+        descending: bool,
+    },
     Repeat,
     #[cfg(feature = "round_series")]
     Clip {
@@ -209,6 +228,10 @@ pub enum IRFunctionExpr {
         reverse: bool,
     },
     #[cfg(feature = "cum_agg")]
+    CumNUnique {
+        reverse: bool,
+    },
+    #[cfg(feature = "cum_agg")]
     CumSum {
         reverse: bool,
     },
@@ -236,13 +259,19 @@ pub enum IRFunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "quantile_approx")]
+    QuantileApprox {
+        quantile: f64,
+        compression: usize,
+    },
     Coalesce,
+    OrElse,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
     #[cfg(feature = "pct_change")]
     PctChange,
     #[cfg(feature = "interpolate")]
-    Interpolate(InterpolationMethod),
+    Interpolate(InterpolateOptions),
     #[cfg(feature = "interpolate_by")]
     InterpolateBy,
     #[cfg(feature = "log")]
@@ -256,6 +285,11 @@ pub enum IRFunctionExpr {
     Log1p,
     #[cfg(feature = "log")]
     Exp,
+    #[cfg(feature = "log")]
+    Expm1,
+    Gamma,
+    LGamma,
+    Erf,
     Unique(bool),
     #[cfg(feature = "round_series")]
     Round {
@@ -277,10 +311,30 @@ pub enum IRFunctionExpr {
     Correlation {
         method: correlation::IRCorrelationMethod,
     },
+    #[cfg(feature = "dtype-struct")]
+    Lstsq {
+        fit_intercept: bool,
+    },
+    #[cfg(feature = "dtype-struct")]
+    PolyFit {
+        degree: usize,
+    },
+    Standardize {
+        ddof: u8,
+    },
+    MinMaxScale,
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
     PeakMax,
+    #[cfg(feature = "peaks")]
+    LocalMin {
+        window: usize,
+    },
+    #[cfg(feature = "peaks")]
+    LocalMax {
+        window: usize,
+    },
     #[cfg(feature = "cutqcut")]
     Cut {
         breaks: Vec<f64>,
@@ -289,6 +343,11 @@ pub enum IRFunctionExpr {
         include_breaks: bool,
     },
     #[cfg(feature = "cutqcut")]
+    Digitize {
+        breaks: Vec<f64>,
+        left_closed: bool,
+    },
+    #[cfg(feature = "cutqcut")]
     QCut {
         probs: Vec<f64>,
         labels: Option<Vec<PlSmallStr>>,
@@ -423,9 +482,16 @@ impl Hash for IRFunctionExpr {
                 descending.hash(state);
             },
             #[cfg(feature = "random")]
-            Random { method, .. } => method.hash(state),
+            Random { method, seed } => {
+                method.hash(state);
+                seed.hash(state);
+            },
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "dtype-struct")]
+            Lstsq { fit_intercept } => fit_intercept.hash(state),
+            #[cfg(feature = "dtype-struct")]
+            PolyFit { degree } => degree.hash(state),
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
@@ -520,12 +586,19 @@ impl Hash for IRFunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            HashWithAlgorithm { algorithm, seed } => (algorithm, seed).hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
                 function.hash(state);
                 options.hash(state);
             },
+            #[cfg(feature = "rolling_window")]
+            RollingFrame { agg, frame } => {
+                agg.hash(state);
+                frame.hash(state);
+            },
             #[cfg(feature = "rolling_window_by")]
             RollingExprBy {
                 function_by,
@@ -547,6 +620,13 @@ impl Hash for IRFunctionExpr {
                 options.hash(state);
                 seed.hash(state);
             },
+            #[cfg(feature = "rank")]
+            PercentRank { descending } => descending.hash(state),
+            #[cfg(feature = "rank")]
+            NTile { n, descending } => {
+                n.hash(state);
+                descending.hash(state);
+            },
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 has_min.hash(state);
@@ -557,6 +637,8 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
+            CumNUnique { reverse } => reverse.hash(state),
+            #[cfg(feature = "cum_agg")]
             CumSum { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
             CumProd { reverse } => reverse.hash(state),
@@ -580,7 +662,16 @@ impl Hash for IRFunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "quantile_approx")]
+            QuantileApprox {
+                quantile,
+                compression,
+            } => {
+                quantile.to_bits().hash(state);
+                compression.hash(state);
+            },
             Coalesce => {},
+            OrElse => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
             #[cfg(feature = "log")]
@@ -594,6 +685,9 @@ impl Hash for IRFunctionExpr {
             Log1p => {},
             #[cfg(feature = "log")]
             Exp => {},
+            #[cfg(feature = "log")]
+            Expm1 => {},
+            Gamma | LGamma | Erf => {},
             Unique(a) => a.hash(state),
             #[cfg(feature = "round_series")]
             Round { decimals, mode } => {
@@ -607,10 +701,14 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "round_series")]
             Ceil => {},
             ConcatExpr(a) => a.hash(state),
+            Standardize { ddof } => ddof.hash(state),
+            MinMaxScale => {},
             #[cfg(feature = "peaks")]
             PeakMin => {},
             #[cfg(feature = "peaks")]
             PeakMax => {},
+            #[cfg(feature = "peaks")]
+            LocalMin { window } | LocalMax { window } => window.hash(state),
             #[cfg(feature = "cutqcut")]
             Cut {
                 breaks,
@@ -629,6 +727,15 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "repeat_by")]
             RepeatBy => {},
             #[cfg(feature = "cutqcut")]
+            Digitize {
+                breaks,
+                left_closed,
+            } => {
+                let slice = bytemuck::cast_slice::<_, u64>(breaks);
+                slice.hash(state);
+                left_closed.hash(state);
+            },
+            #[cfg(feature = "cutqcut")]
             QCut {
                 probs,
                 labels,
@@ -725,6 +832,8 @@ impl Display for IRFunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            HashWithAlgorithm { .. } => "hash",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -742,6 +851,8 @@ impl Display for IRFunctionExpr {
             FillNull => "fill_null",
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, .. } => return write!(f, "{function}"),
+            #[cfg(feature = "rolling_window")]
+            RollingFrame { agg, .. } => return write!(f, "rolling_frame_{}", agg.as_str()),
             #[cfg(feature = "rolling_window_by")]
             RollingExprBy { function_by, .. } => return write!(f, "{function_by}"),
             Rechunk => "rechunk",
@@ -769,6 +880,10 @@ impl Display for IRFunctionExpr {
             Repeat => "repeat",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
+            #[cfg(feature = "rank")]
+            PercentRank { .. } => "percent_rank",
+            #[cfg(feature = "rank")]
+            NTile { .. } => "ntile",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -792,6 +907,8 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => "cum_count",
             #[cfg(feature = "cum_agg")]
+            CumNUnique { .. } => "cum_n_unique",
+            #[cfg(feature = "cum_agg")]
             CumSum { .. } => "cum_sum",
             #[cfg(feature = "cum_agg")]
             CumProd { .. } => "cum_prod",
@@ -806,7 +923,10 @@ impl Display for IRFunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "quantile_approx")]
+            QuantileApprox { .. } => "quantile_approx",
             Coalesce => "coalesce",
+            OrElse => "or_else",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
             #[cfg(feature = "pct_change")]
@@ -823,6 +943,11 @@ impl Display for IRFunctionExpr {
             Log1p => "log1p",
             #[cfg(feature = "log")]
             Exp => "exp",
+            #[cfg(feature = "log")]
+            Expm1 => "expm1",
+            Gamma => "gamma",
+            LGamma => "lgamma",
+            Erf => "erf",
             Unique(stable) => {
                 if *stable {
                     "unique_stable"
@@ -843,13 +968,25 @@ impl Display for IRFunctionExpr {
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "dtype-struct")]
+            Lstsq { .. } => "lstsq",
+            #[cfg(feature = "dtype-struct")]
+            PolyFit { .. } => "polyfit",
+            Standardize { .. } => "standardize",
+            MinMaxScale => "min_max_scale",
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
             PeakMax => "peak_max",
+            #[cfg(feature = "peaks")]
+            LocalMin { .. } => "local_min",
+            #[cfg(feature = "peaks")]
+            LocalMax { .. } => "local_max",
             #[cfg(feature = "cutqcut")]
             Cut { .. } => "cut",
             #[cfg(feature = "cutqcut")]
+            Digitize { .. } => "digitize",
+            #[cfg(feature = "cutqcut")]
             QCut { .. } => "qcut",
             #[cfg(feature = "dtype-array")]
             Reshape(_) => "reshape",
@@ -1021,6 +1158,8 @@ impl IRFunctionExpr {
             F::NullCount => FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING),
             #[cfg(feature = "row_hash")]
             F::Hash(_, _, _, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "row_hash")]
+            F::HashWithAlgorithm { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "arg_where")]
             F::ArgWhere => FunctionOptions::groupwise(),
             #[cfg(feature = "index_of")]
@@ -1044,6 +1183,8 @@ impl IRFunctionExpr {
             F::FillNullWithStrategy(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "rolling_window")]
             F::RollingExpr { .. } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "rolling_window")]
+            F::RollingFrame { .. } => FunctionOptions::length_preserving(),
             #[cfg(feature = "rolling_window_by")]
             F::RollingExprBy { .. } => FunctionOptions::length_preserving(),
             F::Rechunk => FunctionOptions::length_preserving(),
@@ -1086,7 +1227,9 @@ impl IRFunctionExpr {
             F::ArgSort { .. } => FunctionOptions::length_preserving(),
             F::Product => FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING),
             #[cfg(feature = "rank")]
-            F::Rank { .. } => FunctionOptions::length_preserving(),
+            F::Rank { .. } | F::PercentRank { .. } | F::NTile { .. } => {
+                FunctionOptions::length_preserving()
+            },
             F::Repeat => {
                 FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
             },
@@ -1102,6 +1245,7 @@ impl IRFunctionExpr {
             F::TopKBy { .. } => FunctionOptions::groupwise(),
             #[cfg(feature = "cum_agg")]
             F::CumCount { .. }
+            | F::CumNUnique { .. }
             | F::CumSum { .. }
             | F::CumProd { .. }
             | F::CumMin { .. }
@@ -1121,9 +1265,14 @@ impl IRFunctionExpr {
             F::ApproxNUnique => {
                 FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
             },
+            #[cfg(feature = "quantile_approx")]
+            F::QuantileApprox { .. } => {
+                FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
+            },
             F::Coalesce => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION)
                 .with_supertyping(Default::default()),
+            F::OrElse => FunctionOptions::elementwise().with_supertyping(Default::default()),
             #[cfg(feature = "diff")]
             F::Diff(NullBehavior::Drop) => FunctionOptions::groupwise(),
             #[cfg(feature = "diff")]
@@ -1135,7 +1284,8 @@ impl IRFunctionExpr {
             #[cfg(feature = "interpolate_by")]
             F::InterpolateBy => FunctionOptions::length_preserving(),
             #[cfg(feature = "log")]
-            F::Log | F::Log1p | F::Exp => FunctionOptions::elementwise(),
+            F::Log | F::Log1p | F::Exp | F::Expm1 => FunctionOptions::elementwise(),
+            F::Gamma | F::LGamma | F::Erf => FunctionOptions::elementwise(),
             #[cfg(feature = "log")]
             F::Entropy { .. } => {
                 FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
@@ -1162,11 +1312,20 @@ impl IRFunctionExpr {
             F::Correlation { .. } => {
                 FunctionOptions::aggregation().with_supertyping(Default::default())
             },
+            #[cfg(feature = "dtype-struct")]
+            F::Lstsq { .. } | F::PolyFit { .. } => {
+                FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
+            },
+            F::Standardize { .. } | F::MinMaxScale => FunctionOptions::length_preserving(),
             #[cfg(feature = "peaks")]
             F::PeakMin | F::PeakMax => FunctionOptions::length_preserving(),
+            #[cfg(feature = "peaks")]
+            F::LocalMin { .. } | F::LocalMax { .. } => FunctionOptions::length_preserving(),
             #[cfg(feature = "cutqcut")]
             F::Cut { .. } | F::QCut { .. } => FunctionOptions::length_preserving()
                 .with_flags(|f| f | FunctionFlags::PASS_NAME_TO_APPLY),
+            #[cfg(feature = "cutqcut")]
+            F::Digitize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "rle")]
             F::RLE => FunctionOptions::groupwise(),
             #[cfg(feature = "rle")]
@@ -1182,6 +1341,14 @@ impl IRFunctionExpr {
                 method: IRRandomMethod::Shuffle,
                 ..
             } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "random")]
+            F::Random {
+                method:
+                    IRRandomMethod::Uniform { .. }
+                    | IRRandomMethod::Normal { .. }
+                    | IRRandomMethod::Bernoulli { .. },
+                ..
+            } => FunctionOptions::row_separable().with_flags(|f| f | FunctionFlags::ALLOW_RENAME),
             F::SetSortedFlag(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "ffi_plugin")]
             F::FfiPlugin { flags, .. } => *flags,
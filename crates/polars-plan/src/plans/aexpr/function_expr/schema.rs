@@ -42,9 +42,11 @@ impl IRFunctionExpr {
                 IRPowFunction::Generic => mapper.pow_dtype(),
                 _ => mapper.map_numeric_to_float_dtype(true),
             },
-            Coalesce => mapper.map_to_supertype(),
+            Coalesce | OrElse => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "row_hash")]
+            HashWithAlgorithm { .. } => mapper.with_dtype(DataType::UInt64),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "index_of")]
@@ -97,6 +99,12 @@ impl IRFunctionExpr {
                     }),
                 }
             },
+            #[cfg(feature = "rolling_window")]
+            RollingFrame { agg, .. } => match agg {
+                FrameAggregation::Min | FrameAggregation::Max => mapper.with_same_dtype(),
+                FrameAggregation::Mean => mapper.moment_dtype(),
+                FrameAggregation::Sum => mapper.sum_dtype(),
+            },
             #[cfg(feature = "rolling_window_by")]
             RollingExprBy {
                 function_by,
@@ -161,6 +169,10 @@ impl IRFunctionExpr {
                 RankMethod::Average => DataType::Float64,
                 _ => IDX_DTYPE,
             }),
+            #[cfg(feature = "rank")]
+            PercentRank { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "rank")]
+            NTile { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "dtype-struct")]
             AsStruct => {
                 let mut field_names = PlHashSet::with_capacity(fields.len() - 1);
@@ -204,7 +216,7 @@ impl IRFunctionExpr {
             UniqueCounts => mapper.with_dtype(IDX_DTYPE),
             Shift | Reverse => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
-            CumCount { .. } => mapper.with_dtype(IDX_DTYPE),
+            CumCount { .. } | CumNUnique { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "cum_agg")]
             CumSum { .. } => mapper.map_dtype(cum::dtypes::cum_sum),
             #[cfg(feature = "cum_agg")]
@@ -215,6 +227,8 @@ impl IRFunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "quantile_approx")]
+            QuantileApprox { .. } => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -262,14 +276,17 @@ impl IRFunctionExpr {
                 _ => DataType::Float64,
             }),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => match method {
-                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(false),
+            Interpolate(options) => match options.method {
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
+                InterpolationMethod::Linear
+                | InterpolationMethod::Spline
+                | InterpolationMethod::Polynomial(_) => mapper.map_numeric_to_float_dtype(false),
             },
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => mapper.map_numeric_to_float_dtype(true),
             #[cfg(feature = "log")]
-            Entropy { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
+            Entropy { .. } | Log1p | Exp | Expm1 => mapper.map_to_float_dtype(),
+            Gamma | LGamma | Erf => mapper.map_to_float_dtype(),
             #[cfg(feature = "log")]
             Log => mapper.log_dtype(),
             Unique(_) => mapper.with_same_dtype(),
@@ -280,8 +297,21 @@ impl IRFunctionExpr {
             ConcatExpr(_) => mapper.map_to_supertype(),
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "dtype-struct")]
+            Lstsq { fit_intercept } => {
+                let names =
+                    polars_ops::series::lstsq_field_names(&mapper.args()[1..], *fit_intercept)?;
+                mapper.with_dtype(coefficients_dtype(names))
+            },
+            #[cfg(feature = "dtype-struct")]
+            PolyFit { degree } => mapper.with_dtype(coefficients_dtype(
+                polars_ops::series::polyfit_field_names(*degree),
+            )),
+            Standardize { .. } | MinMaxScale => mapper.map_to_float_dtype(),
             #[cfg(feature = "peaks")]
             PeakMin | PeakMax => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "peaks")]
+            LocalMin { .. } | LocalMax { .. } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "cutqcut")]
             Cut {
                 include_breaks: false,
@@ -315,6 +345,8 @@ impl IRFunctionExpr {
                 Ok(wrapped_dtype)
             }),
             #[cfg(feature = "cutqcut")]
+            Digitize { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "cutqcut")]
             QCut {
                 include_breaks: false,
                 ..
@@ -344,6 +376,16 @@ impl IRFunctionExpr {
             RLEID => mapper.with_dtype(IDX_DTYPE),
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
+            Random {
+                method: IRRandomMethod::Uniform { .. } | IRRandomMethod::Normal { .. },
+                ..
+            } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "random")]
+            Random {
+                method: IRRandomMethod::Bernoulli { .. },
+                ..
+            } => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
             SetSortedFlag(_) => mapper.with_same_dtype(),
             #[cfg(feature = "ffi_plugin")]
@@ -807,6 +849,16 @@ impl<'a> FieldsMapper<'a> {
     }
 }
 
+#[cfg(feature = "dtype-struct")]
+fn coefficients_dtype(names: Vec<PlSmallStr>) -> DataType {
+    DataType::Struct(
+        names
+            .into_iter()
+            .map(|name| Field::new(name, DataType::Float64))
+            .collect(),
+    )
+}
+
 pub(crate) fn args_to_supertype<D: AsRef<DataType>>(dtypes: &[D]) -> PolarsResult<DataType> {
     let mut st = dtypes[0].as_ref().clone();
     for dt in &dtypes[1..] {
@@ -232,6 +232,18 @@ impl ExprPushdownGroup {
                 if !is_elementwise(stack, ae, expr_arena) {
                     *self = ExprPushdownGroup::Barrier
                 }
+
+                // `or_else` catches the errors of its whole input, which changes when the
+                // predicate is evaluated on a different set of rows.
+                if matches!(
+                    ae,
+                    AExpr::Function {
+                        function: IRFunctionExpr::OrElse,
+                        ..
+                    }
+                ) {
+                    *self = ExprPushdownGroup::Barrier
+                }
             },
 
             ExprPushdownGroup::Barrier => {},
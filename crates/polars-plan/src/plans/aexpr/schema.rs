@@ -1,5 +1,5 @@
 #[cfg(feature = "dtype-decimal")]
-use polars_compute::decimal::DEC128_MAX_PREC;
+use polars_compute::decimal::{DEC128_MAX_PREC, dec128_mean_scale, dec128_mul_scale};
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
 
@@ -183,7 +183,14 @@ impl AExpr {
                         mapper.moment_dtype()
                     },
                     Mean(expr) => {
-                        let field = [ctx.arena.get(*expr).to_field_impl(ctx)?];
+                        let field = ctx.arena.get(*expr).to_field_impl(ctx)?;
+                        #[cfg(feature = "dtype-decimal")]
+                        if let DataType::Decimal(_, scale) = field.dtype() {
+                            let dtype =
+                                DataType::Decimal(DEC128_MAX_PREC, dec128_mean_scale(*scale));
+                            return Ok(Field::new(field.name().clone(), dtype));
+                        }
+                        let field = [field];
                         let mapper = FieldsMapper::new(&field);
                         mapper.moment_dtype()
                     },
@@ -596,7 +603,11 @@ fn get_arithmetic_field(
                 },
                 #[cfg(feature = "dtype-decimal")]
                 (Decimal(_, scale_left), Decimal(_, scale_right)) => {
-                    let dtype = Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right));
+                    let scale = match op {
+                        Operator::Multiply => dec128_mul_scale(*scale_left, *scale_right),
+                        _ => *scale_left.max(scale_right),
+                    };
+                    let dtype = Decimal(DEC128_MAX_PREC, scale);
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
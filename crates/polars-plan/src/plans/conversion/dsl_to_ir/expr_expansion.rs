@@ -104,6 +104,7 @@ fn function_input_wildcard_expansion(function: &FunctionExpr) -> FunctionExpansi
             function,
             F::CumReduceHorizontal { .. } | F::CumFoldHorizontal { .. }
         );
+        expand_into_inputs |= matches!(function, F::Lstsq { .. });
     }
     #[cfg(feature = "ffi_plugin")]
     {
@@ -445,6 +445,9 @@ pub(super) fn convert_functions(
             BitwiseFunction::LeadingZeros => IRBitwiseFunction::LeadingZeros,
             BitwiseFunction::TrailingOnes => IRBitwiseFunction::TrailingOnes,
             BitwiseFunction::TrailingZeros => IRBitwiseFunction::TrailingZeros,
+            BitwiseFunction::Not => IRBitwiseFunction::Not,
+            BitwiseFunction::ShiftLeft => IRBitwiseFunction::ShiftLeft,
+            BitwiseFunction::ShiftRight => IRBitwiseFunction::ShiftRight,
             BitwiseFunction::And => IRBitwiseFunction::And,
             BitwiseFunction::Or => IRBitwiseFunction::Or,
             BitwiseFunction::Xor => IRBitwiseFunction::Xor,
@@ -591,6 +594,8 @@ pub(super) fn convert_functions(
         }),
         #[cfg(feature = "row_hash")]
         F::Hash(s0, s1, s2, s3) => I::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "row_hash")]
+        F::HashWithAlgorithm { algorithm, seed } => I::HashWithAlgorithm { algorithm, seed },
         #[cfg(feature = "arg_where")]
         F::ArgWhere => I::ArgWhere,
         #[cfg(feature = "index_of")]
@@ -766,6 +771,8 @@ pub(super) fn convert_functions(
                 options,
             }
         },
+        #[cfg(feature = "rolling_window")]
+        F::RollingFrame { agg, frame } => I::RollingFrame { agg, frame },
         #[cfg(feature = "rolling_window_by")]
         F::RollingExprBy {
             function_by,
@@ -791,8 +798,9 @@ pub(super) fn convert_functions(
         F::Rechunk => I::Rechunk,
         F::Append { upcast } => I::Append { upcast },
         F::ShiftAndFill => {
+            // The fill value is a scalar or has a value for every row, which is checked when the
+            // lengths are known.
             polars_ensure!(&e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
-            polars_ensure!(&e[2].is_scalar(ctx.arena), ShapeMismatch: "'fill_value' must be a scalar value");
             I::ShiftAndFill
         },
         F::Shift => {
@@ -824,6 +832,13 @@ pub(super) fn convert_functions(
         F::Product => I::Product,
         #[cfg(feature = "rank")]
         F::Rank { options, seed } => I::Rank { options, seed },
+        #[cfg(feature = "rank")]
+        F::PercentRank { descending } => I::PercentRank { descending },
+        #[cfg(feature = "rank")]
+        F::NTile { n, descending } => {
+            polars_ensure!(n > 0, InvalidOperation: "`ntile` expects a positive number of buckets");
+            I::NTile { n, descending }
+        },
         F::Repeat => {
             polars_ensure!(&e[0].is_scalar(ctx.arena), ShapeMismatch: "'value' must be a scalar value");
             polars_ensure!(&e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
@@ -840,6 +855,8 @@ pub(super) fn convert_functions(
         #[cfg(feature = "cum_agg")]
         F::CumCount { reverse } => I::CumCount { reverse },
         #[cfg(feature = "cum_agg")]
+        F::CumNUnique { reverse } => I::CumNUnique { reverse },
+        #[cfg(feature = "cum_agg")]
         F::CumSum { reverse } => I::CumSum { reverse },
         #[cfg(feature = "cum_agg")]
         F::CumProd { reverse } => I::CumProd { reverse },
@@ -864,7 +881,22 @@ pub(super) fn convert_functions(
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => I::ApproxNUnique,
+        #[cfg(feature = "quantile_approx")]
+        F::QuantileApprox {
+            quantile,
+            compression,
+        } => {
+            polars_ensure!(
+                (0.0..=1.0).contains(&quantile),
+                ComputeError: "quantile should be between 0.0 and 1.0",
+            );
+            I::QuantileApprox {
+                quantile,
+                compression,
+            }
+        },
         F::Coalesce => I::Coalesce,
+        F::OrElse => I::OrElse,
         #[cfg(feature = "diff")]
         F::Diff(n) => {
             polars_ensure!(&e[1].is_scalar(ctx.arena), ShapeMismatch: "'n' must be a scalar value");
@@ -884,6 +916,11 @@ pub(super) fn convert_functions(
         F::Log1p => I::Log1p,
         #[cfg(feature = "log")]
         F::Exp => I::Exp,
+        #[cfg(feature = "log")]
+        F::Expm1 => I::Expm1,
+        F::Gamma => I::Gamma,
+        F::LGamma => I::LGamma,
+        F::Erf => I::Erf,
         F::Unique(v) => I::Unique(v),
         #[cfg(feature = "round_series")]
         F::Round { decimals, mode } => I::Round { decimals, mode },
@@ -910,6 +947,10 @@ pub(super) fn convert_functions(
             ));
         },
         F::ConcatExpr(v) => I::ConcatExpr(v),
+        #[cfg(feature = "dtype-struct")]
+        F::Lstsq { fit_intercept } => I::Lstsq { fit_intercept },
+        #[cfg(feature = "dtype-struct")]
+        F::PolyFit { degree } => I::PolyFit { degree },
         #[cfg(feature = "cov")]
         F::Correlation { method } => {
             use {CorrelationMethod as C, IRCorrelationMethod as IC};
@@ -922,10 +963,16 @@ pub(super) fn convert_functions(
                 },
             }
         },
+        F::Standardize { ddof } => I::Standardize { ddof },
+        F::MinMaxScale => I::MinMaxScale,
         #[cfg(feature = "peaks")]
         F::PeakMin => I::PeakMin,
         #[cfg(feature = "peaks")]
         F::PeakMax => I::PeakMax,
+        #[cfg(feature = "peaks")]
+        F::LocalMin { window } => I::LocalMin { window },
+        #[cfg(feature = "peaks")]
+        F::LocalMax { window } => I::LocalMax { window },
         #[cfg(feature = "cutqcut")]
         F::Cut {
             breaks,
@@ -939,6 +986,14 @@ pub(super) fn convert_functions(
             include_breaks,
         },
         #[cfg(feature = "cutqcut")]
+        F::Digitize {
+            breaks,
+            left_closed,
+        } => I::Digitize {
+            breaks,
+            left_closed,
+        },
+        #[cfg(feature = "cutqcut")]
         F::QCut {
             probs,
             labels,
@@ -972,6 +1027,9 @@ pub(super) fn convert_functions(
                         with_replacement,
                         shuffle,
                     },
+                    R::Uniform { low, high } => IR::Uniform { low, high },
+                    R::Normal { mean, std } => IR::Normal { mean, std },
+                    R::Bernoulli { p } => IR::Bernoulli { p },
                 },
                 seed,
             }
@@ -271,21 +271,31 @@ pub fn resolve_join(
         let ltype = get_dtype!(lnode, &schema_left)?;
         let rtype = get_dtype!(rnode, &schema_right)?;
 
-        if let Some(dtype) = get_numeric_upcast_supertype_lossless(&ltype, &rtype) {
+        let cast_to = if let Some(dtype) = get_numeric_upcast_supertype_lossless(&ltype, &rtype) {
             // We use overflowing cast to allow better optimization as we are casting to a known
             // lossless supertype.
-            //
+            Some((dtype, CastOptions::Overflowing))
+        } else if ltype.is_categorical() && rtype.is_categorical() && ltype != rtype {
+            // Categorical keys with different categories are joined in their supertype, the
+            // global categories, to which both keys are remapped.
+            polars_core::utils::get_supertype(&ltype, &rtype)
+                .map(|dtype| (dtype, CastOptions::Strict))
+        } else {
+            None
+        };
+
+        if let Some((dtype, options)) = cast_to {
             // We have unique references to these nodes (they are created by this function),
             // so we can mutate in-place without causing side effects somewhere else.
             let casted_l = ctxt.expr_arena.add(AExpr::Cast {
                 expr: lnode.node(),
                 dtype: dtype.clone(),
-                options: CastOptions::Overflowing,
+                options,
             });
             let casted_r = ctxt.expr_arena.add(AExpr::Cast {
                 expr: rnode.node(),
                 dtype,
-                options: CastOptions::Overflowing,
+                options,
             });
 
             if key_cols_coalesced {
@@ -47,6 +47,7 @@ pub fn to_alp(
         opt_flags.contains(OptFlags::SIMPLIFY_EXPR),
         opt_flags.contains(OptFlags::TYPE_COERCION),
         opt_flags.contains(OptFlags::TYPE_CHECK),
+        opt_flags.coercion_policy(),
     );
 
     let mut ctxt = DslConversionContext {
@@ -165,13 +166,32 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             let first = *inputs.first().ok_or_else(
                 || polars_err!(InvalidOperation: "expected at least one input in 'union'/'concat'"),
             )?;
-            let schema = ctxt.lp_arena.get(first).schema(ctxt.lp_arena);
-            for n in &inputs[1..] {
-                let schema_i = ctxt.lp_arena.get(*n).schema(ctxt.lp_arena);
-                // The first argument
-                schema_i.matches_schema(schema.as_ref()).map_err(|_| polars_err!(InvalidOperation:  "'union'/'concat' inputs should all have the same schema,\
-                    got\n{:?} and \n{:?}", schema, schema_i)
-                )?;
+            let unify_categories = {
+                let schema = ctxt.lp_arena.get(first).schema(ctxt.lp_arena);
+                let mut must_cast = false;
+                for n in &inputs[1..] {
+                    let schema_i = ctxt.lp_arena.get(*n).schema(ctxt.lp_arena);
+                    // The first argument
+                    must_cast |= schema_i.matches_schema(schema.as_ref()).map_err(|_| polars_err!(InvalidOperation:  "'union'/'concat' inputs should all have the same schema,\
+                        got\n{:?} and \n{:?}", schema, schema_i)
+                    )?;
+                }
+                must_cast
+                    && schema
+                        .iter_values()
+                        .any(|dtype| dtype.contains_categoricals())
+            };
+
+            // Categoricals with different categories are unified into their supertype, the
+            // global categories, on all inputs.
+            if unify_categories {
+                concat::convert_st_union(
+                    &mut inputs,
+                    ctxt.lp_arena,
+                    ctxt.expr_arena,
+                    ctxt.opt_flags,
+                )
+                .map_err(|e| e.context(failed_here!(vertical concat)))?;
             }
 
             let options = args.into();
@@ -608,6 +628,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
 
             let mut exprs = Vec::with_capacity(match_schema.len());
             let mut found_missing_columns = Vec::new();
+            let mut found_mismatched_columns = Vec::new();
             let mut used_input_columns = 0;
 
             for ((column, dtype), per_column) in match_schema.iter().zip(per_column.iter()) {
@@ -642,7 +663,13 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         };
 
                         let should_cast =
-                            policy.should_cast_column(column, to_dtype, from_dtype)?;
+                            match policy.should_cast_column(column, to_dtype, from_dtype) {
+                                Ok(should_cast) => should_cast,
+                                Err(err) => {
+                                    found_mismatched_columns.push((column, from_dtype, err));
+                                    false
+                                },
+                            };
 
                         let mut expr = Expr::Column(PlSmallStr::from_str(column));
                         if should_cast {
@@ -655,36 +682,31 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 }
             }
 
-            // Report the error for missing columns
-            if let Some(lst) = found_missing_columns.first() {
-                use std::fmt::Write;
-                let mut formatted = String::new();
-                write!(&mut formatted, "\"{}\"", found_missing_columns[0]).unwrap();
-                for c in &found_missing_columns[1..] {
-                    write!(&mut formatted, ", \"{c}\"").unwrap();
-                }
-
-                write!(&mut formatted, "\"{lst}\"").unwrap();
-                polars_bail!(SchemaMismatch: "missing columns in `match_to_schema`: {formatted}");
-            }
-
-            // Report the error for extra columns
-            if used_input_columns != input_schema.len()
+            let found_extra_columns = if used_input_columns != input_schema.len()
                 && extra_columns == ExtraColumnsPolicy::Raise
             {
-                let found_extra_columns = input_schema
-                    .iter_names()
-                    .filter(|n| !match_schema.contains(n))
-                    .collect::<Vec<_>>();
-
-                use std::fmt::Write;
-                let mut formatted = String::new();
-                write!(&mut formatted, "\"{}\"", found_extra_columns[0]).unwrap();
-                for c in &found_extra_columns[1..] {
-                    write!(&mut formatted, ", \"{c}\"").unwrap();
-                }
+                input_schema
+                    .iter()
+                    .filter(|(n, _)| !match_schema.contains(n))
+                    .collect::<Vec<_>>()
+            } else {
+                Vec::new()
+            };
 
-                polars_bail!(SchemaMismatch: "extra columns in `match_to_schema`: {formatted}");
+            // Report all mismatches at once, so that they can be fixed in one go.
+            if !found_missing_columns.is_empty()
+                || !found_mismatched_columns.is_empty()
+                || !found_extra_columns.is_empty()
+            {
+                polars_bail!(
+                    SchemaMismatch: "{}",
+                    format_match_to_schema_mismatch(
+                        &match_schema,
+                        &found_missing_columns,
+                        &found_mismatched_columns,
+                        &found_extra_columns,
+                    )
+                );
             }
 
             let exprs = to_expr_irs(
@@ -1506,6 +1528,49 @@ fn resolve_group_by(
     Ok((keys, aggs, Arc::new(output_schema)))
 }
 
+/// Format all mismatches found by `match_to_schema`, followed by a diff of the offending columns.
+fn format_match_to_schema_mismatch(
+    match_schema: &Schema,
+    missing: &[&PlSmallStr],
+    mismatched: &[(&PlSmallStr, &DataType, PolarsError)],
+    extra: &[(&PlSmallStr, &DataType)],
+) -> String {
+    use std::fmt::Write;
+
+    let quoted = |names: &mut dyn Iterator<Item = &PlSmallStr>| {
+        names
+            .map(|n| format!("\"{n}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut out = String::new();
+    if !missing.is_empty() {
+        let names = quoted(&mut missing.iter().copied());
+        writeln!(out, "missing columns in `match_to_schema`: {names}").unwrap();
+    }
+    for (_, _, err) in mismatched {
+        writeln!(out, "{err}").unwrap();
+    }
+    if !extra.is_empty() {
+        let names = quoted(&mut extra.iter().map(|(n, _)| *n));
+        writeln!(out, "extra columns in `match_to_schema`: {names}").unwrap();
+    }
+
+    out.push_str("\nschema diff (- input, + expected):");
+    for (name, dtype) in match_schema.iter() {
+        if missing.contains(&name) {
+            write!(out, "\n+ {name}: {dtype}").unwrap();
+        } else if let Some((_, input_dtype, _)) = mismatched.iter().find(|(n, ..)| *n == name) {
+            write!(out, "\n- {name}: {input_dtype}\n+ {name}: {dtype}").unwrap();
+        }
+    }
+    for (name, dtype) in extra {
+        write!(out, "\n- {name}: {dtype}").unwrap();
+    }
+    out
+}
+
 fn stats_helper<F, E>(condition: F, expr: E, schema: &Schema) -> Vec<Expr>
 where
     F: Fn(&DataType) -> bool,
@@ -301,9 +301,14 @@ pub(super) fn ipc_file_info(
                     })?
                 })
             },
-            PlPathRef::Local(path) => arrow::io::ipc::read::read_file_metadata(
-                &mut std::io::BufReader::new(polars_utils::open_file(path)?),
-            )?,
+            PlPathRef::Local(local_path) => match crate::dsl::read_from_file_provider(path) {
+                Some(buff) => {
+                    arrow::io::ipc::read::read_file_metadata(&mut std::io::Cursor::new(buff?))?
+                },
+                None => arrow::io::ipc::read::read_file_metadata(&mut std::io::BufReader::new(
+                    polars_utils::open_file(local_path)?,
+                ))?,
+            },
         },
         ScanSourceRef::File(file) => {
             arrow::io::ipc::read::read_file_metadata(&mut std::io::BufReader::new(file))?
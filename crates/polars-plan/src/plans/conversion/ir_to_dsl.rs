@@ -642,6 +642,9 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::LeadingZeros => B::LeadingZeros,
                 IB::TrailingOnes => B::TrailingOnes,
                 IB::TrailingZeros => B::TrailingZeros,
+                IB::Not => B::Not,
+                IB::ShiftLeft => B::ShiftLeft,
+                IB::ShiftRight => B::ShiftRight,
                 IB::And => B::And,
                 IB::Or => B::Or,
                 IB::Xor => B::Xor,
@@ -738,6 +741,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         },
         #[cfg(feature = "row_hash")]
         IF::Hash(s0, s1, s2, s3) => F::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "row_hash")]
+        IF::HashWithAlgorithm { algorithm, seed } => F::HashWithAlgorithm { algorithm, seed },
         #[cfg(feature = "arg_where")]
         IF::ArgWhere => F::ArgWhere,
         #[cfg(feature = "index_of")]
@@ -874,6 +879,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 options,
             }
         },
+        #[cfg(feature = "rolling_window")]
+        IF::RollingFrame { agg, frame } => F::RollingFrame { agg, frame },
         #[cfg(feature = "rolling_window_by")]
         IF::RollingExprBy {
             function_by,
@@ -923,6 +930,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Product => F::Product,
         #[cfg(feature = "rank")]
         IF::Rank { options, seed } => F::Rank { options, seed },
+        #[cfg(feature = "rank")]
+        IF::PercentRank { descending } => F::PercentRank { descending },
+        #[cfg(feature = "rank")]
+        IF::NTile { n, descending } => F::NTile { n, descending },
         IF::Repeat => F::Repeat,
         #[cfg(feature = "round_series")]
         IF::Clip { has_min, has_max } => F::Clip { has_min, has_max },
@@ -935,6 +946,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "cum_agg")]
         IF::CumCount { reverse } => F::CumCount { reverse },
         #[cfg(feature = "cum_agg")]
+        IF::CumNUnique { reverse } => F::CumNUnique { reverse },
+        #[cfg(feature = "cum_agg")]
         IF::CumSum { reverse } => F::CumSum { reverse },
         #[cfg(feature = "cum_agg")]
         IF::CumProd { reverse } => F::CumProd { reverse },
@@ -959,7 +972,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::UniqueCounts => F::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         IF::ApproxNUnique => F::ApproxNUnique,
+        #[cfg(feature = "quantile_approx")]
+        IF::QuantileApprox {
+            quantile,
+            compression,
+        } => F::QuantileApprox {
+            quantile,
+            compression,
+        },
         IF::Coalesce => F::Coalesce,
+        IF::OrElse => F::OrElse,
         #[cfg(feature = "diff")]
         IF::Diff(nb) => F::Diff(nb),
         #[cfg(feature = "pct_change")]
@@ -976,6 +998,11 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Log1p => F::Log1p,
         #[cfg(feature = "log")]
         IF::Exp => F::Exp,
+        #[cfg(feature = "log")]
+        IF::Expm1 => F::Expm1,
+        IF::Gamma => F::Gamma,
+        IF::LGamma => F::LGamma,
+        IF::Erf => F::Erf,
         IF::Unique(v) => F::Unique(v),
         #[cfg(feature = "round_series")]
         IF::Round { decimals, mode } => F::Round { decimals, mode },
@@ -999,6 +1026,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             };
         },
         IF::ConcatExpr(v) => F::ConcatExpr(v),
+        #[cfg(feature = "dtype-struct")]
+        IF::Lstsq { fit_intercept } => F::Lstsq { fit_intercept },
+        #[cfg(feature = "dtype-struct")]
+        IF::PolyFit { degree } => F::PolyFit { degree },
         #[cfg(feature = "cov")]
         IF::Correlation { method } => {
             use {CorrelationMethod as C, IRCorrelationMethod as IC};
@@ -1011,10 +1042,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 },
             }
         },
+        IF::Standardize { ddof } => F::Standardize { ddof },
+        IF::MinMaxScale => F::MinMaxScale,
         #[cfg(feature = "peaks")]
         IF::PeakMin => F::PeakMin,
         #[cfg(feature = "peaks")]
         IF::PeakMax => F::PeakMax,
+        #[cfg(feature = "peaks")]
+        IF::LocalMin { window } => F::LocalMin { window },
+        #[cfg(feature = "peaks")]
+        IF::LocalMax { window } => F::LocalMax { window },
         #[cfg(feature = "cutqcut")]
         IF::Cut {
             breaks,
@@ -1028,6 +1065,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             include_breaks,
         },
         #[cfg(feature = "cutqcut")]
+        IF::Digitize {
+            breaks,
+            left_closed,
+        } => F::Digitize {
+            breaks,
+            left_closed,
+        },
+        #[cfg(feature = "cutqcut")]
         IF::QCut {
             probs,
             labels,
@@ -1061,6 +1106,9 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                         with_replacement,
                         shuffle,
                     },
+                    IR::Uniform { low, high } => R::Uniform { low, high },
+                    IR::Normal { mean, std } => R::Normal { mean, std },
+                    IR::Bernoulli { p } => R::Bernoulli { p },
                 },
                 seed,
             }
@@ -32,7 +32,12 @@ impl Extend<Node> for ExtendVec<'_> {
 }
 
 impl ConversionOptimizer {
-    pub fn new(simplify: bool, type_coercion: bool, type_check: bool) -> Self {
+    pub fn new(
+        simplify: bool,
+        type_coercion: bool,
+        type_check: bool,
+        coercion_policy: CoercionPolicy,
+    ) -> Self {
         let simplify = if simplify {
             Some(SimplifyExprRule {})
         } else {
@@ -40,7 +45,9 @@ impl ConversionOptimizer {
         };
 
         let coerce = if type_coercion {
-            Some(TypeCoercionRule {})
+            Some(TypeCoercionRule {
+                policy: coercion_policy,
+            })
         } else {
             None
         };
@@ -121,6 +121,7 @@ pub(super) fn process_binary(
     node_left: Node,
     op: Operator,
     node_right: Node,
+    policy: CoercionPolicy,
 ) -> PolarsResult<Option<AExpr>> {
     let (left, type_left): (&AExpr, DataType) =
         unpack!(get_aexpr_and_type(expr_arena, node_left, input_schema));
@@ -161,6 +162,19 @@ pub(super) fn process_binary(
     }
 
     use DataType::*;
+    if policy.forbid_string_numeric_comparison && op.is_comparison() {
+        let is_str = |dt: &DataType| matches!(dt, String | Unknown(UnknownKind::Str));
+        let is_num =
+            |dt: &DataType| dt.is_primitive_numeric() || matches!(dt, Unknown(UnknownKind::Int(_)));
+        polars_ensure!(
+            !((is_str(&type_left) && is_num(&type_right))
+                || (is_num(&type_left) && is_str(&type_right))),
+            InvalidOperation: "comparison between {} and {} is forbidden by the coercion policy, \
+            add an explicit cast",
+            type_left, type_right
+        );
+    }
+
     // don't coerce string with number comparisons. They must error
     match (&type_left, &type_right, op) {
         #[cfg(not(feature = "dtype-categorical"))]
@@ -226,6 +240,9 @@ pub(super) fn process_binary(
             | (_, List(_)) => return Ok(None),
             #[cfg(feature = "dtype-array")]
             (Array(..), _) | (_, Array(..)) => return Ok(None),
+            // Casting both to the supertype would inflate the scale of the product.
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(_, _), Decimal(_, _)) if op == Operator::Multiply => return Ok(None),
             #[cfg(feature = "dtype-struct")]
             (Struct(_), a) | (a, Struct(_)) if a.is_primitive_numeric() => {
                 return process_struct_numeric_arithmetic(
@@ -251,6 +268,7 @@ pub(super) fn process_binary(
     if matches!(st, DataType::Unknown(UnknownKind::Any)) {
         return Ok(None);
     }
+    policy.check_supertype(&type_left, &type_right, &st)?;
 
     // Only cast if the type is not already the super type.
     // this can prevent an expensive flattening and subsequent aggregation
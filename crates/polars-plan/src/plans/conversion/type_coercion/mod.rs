@@ -32,7 +32,47 @@ use polars_utils::itertools::Itertools;
 
 use super::*;
 
-pub struct TypeCoercionRule {}
+/// Overrides of the default type coercion rules, to enforce stricter pipelines.
+///
+/// The default policy allows all implicit casts. Set it on a query with
+/// [`OptFlags::set_coercion_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CoercionPolicy {
+    /// Raise instead of casting integers to floats to find a supertype, e.g. for
+    /// `int_col + float_col` or `when(..).then(int_col).otherwise(float_col)`.
+    pub forbid_int_to_float: bool,
+    /// Raise on comparisons between strings and numbers at planning time.
+    pub forbid_string_numeric_comparison: bool,
+}
+
+impl CoercionPolicy {
+    /// Check that casting `left` and `right` to their supertype `st` is allowed.
+    fn check_supertype(
+        &self,
+        type_left: &DataType,
+        type_right: &DataType,
+        st: &DataType,
+    ) -> PolarsResult<()> {
+        if self.forbid_int_to_float
+            && st.is_float()
+            && let Some(int) = [type_left, type_right]
+                .into_iter()
+                .find(|dt| dt.is_integer())
+        {
+            polars_bail!(
+                InvalidOperation: "implicit cast from {} to {} is forbidden by the coercion policy, \
+                add an explicit cast",
+                int, st
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct TypeCoercionRule {
+    pub policy: CoercionPolicy,
+}
 
 macro_rules! unpack {
     ($packed:expr) => {
@@ -197,6 +237,7 @@ impl OptimizationRule for TypeCoercionRule {
                 }
                 let st = unpack!(get_supertype(&type_true, &type_false));
                 let st = modify_supertype(st, truthy, falsy, &type_true, &type_false);
+                self.policy.check_supertype(&type_true, &type_false, &st)?;
 
                 // only cast if the type is not already the super type.
                 // this can prevent an expensive flattening and subsequent aggregation
@@ -232,7 +273,9 @@ impl OptimizationRule for TypeCoercionRule {
                 left: node_left,
                 op,
                 right: node_right,
-            } => return process_binary(expr_arena, schema, node_left, op, node_right),
+            } => {
+                return process_binary(expr_arena, schema, node_left, op, node_right, self.policy);
+            },
             #[cfg(feature = "is_in")]
             AExpr::Function {
                 ref function,
@@ -392,6 +435,8 @@ impl OptimizationRule for TypeCoercionRule {
                 let super_type = unpack!(get_supertype(&type_left, &type_fill_value));
                 let super_type =
                     modify_supertype(super_type, left, fill_value, &type_left, &type_fill_value);
+                self.policy
+                    .check_supertype(&type_left, &type_fill_value, &super_type)?;
 
                 let mut input = input.clone();
                 let new_node_left = if type_left != super_type {
@@ -521,6 +566,7 @@ impl OptimizationRule for TypeCoercionRule {
                                     raise_supertype(&function, &input, schema, expr_arena)?;
                                     unreachable!()
                                 };
+                                let prev_super_type = super_type;
                                 if input.len() == 2 {
                                     // modify_supertype is a bit more conservative of casting columns
                                     // to literals
@@ -535,6 +581,11 @@ impl OptimizationRule for TypeCoercionRule {
                                     // when dealing with more than 1 argument, we simply find the supertypes
                                     super_type = new_st
                                 }
+                                self.policy.check_supertype(
+                                    &prev_super_type,
+                                    &type_other,
+                                    &super_type,
+                                )?;
                             }
                         },
                         CastingRules::FirstArgLossless => {
@@ -1,5 +1,7 @@
 #[cfg(feature = "ipc")]
 use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+use polars_core::POOL;
 #[cfg(any(
     feature = "parquet",
     feature = "ipc",
@@ -16,6 +18,8 @@ use polars_io::parquet::read::ParquetReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
 use polars_utils::plpath::PlPath;
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use super::*;
 
@@ -74,6 +78,20 @@ pub fn count_rows(
     }
 }
 
+/// Sum the row counts of all sources, counting the sources in parallel.
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
+fn count_sources_par<F>(sources: &ScanSources, count: F) -> PolarsResult<usize>
+where
+    F: Fn(ScanSourceRef<'_>) -> PolarsResult<usize> + Sync,
+{
+    POOL.install(|| {
+        (0..sources.len())
+            .into_par_iter()
+            .map(|i| count(sources.at(i)))
+            .sum()
+    })
+}
+
 #[cfg(feature = "csv")]
 fn count_all_rows_csv(
     sources: &ScanSources,
@@ -81,11 +99,22 @@ fn count_all_rows_csv(
 ) -> PolarsResult<usize> {
     let parse_options = options.get_parse_options();
 
-    sources
-        .iter()
-        .map(|source| match source {
-            ScanSourceRef::Path(addr) => polars_io::csv::read::count_rows(
-                addr,
+    count_sources_par(sources, |source| match source {
+        ScanSourceRef::Path(addr) => polars_io::csv::read::count_rows(
+            addr,
+            parse_options.quote_char,
+            parse_options.comment_prefix.as_ref(),
+            parse_options.eol_char,
+            options.has_header,
+            options.skip_lines,
+            options.skip_rows,
+            options.skip_rows_after_header,
+        ),
+        _ => {
+            let memslice = source.to_memslice()?;
+
+            polars_io::csv::read::count_rows_from_slice_par(
+                &memslice[..],
                 parse_options.quote_char,
                 parse_options.comment_prefix.as_ref(),
                 parse_options.eol_char,
@@ -93,23 +122,9 @@ fn count_all_rows_csv(
                 options.skip_lines,
                 options.skip_rows,
                 options.skip_rows_after_header,
-            ),
-            _ => {
-                let memslice = source.to_memslice()?;
-
-                polars_io::csv::read::count_rows_from_slice_par(
-                    &memslice[..],
-                    parse_options.quote_char,
-                    parse_options.comment_prefix.as_ref(),
-                    parse_options.eol_char,
-                    options.has_header,
-                    options.skip_lines,
-                    options.skip_rows,
-                    options.skip_rows_after_header,
-                )
-            },
-        })
-        .sum()
+            )
+        },
+    })
 }
 
 #[cfg(feature = "parquet")]
@@ -129,12 +144,9 @@ pub(super) fn count_rows_parquet(
             ))
         })
     } else {
-        sources
-            .iter()
-            .map(|source| {
-                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
-            })
-            .sum::<PolarsResult<usize>>()
+        count_sources_par(sources, |source| {
+            ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
+        })
     }
 }
 
@@ -177,13 +189,10 @@ pub(super) fn count_rows_ipc(
             ))
         })
     } else {
-        sources
-            .iter()
-            .map(|source| {
-                let memslice = source.to_memslice()?;
-                count_rows_ipc_sync(&mut std::io::Cursor::new(memslice)).map(|v| v as usize)
-            })
-            .sum::<PolarsResult<usize>>()
+        count_sources_par(sources, |source| {
+            let memslice = source.to_memslice()?;
+            count_rows_ipc_sync(&mut std::io::Cursor::new(memslice)).map(|v| v as usize)
+        })
     }
 }
 
@@ -160,14 +160,28 @@ fn visit_logical_plan_for_scan_paths(
             scan_type,
             sources,
             unified_scan_args,
+            predicate,
             ..
         } => {
-            // New-streaming is generally on par for all except CSV (see https://github.com/pola-rs/polars/pull/22363).
-            // In the future we can potentially remove the dedicated count codepaths.
+            // The files alone don't know how many rows remain after a slice, a filter or deleted
+            // rows.
+            if predicate.is_some()
+                || unified_scan_args.pre_slice.is_some()
+                || unified_scan_args.deletion_files.is_some()
+            {
+                return None;
+            }
 
+            // Parquet and IPC files store their row count in their metadata and CSV files are
+            // counted with a parallel newline count, so none of them have to be decoded.
+            // New-streaming is on par for the other formats (see https://github.com/pola-rs/polars/pull/22363).
             let use_fast_file_count = use_fast_file_count.unwrap_or(match scan_type.as_ref() {
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
+                #[cfg(feature = "parquet")]
+                FileScanIR::Parquet { .. } => true,
+                #[cfg(feature = "ipc")]
+                FileScanIR::Ipc { .. } => true,
                 _ => false,
             });
 
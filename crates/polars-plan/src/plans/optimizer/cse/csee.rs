@@ -247,6 +247,12 @@ fn skip_pre_visit(ae: &AExpr, is_groupby: bool) -> bool {
         #[cfg(feature = "dynamic_group_by")]
         AExpr::Rolling { .. } => true,
         AExpr::Over { .. } => true,
+        // The inputs of `or_else` must be evaluated by the `or_else` itself, so that their errors
+        // are caught.
+        AExpr::Function {
+            function: IRFunctionExpr::OrElse,
+            ..
+        } => true,
         #[cfg(feature = "dtype-struct")]
         AExpr::Ternary { .. } => is_groupby,
         _ => false,
@@ -370,6 +376,10 @@ impl ExprIdentifierVisitor<'_> {
             #[cfg(feature = "dynamic_group_by")]
             AExpr::Rolling { .. } => REFUSE_SKIP,
             AExpr::Over { .. } => REFUSE_SKIP,
+            AExpr::Function {
+                function: IRFunctionExpr::OrElse,
+                ..
+            } => REFUSE_SKIP,
             // Don't allow this for now, as we can get `null().cast()` in ternary expressions.
             // TODO! Add a typed null
             AExpr::Literal(LiteralValue::Scalar(sc)) if sc.is_null() => REFUSE_NO_MEMBER,
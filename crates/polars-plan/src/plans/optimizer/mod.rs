@@ -43,7 +43,7 @@ pub use stack_opt::{OptimizationRule, OptimizeExprContext, StackOptimizer};
 
 use self::flatten_union::FlattenUnionRule;
 pub use crate::frame::{AllowedOptimizations, OptFlags};
-pub use crate::plans::conversion::type_coercion::TypeCoercionRule;
+pub use crate::plans::conversion::type_coercion::{CoercionPolicy, TypeCoercionRule};
 use crate::plans::optimizer::count_star::CountStar;
 #[cfg(feature = "cse")]
 use crate::plans::optimizer::cse::CommonSubExprOptimizer;
@@ -492,11 +492,11 @@ impl PredicatePushDown {
                         },
                         #[cfg(feature = "pivot")]
                         FunctionIR::Unpivot { args, .. } => {
-                            // predicates that will be done at this level
+                            // Predicates on the generated columns are done at this level; as
+                            // the index columns are only repeated, predicates on those can be
+                            // applied before the unpivot.
                             let condition = |name: &PlSmallStr| {
-                                name == &args.variable_name
-                                    || name == &args.value_name
-                                    || args.index.iter().any(|s| s == name)
+                                name == &args.variable_name || name == &args.value_name
                             };
                             let local_predicates = transfer_to_local_by_name(
                                 expr_arena,
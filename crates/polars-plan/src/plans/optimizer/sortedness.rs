@@ -4,6 +4,7 @@ use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::{FillNullStrategy, PlHashMap, PlHashSet};
 use polars_core::schema::Schema;
 use polars_core::series::IsSorted;
+use polars_ops::frame::{JoinType, MaintainOrderJoin};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::unique_id::UniqueId;
@@ -321,6 +322,31 @@ fn is_sorted_rec(
         },
 
         IR::GroupBy { .. } => None,
+        IR::Join {
+            input_left,
+            schema,
+            options,
+            ..
+        } if matches!(options.args.how, JoinType::Inner | JoinType::Left)
+            && matches!(
+                options.args.maintain_order,
+                MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight
+            ) =>
+        {
+            // The left rows keep their order and the left columns keep their names, so we can
+            // keep the sorted columns of the left input that are in the output.
+            let (input_left, schema) = (*input_left, schema.clone());
+            let input_sorted = rec!(input_left)?;
+            let first_dropped_key = input_sorted
+                .0
+                .iter()
+                .position(|v| !schema.contains(&v.column));
+            match first_dropped_key {
+                None => Some(input_sorted),
+                Some(0) => None,
+                Some(i) => Some(IRSorted(input_sorted.0.iter().take(i).cloned().collect())),
+            }
+        },
         IR::Join { .. } => None,
         IR::MapFunction { input, function } => match function {
             FunctionIR::Hint(hint) => match hint {
@@ -328,6 +354,20 @@ fn is_sorted_rec(
                 #[expect(unreachable_patterns)]
                 _ => rec!(*input),
             },
+            FunctionIR::Rechunk => rec!(*input),
+            FunctionIR::RowIndex { name, .. } => {
+                let name = name.clone();
+                rec!(*input).or_else(|| {
+                    Some(IRSorted(
+                        [Sorted {
+                            column: name,
+                            descending: Some(false),
+                            nulls_last: Some(false),
+                        }]
+                        .into(),
+                    ))
+                })
+            },
             _ => None,
         },
         IR::Union { .. } => None,
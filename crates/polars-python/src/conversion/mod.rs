@@ -843,9 +843,10 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "spline" => InterpolationMethod::Spline,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'spline'}}, got {v}",
                 )));
             },
         };
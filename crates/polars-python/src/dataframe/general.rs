@@ -458,8 +458,13 @@ impl PyDataFrame {
                 separator,
                 drop_first,
                 drop_nulls,
+                DummyDtype::default(),
             ),
-            None => self.df.read().to_dummies(separator, drop_first, drop_nulls),
+            None => {
+                self.df
+                    .read()
+                    .to_dummies(separator, drop_first, drop_nulls, DummyDtype::default())
+            },
         })
     }
 
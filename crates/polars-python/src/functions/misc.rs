@@ -50,16 +50,17 @@ pub fn register_plugin_function(
         ..Default::default()
     };
 
-    Ok(Expr::Function {
-        input: args.to_exprs(),
-        function: FunctionExpr::FfiPlugin {
-            flags: options,
-            lib: plugin_path.into(),
-            symbol: function_name.into(),
-            kwargs: kwargs.into(),
-        },
-    }
-    .into())
+    // SAFETY: the plugin is loaded and called on behalf of the user that registered it.
+    let expr = unsafe {
+        polars_plan::dsl::register_plugin_function(
+            plugin_path,
+            function_name,
+            args.to_exprs(),
+            kwargs,
+            options,
+        )
+    };
+    Ok(expr.into())
 }
 
 #[pyfunction]
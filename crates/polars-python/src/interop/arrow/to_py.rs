@@ -5,10 +5,9 @@ use arrow::ffi;
 use arrow::record_batch::RecordBatch;
 use polars::datatypes::CompatLevel;
 use polars::frame::DataFrame;
-use polars::prelude::{ArrayRef, ArrowField, PlSmallStr, SchemaExt};
+use polars::prelude::{ArrayRef, ArrowField, PlSmallStr};
 use polars::series::Series;
 use polars_core::utils::arrow;
-use polars_error::PolarsResult;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
 use pyo3::types::PyCapsule;
@@ -70,13 +69,7 @@ pub(crate) fn series_to_stream<'py>(
     series: &Series,
     py: Python<'py>,
 ) -> PyResult<Bound<'py, PyCapsule>> {
-    let field = series.field().to_arrow(CompatLevel::newest());
-    let series = series.clone();
-    let iter = Box::new(
-        (0..series.n_chunks()).map(move |i| Ok(series.to_arrow(i, CompatLevel::newest()))),
-    ) as _;
-
-    let stream = ffi::export_iterator(iter, field);
+    let stream = series.to_arrow_c_stream(CompatLevel::newest());
     let stream_capsule_name = CString::new("arrow_array_stream").unwrap();
     PyCapsule::new(py, stream, Some(stream_capsule_name))
 }
@@ -85,9 +78,7 @@ pub(crate) fn dataframe_to_stream<'py>(
     df: &DataFrame,
     py: Python<'py>,
 ) -> PyResult<Bound<'py, PyCapsule>> {
-    let iter = Box::new(DataFrameStreamIterator::new(df));
-    let field = iter.field();
-    let stream = ffi::export_iterator(iter, field);
+    let stream = df.to_arrow_c_stream(CompatLevel::newest());
     let stream_capsule_name = CString::new("arrow_array_stream").unwrap();
     PyCapsule::new(py, stream, Some(stream_capsule_name))
 }
@@ -114,58 +105,3 @@ pub(crate) fn polars_schema_to_pycapsule<'py>(
     let capsule_name = CString::new("arrow_schema").unwrap();
     PyCapsule::new(py, schema, Some(capsule_name))
 }
-
-pub struct DataFrameStreamIterator {
-    columns: Vec<Series>,
-    dtype: ArrowDataType,
-    idx: usize,
-    n_chunks: usize,
-}
-
-impl DataFrameStreamIterator {
-    fn new(df: &DataFrame) -> Self {
-        let schema = df.schema().to_arrow(CompatLevel::newest());
-        let dtype = ArrowDataType::Struct(schema.into_iter_values().collect());
-
-        Self {
-            columns: df
-                .get_columns()
-                .iter()
-                .map(|v| v.as_materialized_series().clone())
-                .collect(),
-            dtype,
-            idx: 0,
-            n_chunks: df.first_col_n_chunks(),
-        }
-    }
-
-    fn field(&self) -> ArrowField {
-        ArrowField::new(PlSmallStr::EMPTY, self.dtype.clone(), false)
-    }
-}
-
-impl Iterator for DataFrameStreamIterator {
-    type Item = PolarsResult<ArrayRef>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.idx >= self.n_chunks {
-            None
-        } else {
-            // create a batch of the columns with the same chunk no.
-            let batch_cols = self
-                .columns
-                .iter()
-                .map(|s| s.to_arrow(self.idx, CompatLevel::newest()))
-                .collect::<Vec<_>>();
-            self.idx += 1;
-
-            let array = arrow::array::StructArray::new(
-                self.dtype.clone(),
-                batch_cols[0].len(),
-                batch_cols,
-                None,
-            );
-            Some(Ok(Box::new(array)))
-        }
-    }
-}
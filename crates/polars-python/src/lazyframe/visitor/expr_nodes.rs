@@ -1146,6 +1146,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Hash(seed, seed_1, seed_2, seed_3) => {
                     ("hash", seed, seed_1, seed_2, seed_3).into_py_any(py)
                 },
+                IRFunctionExpr::HashWithAlgorithm { algorithm, seed } => {
+                    ("hash_with_algorithm", algorithm.as_str(), seed).into_py_any(py)
+                },
                 IRFunctionExpr::ArgWhere => ("argwhere",).into_py_any(py),
                 #[cfg(feature = "index_of")]
                 IRFunctionExpr::IndexOf => ("index_of",).into_py_any(py),
@@ -1192,6 +1195,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::RollingExpr { function, .. } => {
                     return Err(PyNotImplementedError::new_err(format!("{function}")));
                 },
+                IRFunctionExpr::RollingFrame { .. } => {
+                    return Err(PyNotImplementedError::new_err("rolling frame"));
+                },
                 IRFunctionExpr::RollingExprBy { function_by, .. } => match function_by {
                     IRRollingFunctionBy::MinBy => {
                         return Err(PyNotImplementedError::new_err("rolling min by"));
@@ -1256,6 +1262,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     };
                     ("rank", method, options.descending, seed.map(|s| s as i64)).into_py_any(py)
                 },
+                IRFunctionExpr::PercentRank { descending } => {
+                    ("percent_rank", descending).into_py_any(py)
+                },
+                IRFunctionExpr::NTile { n, descending } => ("ntile", n, descending).into_py_any(py),
                 IRFunctionExpr::Clip { has_min, has_max } => {
                     ("clip", has_min, has_max).into_py_any(py)
                 },
@@ -1263,6 +1273,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 #[cfg(feature = "top_k")]
                 IRFunctionExpr::TopK { descending } => ("top_k", descending).into_py_any(py),
                 IRFunctionExpr::CumCount { reverse } => ("cum_count", reverse).into_py_any(py),
+                IRFunctionExpr::CumNUnique { reverse } => ("cum_n_unique", reverse).into_py_any(py),
                 IRFunctionExpr::CumSum { reverse } => ("cum_sum", reverse).into_py_any(py),
                 IRFunctionExpr::CumProd { reverse } => ("cum_prod", reverse).into_py_any(py),
                 IRFunctionExpr::CumMin { reverse } => ("cum_min", reverse).into_py_any(py),
@@ -1276,7 +1287,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 IRFunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
                 IRFunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                #[cfg(feature = "quantile_approx")]
+                IRFunctionExpr::QuantileApprox {
+                    quantile,
+                    compression,
+                } => ("quantile_approx", quantile, compression).into_py_any(py),
                 IRFunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
+                IRFunctionExpr::OrElse => ("or_else",).into_py_any(py),
                 IRFunctionExpr::Diff(null_behaviour) => (
                     "diff",
                     match null_behaviour {
@@ -1287,14 +1304,20 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     .into_py_any(py),
                 #[cfg(feature = "pct_change")]
                 IRFunctionExpr::PctChange => ("pct_change",).into_py_any(py),
-                IRFunctionExpr::Interpolate(method) => (
-                    "interpolate",
-                    match method {
+                IRFunctionExpr::Interpolate(options) => {
+                    if options.limit.is_some() {
+                        return Err(PyNotImplementedError::new_err("interpolate with limit"));
+                    }
+                    let method = match options.method {
                         InterpolationMethod::Linear => "linear",
                         InterpolationMethod::Nearest => "nearest",
-                    },
-                )
-                    .into_py_any(py),
+                        InterpolationMethod::Spline => "spline",
+                        InterpolationMethod::Polynomial(_) => {
+                            return Err(PyNotImplementedError::new_err("polynomial interpolation"));
+                        },
+                    };
+                    ("interpolate", method).into_py_any(py)
+                },
                 IRFunctionExpr::InterpolateBy => ("interpolate_by",).into_py_any(py),
                 IRFunctionExpr::Entropy { base, normalize } => {
                     ("entropy", base, normalize).into_py_any(py)
@@ -1302,6 +1325,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Log => ("log",).into_py_any(py),
                 IRFunctionExpr::Log1p => ("log1p",).into_py_any(py),
                 IRFunctionExpr::Exp => ("exp",).into_py_any(py),
+                IRFunctionExpr::Expm1 => ("expm1",).into_py_any(py),
+                IRFunctionExpr::Gamma => ("gamma",).into_py_any(py),
+                IRFunctionExpr::LGamma => ("lgamma",).into_py_any(py),
+                IRFunctionExpr::Erf => ("erf",).into_py_any(py),
                 IRFunctionExpr::Unique(maintain_order) => {
                     ("unique", maintain_order).into_py_any(py)
                 },
@@ -1318,13 +1345,26 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Correlation { .. } => {
                     return Err(PyNotImplementedError::new_err("corr"));
                 },
+                IRFunctionExpr::Lstsq { fit_intercept } => ("lstsq", fit_intercept).into_py_any(py),
+                IRFunctionExpr::PolyFit { degree } => ("polyfit", degree).into_py_any(py),
+                IRFunctionExpr::Standardize { ddof } => ("standardize", ddof).into_py_any(py),
+                IRFunctionExpr::MinMaxScale => ("min_max_scale",).into_py_any(py),
                 #[cfg(feature = "peaks")]
                 IRFunctionExpr::PeakMin => ("peak_max",).into_py_any(py),
                 #[cfg(feature = "peaks")]
                 IRFunctionExpr::PeakMax => ("peak_min",).into_py_any(py),
+                #[cfg(feature = "peaks")]
+                IRFunctionExpr::LocalMin { window } => ("local_min", window).into_py_any(py),
+                #[cfg(feature = "peaks")]
+                IRFunctionExpr::LocalMax { window } => ("local_max", window).into_py_any(py),
                 #[cfg(feature = "cutqcut")]
                 IRFunctionExpr::Cut { .. } => return Err(PyNotImplementedError::new_err("cut")),
                 #[cfg(feature = "cutqcut")]
+                IRFunctionExpr::Digitize {
+                    breaks,
+                    left_closed,
+                } => ("digitize", breaks.clone(), left_closed).into_py_any(py),
+                #[cfg(feature = "cutqcut")]
                 IRFunctionExpr::QCut { .. } => return Err(PyNotImplementedError::new_err("qcut")),
                 #[cfg(feature = "rle")]
                 IRFunctionExpr::RLE => ("rle",).into_py_any(py),
@@ -319,7 +319,7 @@ impl PySeries {
         py.enter_polars_df(|| {
             self.series
                 .read()
-                .to_dummies(separator, drop_first, drop_nulls)
+                .to_dummies(separator, drop_first, drop_nulls, DummyDtype::default())
         })
     }
 
@@ -1,6 +1,6 @@
 use arrow::array::{Array, PrimitiveArray};
 use arrow::ffi;
-use arrow::ffi::{ArrowArray, ArrowArrayStream, ArrowArrayStreamReader, ArrowSchema};
+use arrow::ffi::{ArrowArray, ArrowArrayStream, ArrowSchema};
 use polars::prelude::*;
 use polars_ffi::version_0::SeriesExport;
 use pyo3::exceptions::{PyTypeError, PyValueError};
@@ -105,28 +105,11 @@ pub(crate) fn import_stream_pycapsule(capsule: &Bound<PyCapsule>) -> PyResult<Py
     // # Safety
     // capsule holds a valid C ArrowArrayStream pointer, as defined by the Arrow PyCapsule
     // Interface
-    let mut stream = unsafe {
+    let s = unsafe {
         // Takes ownership of the pointed to ArrowArrayStream
         // This acts to move the data out of the capsule pointer, setting the release callback to NULL
-        let stream_ptr = Box::new(std::ptr::replace(
-            capsule.pointer() as _,
-            ArrowArrayStream::empty(),
-        ));
-        ArrowArrayStreamReader::try_new(stream_ptr)
-            .map_err(|err| PyValueError::new_err(err.to_string()))?
-    };
-
-    let mut produced_arrays: Vec<Box<dyn Array>> = vec![];
-    while let Some(array) = unsafe { stream.next() } {
-        produced_arrays.push(array.unwrap());
-    }
-
-    // Series::try_from fails for an empty vec of chunks
-    let s = if produced_arrays.is_empty() {
-        let polars_dt = DataType::from_arrow_field(stream.field());
-        Series::new_empty(stream.field().name.clone(), &polars_dt)
-    } else {
-        Series::try_from((stream.field(), produced_arrays)).unwrap()
+        let stream = std::ptr::replace(capsule.pointer() as _, ArrowArrayStream::empty());
+        Series::from_arrow_c_stream(stream).map_err(|err| PyValueError::new_err(err.to_string()))?
     };
     Ok(PySeries::new(s))
 }
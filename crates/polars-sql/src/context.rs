@@ -2,23 +2,29 @@ use std::ops::{ControlFlow, Deref};
 
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
+use rayon::ThreadPool;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, NamedWindowDefinition,
-    NamedWindowExpr, ObjectName, ObjectType, Offset, OrderBy, Query, RenameSelectItem, Select,
-    SelectItem, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias, TableFactor,
-    TableWithJoins, UnaryOperator, Value as SQLValue, Values, Visit, Visitor,
-    WildcardAdditionalOptions, WindowSpec,
+    BinaryOperator, CreateTable, Cte, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr,
+    ExprWithAlias, FromTable, FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator,
+    NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType, Offset, OrderBy,
+    PivotValueSource, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator,
+    SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, Visit, Visitor, WildcardAdditionalOptions, WindowSpec,
+    visit_relations,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::prepared::{SQLStatement, param_name};
+#[cfg(feature = "asof_join")]
+use crate::sql_expr::interval_to_duration;
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
@@ -53,6 +59,9 @@ impl SelectModifiers {
     }
 }
 
+/// Maximum number of times the recursive term of a `WITH RECURSIVE` CTE is evaluated.
+const MAX_RECURSIVE_CTE_ITERATIONS: usize = 1000;
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
@@ -65,6 +74,10 @@ pub struct SQLContext {
     table_aliases: PlHashMap<String, String>,
     joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
     pub(crate) named_windows: PlHashMap<String, WindowSpec>,
+    /// Values bound to the parameters of a prepared statement, by parameter name.
+    pub(crate) params: PlHashMap<PlSmallStr, AnyValue<'static>>,
+    /// The thread pool on which [`SQLContext::collect`] runs queries, if not the global one.
+    thread_pool: Option<Arc<ThreadPool>>,
 }
 
 impl Default for SQLContext {
@@ -76,6 +89,8 @@ impl Default for SQLContext {
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             named_windows: Default::default(),
+            params: Default::default(),
+            thread_pool: None,
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -144,36 +159,82 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
-
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
+        let stmt = parse_single_statement(query)?;
+        self.execute_parsed(&stmt)
+    }
 
-        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+    /// Parse a SQL statement containing parameter placeholders, so that it can be executed
+    /// repeatedly with different parameter values (see [`SQLStatement`]).
+    ///
+    /// Parameters can be positional (`?`, or `$1`, `$2`, ...) or named (`:name` or
+    /// `$name`), and can be used wherever a literal value is accepted.
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register("df", df! { "a" => [1, 2, 3] }.unwrap().lazy());
+    ///
+    /// let stmt = ctx.prepare("SELECT * FROM df WHERE a > ?").unwrap();
+    /// for (n, expected_height) in [(0, 3), (2, 1)] {
+    ///     let df = stmt.execute(&mut ctx, vec![AnyValue::Int32(n)]).unwrap();
+    ///     assert_eq!(df.collect().unwrap().height(), expected_height);
+    /// }
+    /// # }
+    ///```
+    pub fn prepare(&self, query: &str) -> PolarsResult<SQLStatement> {
+        SQLStatement::new(parse_single_statement(query)?)
+    }
 
-        // Ensure the result uses the proper arenas.
-        // This will instantiate new arenas with a new version.
-        let lp_arena = std::mem::take(&mut self.lp_arena);
-        let expr_arena = std::mem::take(&mut self.expr_arena);
-        res.set_cached_arena(lp_arena, expr_arena);
+    /// Execute a parsed statement, finalising the result and resetting the statement-level state.
+    pub(crate) fn execute_parsed(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        let res = self.execute_statement(stmt);
 
         // Every execution should clear the statement-level maps.
         self.cte_map.clear();
         self.table_aliases.clear();
         self.joined_aliases.clear();
         self.named_windows.clear();
+        self.params.clear();
+
+        // Ensure the result uses the proper arenas.
+        // This will instantiate new arenas with a new version.
+        let res = res?;
+        let lp_arena = std::mem::take(&mut self.lp_arena);
+        let expr_arena = std::mem::take(&mut self.expr_arena);
+        res.set_cached_arena(lp_arena, expr_arena);
 
         Ok(res)
     }
 
+    /// Get the value bound to the given parameter placeholder (e.g. `$1` or `:name`).
+    pub(crate) fn get_param(&self, placeholder: &str) -> PolarsResult<&AnyValue<'static>> {
+        self.params.get(param_name(placeholder)).ok_or_else(|| {
+            polars_err!(
+                SQLInterface: "no value bound for parameter '{}'; parameterised statements must be executed with `SQLContext::prepare`", placeholder
+            )
+        })
+    }
+
+    /// Execute a SQL query and collect the result, on the thread pool of this SQLContext
+    /// (see [`SQLContext::with_thread_pool`]).
+    pub fn collect(&mut self, query: &str) -> PolarsResult<DataFrame> {
+        let lf = self.execute(query)?;
+        match &self.thread_pool {
+            Some(pool) => lf.collect_with_thread_pool(pool),
+            None => lf.collect(),
+        }
+    }
+
+    /// Run the queries collected with [`SQLContext::collect`] on the given thread pool, instead
+    /// of the global one; this isolates their parallelism from that of other queries.
+    pub fn with_thread_pool(mut self, pool: Arc<ThreadPool>) -> Self {
+        self.thread_pool = Some(pool);
+        self
+    }
+
     /// Add a function registry to the SQLContext.
     /// The registry provides the ability to add custom functions to the SQLContext.
     pub fn with_function_registry(mut self, function_registry: Arc<dyn FunctionRegistry>) -> Self {
@@ -360,11 +421,11 @@ impl SQLContext {
 
             #[cfg(feature = "semi_anti_join")]
             SetExpr::SetOperation {
-                op: SetOperator::Intersect | SetOperator::Except,
+                op: op @ (SetOperator::Intersect | SetOperator::Except),
                 set_quantifier,
                 left,
                 right,
-            } => self.process_except_intersect(left, right, set_quantifier, query),
+            } => self.process_except_intersect(left, right, op, set_quantifier, query),
 
             SetExpr::Values(Values {
                 explicit_row: _,
@@ -391,46 +452,83 @@ impl SQLContext {
         }
     }
 
+    /// Process `EXCEPT` and `INTERSECT` as anti/semi joins on all columns.
+    ///
+    /// Without `ALL` the result is deduplicated. With `ALL` duplicates are kept following
+    /// multiset semantics: a row that occurs `m` times on the left and `n` times on the right is
+    /// returned `min(m, n)` times by `INTERSECT ALL`, and `max(m - n, 0)` times by `EXCEPT ALL`.
+    /// Both sides are compared using the supertypes of their respective columns.
     #[cfg(feature = "semi_anti_join")]
     fn process_except_intersect(
         &mut self,
         left: &SetExpr,
         right: &SetExpr,
+        op: &SetOperator,
         quantifier: &SetQuantifier,
         query: &Query,
     ) -> PolarsResult<LazyFrame> {
-        let (join_type, op_name) = match *query.body {
-            SetExpr::SetOperation {
-                op: SetOperator::Except,
-                ..
-            } => (JoinType::Anti, "EXCEPT"),
+        let (join_type, op_name) = match op {
+            SetOperator::Except => (JoinType::Anti, "EXCEPT"),
             _ => (JoinType::Semi, "INTERSECT"),
         };
+        let (keep_all, by_name) = match quantifier {
+            SetQuantifier::Distinct | SetQuantifier::None => (false, false),
+            SetQuantifier::All => (true, false),
+            SetQuantifier::ByName | SetQuantifier::DistinctByName => (false, true),
+            SetQuantifier::AllByName => (true, true),
+        };
         let mut lf = self.process_query(left, query)?;
         let mut rf = self.process_query(right, query)?;
         let lf_schema = self.get_frame_schema(&mut lf)?;
+        let rf_schema = self.get_frame_schema(&mut rf)?;
 
-        let lf_cols: Vec<_> = lf_schema.iter_names_cloned().map(col).collect();
-        let rf_cols = match quantifier {
-            SetQuantifier::ByName => None,
-            SetQuantifier::Distinct | SetQuantifier::None => {
-                let rf_schema = self.get_frame_schema(&mut rf)?;
-                let rf_cols: Vec<_> = rf_schema.iter_names_cloned().map(col).collect();
-                if lf_cols.len() != rf_cols.len() {
-                    polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
-                }
-                Some(rf_cols)
-            },
-            _ => {
-                polars_bail!(SQLInterface: "'{} {}' is not supported", op_name, quantifier.to_string())
-            },
-        };
-        let join = lf.join_builder().with(rf).how(join_type).join_nulls(true);
-        let joined_tbl = match rf_cols {
-            Some(rf_cols) => join.left_on(lf_cols).right_on(rf_cols).finish(),
-            None => join.on(lf_cols).finish(),
+        // pair up columns by name, or positionally
+        let rf_names: Vec<PlSmallStr> = if by_name {
+            lf_schema.iter_names_cloned().collect()
+        } else {
+            if lf_schema.len() != rf_schema.len() {
+                polars_bail!(SQLInterface: "{} requires equal number of columns in each table (use '{} BY NAME' to combine mismatched tables)", op_name, op_name)
+            }
+            rf_schema.iter_names_cloned().collect()
         };
-        Ok(joined_tbl.unique(None, UniqueKeepStrategy::Any))
+        let mut left_on = Vec::with_capacity(rf_names.len() + 1);
+        let mut right_on = Vec::with_capacity(rf_names.len() + 1);
+        for ((name, dtype), rf_name) in lf_schema.iter().zip(rf_names) {
+            let rf_dtype = rf_schema.get(&rf_name).ok_or_else(|| {
+                polars_err!(SQLInterface: "{} BY NAME: column '{}' not found in the right table", op_name, rf_name)
+            })?;
+            let supertype = try_get_supertype(dtype, rf_dtype)?;
+            left_on.push(col(name.clone()).cast(supertype.clone()));
+            right_on.push(col(rf_name).cast(supertype));
+        }
+
+        // number the occurrences of every distinct row, so that each occurrence on the
+        // left only matches (at most) one occurrence on the right
+        const OCCURRENCE: PlSmallStr = PlSmallStr::from_static("__POLARS_SET_OP_OCCURRENCE");
+        if keep_all {
+            let occurrence = |keys: &[Expr]| {
+                int_range(lit(0), len(), 1, IDX_DTYPE)
+                    .over(keys)
+                    .alias(OCCURRENCE)
+            };
+            lf = lf.with_column(occurrence(&left_on));
+            rf = rf.with_column(occurrence(&right_on));
+            left_on.push(col(OCCURRENCE));
+            right_on.push(col(OCCURRENCE));
+        }
+        let joined_tbl = lf
+            .join_builder()
+            .with(rf)
+            .how(join_type)
+            .join_nulls(true)
+            .left_on(left_on)
+            .right_on(right_on)
+            .finish();
+        Ok(if keep_all {
+            joined_tbl.drop(cols([OCCURRENCE]))
+        } else {
+            joined_tbl.unique(None, UniqueKeepStrategy::Any)
+        })
     }
 
     fn process_union(
@@ -501,9 +599,21 @@ impl SQLContext {
         Ok(DataFrame::from_rows(frame_rows.as_ref())?.lazy())
     }
 
-    // EXPLAIN SELECT * FROM DF
+    // EXPLAIN [ANALYZE] SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
+            Statement::Explain {
+                statement,
+                analyze: true,
+                ..
+            } => {
+                // run the query, returning the time spent in each node (in microseconds)
+                let lf = self.execute_statement(statement)?;
+                let (_, timings) = lf.profile()?;
+                Ok(timings
+                    .lazy()
+                    .with_column((col("end") - col("start")).alias("duration")))
+            },
             Statement::Explain { statement, .. } => {
                 let lf = self.execute_statement(statement)?;
                 let plan = lf.describe_optimized_plan()?;
@@ -526,6 +636,49 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
+    // information_schema.tables, information_schema.columns
+    fn get_information_schema_table(&self, name: &ObjectName) -> PolarsResult<Option<LazyFrame>> {
+        let [schema, view] = name.0.as_slice() else {
+            return Ok(None);
+        };
+        if !schema.value.eq_ignore_ascii_case("information_schema") {
+            return Ok(None);
+        }
+        let tables = self.get_tables();
+        let df = match view.value.to_lowercase().as_str() {
+            "tables" => {
+                let table_type = vec!["BASE TABLE"; tables.len()];
+                df! {
+                    "table_name" => tables,
+                    "table_type" => table_type,
+                }?
+            },
+            "columns" => {
+                let (mut table_names, mut column_names) = (vec![], vec![]);
+                let (mut ordinal_positions, mut data_types) = (vec![], vec![]);
+                for tbl in tables {
+                    let schema = self.table_map[&tbl].clone().collect_schema()?;
+                    for (idx, (col, dtype)) in schema.iter().enumerate() {
+                        table_names.push(tbl.clone());
+                        column_names.push(col.to_string());
+                        ordinal_positions.push(idx as u32 + 1);
+                        data_types.push(dtype.to_string());
+                    }
+                }
+                df! {
+                    "table_name" => table_names,
+                    "column_name" => column_names,
+                    "ordinal_position" => ordinal_positions,
+                    "data_type" => data_types,
+                }?
+            },
+            other => {
+                polars_bail!(SQLInterface: "relation 'information_schema.{}' was not found; expected 'tables' or 'columns'", other)
+            },
+        };
+        Ok(Some(df.lazy()))
+    }
+
     // DROP TABLE <tbl>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
@@ -637,19 +790,103 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = if with.recursive && refers_to_relation(&cte.query, &cte_name) {
+                    self.execute_recursive_cte(cte)?
+                } else {
+                    let lf = self.execute_query(&cte.query)?;
+                    self.rename_columns_from_table_alias(lf, &cte.alias)?
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a self-referencing CTE of the form `<anchor> UNION [ALL] <recursive term>`.
+    ///
+    /// The anchor is evaluated first; the recursive term is then evaluated repeatedly against
+    /// the rows produced by the previous iteration, until it no longer produces new rows. With
+    /// `UNION` (rather than `UNION ALL`) rows that were already produced are discarded.
+    fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = cte.query.body.as_ref()
+        else {
+            polars_bail!(
+                SQLInterface: "recursive CTE '{}' must be of the form '<anchor> UNION [ALL] <recursive term>'",
+                cte_name
+            )
+        };
+        polars_ensure!(
+            !refers_to_relation(left.as_ref(), cte_name),
+            SQLInterface: "the anchor (non-recursive) term of recursive CTE '{}' cannot refer to '{}'",
+            cte_name, cte_name
+        );
+        let distinct = match set_quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'",
+                set_quantifier, cte_name
+            ),
+        };
+        let dedup = |df: &DataFrame| df.unique_stable(None, UniqueKeepStrategy::First, None);
+
+        let (anchor, _) = self.execute_isolated(|ctx| {
+            let lf = ctx.process_query(left, &cte.query)?;
+            ctx.rename_columns_from_table_alias(lf, &cte.alias)
+        })?;
+        let mut result = anchor.collect()?;
+        if distinct {
+            result = dedup(&result)?;
+        }
+        let schema = result.schema().clone();
+        let mut working = result.clone();
+
+        for _ in 0..MAX_RECURSIVE_CTE_ITERATIONS {
+            if working.is_empty() {
+                return Ok(result.lazy());
+            }
+            self.register_cte(cte_name, working.lazy());
+            let (lf, rf_schema) =
+                self.execute_isolated(|ctx| ctx.process_query(right, &cte.query))?;
+            if rf_schema.len() != schema.len() {
+                polars_bail!(
+                    SQLInterface: "recursive term of CTE '{}' returns {} columns; expected {}",
+                    cte_name, rf_schema.len(), schema.len()
+                )
+            }
+            // The columns (and types) of the anchor determine those of the whole CTE.
+            let exprs = rf_schema
+                .iter_names()
+                .zip(schema.iter())
+                .map(|(rf_name, (name, dtype))| {
+                    col(rf_name.clone()).cast(dtype.clone()).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            let new = lf.select(exprs).collect()?;
+
+            working = if distinct {
+                let n_rows = result.height();
+                result = dedup(&result.vstack(&new)?)?;
+                result.slice(n_rows as i64, result.height() - n_rows)
+            } else {
+                result.vstack_mut(&new)?;
+                new
+            };
+        }
+        polars_bail!(
+            SQLInterface: "recursive CTE '{}' did not complete within {} iterations",
+            cte_name, MAX_RECURSIVE_CTE_ITERATIONS
+        )
+    }
+
     fn register_named_windows(
         &mut self,
         named_windows: &[NamedWindowDefinition],
@@ -741,6 +978,24 @@ impl SQLContext {
                     JoinOperator::CrossJoin => {
                         lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)))
                     },
+                    #[cfg(feature = "asof_join")]
+                    JoinOperator::AsOf {
+                        match_condition,
+                        constraint,
+                    } => self.process_asof_join(
+                        &TableInfo {
+                            frame: lf,
+                            name: (&l_name).into(),
+                            schema: left_schema.clone(),
+                        },
+                        &TableInfo {
+                            frame: rf,
+                            name: (&r_name).into(),
+                            schema: right_schema.clone(),
+                        },
+                        match_condition,
+                        constraint,
+                    )?,
                     join_type => {
                         polars_bail!(SQLInterface: "join type '{:?}' not currently supported", join_type)
                     },
@@ -853,6 +1108,7 @@ impl SQLContext {
 
         let mut projections =
             self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
+        lf = self.process_subqueries(lf, projections.iter_mut().collect());
 
         // Apply `UNNEST` expressions
         let mut explode_names = Vec::new();
@@ -1199,6 +1455,22 @@ impl SQLContext {
                 return Ok(DataFrame::empty_with_schema(schema.as_ref()).lazy());
             }
 
+            // rewrite correlated EXISTS subqueries into semi/anti joins
+            #[cfg(feature = "semi_anti_join")]
+            let remaining;
+            #[cfg(feature = "semi_anti_join")]
+            let expr = if invert_filter {
+                expr
+            } else {
+                let (exists_lf, remaining_expr) = self.process_correlated_exists(lf, expr)?;
+                lf = exists_lf;
+                remaining = remaining_expr;
+                match &remaining {
+                    Some(expr) => expr,
+                    None => return Ok(lf),
+                }
+            };
+
             // ...otherwise parse and apply the filter as normal
             let mut filter_expression = parse_sql_expr(expr, self, Some(schema).as_deref())?;
             if filter_expression.clone().meta().has_multiple_outputs() {
@@ -1214,6 +1486,55 @@ impl SQLContext {
         Ok(lf)
     }
 
+    /// Rewrite the `[NOT] EXISTS (...)` conjuncts of a WHERE clause that are correlated with
+    /// the outer query into semi (or anti) joins, returning the remaining predicate (if any).
+    ///
+    /// A subquery is correlated if its WHERE clause equates columns of its (single) table with
+    /// columns of another table, e.g.
+    /// `EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.total > 100)`.
+    /// Uncorrelated subqueries are left in place, to be evaluated as regular expressions.
+    #[cfg(feature = "semi_anti_join")]
+    fn process_correlated_exists(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &SQLExpr,
+    ) -> PolarsResult<(LazyFrame, Option<SQLExpr>)> {
+        let mut remaining = Vec::new();
+        for conjunct in split_conjunction(expr) {
+            let SQLExpr::Exists { subquery, negated } = conjunct else {
+                remaining.push(conjunct.clone());
+                continue;
+            };
+            let Some((outer_keys, inner_query)) = decorrelate_exists(subquery) else {
+                remaining.push(conjunct.clone());
+                continue;
+            };
+            let schema = self.get_frame_schema(&mut lf)?;
+            let left_on = outer_keys
+                .iter()
+                .map(|e| parse_sql_expr(e, self, Some(schema.as_ref())))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let right_on = (0..left_on.len())
+                .map(|i| col(format_pl_smallstr!("__POLARS_EXISTS_KEY_{i}")))
+                .collect::<Vec<_>>();
+            let (rf, _) = self.execute_isolated(|ctx| ctx.execute_query_no_ctes(&inner_query))?;
+            let how = if *negated {
+                JoinType::Anti
+            } else {
+                JoinType::Semi
+            };
+            lf = lf.join(rf, left_on, right_on, JoinArgs::new(how));
+        }
+        let remaining = remaining
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            });
+        Ok((lf, remaining))
+    }
+
     pub(super) fn process_join(
         &mut self,
         tbl_left: &TableInfo,
@@ -1242,6 +1563,159 @@ impl SQLContext {
         Ok(joined)
     }
 
+    /// Translate `ASOF JOIN <tbl> MATCH_CONDITION (<cond>) [ON <equi-join>]`.
+    ///
+    /// The match condition must contain a single inequality between the left and right "asof"
+    /// columns (`>=`/`>` match backward, `<=`/`<` match forward), optionally combined (with AND)
+    /// with a tolerance on the difference between the two, such as `l.t - r.t <= INTERVAL '5m'`.
+    /// Equality conditions in the ON (or USING) clause are used as the "by" columns.
+    #[cfg(feature = "asof_join")]
+    fn process_asof_join(
+        &mut self,
+        tbl_left: &TableInfo,
+        tbl_right: &TableInfo,
+        match_condition: &SQLExpr,
+        constraint: &JoinConstraint,
+    ) -> PolarsResult<LazyFrame> {
+        use polars_ops::frame::{AsOfOptions, AsofStrategy};
+
+        let join_schema = unified_join_schema(tbl_left, tbl_right)?;
+        let parse_key = |ctx: &mut Self, e: &SQLExpr| -> PolarsResult<Expr> {
+            Ok(match parse_sql_expr(e, ctx, Some(&join_schema))? {
+                Expr::Alias(inner, _) => Arc::unwrap_or_clone(inner),
+                e => e,
+            })
+        };
+
+        let mut keys = None;
+        let mut tolerance = None;
+        for cond in split_conjunction(match_condition) {
+            let SQLExpr::BinaryOp { left, op, right } = cond else {
+                polars_bail!(SQLSyntax: "invalid ASOF JOIN match condition: {}", cond)
+            };
+            let is_literal = |e: &SQLExpr| matches!(e, SQLExpr::Value(_) | SQLExpr::Interval(_));
+            match (is_literal(left), is_literal(right)) {
+                (false, false) if keys.is_none() => {
+                    let (l, r) = determine_left_right_join_on(
+                        self,
+                        left,
+                        right,
+                        tbl_left,
+                        tbl_right,
+                        &join_schema,
+                    )?;
+                    let (l, r) = (l.into_iter().next().unwrap(), r.into_iter().next().unwrap());
+                    let swapped = l != parse_key(self, left)?;
+                    let (strategy, allow_eq) = match (op, swapped) {
+                        (BinaryOperator::GtEq, false) | (BinaryOperator::LtEq, true) => {
+                            (AsofStrategy::Backward, true)
+                        },
+                        (BinaryOperator::Gt, false) | (BinaryOperator::Lt, true) => {
+                            (AsofStrategy::Backward, false)
+                        },
+                        (BinaryOperator::LtEq, false) | (BinaryOperator::GtEq, true) => {
+                            (AsofStrategy::Forward, true)
+                        },
+                        (BinaryOperator::Lt, false) | (BinaryOperator::Gt, true) => {
+                            (AsofStrategy::Forward, false)
+                        },
+                        _ => polars_bail!(
+                            SQLSyntax: "ASOF JOIN match condition must be an inequality (>=, >, <=, <); found {}", cond
+                        ),
+                    };
+                    keys = Some((l, r, strategy, allow_eq));
+                },
+                (true, false) | (false, true) if tolerance.is_none() => {
+                    let (diff, bound) = match (op, is_literal(right)) {
+                        (BinaryOperator::LtEq, true) => (left, right),
+                        (BinaryOperator::GtEq, false) => (right, left),
+                        _ => polars_bail!(
+                            SQLSyntax: "ASOF JOIN tolerance must be given as '<difference> <= <value>'; found {}", cond
+                        ),
+                    };
+                    polars_ensure!(
+                        matches!(&**diff, SQLExpr::BinaryOp { op: BinaryOperator::Minus, .. }),
+                        SQLSyntax: "ASOF JOIN tolerance must bound the difference between the match columns; found {}", cond
+                    );
+                    tolerance = Some((&**diff, &**bound));
+                },
+                _ => polars_bail!(SQLSyntax: "invalid ASOF JOIN match condition: {}", cond),
+            }
+        }
+        let Some((left_on, right_on, strategy, allow_eq)) = keys else {
+            polars_bail!(SQLSyntax: "ASOF JOIN match condition must compare a left and a right column; found {}", match_condition)
+        };
+
+        let mut options = AsOfOptions {
+            strategy,
+            allow_eq,
+            ..Default::default()
+        };
+        if let Some((diff, bound)) = tolerance {
+            let SQLExpr::BinaryOp { left, right, .. } = diff else {
+                unreachable!()
+            };
+            let operands = [parse_key(self, left)?, parse_key(self, right)?];
+            polars_ensure!(
+                operands.contains(&left_on) && operands.contains(&right_on),
+                SQLSyntax: "ASOF JOIN tolerance must bound the difference between the match columns; found {}", diff
+            );
+            match bound {
+                SQLExpr::Interval(interval) => {
+                    let duration = interval_to_duration(interval, true)?;
+                    options.tolerance_str = Some(format_pl_smallstr!("{duration}"));
+                },
+                SQLExpr::Value(SQLValue::Number(n, _)) => {
+                    let value = match n.parse::<i64>() {
+                        Ok(n) => AnyValue::Int64(n),
+                        Err(_) => AnyValue::Float64(n.parse().map_err(
+                            |_| polars_err!(SQLSyntax: "invalid ASOF JOIN tolerance: {}", n),
+                        )?),
+                    };
+                    options.tolerance = Some(Scalar::new(value.dtype(), value));
+                },
+                _ => polars_bail!(SQLSyntax: "invalid ASOF JOIN tolerance: {}", bound),
+            }
+        }
+
+        if !matches!(constraint, JoinConstraint::None) {
+            let (left_by, right_by) =
+                process_join_constraint(constraint, tbl_left, tbl_right, self)?;
+            let to_names = |exprs: Vec<Expr>| {
+                exprs
+                    .into_iter()
+                    .map(|e| match e {
+                        Expr::Column(name) => Ok(name),
+                        e => polars_bail!(SQLInterface: "ASOF JOIN only supports column equality in the ON clause; found {}", e),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            };
+            options.left_by = Some(to_names(left_by)?);
+            options.right_by = Some(to_names(right_by)?);
+        }
+
+        // asof joins require both sides to be sorted on the match column
+        let joined = tbl_left
+            .frame
+            .clone()
+            .sort_by_exprs([left_on.clone()], Default::default())
+            .join_builder()
+            .with(
+                tbl_right
+                    .frame
+                    .clone()
+                    .sort_by_exprs([right_on.clone()], Default::default()),
+            )
+            .left_on([left_on])
+            .right_on([right_on])
+            .how(JoinType::AsOf(Box::new(options)))
+            .suffix(format!(":{}", tbl_right.name))
+            .coalesce(JoinCoalesce::KeepColumns)
+            .finish();
+
+        Ok(joined)
+    }
+
     fn process_subqueries(&self, lf: LazyFrame, exprs: Vec<&mut Expr>) -> LazyFrame {
         let mut contexts = vec![];
         for expr in exprs {
@@ -1341,6 +1815,10 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
+                if let Some(lf) = self.get_information_schema_table(name)? {
+                    let tbl_name = name.0.last().unwrap().value.clone();
+                    return self.register_table_factor_alias(tbl_name, lf, alias.as_ref());
+                }
                 let tbl_name = name.0.first().unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
                     match alias {
@@ -1442,6 +1920,28 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => self.execute_pivot(
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null.as_ref(),
+                alias.as_ref(),
+            ),
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => self.execute_unpivot(table, value, name, columns, alias.as_ref()),
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
@@ -1466,6 +1966,155 @@ impl SQLContext {
         Ok((tbl_name, lf))
     }
 
+    /// Pivot the given table, grouping on all columns that are neither pivoted nor aggregated.
+    ///
+    /// Every value in the `IN` list becomes a column holding the aggregate(s) over the rows
+    /// where the pivot column equals that value.
+    fn execute_pivot(
+        &mut self,
+        table: &TableFactor,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+        alias: Option<&TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let pivot_col = match value_column.last() {
+            Some(ident) => PlSmallStr::from_str(ident.value.as_str()),
+            None => polars_bail!(SQLSyntax: "PIVOT requires a FOR column"),
+        };
+        polars_ensure!(
+            schema.contains(&pivot_col),
+            ColumnNotFound: "PIVOT column '{}' was not found", pivot_col
+        );
+        let PivotValueSource::List(values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT currently requires an explicit list of values; found IN ({})", value_source)
+        };
+
+        let mut aggs = Vec::with_capacity(aggregate_functions.len());
+        let mut pivoted = PlHashSet::from_iter([pivot_col.clone()]);
+        for agg in aggregate_functions {
+            let expr = parse_sql_expr(&agg.expr, self, Some(&schema))?;
+            pivoted.extend(expr.clone().meta().root_names());
+            let name = match &agg.alias {
+                Some(alias) => alias.value.clone(),
+                None => agg.expr.to_string(),
+            };
+            aggs.push((expr, name));
+        }
+        let default = default_on_null
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .transpose()?;
+
+        let mut agg_exprs = Vec::with_capacity(values.len() * aggs.len());
+        for value in values {
+            let value_name = match (&value.alias, &value.expr) {
+                (Some(alias), _) => alias.value.clone(),
+                (None, SQLExpr::Value(SQLValue::SingleQuotedString(s))) => s.clone(),
+                (None, expr) => expr.to_string(),
+            };
+            let matches = col(pivot_col.clone()).eq(parse_sql_expr(&value.expr, self, None)?);
+            for (agg, agg_name) in &aggs {
+                // restrict the aggregate inputs to the rows matching this pivot value; groups
+                // without any matching rows are NULL (as opposed to an empty aggregate)
+                let expr = agg.clone().map_expr(|e| match e {
+                    Expr::Column(name) => Expr::Column(name).filter(matches.clone()),
+                    Expr::Len => col(pivot_col.clone()).filter(matches.clone()).len(),
+                    e => e,
+                });
+                let mut expr = when(matches.clone().any(true))
+                    .then(expr)
+                    .otherwise(lit(LiteralValue::untyped_null()));
+                if let Some(default) = &default {
+                    expr = expr.fill_null(default.clone());
+                }
+                let name = if aggs.len() == 1 {
+                    value_name.clone()
+                } else {
+                    format!("{value_name}_{agg_name}")
+                };
+                agg_exprs.push(expr.alias(name));
+            }
+        }
+
+        let index: Vec<Expr> = schema
+            .iter_names()
+            .filter(|name| !pivoted.contains(*name))
+            .map(|name| col(name.clone()))
+            .collect();
+        lf = if index.is_empty() {
+            lf.select(agg_exprs)
+        } else {
+            lf.group_by_stable(index).agg(agg_exprs)
+        };
+        self.register_table_factor_alias(tbl_name, lf, alias)
+    }
+
+    /// Unpivot the given columns into name/value rows; rows with a NULL value are excluded.
+    fn execute_unpivot(
+        &mut self,
+        table: &TableFactor,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+        alias: Option<&TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        let (tbl_name, mut lf) = self.get_table(table)?;
+        let schema = self.get_frame_schema(&mut lf)?;
+
+        let on: Vec<PlSmallStr> = columns
+            .iter()
+            .map(|c| PlSmallStr::from_str(c.value.as_str()))
+            .collect();
+        for c in &on {
+            polars_ensure!(
+                schema.contains(c),
+                ColumnNotFound: "UNPIVOT column '{}' was not found", c
+            );
+        }
+        let index: Vec<PlSmallStr> = schema
+            .iter_names()
+            .filter(|name| !on.contains(name))
+            .cloned()
+            .collect();
+
+        let value_name = PlSmallStr::from_str(value.value.as_str());
+        lf = lf
+            .unpivot(UnpivotArgsDSL {
+                on: Some(Selector::ByName {
+                    names: Arc::from(on),
+                    strict: true,
+                }),
+                index: Selector::ByName {
+                    names: Arc::from(index),
+                    strict: true,
+                },
+                variable_name: Some(PlSmallStr::from_str(name.value.as_str())),
+                value_name: Some(value_name.clone()),
+            })
+            .filter(col(value_name).is_not_null());
+        self.register_table_factor_alias(tbl_name, lf, alias)
+    }
+
+    fn register_table_factor_alias(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: Option<&TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
     fn process_order_by(
         &mut self,
         mut lf: LazyFrame,
@@ -1863,6 +2512,23 @@ impl SQLContext {
     }
 }
 
+fn parse_single_statement(query: &str) -> PolarsResult<Statement> {
+    let mut parser = Parser::new(&GenericDialect);
+    parser = parser.with_options(ParserOptions {
+        trailing_commas: true,
+        ..Default::default()
+    });
+
+    let mut ast = parser
+        .try_with_sql(query)
+        .map_err(to_sql_interface_err)?
+        .parse_statements()
+        .map_err(to_sql_interface_err)?;
+
+    polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
+    Ok(ast.pop().unwrap())
+}
+
 fn expand_exprs(expr: Expr, schema: &SchemaRef) -> Vec<Expr> {
     match expr {
         Expr::Column(nm) if is_regex_colname(nm.as_str()) => {
@@ -1907,6 +2573,117 @@ impl<'a> Visitor for FindTableIdentifier<'a> {
     }
 }
 
+/// Split a predicate into its top-level `AND` conjuncts.
+#[cfg(any(feature = "semi_anti_join", feature = "asof_join"))]
+fn split_conjunction(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut conjuncts = split_conjunction(left);
+            conjuncts.extend(split_conjunction(right));
+            conjuncts
+        },
+        SQLExpr::Nested(expr) => split_conjunction(expr),
+        expr => vec![expr],
+    }
+}
+
+/// Split the WHERE clause of a correlated `EXISTS` subquery into the outer expressions it is
+/// correlated on and a query that selects the matching inner expressions (as the columns
+/// `__POLARS_EXISTS_KEY_<i>`) under the remaining predicates.
+///
+/// Returns `None` if the subquery is not a correlated `SELECT` from a single table.
+#[cfg(feature = "semi_anti_join")]
+fn decorrelate_exists(subquery: &Query) -> Option<(Vec<SQLExpr>, Query)> {
+    let SetExpr::Select(select) = subquery.body.as_ref() else {
+        return None;
+    };
+    let [from] = select.from.as_slice() else {
+        return None;
+    };
+    let inner_name = match &from.relation {
+        TableFactor::Table { name, alias, .. } if from.joins.is_empty() => alias
+            .as_ref()
+            .map(|alias| alias.name.value.as_str())
+            .or_else(|| name.0.last().map(|ident| ident.value.as_str()))?,
+        _ => return None,
+    };
+    let qualifier = |e: &SQLExpr| match e {
+        SQLExpr::CompoundIdentifier(idents) if idents.len() == 2 => Some(idents[0].value.clone()),
+        _ => None,
+    };
+
+    let mut outer_keys = Vec::new();
+    let mut inner_keys = Vec::new();
+    let mut predicates = Vec::new();
+    for conjunct in split_conjunction(select.selection.as_ref()?) {
+        if let SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } = conjunct
+        {
+            match (qualifier(left), qualifier(right)) {
+                (Some(l), Some(r)) if l == inner_name && r != inner_name => {
+                    inner_keys.push(left.as_ref().clone());
+                    outer_keys.push(right.as_ref().clone());
+                    continue;
+                },
+                (Some(l), Some(r)) if r == inner_name && l != inner_name => {
+                    inner_keys.push(right.as_ref().clone());
+                    outer_keys.push(left.as_ref().clone());
+                    continue;
+                },
+                _ => {},
+            }
+        }
+        predicates.push(conjunct.clone());
+    }
+    if outer_keys.is_empty() {
+        return None;
+    }
+
+    let mut select = select.as_ref().clone();
+    select.distinct = None;
+    select.projection = inner_keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, expr)| SelectItem::ExprWithAlias {
+            expr,
+            alias: Ident::new(format!("__POLARS_EXISTS_KEY_{i}")),
+        })
+        .collect();
+    select.selection = predicates
+        .into_iter()
+        .reduce(|left, right| SQLExpr::BinaryOp {
+            left: Box::new(left),
+            op: BinaryOperator::And,
+            right: Box::new(right),
+        });
+    // note: ordering and (non-zero) limits do not affect whether any row exists
+    let mut query = subquery.clone();
+    query.body = Box::new(SetExpr::Select(Box::new(select)));
+    query.order_by = None;
+    query.limit = None;
+    query.offset = None;
+    Some((outer_keys, query))
+}
+
+/// Check if a query (or set expression) reads from a table or CTE with the given name.
+fn refers_to_relation<V: Visit>(query: &V, name: &str) -> bool {
+    visit_relations(query, |relation| {
+        if relation.0.last().is_some_and(|ident| ident.value == name) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_break()
+}
+
 /// Check if all columns referred to in a Polars expression exist in the given Schema.
 fn expr_cols_all_in_schema(expr: &Expr, schema: &Schema) -> bool {
     let mut found_cols = false;
@@ -2017,6 +2794,21 @@ fn determine_left_right_join_on(
     }
 }
 
+/// Establish a unified schema with cols from both tables; needed for multi/chained
+/// joins where suffixed intermediary/joined cols aren't in an existing schema.
+fn unified_join_schema(tbl_left: &TableInfo, tbl_right: &TableInfo) -> PolarsResult<Schema> {
+    let mut join_schema = Schema::with_capacity(tbl_left.schema.len() + tbl_right.schema.len());
+    for (name, dtype) in tbl_left.schema.iter() {
+        join_schema.insert_at_index(join_schema.len(), name.clone(), dtype.clone())?;
+    }
+    for (name, dtype) in tbl_right.schema.iter() {
+        if !join_schema.contains(name) {
+            join_schema.insert_at_index(join_schema.len(), name.clone(), dtype.clone())?;
+        }
+    }
+    Ok(join_schema)
+}
+
 fn process_join_on(
     ctx: &mut SQLContext,
     sql_expr: &SQLExpr,
@@ -2033,22 +2825,7 @@ fn process_join_on(
                 Ok((left_i, right_i))
             },
             BinaryOperator::Eq => {
-                // establish unified schema with cols from both tables; needed for multi/chained
-                // joins where suffixed intermediary/joined cols aren't in an existing schema.
-                let mut join_schema =
-                    Schema::with_capacity(tbl_left.schema.len() + tbl_right.schema.len());
-                for (name, dtype) in tbl_left.schema.iter() {
-                    join_schema.insert_at_index(join_schema.len(), name.clone(), dtype.clone())?;
-                }
-                for (name, dtype) in tbl_right.schema.iter() {
-                    if !join_schema.contains(name) {
-                        join_schema.insert_at_index(
-                            join_schema.len(),
-                            name.clone(),
-                            dtype.clone(),
-                        )?;
-                    }
-                }
+                let join_schema = unified_join_schema(tbl_left, tbl_right)?;
                 determine_left_right_join_on(ctx, left, right, tbl_left, tbl_right, &join_schema)
             },
             _ => polars_bail!(
@@ -0,0 +1,183 @@
+//! A handler for [Arrow Flight SQL] statement queries against the tables registered in a
+//! [`SQLContext`].
+//!
+//! [`FlightSqlHandler`] implements the statement query flow of Flight SQL: a client sends a
+//! `CommandStatementQuery` in the descriptor of a `GetFlightInfo` call and receives a
+//! [`FlightInfo`] holding the result schema and a ticket, which it then redeems with `DoGet`
+//! to stream the result batches.
+//!
+//! The handler is transport-agnostic: it consumes and produces the Flight protobuf messages,
+//! so that it can be plugged into any gRPC `FlightService` implementation by delegating the
+//! `get_flight_info` and `do_get` calls to it. This module does not provide the gRPC server
+//! itself.
+//!
+//! [Arrow Flight SQL]: https://arrow.apache.org/docs/format/FlightSql.html
+use std::sync::Mutex;
+
+use arrow::io::ipc::write::{
+    DictionaryTracker, EncodedData, WriteOptions, default_ipc_fields, encode_chunk, schema_to_bytes,
+};
+use arrow_format::flight::data::{
+    FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, Ticket,
+};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_lazy::prelude::*;
+use prost::Message;
+
+use crate::SQLContext;
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/arrow.flight.protocol.sql.";
+
+/// `google.protobuf.Any`, in which all Flight SQL commands are wrapped.
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+impl Any {
+    fn pack<M: Message>(name: &str, msg: &M) -> Self {
+        Self {
+            type_url: format!("{TYPE_URL_PREFIX}{name}"),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn unpack<M: Message + Default>(bytes: &[u8], name: &str) -> PolarsResult<M> {
+        let any = Any::decode(bytes).map_err(to_compute_err)?;
+        let type_name = any.type_url.strip_prefix(TYPE_URL_PREFIX);
+        polars_ensure!(
+            type_name == Some(name),
+            InvalidOperation: "unsupported Flight SQL command '{}'; expected '{}'", any.type_url, name
+        );
+        M::decode(any.value.as_slice()).map_err(to_compute_err)
+    }
+}
+
+/// Flight SQL `CommandStatementQuery`: execute an ad-hoc SQL query.
+#[derive(Clone, PartialEq, Message)]
+struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    query: String,
+    #[prost(bytes = "vec", optional, tag = "2")]
+    transaction_id: Option<Vec<u8>>,
+}
+
+/// Flight SQL `TicketStatementQuery`: the ticket used to fetch the results of a query.
+#[derive(Clone, PartialEq, Message)]
+struct TicketStatementQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    statement_handle: Vec<u8>,
+}
+
+/// Answers Flight SQL statement queries against a [`SQLContext`].
+///
+/// The statement handle in the returned tickets is the query itself, so tickets can be
+/// redeemed any number of times, by any handler serving the same tables.
+pub struct FlightSqlHandler {
+    ctx: Mutex<SQLContext>,
+}
+
+impl FlightSqlHandler {
+    /// Serve the tables registered in the given context.
+    pub fn new(ctx: SQLContext) -> Self {
+        Self {
+            ctx: Mutex::new(ctx),
+        }
+    }
+
+    fn execute(&self, query: &str) -> PolarsResult<LazyFrame> {
+        // A panic while planning an earlier query poisons the lock, report it instead of
+        // panicking on every later request.
+        let mut ctx = self.ctx.lock().map_err(
+            |_| polars_err!(ComputeError: "the SQL context of the Flight SQL handler is poisoned"),
+        )?;
+        ctx.execute(query)
+    }
+
+    /// Handle a `GetFlightInfo` request for a `CommandStatementQuery`.
+    ///
+    /// The query is planned (but not executed) to determine the schema of its result.
+    pub fn get_flight_info(&self, descriptor: &FlightDescriptor) -> PolarsResult<FlightInfo> {
+        let cmd: CommandStatementQuery = Any::unpack(&descriptor.cmd, "CommandStatementQuery")?;
+        polars_ensure!(
+            cmd.transaction_id.is_none(),
+            InvalidOperation: "Flight SQL transactions are not supported"
+        );
+        let schema = self.execute(&cmd.query)?.collect_schema()?;
+        let schema = schema.to_arrow(CompatLevel::oldest());
+        let ipc_fields = default_ipc_fields(schema.iter_values());
+
+        let ticket = Any::pack(
+            "TicketStatementQuery",
+            &TicketStatementQuery {
+                statement_handle: cmd.query.into_bytes(),
+            },
+        );
+        Ok(FlightInfo {
+            schema: encapsulate_message(schema_to_bytes(&schema, &ipc_fields, None)),
+            flight_descriptor: Some(descriptor.clone()),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket {
+                    ticket: ticket.encode_to_vec(),
+                }),
+                ..Default::default()
+            }],
+            total_records: -1,
+            total_bytes: -1,
+            ..Default::default()
+        })
+    }
+
+    /// Handle a `DoGet` request for a ticket returned by [`FlightSqlHandler::get_flight_info`].
+    ///
+    /// Returns the messages to stream to the client: the schema, followed by one message per
+    /// record batch (preceded by any dictionaries it uses).
+    pub fn do_get(&self, ticket: &Ticket) -> PolarsResult<Vec<FlightData>> {
+        let ticket: TicketStatementQuery = Any::unpack(&ticket.ticket, "TicketStatementQuery")?;
+        let query = String::from_utf8(ticket.statement_handle).map_err(to_compute_err)?;
+        let mut df = self.execute(&query)?.collect()?;
+        dataframe_to_flight_data(&mut df)
+    }
+}
+
+/// Prefix an IPC message with the continuation marker and its (padded) length.
+fn encapsulate_message(mut message: Vec<u8>) -> Vec<u8> {
+    message.resize(message.len().next_multiple_of(8), 0);
+    let mut out = Vec::with_capacity(message.len() + 8);
+    out.extend_from_slice(&[0xff; 4]);
+    out.extend_from_slice(&(message.len() as i32).to_le_bytes());
+    out.extend_from_slice(&message);
+    out
+}
+
+fn dataframe_to_flight_data(df: &mut DataFrame) -> PolarsResult<Vec<FlightData>> {
+    let schema = df.schema().to_arrow(CompatLevel::oldest());
+    let ipc_fields = default_ipc_fields(schema.iter_values());
+    let mut out = vec![FlightData {
+        data_header: schema_to_bytes(&schema, &ipc_fields, None),
+        ..Default::default()
+    }];
+
+    let mut dictionary_tracker = DictionaryTracker {
+        dictionaries: Default::default(),
+        cannot_replace: false,
+    };
+    let options = WriteOptions { compression: None };
+    let to_flight_data = |encoded: EncodedData| FlightData {
+        data_header: encoded.ipc_message,
+        data_body: encoded.arrow_data,
+        ..Default::default()
+    };
+    df.align_chunks();
+    for batch in df.iter_chunks(CompatLevel::oldest(), false) {
+        let (dictionaries, message) =
+            encode_chunk(&batch, &ipc_fields, &mut dictionary_tracker, &options)?;
+        out.extend(dictionaries.into_iter().map(to_flight_data));
+        out.push(to_flight_data(message));
+    }
+    Ok(out)
+}
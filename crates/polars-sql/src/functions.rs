@@ -2,10 +2,11 @@ use std::ops::{Add, Sub};
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, ExplodeOptions, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail,
-    polars_err,
+    DataType, ExplodeOptions, IDX_DTYPE, PolarsResult, QuantileMethod, Schema, TimeUnit,
+    polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
+use polars_lazy::prelude::{FrameAggregation, FrameBound, WindowFrame as RowsFrame};
 #[cfg(feature = "rank")]
 use polars_lazy::prelude::{RankMethod, RankOptions};
 use polars_ops::chunked_array::UnicodeForm;
@@ -26,7 +27,9 @@ use sqlparser::ast::{
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -296,12 +299,25 @@ pub(crate) enum PolarsSQLFunctions {
     // ----
     // Temporal functions
     // ----
+    /// SQL 'date_add' function.
+    /// Adds an interval, or a number of units, to a date (or datetime).
+    /// ```sql
+    /// SELECT DATE_ADD(col1, INTERVAL '3 days') FROM df;
+    /// SELECT DATE_ADD('month', col2, col1) FROM df;
+    /// ```
+    DateAdd,
     /// SQL 'date_part' function.
     /// Extracts a part of a date (or datetime) such as 'year', 'month', etc.
     /// ```sql
     /// SELECT DATE_PART('year', col1) FROM df;
     /// SELECT DATE_PART('day', col1) FROM df;
     DatePart,
+    /// SQL 'date_trunc' function.
+    /// Truncates a date (or datetime) to the given unit, such as 'month' or 'hour'.
+    /// ```sql
+    /// SELECT DATE_TRUNC('month', col1) FROM df;
+    /// ```
+    DateTrunc,
     /// SQL 'strftime' function.
     /// Converts a datetime to a string using a format string.
     /// ```sql
@@ -346,6 +362,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT col2 from df WHERE ENDS_WITH(col1, 'a');
     /// ```
     EndsWith,
+    /// SQL 'format' function.
+    /// Returns the arguments formatted according to the format string, where
+    /// each `%s` is replaced by the next argument.
+    /// ```sql
+    /// SELECT FORMAT('%s has %s items', col1, col2) FROM df;
+    /// ```
+    Format,
     /// SQL 'initcap' function.
     /// Returns the value with the first letter capitalized.
     /// ```sql
@@ -371,6 +394,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT LOWER(col1) FROM df;
     /// ```
     Lower,
+    /// SQL 'lpad' function.
+    /// Pad the start of the string with `fill` (default: space) to the given length,
+    /// truncating strings that are longer.
+    /// ```sql
+    /// SELECT LPAD(col1, 5, '0') FROM df;
+    /// ```
+    LPad,
     /// SQL 'ltrim' function.
     /// Strip whitespaces from the left.
     /// ```sql
@@ -396,6 +426,19 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT REGEXP_LIKE(col1, 'xyz', 'i') FROM df;
     /// ```
     RegexpLike,
+    /// SQL 'regexp_extract' function.
+    /// Returns the first match of `pattern` (optional: the given capture `group`).
+    /// ```sql
+    /// SELECT REGEXP_EXTRACT(col1, '(\d+)-(\d+)', 2) FROM df;
+    /// ```
+    RegexpExtract,
+    /// SQL 'regexp_replace' function.
+    /// Replace the first match of `pattern` with `replacement` (optional: `flags`,
+    /// where 'g' replaces all matches).
+    /// ```sql
+    /// SELECT REGEXP_REPLACE(col1, '\s+', ' ', 'g') FROM df;
+    /// ```
+    RegexpReplace,
     /// SQL 'replace' function.
     /// Replace a given substring with another string.
     /// ```sql
@@ -414,6 +457,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT RIGHT(col1, 3) FROM df;
     /// ```
     Right,
+    /// SQL 'rpad' function.
+    /// Pad the end of the string with `fill` (default: space) to the given length,
+    /// truncating strings that are longer.
+    /// ```sql
+    /// SELECT RPAD(col1, 5, '.') FROM df;
+    /// ```
+    RPad,
     /// SQL 'rtrim' function.
     /// Strip whitespaces from the right.
     /// ```sql
@@ -477,6 +527,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT TIMESTAMP('2021-15-03T00:01:02.333', '%Y-d%-%m %H:%M:%S') FROM df;
     /// ```
     Timestamp,
+    /// SQL 'translate' function.
+    /// Replace every character in `from` with the corresponding character in `to`;
+    /// characters without a counterpart in `to` are removed.
+    /// ```sql
+    /// SELECT TRANSLATE(col1, 'abc', 'xy') FROM df;
+    /// ```
+    Translate,
     /// SQL 'upper' function.
     /// Returns an uppercased column.
     /// ```sql
@@ -742,6 +799,21 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     #[cfg(feature = "rank")]
     DenseRank,
+    /// SQL 'percent_rank' function.
+    /// Returns the relative rank `(rank - 1) / (rows - 1)` of each row within a window partition.
+    /// ```sql
+    /// SELECT PERCENT_RANK() OVER (ORDER BY col1) FROM df;
+    /// ```
+    #[cfg(feature = "rank")]
+    PercentRank,
+    /// SQL 'ntile' function.
+    /// Divides the ordered rows of a window partition into `n` buckets of (nearly) equal size
+    /// and returns the 1-based bucket of each row.
+    /// ```sql
+    /// SELECT NTILE(4) OVER (PARTITION BY col1 ORDER BY col2) FROM df;
+    /// ```
+    #[cfg(feature = "rank")]
+    NTile,
 
     // ----
     // Column selection
@@ -817,7 +889,9 @@ impl PolarsSQLFunctions {
             "covar_pop",
             "covar_samp",
             "date",
+            "date_add",
             "date_part",
+            "date_trunc",
             "degrees",
             "dense_rank",
             "ends_with",
@@ -825,6 +899,7 @@ impl PolarsSQLFunctions {
             "first",
             "first_value",
             "floor",
+            "format",
             "greatest",
             "if",
             "ifnull",
@@ -842,14 +917,17 @@ impl PolarsSQLFunctions {
             "log1p",
             "log2",
             "lower",
+            "lpad",
             "ltrim",
             "max",
             "median",
             "quantile_disc",
             "min",
             "mod",
+            "ntile",
             "nullif",
             "octet_length",
+            "percent_rank",
             "pi",
             "pow",
             "power",
@@ -857,12 +935,15 @@ impl PolarsSQLFunctions {
             "quantile_disc",
             "radians",
             "rank",
+            "regexp_extract",
             "regexp_like",
+            "regexp_replace",
             "replace",
             "reverse",
             "right",
             "round",
             "row_number",
+            "rpad",
             "rtrim",
             "sign",
             "sin",
@@ -880,6 +961,7 @@ impl PolarsSQLFunctions {
             "sum",
             "tan",
             "tand",
+            "translate",
             "unnest",
             "upper",
             "var",
@@ -959,7 +1041,9 @@ impl PolarsSQLFunctions {
             // ----
             // Date functions
             // ----
+            "date_add" | "dateadd" => Self::DateAdd,
             "date_part" => Self::DatePart,
+            "date_trunc" => Self::DateTrunc,
             "strftime" => Self::Strftime,
 
             // ----
@@ -968,6 +1052,7 @@ impl PolarsSQLFunctions {
             "bit_length" => Self::BitLength,
             "concat" => Self::Concat,
             "concat_ws" => Self::ConcatWS,
+            "format" => Self::Format,
             "date" => Self::Date,
             "timestamp" | "datetime" => Self::Timestamp,
             "ends_with" => Self::EndsWith,
@@ -976,14 +1061,18 @@ impl PolarsSQLFunctions {
             "length" | "char_length" | "character_length" => Self::Length,
             "left" => Self::Left,
             "lower" => Self::Lower,
+            "lpad" => Self::LPad,
             "ltrim" => Self::LTrim,
             "normalize" => Self::Normalize,
             "octet_length" => Self::OctetLength,
             "strpos" => Self::StrPos,
+            "regexp_extract" => Self::RegexpExtract,
             "regexp_like" => Self::RegexpLike,
+            "regexp_replace" => Self::RegexpReplace,
             "replace" => Self::Replace,
             "reverse" => Self::Reverse,
             "right" => Self::Right,
+            "rpad" => Self::RPad,
             "rtrim" => Self::RTrim,
             "split_part" => Self::SplitPart,
             "starts_with" => Self::StartsWith,
@@ -991,6 +1080,7 @@ impl PolarsSQLFunctions {
             "strptime" => Self::Strptime,
             "substr" => Self::Substring,
             "time" => Self::Time,
+            "translate" => Self::Translate,
             "upper" => Self::Upper,
 
             // ----
@@ -1036,6 +1126,10 @@ impl PolarsSQLFunctions {
             "first_value" => Self::FirstValue,
             "last_value" => Self::LastValue,
             #[cfg(feature = "rank")]
+            "ntile" => Self::NTile,
+            #[cfg(feature = "rank")]
+            "percent_rank" => Self::PercentRank,
+            #[cfg(feature = "rank")]
             "rank" => Self::Rank,
             "row_number" => Self::RowNumber,
 
@@ -1197,6 +1291,43 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Date functions
             // ----
+            DateAdd => {
+                let args = extract_args(function)?;
+                match args.as_slice() {
+                    [
+                        FunctionArgExpr::Expr(sql_expr),
+                        FunctionArgExpr::Expr(SQLExpr::Interval(interval)),
+                    ] => {
+                        let duration = interval_to_duration(interval, false)?;
+                        let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
+                        Ok(expr.dt().offset_by(lit(format!("{duration}"))))
+                    },
+                    [
+                        FunctionArgExpr::Expr(unit),
+                        FunctionArgExpr::Expr(n),
+                        FunctionArgExpr::Expr(sql_expr),
+                    ] => {
+                        // note: the unit can be given as a string or as a bare keyword
+                        let unit = match unit {
+                            SQLExpr::Identifier(ident) => ident.value.clone(),
+                            SQLExpr::Value(SQLValue::SingleQuotedString(s)) => s.clone(),
+                            _ => polars_bail!(SQLSyntax: "invalid 'unit' for DATE_ADD ({})", unit),
+                        };
+                        let (multiple, unit) = parse_date_unit(&unit, "DATE_ADD")?;
+                        let mut n = parse_sql_expr(n, self.ctx, self.active_schema)?;
+                        if multiple != 1 {
+                            n = n * lit(multiple);
+                        }
+                        let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
+                        let offset = concat_str([n.cast(DataType::String), lit(unit)], "", false);
+                        Ok(expr.dt().offset_by(offset))
+                    },
+                    _ => polars_bail!(
+                        SQLSyntax: "DATE_ADD expects a date and an interval, or a unit, a count and a date (found {} arguments)",
+                        args.len()
+                    ),
+                }
+            },
             DatePart => self.try_visit_binary(|part, e| {
                 match part {
                     Expr::Literal(p) if p.extract_str().is_some() => {
@@ -1217,6 +1348,27 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             }),
+            DateTrunc => self.try_visit_binary(|part, e| match part {
+                Expr::Literal(p) if p.extract_str().is_some() => {
+                    let (multiple, unit) = parse_date_unit(p.extract_str().unwrap(), "DATE_TRUNC")?;
+                    if unit == "y" && multiple >= 100 {
+                        // Centuries and millennia start at a year ending in 1 (the 21st century
+                        // starts at 2001-01-01), while `truncate` anchors them at 1970.
+                        let year = e.clone().dt().year();
+                        let start = (year.clone() - lit(1)).floor_div(lit(multiple))
+                            * lit(multiple)
+                            + lit(1);
+                        let offset = concat_str(
+                            [(start - year).cast(DataType::String), lit("y")],
+                            "",
+                            false,
+                        );
+                        return Ok(e.dt().truncate(lit("1y")).dt().offset_by(offset));
+                    }
+                    Ok(e.dt().truncate(lit(format!("{multiple}{unit}"))))
+                },
+                _ => polars_bail!(SQLSyntax: "invalid 'part' for DATE_TRUNC ({})", part),
+            }),
             Strftime => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1263,6 +1415,19 @@ impl SQLFunctionVisitor<'_> {
                 }
             },
             EndsWith => self.visit_binary(|e, s| e.str().ends_with(s)),
+            Format => {
+                let args = extract_args(function)?;
+                if args.is_empty() {
+                    polars_bail!(SQLSyntax: "FORMAT expects at least 1 argument (found 0)");
+                }
+                self.try_visit_variadic(|exprs: &[Expr]| {
+                    let Some(fmt) = extract_str_literal(&exprs[0]) else {
+                        polars_bail!(SQLSyntax: "FORMAT 'format' must be a literal string (found {:?})", exprs[0]);
+                    };
+                    format_str(&sql_format_to_polars(fmt)?, &exprs[1..])
+                        .map_err(|_| polars_err!(SQLSyntax: "FORMAT string has {} placeholder(s) but {} argument(s) were given", fmt.matches("%s").count(), exprs.len() - 1))
+                })
+            },
             #[cfg(feature = "nightly")]
             InitCap => self.visit_unary(|e| e.str().to_titlecase()),
             Left => self.try_visit_binary(|e, length| {
@@ -1290,6 +1455,34 @@ impl SQLFunctionVisitor<'_> {
             }),
             Length => self.visit_unary(|e| e.str().len_chars()),
             Lower => self.visit_unary(|e| e.str().to_lowercase()),
+            LPad | RPad => {
+                let args = extract_args(function)?;
+                let is_lpad = matches!(function_name, LPad);
+                let name = if is_lpad { "LPAD" } else { "RPAD" };
+                if !(2..=3).contains(&args.len()) {
+                    polars_bail!(SQLSyntax: "{} expects 2-3 arguments (found {})", name, args.len())
+                }
+                self.try_visit_variadic(|exprs: &[Expr]| {
+                    let fill_char = match exprs.get(2) {
+                        None => ' ',
+                        Some(fill) => {
+                            let mut chars = extract_str_literal(fill).map(|s| s.chars());
+                            match chars.as_mut().map(|c| (c.next(), c.next())) {
+                                Some((Some(c), None)) => c,
+                                _ => polars_bail!(SQLSyntax: "{} 'fill' must be a single-character literal string (found {:?})", name, fill),
+                            }
+                        },
+                    };
+                    let (e, length) = (exprs[0].clone(), exprs[1].clone());
+                    let padded = if is_lpad {
+                        e.str().pad_start(length.clone(), fill_char)
+                    } else {
+                        e.str().pad_end(length.clone(), fill_char)
+                    };
+                    // note: strings longer than the given length are truncated
+                    Ok(padded.str().slice(lit(0), length))
+                })
+            },
             LTrim => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1363,6 +1556,56 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "REGEXP_LIKE expects 2-3 arguments (found {})",args.len()),
                 }
             },
+            RegexpExtract => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    2 => self.visit_binary(|e, pat| e.str().extract(pat, 0)),
+                    3 => self.try_visit_ternary(|e, pat, group: Expr| match group {
+                        Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => {
+                            Ok(e.str().extract(pat, n as usize))
+                        },
+                        _ => {
+                            polars_bail!(SQLSyntax: "invalid 'group' for REGEXP_EXTRACT ({})", args[2])
+                        },
+                    }),
+                    _ => {
+                        polars_bail!(SQLSyntax: "REGEXP_EXTRACT expects 2-3 arguments (found {})", args.len())
+                    },
+                }
+            },
+            RegexpReplace => {
+                let args = extract_args(function)?;
+                if !(3..=4).contains(&args.len()) {
+                    polars_bail!(SQLSyntax: "REGEXP_REPLACE expects 3-4 arguments (found {})", args.len())
+                }
+                self.try_visit_variadic(|exprs: &[Expr]| {
+                    let (e, mut pat, mut value) = (exprs[0].clone(), exprs[1].clone(), exprs[2].clone());
+                    // use polars-style references to capture groups (eg: '\1' → '${1}')
+                    if let Some(v) = extract_str_literal(&value) {
+                        let re = regex::Regex::new(r"\\(\d+)").unwrap();
+                        value = lit(re.replace_all(v, "$${$1}").into_owned());
+                    }
+                    let mut replace_all = false;
+                    if let Some(flags) = exprs.get(3) {
+                        let Some(flags) = extract_str_literal(flags) else {
+                            polars_bail!(SQLSyntax: "invalid 'flags' for REGEXP_REPLACE ({})", args[3]);
+                        };
+                        replace_all = flags.contains('g');
+                        let flags = flags.replace('g', "");
+                        if !flags.is_empty() {
+                            let Some(p) = extract_str_literal(&pat) else {
+                                polars_bail!(SQLSyntax: "REGEXP_REPLACE 'pattern' must be a literal string when 'flags' are given ({})", args[1]);
+                            };
+                            pat = lit(format!("(?{flags}){p}"));
+                        }
+                    }
+                    Ok(if replace_all {
+                        e.str().replace_all(pat, value, false)
+                    } else {
+                        e.str().replace(pat, value, false)
+                    })
+                })
+            },
             Replace => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1463,6 +1706,35 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             },
+            Translate => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    3 => self.try_visit_ternary(|e, from: String, to: String| {
+                        let to = to.chars().collect::<Vec<_>>();
+                        let (mut patterns, mut replacements) = (vec![], vec![]);
+                        for (i, c) in from.chars().enumerate() {
+                            // the first occurrence of a character takes precedence
+                            if !patterns.iter().any(|p: &String| p.starts_with(c)) {
+                                patterns.push(c.to_string());
+                                replacements
+                                    .push(to.get(i).map_or_else(String::new, |c| c.to_string()));
+                            }
+                        }
+                        if patterns.is_empty() {
+                            return Ok(e);
+                        }
+                        Ok(e.str().replace_many(
+                            lit(Series::new(PlSmallStr::EMPTY, patterns)),
+                            lit(Series::new(PlSmallStr::EMPTY, replacements)),
+                            false,
+                            false,
+                        ))
+                    }),
+                    _ => {
+                        polars_bail!(SQLSyntax: "TRANSLATE expects 3 arguments (found {})", args.len())
+                    },
+                }
+            },
             Time => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1530,14 +1802,25 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_cumulative(
+                Expr::mean,
+                |e, _| {
+                    e.rolling_frame(
+                        FrameAggregation::Mean,
+                        RowsFrame::new(FrameBound::UnboundedPreceding, FrameBound::CurrentRow),
+                    )
+                },
+                |e, frame| e.rolling_frame(FrameAggregation::Mean, frame),
+            ),
             Corr => self.visit_binary(polars_lazy::dsl::pearson_corr),
             Count => self.visit_count(),
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max, |e, frame| {
+                e.rolling_frame(FrameAggregation::Max, frame)
+            }),
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
                 let args = extract_args(function)?;
@@ -1591,9 +1874,13 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "QUANTILE_DISC expects 2 arguments (found {})", args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min, |e, frame| {
+                e.rolling_frame(FrameAggregation::Min, frame)
+            }),
             StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
+            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum, |e, frame| {
+                e.rolling_frame(FrameAggregation::Sum, frame)
+            }),
             Variance => self.visit_unary(|e| e.var(1)),
 
             // ----
@@ -1695,33 +1982,44 @@ impl SQLFunctionVisitor<'_> {
                 if !args.is_empty() {
                     polars_bail!(SQLSyntax: "{} expects 0 arguments (found {})", func_name, args.len());
                 }
-                let window_spec = match &self.func.over {
-                    Some(WindowType::WindowSpec(spec)) if !spec.order_by.is_empty() => spec,
-                    _ => {
-                        polars_bail!(SQLSyntax: "{} requires an OVER clause with ORDER BY", func_name)
+                let (order_expr, all_desc) = self.window_rank_order(func_name)?;
+                let rank_expr = order_expr.rank(
+                    RankOptions {
+                        method: rank_method,
+                        descending: all_desc,
                     },
-                };
-                let (order_exprs, all_desc) =
-                    self.parse_order_by_in_window(&window_spec.order_by)?;
-                let rank_expr = if order_exprs.len() == 1 {
-                    order_exprs[0].clone().rank(
-                        RankOptions {
-                            method: rank_method,
-                            descending: all_desc,
-                        },
-                        None,
-                    )
-                } else {
-                    as_struct(order_exprs).rank(
-                        RankOptions {
-                            method: rank_method,
-                            descending: all_desc,
-                        },
-                        None,
-                    )
-                };
+                    None,
+                );
                 self.apply_window_spec(rank_expr, &self.func.over)
             },
+            #[cfg(feature = "rank")]
+            PercentRank => {
+                let args = extract_args(function)?;
+                if !args.is_empty() {
+                    polars_bail!(SQLSyntax: "PERCENT_RANK expects 0 arguments (found {})", args.len());
+                }
+                let (order_expr, all_desc) = self.window_rank_order("PERCENT_RANK")?;
+                self.apply_window_spec(order_expr.percent_rank(all_desc), &self.func.over)
+            },
+            #[cfg(feature = "rank")]
+            NTile => {
+                let args = extract_args(function)?;
+                let n = match args.as_slice() {
+                    [FunctionArgExpr::Expr(sql_expr)] => {
+                        match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+                            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n > 0 => {
+                                n as usize
+                            },
+                            n => polars_bail!(
+                                SQLSyntax: "NTILE expects a positive integer number of buckets (found {:?})", n
+                            ),
+                        }
+                    },
+                    _ => polars_bail!(SQLSyntax: "NTILE expects 1 argument (found {})", args.len()),
+                };
+                let (order_expr, all_desc) = self.window_rank_order("NTILE")?;
+                self.apply_window_spec(order_expr.ntile(n, all_desc), &self.func.over)
+            },
             RowNumber => {
                 let args = extract_args(function)?;
                 if !args.is_empty() {
@@ -1797,10 +2095,10 @@ impl SQLFunctionVisitor<'_> {
             .call(args))
     }
 
-    /// Validate window frame specifications.
+    /// Validate window frame specifications for functions that do not support custom frames.
     ///
-    /// Polars only supports ROWS frame semantics, and does
-    /// not currently support customising the window.
+    /// Polars only supports ROWS frame semantics; aggregate functions accept any ROWS
+    /// frame (see `parse_rows_frame`), other window functions only the default frame.
     ///
     /// **Supported Frame Spec**
     /// - `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`
@@ -1851,6 +2149,69 @@ impl SQLFunctionVisitor<'_> {
         Ok(())
     }
 
+    /// Parse a ROWS window frame for aggregate functions.
+    ///
+    /// Returns `None` for the default frame (`ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`),
+    /// which maps to the cumulative functions. RANGE and GROUPS frames are not supported.
+    fn parse_rows_frame(
+        &self,
+        window_frame: &Option<WindowFrame>,
+    ) -> PolarsResult<Option<RowsFrame>> {
+        let Some(frame) = window_frame else {
+            return Ok(None);
+        };
+        if !matches!(frame.units, WindowFrameUnits::Rows) {
+            self.validate_window_frame(window_frame)?;
+        }
+        let parse_offset = |n: &Option<Box<SQLExpr>>| -> PolarsResult<Option<usize>> {
+            match n.as_deref() {
+                None => Ok(None),
+                Some(SQLExpr::Value(SQLValue::Number(n, _))) => n.parse().map(Some).map_err(|_| {
+                    polars_err!(SQLSyntax: "window frame offset must be a non-negative integer (found {})", n)
+                }),
+                Some(e) => polars_bail!(
+                    SQLSyntax: "window frame offset must be a non-negative integer (found {})", e
+                ),
+            }
+        };
+        let parse_bound =
+            |bound: &WindowFrameBound| -> PolarsResult<FrameBound> {
+                Ok(match bound {
+                    WindowFrameBound::CurrentRow => FrameBound::CurrentRow,
+                    WindowFrameBound::Preceding(n) => parse_offset(n)?
+                        .map_or(FrameBound::UnboundedPreceding, FrameBound::Preceding),
+                    WindowFrameBound::Following(n) => parse_offset(n)?
+                        .map_or(FrameBound::UnboundedFollowing, FrameBound::Following),
+                })
+            };
+        let start = parse_bound(&frame.start_bound)?;
+        let end = match &frame.end_bound {
+            Some(bound) => parse_bound(bound)?,
+            None => FrameBound::CurrentRow,
+        };
+
+        // Position of a bound relative to the current row, to check the frame is not empty.
+        let position = |bound: FrameBound| match bound {
+            FrameBound::UnboundedPreceding => i128::MIN,
+            FrameBound::Preceding(n) => -(n as i128),
+            FrameBound::CurrentRow => 0,
+            FrameBound::Following(n) => n as i128,
+            FrameBound::UnboundedFollowing => i128::MAX,
+        };
+        polars_ensure!(
+            start != FrameBound::UnboundedFollowing
+                && end != FrameBound::UnboundedPreceding
+                && position(start) <= position(end),
+            SQLSyntax: "invalid window frame 'ROWS BETWEEN {} AND {}'",
+            frame.start_bound,
+            frame.end_bound.as_ref().map_or("CURRENT ROW".to_string(), |b| b.to_string()),
+        );
+        Ok(match (start, end) {
+            (FrameBound::UnboundedPreceding, FrameBound::CurrentRow) => None,
+            frame => Some(RowsFrame::new(frame.0, frame.1)),
+        })
+    }
+
     /// Window specs that map to cumulative functions.
     ///
     /// Converts SQL window functions with ORDER BY to compatible cumulative ops:
@@ -1874,10 +2235,14 @@ impl SQLFunctionVisitor<'_> {
     /// Same data, query with RANGE (eg: using a relational DB):
     /// Result: [25, 25, 45]  ← both (A,X) rows get 25
     /// ```
+    ///
+    /// Any other ROWS frame (e.g. `ROWS BETWEEN 2 PRECEDING AND 1 FOLLOWING`) maps to
+    /// `framed_fn`, computed within every partition in ORDER BY order (or in row order).
     fn apply_cumulative_window(
         &mut self,
         f: impl Fn(Expr) -> Expr,
         cumulative_fn: impl Fn(Expr, bool) -> Expr,
+        framed_fn: impl Fn(Expr, RowsFrame) -> Expr,
         WindowSpec {
             partition_by,
             order_by,
@@ -1885,9 +2250,9 @@ impl SQLFunctionVisitor<'_> {
             ..
         }: &WindowSpec,
     ) -> PolarsResult<Expr> {
-        self.validate_window_frame(window_frame)?;
+        let rows_frame = self.parse_rows_frame(window_frame)?;
 
-        if !order_by.is_empty() {
+        if !order_by.is_empty() || rows_frame.is_some() {
             // Extract ORDER BY exprs and sort direction
             let (order_by_exprs, all_desc) = self.parse_order_by_in_window(order_by)?;
 
@@ -1910,10 +2275,19 @@ impl SQLFunctionVisitor<'_> {
                 )
             };
 
-            // Apply cumulative function and wrap with window spec
-            let cumulative_expr = cumulative_fn(base_expr, false);
+            // Apply cumulative (or framed) function and wrap with window spec
+            let window_expr = match rows_frame {
+                Some(frame) => framed_fn(base_expr, frame),
+                None => cumulative_fn(base_expr, false),
+            };
+            if order_by_exprs.is_empty() {
+                return Ok(match partition_by_exprs {
+                    Some(part) => window_expr.over(part),
+                    None => window_expr,
+                });
+            }
             let sort_opts = SortOptions::default().with_order_descending(all_desc);
-            cumulative_expr.over_with_options(
+            window_expr.over_with_options(
                 partition_by_exprs,
                 Some((order_by_exprs, sort_opts)),
                 Default::default(),
@@ -1964,15 +2338,18 @@ impl SQLFunctionVisitor<'_> {
 
     /// Some functions have cumulative equivalents that can be applied to window specs
     /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
+    /// and rolling equivalents for other ROWS frames
+    /// e.g. SUM(a) OVER (ORDER BY b ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)
     fn visit_unary_with_opt_cumulative(
         &mut self,
         f: impl Fn(Expr) -> Expr,
         cumulative_fn: impl Fn(Expr, bool) -> Expr,
+        framed_fn: impl Fn(Expr, RowsFrame) -> Expr,
     ) -> PolarsResult<Expr> {
         match self.func.over.as_ref() {
             Some(window_type) => {
                 let spec = self.resolve_window_spec(window_type)?;
-                self.apply_cumulative_window(f, cumulative_fn, &spec)
+                self.apply_cumulative_window(f, cumulative_fn, framed_fn, &spec)
             },
             None => self.visit_unary(f),
         }
@@ -2143,10 +2520,9 @@ impl SQLFunctionVisitor<'_> {
         };
         if has_order_by && !is_distinct {
             if let Some(WindowType::WindowSpec(spec)) = &self.func.over {
-                self.validate_window_frame(&spec.window_frame)?;
-
                 match args.as_slice() {
                     [FunctionArgExpr::Wildcard] | [] => {
+                        self.validate_window_frame(&spec.window_frame)?;
                         // COUNT(*) with ORDER BY -> map to `int_range`
                         let (order_by_exprs, all_desc) =
                             self.parse_order_by_in_window(&spec.order_by)?;
@@ -2174,6 +2550,12 @@ impl SQLFunctionVisitor<'_> {
                         return self.visit_unary_with_opt_cumulative(
                             |e| e.count(),
                             |e, reverse| e.cum_count(reverse),
+                            |e, frame| {
+                                e.is_not_null()
+                                    .cast(IDX_DTYPE)
+                                    .rolling_frame(FrameAggregation::Sum, frame)
+                                    .fill_null(lit(0))
+                            },
                         );
                     },
                     _ => {},
@@ -2241,6 +2623,26 @@ impl SQLFunctionVisitor<'_> {
         self.apply_order_by(expr, order_by)
     }
 
+    /// Get the ORDER BY of the OVER clause as a single expression to rank by (a struct if there
+    /// are multiple sort keys), along with its sort direction.
+    #[cfg(feature = "rank")]
+    fn window_rank_order(&mut self, func_name: &str) -> PolarsResult<(Expr, bool)> {
+        let window_spec = match &self.func.over {
+            Some(window_type) => self.resolve_window_spec(window_type)?,
+            None => polars_bail!(SQLSyntax: "{} requires an OVER clause with ORDER BY", func_name),
+        };
+        if window_spec.order_by.is_empty() {
+            polars_bail!(SQLSyntax: "{} requires an OVER clause with ORDER BY", func_name)
+        }
+        let (mut order_exprs, all_desc) = self.parse_order_by_in_window(&window_spec.order_by)?;
+        let order_expr = if order_exprs.len() == 1 {
+            order_exprs.pop().unwrap()
+        } else {
+            as_struct(order_exprs)
+        };
+        Ok((order_expr, all_desc))
+    }
+
     /// Parse ORDER BY (in OVER clause), validating uniform direction.
     fn parse_order_by_in_window(
         &mut self,
@@ -2314,6 +2716,56 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Map a SQL date/time unit (e.g. 'day', 'hours', 'decade') to a multiple of a Polars
+/// duration unit, as used by `offset_by` and `truncate`.
+fn parse_date_unit(unit: &str, func_name: &str) -> PolarsResult<(i64, &'static str)> {
+    Ok(match unit.to_ascii_lowercase().as_str() {
+        "nanosecond" | "nanoseconds" | "ns" => (1, "ns"),
+        "microsecond" | "microseconds" | "us" => (1, "us"),
+        "millisecond" | "milliseconds" | "ms" => (1, "ms"),
+        "second" | "seconds" | "sec" | "secs" | "s" => (1, "s"),
+        "minute" | "minutes" | "min" | "mins" | "m" => (1, "m"),
+        "hour" | "hours" | "h" => (1, "h"),
+        "day" | "days" | "d" => (1, "d"),
+        "week" | "weeks" | "w" => (1, "w"),
+        "month" | "months" | "mon" | "mons" => (1, "mo"),
+        "quarter" | "quarters" | "q" => (1, "q"),
+        "year" | "years" | "y" => (1, "y"),
+        "decade" | "decades" => (10, "y"),
+        "century" | "centuries" => (100, "y"),
+        "millennium" | "millennia" => (1000, "y"),
+        _ => polars_bail!(SQLSyntax: "invalid unit for {} ({})", func_name, unit),
+    })
+}
+
+fn extract_str_literal(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Literal(lv) => lv.extract_str(),
+        _ => None,
+    }
+}
+
+/// Convert a SQL format string (`%s` placeholders, `%%` for a literal '%') to the
+/// placeholder syntax used by [`format_str`].
+fn sql_format_to_polars(fmt: &str) -> PolarsResult<String> {
+    polars_ensure!(!fmt.contains("{}"), SQLSyntax: "FORMAT string cannot contain '{{}}'");
+    let mut out = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => match chars.next() {
+                Some('s') => out.push_str("{}"),
+                Some('%') => out.push('%'),
+                other => {
+                    polars_bail!(SQLSyntax: "unsupported FORMAT specifier '%{}'; only '%s' and '%%' are supported", other.map(String::from).unwrap_or_default())
+                },
+            },
+            c => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -2,12 +2,16 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+#[cfg(feature = "flight")]
+pub mod flight;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+mod prepared;
 mod sql_expr;
 mod table_functions;
 mod types;
 
 pub use context::SQLContext;
+pub use prepared::SQLStatement;
 pub use sql_expr::sql_expr;
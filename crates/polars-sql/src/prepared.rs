@@ -0,0 +1,109 @@
+use std::ops::ControlFlow;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::LazyFrame;
+use polars_utils::format_pl_smallstr;
+use sqlparser::ast::{Expr as SQLExpr, Statement, Value as SQLValue, visit_expressions_mut};
+
+use crate::SQLContext;
+
+/// The name under which the value for the given placeholder is bound; positional placeholders
+/// are named by their (1-based) position, named placeholders have their sigil removed.
+pub(crate) fn param_name(placeholder: &str) -> &str {
+    placeholder.strip_prefix(['$', ':']).unwrap_or(placeholder)
+}
+
+/// A parsed SQL statement with parameter placeholders, created with [`SQLContext::prepare`].
+///
+/// The statement is parsed once and can then be executed any number of times, binding new
+/// values to its parameters on each execution. Supported placeholders are:
+///
+/// * `?`: anonymous positional parameters, numbered in order of appearance.
+/// * `$1`, `$2`, ...: numbered positional parameters; the same number can be used repeatedly.
+/// * `:name` or `$name`: named parameters.
+///
+/// Parameter values are bound as literals; they are never interpolated into the SQL text.
+#[derive(Clone, Debug)]
+pub struct SQLStatement {
+    statement: Statement,
+    params: Vec<PlSmallStr>,
+}
+
+impl SQLStatement {
+    pub(crate) fn new(mut statement: Statement) -> PolarsResult<Self> {
+        let mut n_anonymous = 0;
+        let mut has_numbered = false;
+        let mut params: Vec<PlSmallStr> = vec![];
+
+        let _ = visit_expressions_mut(&mut statement, |expr| {
+            if let SQLExpr::Value(SQLValue::Placeholder(p)) = expr {
+                if p == "?" {
+                    n_anonymous += 1;
+                    *p = format!("${n_anonymous}");
+                } else if p.starts_with('$') && p[1..].parse::<usize>().is_ok() {
+                    has_numbered = true;
+                }
+                let name = param_name(p);
+                if !params.iter().any(|n| n == name) {
+                    params.push(name.into());
+                }
+            }
+            ControlFlow::<()>::Continue(())
+        });
+        polars_ensure!(
+            n_anonymous == 0 || !has_numbered,
+            SQLSyntax: "cannot mix anonymous ('?') and numbered ('$n') parameters in the same statement"
+        );
+        Ok(Self { statement, params })
+    }
+
+    /// The names of the parameters of this statement, in order of first appearance.
+    ///
+    /// Positional parameters are named by their position (`"1"`, `"2"`, ...).
+    pub fn parameters(&self) -> &[PlSmallStr] {
+        &self.params
+    }
+
+    /// Execute the statement, binding the given values to its positional parameters.
+    ///
+    /// The first value is bound to `?` (or `$1`), the second to the next `?` (or `$2`), etc.
+    pub fn execute(
+        &self,
+        ctx: &mut SQLContext,
+        params: Vec<AnyValue<'static>>,
+    ) -> PolarsResult<LazyFrame> {
+        let named = params
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (format_pl_smallstr!("{}", i + 1), value));
+        self.execute_named(ctx, named)
+    }
+
+    /// Execute the statement, binding the given values to its parameters by name.
+    ///
+    /// Names can be given with or without their sigil (e.g. both `":id"` and `"id"` bind
+    /// the `:id` parameter); positional parameters are named `"1"`, `"2"`, etc.
+    pub fn execute_named<I, S>(&self, ctx: &mut SQLContext, params: I) -> PolarsResult<LazyFrame>
+    where
+        I: IntoIterator<Item = (S, AnyValue<'static>)>,
+        S: AsRef<str>,
+    {
+        let mut bound = PlHashMap::with_capacity(self.params.len());
+        for (name, value) in params {
+            let name = param_name(name.as_ref());
+            polars_ensure!(
+                self.params.iter().any(|p| p == name),
+                SQLInterface: "statement has no parameter '{}'; expected one of {:?}", name, self.params
+            );
+            polars_ensure!(
+                bound.insert(PlSmallStr::from_str(name), value).is_none(),
+                SQLInterface: "parameter '{}' was bound more than once", name
+            );
+        }
+        if let Some(missing) = self.params.iter().find(|p| !bound.contains_key(*p)) {
+            polars_bail!(SQLInterface: "no value given for parameter '{}'", missing);
+        }
+        ctx.params = bound;
+        ctx.execute_parsed(&self.statement)
+    }
+}
@@ -51,6 +51,15 @@ pub enum SubqueryRestriction {
     // Any
 }
 
+/// Random suffix used to give subquery result columns a unique name.
+fn random_suffix() -> String {
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(16)
+        .map(char::from)
+        .collect()
+}
+
 /// Recursively walks a SQL Expr to create a polars Expr
 pub(crate) struct SQLExprVisitor<'a> {
     ctx: &'a mut SQLContext,
@@ -113,6 +122,7 @@ impl SQLExprVisitor<'_> {
                 syntax: _,
                 expr,
             } => parse_extract_date_part(self.visit_expr(expr)?, field),
+            SQLExpr::Exists { subquery, negated } => self.visit_exists(subquery, *negated),
             SQLExpr::Floor { expr, .. } => Ok(self.visit_expr(expr)?.floor()),
             SQLExpr::Function(function) => self.visit_function(function),
             SQLExpr::Identifier(ident) => self.visit_identifier(ident),
@@ -192,7 +202,7 @@ impl SQLExprVisitor<'_> {
                 Ok(if *negated { matches.not() } else { matches })
             },
             SQLExpr::Subscript { expr, subscript } => self.visit_subscript(expr, subscript),
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(subquery) => self.visit_scalar_subquery(subquery),
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -269,15 +279,9 @@ impl SQLExprVisitor<'_> {
             if schema.len() != 1 {
                 polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
             }
-            let rand_string: String = rand::rng()
-                .sample_iter(&Alphanumeric)
-                .take(16)
-                .map(char::from)
-                .collect();
-
             let schema_entry = schema.get_at_index(0);
             if let Some((old_name, _)) = schema_entry {
-                let new_name = String::from(old_name.as_str()) + rand_string.as_str();
+                let new_name = String::from(old_name.as_str()) + random_suffix().as_str();
                 lf = lf.rename([old_name.to_string()], [new_name.clone()], true);
                 return Ok(Expr::SubPlan(
                     SpecialEq::new(Arc::new(lf.logical_plan)),
@@ -288,6 +292,36 @@ impl SQLExprVisitor<'_> {
         polars_bail!(SQLInterface: "subquery type not supported");
     }
 
+    /// Visit a scalar subquery, e.g. `(SELECT MAX(x) FROM tbl)`.
+    ///
+    /// The subquery must return a single column with at most one row; it evaluates to NULL
+    /// if it returns no rows, and raises an error if it returns more than one.
+    fn visit_scalar_subquery(&mut self, subquery: &Subquery) -> PolarsResult<Expr> {
+        Ok(self
+            .visit_subquery(subquery, SubqueryRestriction::SingleColumn)?
+            .item(true))
+    }
+
+    /// Visit an (uncorrelated) `EXISTS` subquery.
+    ///
+    /// Correlated `EXISTS` subqueries in a `WHERE` clause are rewritten into semi/anti joins
+    /// before the filter expression is parsed; see `SQLContext::process_where`.
+    fn visit_exists(&mut self, subquery: &Subquery, negated: bool) -> PolarsResult<Expr> {
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        let (lf, _) = self
+            .ctx
+            .execute_isolated(|ctx| ctx.execute_query_no_ctes(subquery))?;
+
+        let name = format!("__POLARS_EXISTS_{}", random_suffix());
+        let lf = lf.select([len().alias(name.as_str())]);
+        let exists = Expr::SubPlan(SpecialEq::new(Arc::new(lf.logical_plan)), vec![name])
+            .first()
+            .gt(lit(0));
+        Ok(if negated { exists.not() } else { exists })
+    }
+
     /// Visit a single SQL identifier.
     ///
     /// e.g. column
@@ -469,18 +503,6 @@ impl SQLExprVisitor<'_> {
         op: &SQLBinaryOperator,
         right: &SQLExpr,
     ) -> PolarsResult<Expr> {
-        // check for (unsupported) scalar subquery comparisons
-        if matches!(left, SQLExpr::Subquery(_)) || matches!(right, SQLExpr::Subquery(_)) {
-            let (suggestion, str_op) = match op {
-                SQLBinaryOperator::NotEq => ("; use 'NOT IN' instead", "!=".to_string()),
-                SQLBinaryOperator::Eq => ("; use 'IN' instead", format!("{op}")),
-                _ => ("", format!("{op}")),
-            };
-            polars_bail!(
-                SQLSyntax: "subquery comparisons with '{str_op}' are not supported{suggestion}"
-            );
-        }
-
         // need special handling for interval offsets and comparisons
         let (lhs, mut rhs) = match (left, op, right) {
             (_, SQLBinaryOperator::Minus, SQLExpr::Interval(v)) => {
@@ -497,6 +519,15 @@ impl SQLExprVisitor<'_> {
                     .dt()
                     .offset_by(lit(format!("{duration}"))));
             },
+            (SQLExpr::Interval(v), SQLBinaryOperator::Plus, _)
+                if !matches!(right, SQLExpr::Interval(_)) =>
+            {
+                let duration = interval_to_duration(v, false)?;
+                return Ok(self
+                    .visit_expr(right)?
+                    .dt()
+                    .offset_by(lit(format!("{duration}"))));
+            },
             (SQLExpr::Interval(v1), _, SQLExpr::Interval(v2)) => {
                 // shortcut interval comparison evaluation (-> bool)
                 let d1 = interval_to_duration(v1, false)?;
@@ -847,6 +878,10 @@ impl SQLExprVisitor<'_> {
                 bitstring_to_bytes_literal(b)?
             },
             SQLValue::SingleQuotedString(s) => lit(s.clone()),
+            SQLValue::Placeholder(p) => match self.ctx.get_param(p)? {
+                AnyValue::Null => Expr::Literal(LiteralValue::untyped_null()),
+                av => lit(Scalar::new(av.dtype(), av.clone())),
+            },
             other => {
                 polars_bail!(SQLInterface: "value {:?} is not a supported literal type", other)
             },
@@ -904,6 +939,7 @@ impl SQLExprVisitor<'_> {
                 }
             },
             SQLValue::SingleQuotedString(s) => AnyValue::StringOwned(s.as_str().into()),
+            SQLValue::Placeholder(p) => self.ctx.get_param(p)?.clone(),
             other => polars_bail!(SQLInterface: "value {:?} is not currently supported", other),
         })
     }
@@ -1111,7 +1147,6 @@ pub fn sql_expr<S: AsRef<str>>(s: S) -> PolarsResult<Expr> {
 
 pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsResult<Duration> {
     if interval.last_field.is_some()
-        || interval.leading_field.is_some()
         || interval.leading_precision.is_some()
         || interval.fractional_seconds_precision.is_some()
     {
@@ -1121,7 +1156,17 @@ pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsRe
         SQLExpr::UnaryOp { .. } => {
             polars_bail!(SQLSyntax: "unary ops are not valid on interval strings; found {}", interval.value)
         },
-        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s),
+        // e.g. INTERVAL '3' DAY
+        SQLExpr::Value(SQLValue::SingleQuotedString(s) | SQLValue::Number(s, _))
+            if interval.leading_field.is_some() =>
+        {
+            Some(format!(
+                "{} {}",
+                s,
+                interval.leading_field.as_ref().unwrap()
+            ))
+        },
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s.clone()),
         _ => None,
     };
     match s {
@@ -1131,7 +1176,7 @@ pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsRe
         Some(s) => {
             // years, quarters, and months do not have a fixed duration; these
             // interval parts can only be used with respect to a reference point
-            let duration = Duration::parse_interval(s);
+            let duration = Duration::try_parse_interval(&s)?;
             if fixed && duration.months() != 0 {
                 polars_bail!(SQLSyntax: "fixed-duration interval cannot contain years, quarters, or months; found {}", s)
             };
@@ -1,31 +1,43 @@
 use std::str::FromStr;
 
+use polars_core::prelude::{PolarsError, PolarsResult, polars_bail};
 #[cfg(any(
     feature = "csv",
     feature = "parquet",
     feature = "ipc",
     feature = "json"
 ))]
-use polars_core::prelude::polars_ensure;
-use polars_core::prelude::{PolarsError, PolarsResult, polars_bail};
+use polars_core::prelude::{polars_ensure, polars_err};
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
 use polars_utils::plpath::PlPath;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+use sqlparser::ast::FunctionArg;
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+use sqlparser::ast::{Expr as SQLExpr, FunctionArgExpr, Value as SQLValue};
 
-/// Table functions that are supported by Polars
+/// Table functions that are supported by Polars.
+///
+/// The first (positional) argument is the file path, which may contain glob patterns.
+/// Reader options can follow as named arguments, e.g. `read_csv('data/*.csv', has_header => false)`.
 #[allow(clippy::enum_variant_names)]
 pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function.
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', separator => ';', has_header => false)
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function.
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('path/to/*.parquet', include_file_paths => 'path')
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
@@ -43,6 +55,77 @@ pub(crate) enum PolarsTableFunctions {
     ReadJson,
 }
 
+/// A named table function argument, e.g. `has_header => false`.
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+struct TableFunctionOption<'a> {
+    func_name: &'static str,
+    name: String,
+    value: &'a SQLValue,
+}
+
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+impl TableFunctionOption<'_> {
+    fn invalid(&self, expected: &str) -> PolarsError {
+        polars_err!(
+            SQLSyntax: "`{}` option '{}' expects {}; found {}",
+            self.func_name, self.name, expected, self.value
+        )
+    }
+
+    fn unknown(&self) -> PolarsError {
+        polars_err!(SQLSyntax: "`{}` got an unknown option '{}'", self.func_name, self.name)
+    }
+
+    fn as_bool(&self) -> PolarsResult<bool> {
+        match self.value {
+            SQLValue::Boolean(b) => Ok(*b),
+            _ => Err(self.invalid("a boolean")),
+        }
+    }
+
+    fn as_usize(&self) -> PolarsResult<usize> {
+        match self.value {
+            SQLValue::Number(n, _) => n
+                .parse()
+                .map_err(|_| self.invalid("a non-negative integer")),
+            _ => Err(self.invalid("a non-negative integer")),
+        }
+    }
+
+    #[cfg(any(feature = "csv", feature = "json"))]
+    fn as_opt_usize(&self) -> PolarsResult<Option<usize>> {
+        match self.value {
+            SQLValue::Null => Ok(None),
+            _ => self.as_usize().map(Some),
+        }
+    }
+
+    fn as_str(&self) -> PolarsResult<&str> {
+        match self.value {
+            SQLValue::SingleQuotedString(s) => Ok(s),
+            _ => Err(self.invalid("a single-quoted string")),
+        }
+    }
+
+    #[cfg(feature = "csv")]
+    fn as_byte(&self) -> PolarsResult<u8> {
+        match self.as_str()?.as_bytes() {
+            [b] => Ok(*b),
+            _ => Err(self.invalid("a single-byte character")),
+        }
+    }
+}
+
 impl FromStr for PolarsTableFunctions {
     type Err = PolarsError;
 
@@ -80,49 +163,158 @@ impl PolarsTableFunctions {
 
     #[cfg(feature = "csv")]
     fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(PlPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_csv` expects a single file path; found {:?} arguments", args.len());
-
         use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(path.clone())
+
+        let (path, options) = self.split_args("read_csv", args)?;
+        let mut reader = LazyCsvReader::new(path.clone())
             .with_try_parse_dates(true)
-            .with_missing_is_null(true)
-            .finish()?;
-        Ok((path, lf))
+            .with_missing_is_null(true);
+        for opt in options {
+            reader = match opt.name.as_str() {
+                "comment_prefix" => reader.with_comment_prefix(Some(opt.as_str()?.into())),
+                "glob" => reader.with_glob(opt.as_bool()?),
+                "has_header" => reader.with_has_header(opt.as_bool()?),
+                "ignore_errors" => reader.with_ignore_errors(opt.as_bool()?),
+                "include_file_paths" => reader.with_include_file_paths(Some(opt.as_str()?.into())),
+                "infer_schema_length" => reader.with_infer_schema_length(opt.as_opt_usize()?),
+                "n_rows" => reader.with_n_rows(Some(opt.as_usize()?)),
+                "quote_char" => reader.with_quote_char(match opt.value {
+                    SQLValue::Null => None,
+                    _ => Some(opt.as_byte()?),
+                }),
+                "separator" => reader.with_separator(opt.as_byte()?),
+                "skip_rows" => reader.with_skip_rows(opt.as_usize()?),
+                "truncate_ragged_lines" => reader.with_truncate_ragged_lines(opt.as_bool()?),
+                "try_parse_dates" => reader.with_try_parse_dates(opt.as_bool()?),
+                _ => return Err(opt.unknown()),
+            };
+        }
+        Ok((path, reader.finish()?))
     }
 
     #[cfg(feature = "parquet")]
     fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(PlPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_parquet` expects a single file path; found {:?} arguments", args.len());
-
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(path.clone(), Default::default())?;
+        let (path, options) = self.split_args("read_parquet", args)?;
+        let mut scan_args = polars_lazy::prelude::ScanArgsParquet::default();
+        for opt in options {
+            match opt.name.as_str() {
+                "allow_missing_columns" => scan_args.allow_missing_columns = opt.as_bool()?,
+                "glob" => scan_args.glob = opt.as_bool()?,
+                "hive_partitioning" => scan_args.hive_options.enabled = Some(opt.as_bool()?),
+                "include_file_paths" => scan_args.include_file_paths = Some(opt.as_str()?.into()),
+                "low_memory" => scan_args.low_memory = opt.as_bool()?,
+                "n_rows" => scan_args.n_rows = Some(opt.as_usize()?),
+                "rechunk" => scan_args.rechunk = opt.as_bool()?,
+                "use_statistics" => scan_args.use_statistics = opt.as_bool()?,
+                _ => return Err(opt.unknown()),
+            }
+        }
+        let lf = LazyFrame::scan_parquet(path.clone(), scan_args)?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "ipc")]
     fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(PlPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ipc` expects a single file path; found {:?} arguments", args.len());
+        use polars_lazy::prelude::UnifiedScanArgs;
+        use polars_utils::slice_enum::Slice;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(path.clone(), Default::default(), Default::default())?;
+        let (path, options) = self.split_args("read_ipc", args)?;
+        let mut scan_args = UnifiedScanArgs::default();
+        for opt in options {
+            match opt.name.as_str() {
+                "glob" => scan_args.glob = opt.as_bool()?,
+                "hive_partitioning" => scan_args.hive_options.enabled = Some(opt.as_bool()?),
+                "include_file_paths" => scan_args.include_file_paths = Some(opt.as_str()?.into()),
+                "n_rows" => {
+                    let len = opt.as_usize()?;
+                    scan_args.pre_slice = Some(Slice::Positive { offset: 0, len })
+                },
+                "rechunk" => scan_args.rechunk = opt.as_bool()?,
+                _ => return Err(opt.unknown()),
+            }
+        }
+        let lf = LazyFrame::scan_ipc(path.clone(), Default::default(), scan_args)?;
         Ok((path, lf))
     }
+
     #[cfg(feature = "json")]
     fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(PlPath, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ndjson` expects a single file path; found {:?} arguments", args.len());
+        use std::num::NonZeroUsize;
 
         use polars_lazy::frame::LazyFileListReader;
         use polars_lazy::prelude::LazyJsonLineReader;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
-        Ok((path, lf))
+        let (path, options) = self.split_args("read_ndjson", args)?;
+        let mut reader = LazyJsonLineReader::new(path.clone());
+        for opt in options {
+            reader = match opt.name.as_str() {
+                "ignore_errors" => reader.with_ignore_errors(opt.as_bool()?),
+                "include_file_paths" => reader.with_include_file_paths(Some(opt.as_str()?.into())),
+                "infer_schema_length" => reader.with_infer_schema_length(
+                    opt.as_opt_usize()?
+                        .map(NonZeroUsize::try_from)
+                        .transpose()
+                        .map_err(|_| opt.invalid("a positive integer"))?,
+                ),
+                "n_rows" => reader.with_n_rows(Some(opt.as_usize()?)),
+                _ => return Err(opt.unknown()),
+            };
+        }
+        Ok((path, reader.finish()?))
+    }
+
+    /// Split the table function arguments into the file path and any named options.
+    #[cfg(any(
+        feature = "csv",
+        feature = "parquet",
+        feature = "ipc",
+        feature = "json"
+    ))]
+    fn split_args<'a>(
+        &self,
+        func_name: &'static str,
+        args: &'a [FunctionArg],
+    ) -> PolarsResult<(PlPath, Vec<TableFunctionOption<'a>>)> {
+        let mut paths = vec![];
+        let mut options: Vec<TableFunctionOption> = vec![];
+        for arg in args {
+            match arg {
+                FunctionArg::Unnamed(_) => paths.push(arg),
+                FunctionArg::Named { name, arg, .. } => {
+                    let name = name.value.to_lowercase();
+                    polars_ensure!(
+                        !options.iter().any(|opt| opt.name == name),
+                        SQLSyntax: "`{}` option '{}' was given more than once", func_name, name
+                    );
+                    let value = match arg {
+                        FunctionArgExpr::Expr(SQLExpr::Value(value)) => value,
+                        _ => polars_bail!(
+                            SQLSyntax: "`{}` option '{}' expects a literal value; found {}", func_name, name, arg
+                        ),
+                    };
+                    options.push(TableFunctionOption {
+                        func_name,
+                        name,
+                        value,
+                    });
+                },
+                _ => polars_bail!(SQLSyntax: "unsupported `{}` argument: {}", func_name, arg),
+            }
+        }
+        polars_ensure!(
+            paths.len() == 1,
+            SQLSyntax: "`{}` expects a single file path; found {:?} arguments", func_name, paths.len()
+        );
+        Ok((self.get_file_path_from_arg(paths[0])?, options))
     }
 
-    #[allow(dead_code)]
+    #[cfg(any(
+        feature = "csv",
+        feature = "parquet",
+        feature = "ipc",
+        feature = "json"
+    ))]
     fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<PlPath> {
-        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
         match arg {
             FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(
                 SQLValue::SingleQuotedString(s),
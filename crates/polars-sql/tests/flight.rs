@@ -0,0 +1,73 @@
+#![cfg(feature = "flight")]
+use arrow_format::flight::data::FlightDescriptor;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::SQLContext;
+use polars_sql::flight::FlightSqlHandler;
+use prost::Message;
+
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CommandStatementQuery {
+    #[prost(string, tag = "1")]
+    query: String,
+}
+
+fn descriptor(command: &str, query: &str) -> FlightDescriptor {
+    let cmd = CommandStatementQuery {
+        query: query.to_string(),
+    };
+    let any = Any {
+        type_url: format!("type.googleapis.com/arrow.flight.protocol.sql.{command}"),
+        value: cmd.encode_to_vec(),
+    };
+    FlightDescriptor {
+        cmd: any.encode_to_vec(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_flight_sql_statement_query() {
+    let mut ctx = SQLContext::new();
+    let df = df! { "a" => [1i32, 2, 3], "b" => ["x", "y", "z"] }.unwrap();
+    ctx.register("tbl", df.lazy());
+    let handler = FlightSqlHandler::new(ctx);
+
+    let info = handler
+        .get_flight_info(&descriptor(
+            "CommandStatementQuery",
+            "SELECT b FROM tbl WHERE a > 1",
+        ))
+        .unwrap();
+    assert_eq!(&info.schema[..4], &[0xff; 4]);
+    assert_eq!(info.endpoint.len(), 1);
+
+    // the schema, followed by a single record batch
+    let ticket = info.endpoint[0].ticket.as_ref().unwrap();
+    let data = handler.do_get(ticket).unwrap();
+    assert_eq!(data.len(), 2);
+    assert!(data[0].data_body.is_empty());
+    assert!(!data[1].data_body.is_empty());
+
+    // other Flight SQL commands, and invalid queries, are rejected
+    let err = handler
+        .get_flight_info(&descriptor("CommandGetTables", ""))
+        .unwrap_err();
+    assert!(err.to_string().contains("unsupported Flight SQL command"));
+    assert!(
+        handler
+            .get_flight_info(&descriptor(
+                "CommandStatementQuery",
+                "SELECT * FROM missing"
+            ))
+            .is_err()
+    );
+}
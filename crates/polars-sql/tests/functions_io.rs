@@ -65,6 +65,56 @@ fn read_csv_tbl_func_inline_2() {
     assert!(df_sql.equals(&expected));
 }
 
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_options() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT *
+            FROM read_csv('../../examples/datasets/foods1.csv', has_header => false, n_rows => 5)"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.height(), 5);
+    assert_eq!(
+        df_sql.get_column_names(),
+        &["column_1", "column_2", "column_3", "column_4"]
+    );
+
+    let sql = r#"
+            SELECT path, COUNT(*) AS n
+            FROM read_csv('../../examples/datasets/foods*.csv', include_file_paths => 'path')
+            GROUP BY path
+            ORDER BY path"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.height(), 5);
+    assert!(
+        df_sql
+            .column("n")
+            .unwrap()
+            .u32()
+            .unwrap()
+            .iter()
+            .all(|n| n == Some(27))
+    );
+
+    for (sql, err) in [
+        (
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', headers => true)",
+            "`read_csv` got an unknown option 'headers'",
+        ),
+        (
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', n_rows => 'ten')",
+            "`read_csv` option 'n_rows' expects a non-negative integer; found 'ten'",
+        ),
+        (
+            "SELECT * FROM read_csv('../../examples/datasets/foods1.csv', separator => ';;')",
+            "`read_csv` option 'separator' expects a single-byte character; found ';;'",
+        ),
+    ] {
+        let msg = context.execute(sql).err().unwrap().to_string();
+        assert!(msg.contains(err), "{msg}");
+    }
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 fn read_parquet_tbl() {
@@ -34,3 +34,22 @@ fn test_describe() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_explain_analyze() {
+    let lf = df! {
+      "year"=> [2018, 2019],
+      "sales"=> [1000.0, 1500.0]
+    }
+    .unwrap()
+    .lazy();
+    let mut context = SQLContext::new();
+    context.register("df", lf);
+    let sql = r#"EXPLAIN ANALYZE SELECT year, sales * 2 AS sales FROM df WHERE year > 2018"#;
+    let df = context.execute(sql).unwrap().collect().unwrap();
+
+    assert_eq!(df.get_column_names(), &["node", "start", "end", "duration"]);
+    assert!(df.height() > 0);
+    let duration = df.column("duration").unwrap().u64().unwrap();
+    assert!(duration.into_iter().all(|d| d.is_some()));
+}
@@ -134,3 +134,59 @@ fn test_array_literal() {
     assert!(df_sql.equals(&df_expected));
     assert!(df_sql.height() == 1);
 }
+
+#[test]
+fn test_regexp_pad_translate_format() {
+    let df = df! {
+        "s" => [Some("ab-12-345"), Some("x  y   z"), None],
+        "n" => [Some(1i64), Some(22), Some(333)],
+    }
+    .unwrap();
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+    let sql = r#"
+        SELECT
+            REGEXP_EXTRACT(s, '\d+') AS re_extract,
+            REGEXP_EXTRACT(s, '(\d+)-(\d+)', 2) AS re_extract_group,
+            REGEXP_REPLACE(s, '\s+', '_') AS re_replace_first,
+            REGEXP_REPLACE(s, '\s+', '_', 'g') AS re_replace_all,
+            REGEXP_REPLACE(s, '([A-Z]+)-(\d+)', '\2:\1', 'i') AS re_replace_groups,
+            POSITION('-' IN s) AS pos,
+            LPAD(CAST(n AS VARCHAR), 4, '0') AS lpad,
+            RPAD(s, 4) AS rpad,
+            TRANSLATE(s, 'a-y', 'A_') AS translated,
+            FORMAT('%s=%s (100%%)', n, s) AS formatted
+        FROM df
+    "#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    let df_expected = df! {
+        "re_extract" => [Some("12"), None, None],
+        "re_extract_group" => [Some("345"), None, None],
+        "re_replace_first" => [Some("ab-12-345"), Some("x_y   z"), None],
+        "re_replace_all" => [Some("ab-12-345"), Some("x_y_z"), None],
+        "re_replace_groups" => [Some("12:ab-345"), Some("x  y   z"), None],
+        "pos" => [3u32, 0, 0],
+        "lpad" => ["0001", "0022", "0333"],
+        "rpad" => [Some("ab-1"), Some("x  y"), None],
+        "translated" => [Some("Ab_12_345"), Some("x     z"), None],
+        "formatted" => [Some("1=ab-12-345 (100%)"), Some("22=x  y   z (100%)"), None],
+    }
+    .unwrap();
+    assert!(df_sql.equals_missing(&df_expected));
+
+    for (sql, msg) in [
+        ("SELECT LPAD(s, 4, 'ab') FROM df", "single-character"),
+        (
+            "SELECT FORMAT('%d', n) FROM df",
+            "unsupported FORMAT specifier",
+        ),
+        ("SELECT FORMAT('%s %s', n) FROM df", "placeholder"),
+        (
+            "SELECT REGEXP_REPLACE(s, 'x') FROM df",
+            "expects 3-4 arguments",
+        ),
+    ] {
+        let err = context.execute(sql).err().unwrap();
+        assert!(err.to_string().contains(msg), "{sql}: {err}");
+    }
+}
@@ -223,3 +223,86 @@ fn test_lead_lag_without_order_by() {
         );
     }
 }
+
+fn execute(sql: &str) -> DataFrame {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    ctx.execute(&format!("SELECT {sql} FROM df"))
+        .unwrap()
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_rows_frame() {
+    let df = execute(
+        "
+        SUM(a) OVER (ORDER BY a ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS sum_a,
+        AVG(a) OVER (PARTITION BY b ORDER BY a) AS avg_a,
+        MAX(a) OVER (PARTITION BY b ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS max_a,
+        COUNT(b) OVER (ORDER BY a ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS count_b
+        ",
+    );
+    let column = |name: &str, dtype: &DataType| df.column(name).unwrap().cast(dtype).unwrap();
+
+    assert!(
+        column("sum_a", &DataType::Int64)
+            .equals(&Column::new("sum_a".into(), [2i64, 3, 4, 5, 7, 5]))
+    );
+    assert!(
+        column("avg_a", &DataType::Float64)
+            .equals(&Column::new("avg_a".into(), [1.0, 1.0, 1.0, 1.5, 1.5, 2.0]))
+    );
+    assert!(
+        column("max_a", &DataType::Int64)
+            .equals(&Column::new("max_a".into(), [2i64, 2, 3, 2, 2, 3]))
+    );
+    assert!(
+        column("count_b", &DataType::Int64)
+            .equals(&Column::new("count_b".into(), [1i64, 2, 3, 3, 3, 3]))
+    );
+}
+
+#[test]
+fn test_invalid_rows_frame() {
+    ensure_error(
+        "SUM(a) OVER (ORDER BY b ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) AS c",
+        "invalid window frame",
+    );
+    ensure_error(
+        "SUM(a) OVER (ORDER BY b RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS c",
+        "RANGE-based window frames are not supported",
+    );
+    ensure_error(
+        "LAG(a) OVER (ORDER BY b ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS c",
+        "only 'ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW' is currently supported",
+    );
+}
+
+#[test]
+#[cfg(feature = "rank")]
+fn test_percent_rank_ntile() {
+    let df = execute(
+        "
+        PERCENT_RANK() OVER (ORDER BY a) AS pct,
+        NTILE(4) OVER (ORDER BY a, b) AS tile,
+        NTILE(2) OVER (PARTITION BY a ORDER BY b DESC) AS tile_desc
+        ",
+    );
+    assert!(
+        df.column("pct")
+            .unwrap()
+            .equals(&Column::new("pct".into(), [0.0, 0.0, 0.0, 0.6, 0.6, 1.0]))
+    );
+    let tile = df.column("tile").unwrap().cast(&DataType::Int64).unwrap();
+    assert!(tile.equals(&Column::new("tile".into(), [1i64, 1, 2, 2, 3, 4])));
+    let tile = df
+        .column("tile_desc")
+        .unwrap()
+        .cast(&DataType::Int64)
+        .unwrap();
+    assert!(tile.equals(&Column::new("tile_desc".into(), [2i64, 1, 1, 2, 1, 1])));
+
+    ensure_error("NTILE(0) OVER (ORDER BY a) AS c", "positive integer");
+    ensure_error("PERCENT_RANK() AS c", "requires an OVER clause");
+}
@@ -250,6 +250,104 @@ fn test_implicit_date_string() {
     }
 }
 
+#[test]
+fn test_interval_arithmetic_and_date_functions() {
+    let df = df! {
+        "n" => &[1, 2],
+        "dt" => &["2024-01-31 10:30:00", "2024-02-15 23:59:59"],
+    }
+    .unwrap()
+    .lazy()
+    .select(vec![
+        col("n"),
+        col("dt").cast(DataType::Datetime(TimeUnit::Microseconds, None)),
+    ])
+    .collect()
+    .unwrap();
+
+    let mut context = SQLContext::new();
+    context.register("frame", df.lazy());
+    let df_sql = context
+        .execute(
+            r#"
+            SELECT
+              dt + INTERVAL '3 days' AS d1,
+              INTERVAL '1 hour' + dt AS d2,
+              dt - INTERVAL '2' MONTH AS d3,
+              DATE_TRUNC('month', dt) AS d4,
+              DATE_TRUNC('hour', dt) AS d5,
+              DATE_ADD(dt, INTERVAL '1 week') AS d6,
+              DATE_ADD(month, n, dt) AS d7,
+              DATEADD('decade', 1, dt) AS d8
+            FROM frame
+            "#,
+        )
+        .unwrap()
+        .select([all().dt().to_string("%Y-%m-%d %H:%M:%S")])
+        .collect()
+        .unwrap();
+
+    let expected = df! {
+        "d1" => &["2024-02-03 10:30:00", "2024-02-18 23:59:59"],
+        "d2" => &["2024-01-31 11:30:00", "2024-02-16 00:59:59"],
+        "d3" => &["2023-11-30 10:30:00", "2023-12-15 23:59:59"],
+        "d4" => &["2024-01-01 00:00:00", "2024-02-01 00:00:00"],
+        "d5" => &["2024-01-31 10:00:00", "2024-02-15 23:00:00"],
+        "d6" => &["2024-02-07 10:30:00", "2024-02-22 23:59:59"],
+        "d7" => &["2024-02-29 10:30:00", "2024-04-15 23:59:59"],
+        "d8" => &["2034-01-31 10:30:00", "2034-02-15 23:59:59"],
+    }
+    .unwrap();
+    assert!(df_sql.equals(&expected));
+
+    for sql in [
+        "SELECT DATE_TRUNC('fortnight', dt) FROM frame",
+        "SELECT DATE_ADD(dt) FROM frame",
+        "SELECT dt + INTERVAL '3 lightyears' FROM frame",
+    ] {
+        assert!(context.execute(sql).and_then(|lf| lf.collect()).is_err());
+    }
+}
+
+#[test]
+fn test_date_trunc_century_millennium() {
+    let df = df! {
+        "dt" => &["2024-05-31 10:30:00", "2001-01-01 00:00:00", "2000-12-31 23:59:59", "1066-10-14 09:00:00"],
+    }
+    .unwrap()
+    .lazy()
+    .select(vec![
+        col("dt").cast(DataType::Datetime(TimeUnit::Microseconds, None)),
+    ])
+    .collect()
+    .unwrap();
+
+    let mut context = SQLContext::new();
+    context.register("frame", df.lazy());
+    let df_sql = context
+        .execute(
+            r#"
+            SELECT
+              DATE_TRUNC('decade', dt) AS decade,
+              DATE_TRUNC('century', dt) AS century,
+              DATE_TRUNC('millennium', dt) AS millennium
+            FROM frame
+            "#,
+        )
+        .unwrap()
+        .select([all().dt().to_string("%Y-%m-%d %H:%M:%S")])
+        .collect()
+        .unwrap();
+
+    let expected = df! {
+        "decade" => &["2020-01-01 00:00:00", "2000-01-01 00:00:00", "2000-01-01 00:00:00", "1060-01-01 00:00:00"],
+        "century" => &["2001-01-01 00:00:00", "2001-01-01 00:00:00", "1901-01-01 00:00:00", "1001-01-01 00:00:00"],
+        "millennium" => &["2001-01-01 00:00:00", "2001-01-01 00:00:00", "1001-01-01 00:00:00", "1001-01-01 00:00:00"],
+    }
+    .unwrap();
+    assert!(df_sql.equals(&expected));
+}
+
 #[test]
 fn test_prefixed_column_names() {
     let df = create_sample_df();
@@ -856,6 +954,76 @@ fn test_cte_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_recursive_ctes() -> PolarsResult<()> {
+    let mut context = SQLContext::new();
+    context.register(
+        "employees",
+        df! {
+            "id" => [1, 2, 3, 4, 5],
+            "manager_id" => [None, Some(1), Some(1), Some(2), Some(4)],
+        }?
+        .lazy(),
+    );
+    context.register(
+        "edges",
+        df! {
+            "src" => [1, 2, 3],
+            "dst" => [2, 3, 1],
+        }?
+        .lazy(),
+    );
+
+    let sql = r#"
+        WITH RECURSIVE seq(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM seq WHERE n < 5
+        )
+        SELECT n FROM seq ORDER BY n
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    assert!(df.equals(&df!("n" => [1, 2, 3, 4, 5])?));
+
+    // hierarchical query, alongside a non-recursive CTE
+    let sql = r#"
+        WITH RECURSIVE
+          staff AS (SELECT id, manager_id FROM employees),
+          chain AS (
+            SELECT id, 0 AS depth FROM staff WHERE manager_id IS NULL
+            UNION ALL
+            SELECT staff.id, chain.depth + 1 FROM staff JOIN chain ON staff.manager_id = chain.id
+          )
+        SELECT id, depth FROM chain ORDER BY id
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    assert!(df.equals(&df! {
+        "id" => [1, 2, 3, 4, 5],
+        "depth" => [0, 1, 1, 2, 3],
+    }?));
+
+    // UNION (without ALL) discards rows that were already produced, so cycles terminate
+    let sql = r#"
+        WITH RECURSIVE reach(node) AS (
+            SELECT 1
+            UNION
+            SELECT edges.dst FROM edges JOIN reach ON edges.src = reach.node
+        )
+        SELECT node FROM reach ORDER BY node
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    assert!(df.equals(&df!("node" => [1, 2, 3])?));
+
+    let sql = r#"
+        WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t)
+        SELECT n FROM t
+    "#;
+    let err = context.execute(sql).err().unwrap();
+    assert!(err.to_string().contains("did not complete within"));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "ipc")]
 fn test_group_by_2() -> PolarsResult<()> {
@@ -593,3 +593,401 @@ fn test_compound_invalid_2() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+fn create_subquery_ctx() -> SQLContext {
+    let customers = df![
+        "id" => [1, 2, 3, 4],
+        "name" => ["a", "b", "c", "d"],
+    ]
+    .unwrap();
+    let orders = df![
+        "customer_id" => [1, 1, 3, 4],
+        "total" => [50, 150, 80, 300],
+    ]
+    .unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    ctx
+}
+
+#[test]
+fn test_scalar_subqueries() {
+    let mut ctx = create_subquery_ctx();
+
+    let sql = r#"
+    SELECT name, (SELECT MAX(total) FROM orders) AS max_total
+    FROM customers
+    WHERE id > (SELECT MIN(customer_id) FROM orders)
+    ORDER BY name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df![
+        "name" => ["b", "c", "d"],
+        "max_total" => [300, 300, 300],
+    ]
+    .unwrap();
+    assert!(actual.equals(&expected));
+
+    let sql = r#"
+    SELECT name FROM customers
+    WHERE EXISTS (SELECT * FROM orders WHERE total > 200)
+      AND NOT EXISTS (SELECT * FROM orders WHERE total > 1000)
+    ORDER BY name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 4);
+
+    // a scalar subquery that returns no rows is NULL
+    let sql = "SELECT name, (SELECT total FROM orders WHERE total > 1000) AS t FROM customers";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.column("t").unwrap().null_count(), actual.height());
+
+    // ...and one that returns more than one row is an error
+    let sql = "SELECT name, (SELECT total FROM orders) AS t FROM customers";
+    assert!(ctx.execute(sql).and_then(|lf| lf.collect()).is_err());
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_correlated_exists() {
+    let mut ctx = create_subquery_ctx();
+
+    let sql = r#"
+    SELECT c.name FROM customers c
+    WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.total > 75)
+    ORDER BY c.name
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df!["name" => ["a", "c", "d"]].unwrap();
+    assert!(actual.equals(&expected));
+
+    let sql = r#"
+    SELECT name FROM customers
+    WHERE NOT EXISTS (SELECT 1 FROM orders WHERE customers.id = orders.customer_id)
+      AND id > 0
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df!["name" => ["b"]].unwrap();
+    assert!(actual.equals(&expected));
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_set_operations_duplicate_semantics() {
+    let mut ctx = SQLContext::new();
+    ctx.register(
+        "l",
+        df!["x" => [Some(1), Some(1), Some(1), Some(2), None, None]]
+            .unwrap()
+            .lazy(),
+    );
+    ctx.register(
+        "r",
+        df!["y" => [Some(1i64), Some(1), Some(3), None]]
+            .unwrap()
+            .lazy(),
+    );
+
+    let mut run = |sql: &str| {
+        let df = ctx
+            .execute(&format!("SELECT * FROM ({sql}) AS t ORDER BY x"))
+            .unwrap()
+            .collect()
+            .unwrap();
+        df.column("x")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        run("SELECT x FROM l INTERSECT SELECT y FROM r"),
+        [Some(1), None]
+    );
+    assert_eq!(
+        run("SELECT x FROM l INTERSECT ALL SELECT y FROM r"),
+        [Some(1), Some(1), None]
+    );
+    assert_eq!(run("SELECT x FROM l EXCEPT SELECT y FROM r"), [Some(2)]);
+    assert_eq!(
+        run("SELECT x FROM l EXCEPT ALL SELECT y FROM r"),
+        [Some(1), Some(2), None]
+    );
+    // the outer operation must not be applied to nested set operations
+    assert_eq!(
+        run("(SELECT x FROM l INTERSECT SELECT y FROM r) EXCEPT SELECT 1"),
+        [None]
+    );
+}
+
+#[test]
+fn test_pivot_unpivot() {
+    let sales = df![
+        "region" => ["N", "N", "S", "S", "N"],
+        "quarter" => ["Q1", "Q2", "Q1", "Q1", "Q1"],
+        "amount" => [10i64, 20, 30, 40, 5],
+    ]
+    .unwrap();
+    let wide = df![
+        "id" => [1, 2],
+        "a" => [Some(1), None],
+        "b" => [Some(3), Some(4)],
+    ]
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales.lazy());
+    ctx.register("wide", wide.lazy());
+
+    let sql = r#"
+    SELECT * FROM sales
+    PIVOT (SUM(amount) FOR quarter IN ('Q1' AS first, 'Q2', 'Q3')) AS p
+    ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df![
+        "region" => ["N", "S"],
+        "first" => [15i64, 70],
+        "Q2" => [Some(20i64), None],
+        "Q3" => [None::<i64>, None],
+    ]
+    .unwrap();
+    assert!(actual.equals_missing(&expected));
+
+    let sql = r#"
+    SELECT * FROM sales
+    PIVOT (SUM(amount) AS total, COUNT(*) AS n FOR quarter IN ('Q2') DEFAULT ON NULL (0)) AS p
+    ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.get_column_names(), &["region", "Q2_total", "Q2_n"]);
+    let total: Vec<_> = actual
+        .column("Q2_total")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .iter()
+        .collect();
+    assert_eq!(total, [Some(20), Some(0)]);
+
+    let sql = r#"
+    SELECT * FROM wide
+    UNPIVOT (val FOR attr IN (a, b))
+    ORDER BY id, attr
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df![
+        "id" => [1, 1, 2],
+        "attr" => ["a", "b", "b"],
+        "val" => [1, 3, 4],
+    ]
+    .unwrap();
+    assert!(actual.equals(&expected));
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_asof_join() {
+    let trades = df![
+        "t" => [1, 5, 10],
+        "id" => ["a", "a", "b"],
+    ]
+    .unwrap();
+    let quotes = df![
+        "t" => [0, 4, 6, 9],
+        "id" => ["a", "a", "b", "b"],
+        "bid" => [10, 11, 20, 21],
+    ]
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("trades", trades.lazy());
+    ctx.register("quotes", quotes.lazy());
+
+    let mut bids = |sql: &str| -> Vec<Option<i32>> {
+        let df = ctx.execute(sql).unwrap().collect().unwrap();
+        df.column("bid")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_iter()
+            .collect()
+    };
+    assert_eq!(
+        bids(
+            "SELECT tr.t, q.bid FROM trades tr
+             ASOF JOIN quotes q MATCH_CONDITION (tr.t >= q.t) ON tr.id = q.id
+             ORDER BY tr.t"
+        ),
+        [Some(10), Some(11), Some(21)]
+    );
+    assert_eq!(
+        bids(
+            "SELECT tr.t, q.bid FROM trades tr
+             ASOF JOIN quotes q MATCH_CONDITION (q.t > tr.t)
+             ORDER BY tr.t"
+        ),
+        [Some(11), Some(20), None]
+    );
+    assert_eq!(
+        bids(
+            "SELECT tr.t, q.bid FROM trades tr
+             ASOF JOIN quotes q MATCH_CONDITION (q.t > tr.t AND q.t - tr.t <= 1)
+             ORDER BY tr.t"
+        ),
+        [None, Some(20), None]
+    );
+
+    let sql = "SELECT * FROM trades tr ASOF JOIN quotes q MATCH_CONDITION (tr.t = q.t)";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_prepared_statements() {
+    let mut ctx = create_ctx();
+
+    // anonymous positional parameters, executed repeatedly
+    let stmt = ctx
+        .prepare("SELECT b FROM df WHERE b > ? AND b <= ? ORDER BY b")
+        .unwrap();
+    assert_eq!(stmt.parameters(), &["1", "2"]);
+    for (lo, hi, expected) in [(0, 2, vec![1i64, 2]), (6, 8, vec![7, 8])] {
+        let df = stmt
+            .execute(&mut ctx, vec![AnyValue::Int64(lo), AnyValue::Int64(hi)])
+            .unwrap()
+            .collect()
+            .unwrap();
+        let b: Vec<_> = df
+            .column("b")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(b, expected);
+    }
+
+    // numbered parameters can be reused, and work in IN lists
+    let stmt = ctx
+        .prepare("SELECT b FROM df WHERE b IN ($1, $2) OR b = $1 + 5 ORDER BY b")
+        .unwrap();
+    assert_eq!(stmt.parameters(), &["1", "2"]);
+    let df = stmt
+        .execute(&mut ctx, vec![AnyValue::Int64(1), AnyValue::Int64(3)])
+        .unwrap()
+        .collect()
+        .unwrap();
+    let b: Vec<_> = df
+        .column("b")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(b, [1, 3, 6]);
+
+    // named parameters (with or without sigil)
+    let stmt = ctx
+        .prepare("SELECT b, :label AS label FROM df WHERE b = :value")
+        .unwrap();
+    assert_eq!(stmt.parameters(), &["label", "value"]);
+    let df = stmt
+        .execute_named(
+            &mut ctx,
+            [
+                (":label", AnyValue::StringOwned("x".into())),
+                ("value", AnyValue::Int64(4)),
+            ],
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "b" => [4i64], "label" => ["x"] }.unwrap();
+    assert!(df.equals(&expected));
+
+    // missing, unknown and duplicate parameters are rejected
+    let err = stmt
+        .execute_named(&mut ctx, [("value", AnyValue::Int64(4))])
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("no value given for parameter 'label'")
+    );
+    let err = stmt
+        .execute_named(&mut ctx, [("other", AnyValue::Int64(4))])
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("statement has no parameter 'other'")
+    );
+
+    // placeholders can't be mixed, or executed without binding
+    assert!(
+        ctx.prepare("SELECT * FROM df WHERE a = ? AND b = $1")
+            .is_err()
+    );
+    let err = ctx.execute("SELECT * FROM df WHERE b = $1").err().unwrap();
+    assert!(
+        err.to_string()
+            .contains("no value bound for parameter '$1'")
+    );
+}
+
+#[test]
+fn test_information_schema() {
+    let mut ctx = create_ctx();
+    ctx.register(
+        "tbl",
+        df! { "x" => ["a", "b"], "y" => [1.5, 2.5] }.unwrap().lazy(),
+    );
+
+    let df = ctx
+        .execute("SELECT * FROM information_schema.tables")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "table_name" => ["df", "tbl"],
+        "table_type" => ["BASE TABLE", "BASE TABLE"],
+    }
+    .unwrap();
+    assert!(df.equals(&expected));
+
+    let df = ctx
+        .execute(
+            r#"
+            SELECT c.column_name, c.ordinal_position, c.data_type
+            FROM INFORMATION_SCHEMA.COLUMNS AS c
+            WHERE c.table_name = 'tbl'
+            ORDER BY c.ordinal_position
+            "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "column_name" => ["x", "y"],
+        "ordinal_position" => [1u32, 2],
+        "data_type" => ["str", "f64"],
+    }
+    .unwrap();
+    assert!(df.equals(&expected));
+
+    assert!(
+        ctx.execute("SELECT * FROM information_schema.views")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_collect_with_thread_pool() {
+    let pool = polars_core::new_thread_pool(1);
+    let mut ctx = create_ctx().with_thread_pool(pool);
+    let df = ctx.collect("SELECT SUM(b) AS b FROM df").unwrap();
+    let expected = df! { "b" => [45i64] }.unwrap();
+    assert!(df.equals(&expected));
+}
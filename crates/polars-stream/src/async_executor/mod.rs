@@ -17,7 +17,9 @@ use crossbeam_deque::{Injector, Steal, Stealer, Worker as WorkQueue};
 use crossbeam_utils::CachePadded;
 use park_group::ParkGroup;
 use parking_lot::Mutex;
+use pin_project_lite::pin_project;
 use polars_core::ALLOW_RAYON_THREADS;
+use polars_core::config::ConfigScope;
 use polars_utils::relaxed_cell::RelaxedCell;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
@@ -37,6 +39,33 @@ pub fn track_task_metrics(should_track: bool) {
 
 static GLOBAL_SCHEDULER: OnceLock<Executor> = OnceLock::new();
 
+pin_project! {
+    /// Polls a task with the configuration of the thread that spawned it.
+    struct WithConfig<F> {
+        #[pin]
+        fut: F,
+        config: ConfigScope,
+    }
+}
+
+impl<F: Future> WithConfig<F> {
+    fn new(fut: F) -> Self {
+        Self {
+            fut,
+            config: ConfigScope::current(),
+        }
+    }
+}
+
+impl<F: Future> Future for WithConfig<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.config.enter(|| this.fut.poll(ctx))
+    }
+}
+
 thread_local!(
     /// Used to store which executor thread this is.
     static TLS_THREAD_ID: Cell<usize> = const { Cell::new(usize::MAX) };
@@ -404,7 +433,7 @@ impl<'scope> TaskScope<'scope, '_> {
                 let executor = Executor::global();
                 let on_wake = move |task| executor.schedule_task(task);
                 task::spawn_with_lifetime(
-                    fut,
+                    WithConfig::new(fut),
                     on_wake,
                     TaskMetadata {
                         spawn_location,
@@ -464,7 +493,7 @@ where
     let on_wake = move |task| executor.schedule_task(task);
     let metrics = TRACK_METRICS.load().then(Arc::default);
     let dyn_task = task::spawn(
-        fut,
+        WithConfig::new(fut),
         on_wake,
         TaskMetadata {
             spawn_location,
@@ -353,6 +353,18 @@ pub fn execute_graph(
             break;
         }
 
+        #[cfg(feature = "tracing")]
+        let _phase_span = tracing::info_span!(
+            target: "polars",
+            "polars.stream.phase",
+            nodes = %nodes
+                .iter()
+                .map(|node| graph.nodes[*node].compute.name())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+        .entered();
+
         // Run the subgraph until phase completion.
         run_subgraph(
             graph,
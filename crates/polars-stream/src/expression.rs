@@ -3,7 +3,7 @@ use std::sync::Arc;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{Column, GroupPositions};
 use polars_error::PolarsResult;
-use polars_expr::prelude::{AggregationContext, ExecutionState, PhysicalExpr};
+use polars_expr::prelude::{AggregationContext, ExecutionState, PhysicalExpr, add_expr_provenance};
 
 #[derive(Clone)]
 pub struct StreamExpr {
@@ -35,6 +35,19 @@ impl StreamExpr {
         }
     }
 
+    /// Like [`StreamExpr::evaluate`], but an error names the expression and the node it was
+    /// evaluated in.
+    pub async fn evaluate_in_node(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        node: &str,
+    ) -> PolarsResult<Column> {
+        self.evaluate(df, state)
+            .await
+            .map_err(|err| add_expr_provenance(err, self.inner.as_ref(), node))
+    }
+
     pub fn evaluate_blocking(
         &self,
         df: &DataFrame,
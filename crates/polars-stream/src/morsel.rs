@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::sync::{Arc, OnceLock};
 
+use polars_core::config::Config;
 use polars_core::frame::DataFrame;
 use polars_utils::relaxed_cell::RelaxedCell;
 
@@ -9,6 +10,9 @@ use crate::async_primitives::wait_group::WaitToken;
 static IDEAL_MORSEL_SIZE: OnceLock<usize> = OnceLock::new();
 
 pub fn get_ideal_morsel_size() -> usize {
+    if let Some(size) = Config::get(|c| c.streaming_chunk_size) {
+        return size.max(1);
+    }
     *IDEAL_MORSEL_SIZE.get_or_init(|| {
         std::env::var("POLARS_IDEAL_MORSEL_SIZE")
             .map(|m| m.parse().unwrap())
@@ -47,7 +47,10 @@ impl ComputeNode for FilterNode {
                 while let Ok(morsel) = recv.recv().await {
 
                     let morsel = morsel.async_try_map(|df| async move {
-                        let mask = slf.predicate.evaluate(&df, &state.in_memory_exec_state).await?;
+                        let mask = slf
+                            .predicate
+                            .evaluate_in_node(&df, &state.in_memory_exec_state, slf.name())
+                            .await?;
                         let mask = mask.bool().map_err(|_| {
                             polars_err!(
                                 ComputeError: "filter predicate must be of type `Boolean`, got `{}`", mask.dtype()
@@ -3,6 +3,14 @@ use polars_utils::slice_enum::Slice;
 pub mod resolve_projections;
 pub mod resolve_slice;
 
+/// The maximum number of files a slice can read from, assuming 1 row per file.
+fn max_files_with_slice(pre_slice: Option<&Slice>) -> usize {
+    match pre_slice {
+        Some(v @ Slice::Positive { .. }) => v.end_position().max(1),
+        Some(Slice::Negative { .. }) | None => usize::MAX,
+    }
+}
+
 pub fn calc_n_readers_pre_init(
     num_pipelines: usize,
     num_sources: usize,
@@ -17,21 +25,19 @@ pub fn calc_n_readers_pre_init(
         return v;
     }
 
-    let max_files_with_slice = match pre_slice {
-        // Calculate the max number of files assuming 1 row per file.
-        Some(v @ Slice::Positive { .. }) => v.end_position().max(1),
-        Some(Slice::Negative { .. }) | None => usize::MAX,
-    };
-
     // Set this generously high, there are users who scan 10,000's of small files from the cloud.
     num_pipelines
         .saturating_add(3)
-        .min(max_files_with_slice)
+        .min(max_files_with_slice(pre_slice))
         .min(num_sources.max(1))
         .min(128)
 }
 
-pub fn calc_max_concurrent_scans(num_pipelines: usize, num_sources: usize) -> usize {
+pub fn calc_max_concurrent_scans(
+    num_pipelines: usize,
+    num_sources: usize,
+    pre_slice: Option<&Slice>,
+) -> usize {
     if let Ok(v) = std::env::var("POLARS_MAX_CONCURRENT_SCANS").map(|x| {
         x.parse::<usize>()
             .ok()
@@ -41,5 +47,41 @@ pub fn calc_max_concurrent_scans(num_pipelines: usize, num_sources: usize) -> us
         return v;
     }
 
-    num_pipelines.min(num_sources.max(1)).min(128)
+    // Don't read ahead from files that a small slice will never reach, e.g. for `head(1)` only one
+    // file is read at a time and the scan stops as soon as the slice is filled.
+    num_pipelines
+        .min(max_files_with_slice(pre_slice))
+        .min(num_sources.max(1))
+        .min(128)
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_utils::slice_enum::Slice;
+
+    use super::calc_max_concurrent_scans;
+
+    #[test]
+    fn test_max_concurrent_scans_with_slice() {
+        // `head(1)` over many files only ever needs to read the first one.
+        let head = Slice::Positive { offset: 0, len: 1 };
+        assert_eq!(calc_max_concurrent_scans(16, 10_000, Some(&head)), 1);
+
+        // An empty slice still scans a file.
+        let empty = Slice::Positive { offset: 0, len: 0 };
+        assert_eq!(calc_max_concurrent_scans(16, 10_000, Some(&empty)), 1);
+
+        let slice = Slice::Positive { offset: 2, len: 3 };
+        assert_eq!(calc_max_concurrent_scans(16, 10_000, Some(&slice)), 5);
+
+        // Without a positive slice the scan is bounded by the pipelines and sources.
+        let tail = Slice::Negative {
+            offset_from_end: 1,
+            len: 1,
+        };
+        assert_eq!(calc_max_concurrent_scans(16, 10_000, Some(&tail)), 16);
+        assert_eq!(calc_max_concurrent_scans(16, 10_000, None), 16);
+        assert_eq!(calc_max_concurrent_scans(16, 4, None), 4);
+        assert_eq!(calc_max_concurrent_scans(16, 0, None), 1);
+    }
 }
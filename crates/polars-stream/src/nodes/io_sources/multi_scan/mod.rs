@@ -190,6 +190,7 @@ impl MultiScanState {
         config.max_concurrent_scans.store(calc_max_concurrent_scans(
             num_pipelines,
             config.sources.len(),
+            config.pre_slice.as_ref(),
         ));
 
         let InitializedPipelineState {
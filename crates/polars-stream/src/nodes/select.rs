@@ -61,7 +61,9 @@ impl ComputeNode for SelectNode {
                     let (df, seq, source_token, consume_token) = morsel.into_inner();
                     let mut selected = Vec::new();
                     for selector in slf.selectors.iter() {
-                        let s = selector.evaluate(&df, &state.in_memory_exec_state).await?;
+                        let s = selector
+                            .evaluate_in_node(&df, &state.in_memory_exec_state, slf.name())
+                            .await?;
                         selected.push(s.into_column());
                     }
 
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::chunked_array::cast::CastOptions;
+use polars_core::diagnostics::{DiagnosticKind, emit_diagnostic};
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{
     DataType, Field, IDX_DTYPE, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap,
@@ -81,6 +82,8 @@ pub(crate) fn is_fake_elementwise_function(expr: &AExpr) -> bool {
                 F::Boolean(IRBooleanFunction::IsIn { .. }) => true,
                 #[cfg(feature = "replace")]
                 F::Replace | F::ReplaceStrict { .. } => true,
+                // The inputs must be evaluated by `or_else` itself to catch their errors.
+                F::OrElse => true,
                 _ => false,
             }
         },
@@ -408,6 +411,17 @@ fn build_fallback_node_with_ctx(
     exprs: &[ExprIR],
     ctx: &mut LowerExprContext,
 ) -> PolarsResult<PhysNodeKey> {
+    emit_diagnostic(DiagnosticKind::StreamingFallback, || {
+        let exprs = exprs
+            .iter()
+            .map(|e| e.display(ctx.expr_arena).to_string())
+            .collect_vec();
+        format!(
+            "expressions [{}] are not supported by the streaming engine, running them on the in-memory engine",
+            exprs.join(", ")
+        )
+    });
+
     // Pre-select only the columns that are needed for this fallback expression.
     let input_schema = &ctx.phys_sm[input.node].output_schema;
     let mut select_names: PlHashSet<_> = exprs
@@ -1801,6 +1815,16 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(trans_expr);
             },
 
+            #[cfg(feature = "quantile_approx")]
+            AExpr::Function {
+                function: IRFunctionExpr::QuantileApprox { .. },
+                ..
+            } => {
+                let (trans_stream, trans_expr) = lower_unary_reduce_node(input, expr, ctx)?;
+                input_streams.insert(trans_stream);
+                transformed_exprs.push(trans_expr);
+            },
+
             AExpr::Function {
                 function:
                     IRFunctionExpr::Boolean(
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
+use polars_core::diagnostics::{DiagnosticKind, emit_diagnostic};
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{Field, InitHashMaps, PlIndexMap, SortMultipleOptions};
 use polars_core::schema::Schema;
@@ -37,6 +38,11 @@ fn build_group_by_fallback(
     phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
     format_str: Option<String>,
 ) -> PolarsResult<PhysStream> {
+    emit_diagnostic(DiagnosticKind::StreamingFallback, || {
+        "group_by is not supported by the streaming engine, running it on the in-memory engine"
+            .to_string()
+    });
+
     let input_schema = phys_sm[input.node].output_schema.clone();
     let lmdf = Arc::new(LateMaterializedDataFrame::default());
     let mut lp_arena = Arena::default();
@@ -258,6 +264,12 @@ fn try_lower_elementwise_scalar_agg_expr(
             ..
         } => replace_agg_input!(expr),
 
+        #[cfg(feature = "quantile_approx")]
+        AExpr::Function {
+            function: IRFunctionExpr::QuantileApprox { .. },
+            ..
+        } => replace_agg_input!(expr),
+
         AExpr::Function {
             function:
                 IRFunctionExpr::Boolean(IRBooleanFunction::Any { .. } | IRBooleanFunction::All { .. })
@@ -121,6 +121,7 @@ impl StreamingQuery {
 
         let metrics = if std::env::var("POLARS_TRACK_METRICS").as_deref() == Ok("1")
             || std::env::var("POLARS_LOG_METRICS").as_deref() == Ok("1")
+            || trace_metrics()
         {
             crate::async_executor::track_task_metrics(true);
             Some(Arc::default())
@@ -150,10 +151,34 @@ impl StreamingQuery {
             metrics,
         } = self;
 
+        #[cfg(feature = "tracing")]
+        let _query_span =
+            tracing::info_span!(target: "polars", "polars.query", engine = "streaming").entered();
+
         let query_start = Instant::now();
         let mut results = crate::execute::execute_graph(&mut graph, metrics.clone())?;
         let query_elapsed = query_start.elapsed();
 
+        // The nodes run as tasks on the async executor, so instead of a span per node we emit
+        // their metrics once they're done.
+        #[cfg(feature = "tracing")]
+        if let Some(lock) = metrics.as_ref() {
+            let m = lock.lock();
+            for (graph_node_key, node_metrics) in m.iter() {
+                tracing::info!(
+                    target: "polars",
+                    node = graph.nodes[graph_node_key].compute.name(),
+                    rows_in = node_metrics.rows_received,
+                    rows_out = node_metrics.rows_sent,
+                    morsels_in = node_metrics.morsels_received,
+                    morsels_out = node_metrics.morsels_sent,
+                    poll_time_ns = node_metrics.total_poll_time_ns,
+                    state_update_time_ns = node_metrics.total_state_update_time_ns,
+                    "streaming node finished"
+                );
+            }
+        }
+
         // Print metrics.
         if let Some(lock) = metrics
             && std::env::var("POLARS_LOG_METRICS").as_deref() == Ok("1")
@@ -239,6 +264,18 @@ impl StreamingQuery {
     }
 }
 
+/// Whether node metrics have to be tracked to emit them as `tracing` events.
+fn trace_metrics() -> bool {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::enabled!(target: "polars", tracing::Level::INFO)
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        false
+    }
+}
+
 pub enum QueryResult {
     Single(DataFrame),
     /// Collected to multiple in-memory sinks
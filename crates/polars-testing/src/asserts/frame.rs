@@ -626,4 +626,17 @@ mod tests {
 
         assert_dataframe_equal!(&df1, &df2);
     }
+
+    #[test]
+    fn test_dataframe_mismatch_row_diff() {
+        use crate::asserts::{DataFrameEqualOptions, assert_dataframe_equal};
+
+        let df1 = df!("a" => [1, 2], "b" => ["x", "y"]).unwrap();
+        let df2 = df!("a" => [1, 2], "b" => ["x", "z"]).unwrap();
+        let msg = assert_dataframe_equal(&df1, &df2, DataFrameEqualOptions::default())
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("value mismatch for column \"b\""));
+        assert!(msg.contains("[diff] 1 of 2 values differ:\n  row 1: left = \"y\", right = \"z\""));
+    }
 }
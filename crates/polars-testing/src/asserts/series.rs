@@ -647,4 +647,26 @@ mod tests {
 
         assert_series_equal!(&s1, &s2);
     }
+
+    #[test]
+    fn test_series_mismatch_row_diff() {
+        use crate::asserts::{SeriesEqualOptions, assert_series_equal};
+
+        let s1 = Series::new("".into(), &[1, 2, 3, 4]);
+        let s2 = Series::new("".into(), &[1, 5, 3, 6]);
+        let err = assert_series_equal(&s1, &s2, SeriesEqualOptions::default()).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("exact value mismatch"));
+        assert!(msg.contains(
+            "[diff] 2 of 4 values differ:\n  row 1: left = 2, right = 5\n  row 3: left = 4, right = 6"
+        ));
+
+        let s1 = Series::new("".into(), &[1.0, 2.0, 3.0]);
+        let s2 = Series::new("".into(), &[1.0, 2.5, 3.0 + 1e-9]);
+        let options = SeriesEqualOptions::default().with_check_exact(false);
+        let msg = assert_series_equal(&s1, &s2, options)
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("[diff] 1 of 3 values differ:\n  row 1: left = 2.0, right = 2.5"));
+    }
 }
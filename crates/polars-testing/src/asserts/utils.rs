@@ -1,3 +1,4 @@
+use std::fmt::Write;
 use std::ops::Not;
 
 use polars_core::datatypes::unpack_dtypes;
@@ -169,6 +170,56 @@ fn comparing_nested_floats(left: &DataType, right: &DataType) -> bool {
     left_has_floats && right_has_floats
 }
 
+/// The maximum number of differing rows listed in the diff of an assertion error.
+const MAX_DIFF_ROWS: usize = 10;
+
+/// Marks the start of the row diff in the message of an assertion error.
+const DIFF_MARKER: &str = "\n[diff]";
+
+/// Appends a diff of the `rows` that differ between `left` and `right` to `err`.
+///
+/// The rows are listed with their index and both values, up to [`MAX_DIFF_ROWS`] rows.
+fn with_row_diff(
+    err: PolarsError,
+    left: &Series,
+    right: &Series,
+    rows: &[usize],
+    sorted: bool,
+) -> PolarsError {
+    let mut diff = format!(
+        "{DIFF_MARKER} {} of {} values differ{}:",
+        rows.len(),
+        left.len(),
+        if sorted { " (after sorting)" } else { "" }
+    );
+    for &idx in rows.iter().take(MAX_DIFF_ROWS) {
+        let (Ok(l), Ok(r)) = (left.get(idx), right.get(idx)) else {
+            break;
+        };
+        write!(diff, "\n  row {idx}: left = {l}, right = {r}").unwrap();
+    }
+    if rows.len() > MAX_DIFF_ROWS {
+        write!(diff, "\n  ... and {} more", rows.len() - MAX_DIFF_ROWS).unwrap();
+    }
+    err.wrap_msg(|msg| format!("{msg}{diff}"))
+}
+
+/// The row diff appended by [`with_row_diff`] to the message of `err`, if any.
+fn row_diff(err: &PolarsError) -> String {
+    let msg = err.to_string();
+    msg.find(DIFF_MARKER)
+        .map(|start| msg[start..].to_string())
+        .unwrap_or_default()
+}
+
+/// The indices of the set values of `mask`.
+fn set_indices(mask: &BooleanChunked) -> Vec<usize> {
+    mask.iter()
+        .enumerate()
+        .filter_map(|(idx, v)| (v == Some(true)).then_some(idx))
+        .collect()
+}
+
 /// Ensures that null values in two Series match exactly and returns an error if any mismatches are found.
 fn assert_series_null_values_match(left: &Series, right: &Series) -> PolarsResult<()> {
     let null_value_mismatch = left.is_null().not_equal(&right.is_null());
@@ -222,6 +273,7 @@ fn assert_series_nan_values_match(left: &Series, right: &Series) -> PolarsResult
 /// * `unequal` - Boolean ChunkedArray indicating which elements to check (true = check this element)
 /// * `rel_tol` - Relative tolerance (relative to the maximum absolute value of the two Series)
 /// * `abs_tol` - Absolute tolerance added to the relative tolerance
+/// * `sorted` - Whether the Series were sorted before the comparison, noted in the error
 ///
 /// # Returns
 ///
@@ -239,6 +291,7 @@ fn assert_series_values_within_tolerance(
     unequal: &ChunkedArray<BooleanType>,
     rel_tol: f64,
     abs_tol: f64,
+    sorted: bool,
 ) -> PolarsResult<()> {
     let left_unequal = left.filter(unequal)?;
     let right_unequal = right.filter(unequal)?;
@@ -251,12 +304,18 @@ fn assert_series_values_within_tolerance(
         let problematic_left = left_unequal.filter(&exceeded_indices)?;
         let problematic_right = right_unequal.filter(&exceeded_indices)?;
 
-        Err(polars_err!(
+        let rows = set_indices(unequal)
+            .into_iter()
+            .zip(exceeded_indices.iter())
+            .filter_map(|(idx, exceeded)| (exceeded == Some(true)).then_some(idx))
+            .collect::<Vec<_>>();
+        let err = polars_err!(
             assertion_error = "Series",
             "values not within tolerance",
             problematic_left,
             problematic_right
-        ))
+        );
+        Err(with_row_diff(err, left, right, &rows, sorted))
     }
 }
 
@@ -371,17 +430,24 @@ fn assert_series_values_equal(
     }
 
     if check_exact || !left.dtype().is_float() || !right.dtype().is_float() {
-        return Err(polars_err!(
+        let err = polars_err!(
             assertion_error = "Series",
             "exact value mismatch",
             left,
             right
+        );
+        return Err(with_row_diff(
+            err,
+            &left,
+            &right,
+            &set_indices(&unequal),
+            !check_order,
         ));
     }
 
     assert_series_null_values_match(&left, &right)?;
     assert_series_nan_values_match(&left, &right)?;
-    assert_series_values_within_tolerance(&left, &right, &unequal, rel_tol, abs_tol)?;
+    assert_series_values_within_tolerance(&left, &right, &unequal, rel_tol, abs_tol, !check_order)?;
 
     Ok(())
 }
@@ -887,13 +953,15 @@ pub fn assert_dataframe_equal(
             options.categorical_as_str,
         ) {
             Ok(_) => {},
-            Err(_) => {
+            Err(err) => {
+                let diff = row_diff(&err);
                 return Err(polars_err!(
                     assertion_error = "DataFrames",
                     format!("value mismatch for column {:?}", col),
                     format!("{:?}", s_left_series),
                     format!("{:?}", s_right_series)
-                ));
+                )
+                .wrap_msg(|msg| format!("{msg}{diff}")));
             },
         }
     }
@@ -0,0 +1,200 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// A bound of a [`WindowFrame`], relative to the current row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(usize),
+    CurrentRow,
+    Following(usize),
+    UnboundedFollowing,
+}
+
+impl FrameBound {
+    /// Offset of the bound to the current row. Offsets beyond `len` select the same rows as
+    /// `len` does, so they are clamped to prevent needlessly large windows.
+    fn offset(self, len: usize) -> i64 {
+        match self {
+            Self::UnboundedPreceding => -(len as i64),
+            Self::Preceding(n) => -(n.min(len) as i64),
+            Self::CurrentRow => 0,
+            Self::Following(n) => n.min(len) as i64,
+            Self::UnboundedFollowing => len as i64,
+        }
+    }
+
+    /// Position of the bound relative to the current row, independent of the length of the data.
+    fn position(self) -> (i8, i128) {
+        match self {
+            Self::UnboundedPreceding => (-1, 0),
+            Self::Preceding(n) => (0, -(n as i128)),
+            Self::CurrentRow => (0, 0),
+            Self::Following(n) => (0, n as i128),
+            Self::UnboundedFollowing => (1, 0),
+        }
+    }
+}
+
+/// The rows an aggregation is computed over for every row, like the SQL frame clause
+/// `ROWS BETWEEN <start> AND <end>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct WindowFrame {
+    pub start: FrameBound,
+    pub end: FrameBound,
+}
+
+impl WindowFrame {
+    pub fn new(start: FrameBound, end: FrameBound) -> Self {
+        Self { start, end }
+    }
+
+    fn offsets(&self, len: usize) -> PolarsResult<(i64, i64)> {
+        polars_ensure!(
+            self.start != FrameBound::UnboundedFollowing && self.end != FrameBound::UnboundedPreceding,
+            InvalidOperation: "window frame cannot start at `UnboundedFollowing` or end at `UnboundedPreceding`"
+        );
+        // Check the bounds before clamping them, clamped bounds may coincide.
+        polars_ensure!(
+            self.start.position() <= self.end.position(),
+            InvalidOperation: "window frame start {:?} lies after its end {:?}", self.start, self.end
+        );
+        Ok((self.start.offset(len), self.end.offset(len)))
+    }
+}
+
+/// The aggregations that can be computed over a [`WindowFrame`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FrameAggregation {
+    Min,
+    Max,
+    Mean,
+    Sum,
+}
+
+impl FrameAggregation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Mean => "mean",
+            Self::Sum => "sum",
+        }
+    }
+}
+
+/// Compute `agg` over the `frame` of every row. Null values are ignored and frames without any
+/// valid values result in null.
+///
+/// The frame is evaluated as a trailing fixed window over the values padded with nulls, so rows
+/// near the edges see truncated frames.
+pub fn rolling_frame(
+    s: &Series,
+    agg: FrameAggregation,
+    frame: WindowFrame,
+) -> PolarsResult<Series> {
+    let len = s.len();
+    let (start, end) = frame.offsets(len)?;
+    if len == 0 {
+        return rolling_frame_agg(s, agg, RollingOptionsFixedWindow::default());
+    }
+
+    let prepend = (-end).max(0) as usize;
+    let append = end.max(0) as usize;
+    let mut padded = Series::full_null(s.name().clone(), prepend, s.dtype());
+    padded.append(s)?;
+    let padded = padded.extend_constant(AnyValue::Null, append)?;
+
+    let options = RollingOptionsFixedWindow {
+        window_size: (end - start) as usize + 1,
+        min_periods: 1,
+        ..Default::default()
+    };
+    let out = rolling_frame_agg(&padded, agg, options)?;
+    Ok(out.slice(append as i64, len))
+}
+
+fn rolling_frame_agg(
+    s: &Series,
+    agg: FrameAggregation,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Series> {
+    match agg {
+        FrameAggregation::Min => s.rolling_min(options),
+        FrameAggregation::Max => s.rolling_max(options),
+        FrameAggregation::Mean => s.rolling_mean(options),
+        FrameAggregation::Sum => s.rolling_sum(options),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame_sum(s: &Series, start: FrameBound, end: FrameBound) -> Vec<Option<i32>> {
+        let out = rolling_frame(s, FrameAggregation::Sum, WindowFrame::new(start, end)).unwrap();
+        out.i32().unwrap().iter().collect()
+    }
+
+    #[test]
+    fn test_rolling_frame_sum() {
+        use FrameBound::*;
+        let s = Series::new("a".into(), &[Some(1), Some(2), None, Some(4), Some(5)]);
+
+        assert_eq!(
+            frame_sum(&s, Preceding(1), Following(1)),
+            &[Some(3), Some(3), Some(6), Some(9), Some(9)]
+        );
+        assert_eq!(
+            frame_sum(&s, UnboundedPreceding, CurrentRow),
+            &[Some(1), Some(3), Some(3), Some(7), Some(12)]
+        );
+        assert_eq!(
+            frame_sum(&s, CurrentRow, UnboundedFollowing),
+            &[Some(12), Some(11), Some(9), Some(9), Some(5)]
+        );
+        assert_eq!(
+            frame_sum(&s, Preceding(3), Preceding(2)),
+            &[None, None, Some(1), Some(3), Some(2)]
+        );
+        assert_eq!(
+            frame_sum(&s, Following(2), Following(10)),
+            &[Some(9), Some(9), Some(5), None, None]
+        );
+
+        let frame = WindowFrame::new(Following(1), CurrentRow);
+        assert!(rolling_frame(&s, FrameAggregation::Sum, frame).is_err());
+        // Invalid frames are rejected even if both bounds lie beyond the data.
+        for frame in [
+            WindowFrame::new(Following(12), Following(10)),
+            WindowFrame::new(Preceding(5), Preceding(7)),
+        ] {
+            assert!(rolling_frame(&s, FrameAggregation::Sum, frame).is_err());
+            assert!(rolling_frame(&s.clear(), FrameAggregation::Sum, frame).is_err());
+        }
+    }
+
+    #[test]
+    fn test_rolling_frame_min_max() {
+        use FrameBound::*;
+        let s = Series::new("a".into(), &[3.0, 1.0, 4.0, 1.0, 5.0]);
+        let frame = WindowFrame::new(Preceding(2), CurrentRow);
+
+        let out = rolling_frame(&s, FrameAggregation::Max, frame).unwrap();
+        let out: Vec<_> = out.f64().unwrap().into_no_null_iter().collect();
+        assert_eq!(out, &[3.0, 3.0, 4.0, 4.0, 5.0]);
+
+        let out = rolling_frame(&s, FrameAggregation::Min, frame).unwrap();
+        let out: Vec<_> = out.f64().unwrap().into_no_null_iter().collect();
+        assert_eq!(out, &[3.0, 1.0, 1.0, 1.0, 1.0]);
+    }
+}
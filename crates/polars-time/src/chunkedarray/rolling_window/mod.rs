@@ -1,11 +1,15 @@
 use std::hash::{Hash, Hasher};
 
 mod dispatch;
+#[cfg(feature = "rolling_window")]
+mod frame;
 #[cfg(feature = "rolling_window_by")]
 mod rolling_kernels;
 
 use arrow::array::{ArrayRef, PrimitiveArray};
 pub use dispatch::*;
+#[cfg(feature = "rolling_window")]
+pub use frame::*;
 use polars_compute::rolling;
 use polars_compute::rolling::RollingFnParams;
 use polars_core::prelude::*;
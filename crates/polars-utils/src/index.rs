@@ -146,6 +146,10 @@ pub fn check_bounds(idx: &[IdxSize], len: IdxSize) -> PolarsResult<()> {
     Ok(())
 }
 
+/// Convert an (optionally negative) index into an [`IdxSize`].
+///
+/// Indices that do not fit into [`IdxSize`] map to [`IdxSize::MAX`], so that a subsequent bounds
+/// check rejects them, instead of wrapping around to a valid index.
 pub trait ToIdx {
     fn to_idx(self, len: u64) -> IdxSize;
 }
@@ -154,21 +158,22 @@ macro_rules! impl_to_idx {
     ($ty:ty) => {
         impl ToIdx for $ty {
             #[inline]
+            #[allow(clippy::unnecessary_fallible_conversions)]
             fn to_idx(self, _len: u64) -> IdxSize {
-                self as IdxSize
+                IdxSize::try_from(self).unwrap_or(IdxSize::MAX)
             }
         }
     };
     ($ty:ty, $ity:ty) => {
         impl ToIdx for $ty {
             #[inline]
+            #[allow(clippy::unnecessary_fallible_conversions)]
             fn to_idx(self, len: u64) -> IdxSize {
-                let idx = self as $ity;
+                let mut idx = self as $ity;
                 if idx < 0 {
-                    (idx + len as $ity) as IdxSize
-                } else {
-                    idx as IdxSize
+                    idx += len as $ity;
                 }
+                IdxSize::try_from(idx).unwrap_or(IdxSize::MAX)
             }
         }
     };
@@ -219,6 +224,7 @@ impl<const CHUNK_BITS: u64> ChunkId<CHUNK_BITS> {
     #[allow(clippy::unnecessary_cast)]
     pub fn store(chunk: IdxSize, row: IdxSize) -> Self {
         debug_assert!(chunk < !(u64::MAX << CHUNK_BITS) as IdxSize);
+        debug_assert!((row as u64) < (1u64 << (64 - CHUNK_BITS)));
         let swizzled = ((row as u64) << CHUNK_BITS) | chunk as u64;
 
         Self { swizzled }
@@ -262,4 +268,29 @@ mod test {
         assert_eq!(c, chunk);
         assert_eq!(r, row);
     }
+
+    #[test]
+    #[cfg(feature = "bigidx")]
+    fn test_chunk_idx_bigidx() {
+        let chunk = 7;
+        let row = u32::MAX as IdxSize + 3;
+
+        let ci: ChunkId = ChunkId::store(chunk, row);
+        assert_eq!(ci.extract(), (chunk, row));
+    }
+
+    #[test]
+    fn test_to_idx_out_of_range() {
+        assert_eq!(3i64.to_idx(10), 3);
+        assert_eq!((-1i64).to_idx(10), 9);
+        assert_eq!((-11i64).to_idx(10), IdxSize::MAX);
+        // Must not wrap around to a valid index.
+        let big = u32::MAX as i64 + 2;
+        #[cfg(not(feature = "bigidx"))]
+        assert_eq!(big.to_idx(10), IdxSize::MAX);
+        #[cfg(feature = "bigidx")]
+        assert_eq!(big.to_idx(10), big as IdxSize);
+        #[cfg(not(feature = "bigidx"))]
+        assert_eq!((big as u64).to_idx(10), IdxSize::MAX);
+    }
 }
@@ -30,6 +30,7 @@ pub mod idx_vec;
 pub mod marked_usize;
 pub mod mem;
 pub mod min_max;
+pub mod numa;
 pub mod order_statistic_tree;
 pub mod parma;
 pub mod pl_str;
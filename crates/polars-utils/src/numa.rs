@@ -0,0 +1,105 @@
+//! The NUMA topology of the machine, and pinning threads to NUMA nodes.
+//!
+//! On machines with multiple NUMA nodes, memory is allocated on the node of the thread that first
+//! writes to it. If every thread is pinned to a node, and every partition of a partitioned
+//! operator is always built by the same thread, the memory of a partition stays local to the
+//! thread that processes it.
+use std::sync::LazyLock;
+
+static NUMA_NODES: LazyLock<Vec<Vec<usize>>> =
+    LazyLock::new(|| read_numa_nodes().unwrap_or_default());
+
+/// The CPUs of every NUMA node, empty if the topology is unknown.
+pub fn numa_nodes() -> &'static [Vec<usize>] {
+    &NUMA_NODES
+}
+
+/// The NUMA node of thread `thread_idx` of a pool with `num_threads` threads. The threads are
+/// divided into one contiguous range per node.
+pub fn numa_node_of_thread(thread_idx: usize, num_threads: usize) -> usize {
+    thread_idx * numa_nodes().len().max(1) / num_threads.max(1)
+}
+
+/// Pin the current thread to the CPUs of NUMA `node`. Returns whether the thread was pinned.
+pub fn pin_current_thread_to_node(node: usize) -> bool {
+    match numa_nodes().get(node) {
+        Some(cpus) => pin_current_thread(cpus),
+        None => false,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_numa_nodes() -> Option<Vec<Vec<usize>>> {
+    let mut nodes = Vec::new();
+    for entry in std::fs::read_dir("/sys/devices/system/node").ok()? {
+        let entry = entry.ok()?;
+        let Some(id) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix("node"))
+            .and_then(|id| id.parse::<usize>().ok())
+        else {
+            continue;
+        };
+        let cpus = std::fs::read_to_string(entry.path().join("cpulist")).ok()?;
+        nodes.push((id, parse_cpu_list(cpus.trim())?));
+    }
+    nodes.sort_unstable_by_key(|(id, _)| *id);
+    // Nodes without CPUs only have memory.
+    Some(
+        nodes
+            .into_iter()
+            .map(|(_, cpus)| cpus)
+            .filter(|cpus| !cpus.is_empty())
+            .collect(),
+    )
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_numa_nodes() -> Option<Vec<Vec<usize>>> {
+    None
+}
+
+/// Parse a list of CPUs in the format of the Linux kernel, e.g. `0-3,8,10-11`.
+fn parse_cpu_list(list: &str) -> Option<Vec<usize>> {
+    let mut cpus = Vec::new();
+    for part in list.split(',').filter(|part| !part.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => cpus.extend(start.parse::<usize>().ok()?..=end.parse().ok()?),
+            None => cpus.push(part.parse().ok()?),
+        }
+    }
+    Some(cpus)
+}
+
+#[cfg(target_os = "linux")]
+fn pin_current_thread(cpus: &[usize]) -> bool {
+    // SAFETY: `cpu_set_t` is a bitset, for which all zeroes is the empty set.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        for &cpu in cpus.iter().filter(|&&cpu| cpu < libc::CPU_SETSIZE as usize) {
+            libc::CPU_SET(cpu, &mut set);
+        }
+        libc::sched_setaffinity(0, size_of::<libc::cpu_set_t>(), &set) == 0
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn pin_current_thread(_cpus: &[usize]) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(
+            parse_cpu_list("0-3,8,10-11"),
+            Some(vec![0, 1, 2, 3, 8, 10, 11])
+        );
+        assert_eq!(parse_cpu_list(""), Some(vec![]));
+        assert_eq!(parse_cpu_list("0-x"), None);
+    }
+}
@@ -179,7 +179,10 @@
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
 //! * `sql` - Pass SQL queries to Polars.
 //! * `random` - Generate arrays with randomly sampled values
+//! * `proptest` - [proptest](https://docs.rs/proptest/) strategies that generate random [`Series`]
+//!   and [`DataFrame`]s with configurable dtypes, nulls, chunking and sortedness.
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
+//! * `derive` - `#[derive(FrameSchema)]` to convert between [`DataFrame`]s and `Vec`s of structs
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`]
@@ -195,6 +198,11 @@
 //!       Polars will be a bit slower with this feature activated as many data structures
 //!       are less cache efficient.
 //!     - `cse` - Activate common subplan elimination optimization
+//!     - `tracing` - Emit [tracing](https://docs.rs/tracing/) spans around the execution of the
+//!       physical operators of both engines, also for their work on the thread pool, and
+//!       events for the bytes that are spilled to disk.
+//!     - `spill` - Let joins and group-bys of the in-memory engine spill to disk when their input
+//!       exceeds `POLARS_MEMORY_LIMIT` bytes, see [`polars_core::memory`].
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
@@ -208,6 +216,9 @@
 //!          - gzip
 //!          - zlib
 //!          - zstd
+//!     - `wasm` - Read local paths through a registered
+//!       [`FileProvider`](polars_io::file_provider::FileProvider), for targets without a file
+//!       system such as `wasm32-unknown-unknown`.
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -268,6 +279,7 @@
 //!     - `trigonometry` - Trigonometric functions.
 //!     - `sign` - Compute the element-wise sign of a [`Series`].
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
+//!     - `quantile_approx` - Approximate quantiles with a t-digest.
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
 //!     - `find_many` - Find/replace multiple string patterns at once.
@@ -363,6 +375,11 @@
 //!
 //! ## Config with ENV vars
 //!
+//! Most formatting settings, the verbosity, the streaming morsel size and the temporary
+//! directory can also be set programmatically with [`Config`], which takes precedence over the
+//! environment variables below. It applies to the queries run from the thread that applies it,
+//! until the guard returned by [`Config::apply`] is dropped.
+//!
 //! * `POLARS_FMT_TABLE_FORMATTING` -> define styling of tables using any of the following options (default = UTF8_FULL_CONDENSED). These options are defined by comfy-table which provides examples for each at <https://github.com/Nukesor/comfy-table/blob/main/src/style/presets.rs>
 //!   * `ASCII_FULL`
 //!   * `ASCII_FULL_CONDENSED`
@@ -394,6 +411,8 @@
 //! * `POLARS_FMT_STR_LEN` -> maximum number of characters printed per string value.
 //! * `POLARS_TABLE_WIDTH` -> width of the tables used during DataFrame formatting.
 //! * `POLARS_MAX_THREADS` -> maximum number of threads used to initialize thread pool (on startup).
+//! * `POLARS_NUMA_AWARE` -> pin the threads of the thread pool to NUMA nodes, and build the partitions
+//!   of hash joins and group_by's on the node that processes them (on startup).
 //! * `POLARS_VERBOSE` -> print logging info to stderr.
 //! * `POLARS_NO_PARTITION` -> polars may choose to partition the group_by operation, based on data
 //!   cardinality. Setting this env var will turn partitioned group_by's off.
@@ -419,9 +438,10 @@ pub mod sql;
 
 #[doc(hidden)]
 pub use polars_compute;
+pub use polars_core::config::{Config, IntegerOverflow};
 pub use polars_core::{
-    apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
-    testing,
+    apply_method_all_arrow_series, chunked_array, datatypes, df, diagnostics, error, frame,
+    functions, series, testing,
 };
 #[cfg(feature = "polars-io")]
 pub use polars_io as io;
@@ -1,5 +1,7 @@
 pub use polars_core::prelude::*;
 pub use polars_core::utils::NoNull;
+#[cfg(feature = "derive")]
+pub use polars_derive::FrameSchema;
 #[cfg(feature = "polars-io")]
 pub use polars_io::prelude::*;
 #[cfg(feature = "lazy")]
@@ -39,6 +39,26 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_to_files() -> PolarsResult<()> {
+    let df = create_df();
+
+    let mut bufs = vec![Vec::new(); 2];
+    write_csv_files(&df, &mut bufs, &CsvWriterOptions::default())?;
+    let csvs = bufs
+        .iter()
+        .map(|buf| std::str::from_utf8(buf).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        csvs,
+        [
+            "days,temp\n0,22.1\n1,19.9\n2,7.0\n",
+            "days,temp\n3,2.0\n4,3.0\n"
+        ]
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "timezones")]
 fn write_dates() {
@@ -260,6 +260,40 @@ fn test_parquet_datetime_round_trip() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-decimal")]
+fn test_parquet_decimal_round_trip() -> PolarsResult<()> {
+    use std::io::{Cursor, Seek, SeekFrom};
+
+    let mut f = Cursor::new(vec![]);
+
+    // Parquet stores these as INT32, INT64 and FIXED_LEN_BYTE_ARRAY respectively.
+    let values = Series::new("v".into(), [Some(12345i64), None, Some(-99999)]);
+    let mut df = DataFrame::new(vec![
+        values
+            .cast(&DataType::Decimal(9, 2))?
+            .with_name("p9".into())
+            .into(),
+        values
+            .cast(&DataType::Decimal(18, 4))?
+            .with_name("p18".into())
+            .into(),
+        values
+            .cast(&DataType::Decimal(38, 10))?
+            .with_name("p38".into())
+            .into(),
+    ])?;
+
+    ParquetWriter::new(&mut f).finish(&mut df)?;
+
+    f.seek(SeekFrom::Start(0))?;
+
+    let read = ParquetReader::new(f).finish()?;
+    assert_eq!(read.schema(), df.schema());
+    assert!(read.equals_missing(&df));
+    Ok(())
+}
+
 #[test]
 fn test_read_parquet_with_projection() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -396,3 +396,37 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+fn test_over_ordered() -> PolarsResult<()> {
+    let df = df![
+        "groups" => [1, 2, 1, 2, 1],
+        "time" => [3, 1, 1, 2, 2],
+        "values" => [30, 10, 10, 20, 20],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("values")
+                .shift(lit(1))
+                .over_ordered([col("groups")], [col("time")], SortOptions::default())
+                .alias("previous"),
+            col("values")
+                .first()
+                .over_ordered(
+                    [col("groups")],
+                    [col("time")],
+                    SortOptions::default().with_order_descending(true),
+                )
+                .alias("latest"),
+        ])
+        .collect()?;
+
+    let previous: Vec<_> = out.column("previous")?.i32()?.iter().collect();
+    assert_eq!(previous, &[Some(20), None, None, Some(10), Some(10)]);
+    let latest: Vec<_> = out.column("latest")?.i32()?.iter().collect();
+    assert_eq!(latest, &[Some(30), Some(20), Some(30), Some(20), Some(30)]);
+
+    Ok(())
+}
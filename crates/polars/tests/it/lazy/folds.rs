@@ -34,3 +34,97 @@ fn test_fold_wildcard() -> PolarsResult<()> {
         .collect()?;
     Ok(())
 }
+
+#[test]
+fn test_horizontal_aggregations_null_semantics() -> PolarsResult<()> {
+    use polars_lazy::dsl;
+
+    let df = df![
+        "a" => [Some(1), None, None],
+        "b" => [Some(2), Some(3), None],
+        "p" => [Some(true), None, Some(false)],
+        "q" => [None, Some(false), Some(false)],
+    ]?;
+    let ints = || dtype_col(&DataType::Int32).as_selector().as_expr();
+    let bools = || dtype_col(&DataType::Boolean).as_selector().as_expr();
+
+    let out = df
+        .lazy()
+        .select([
+            dsl::sum_horizontal([ints()], true)?.alias("sum"),
+            dsl::sum_horizontal([ints()], false)?.alias("sum_strict"),
+            dsl::mean_horizontal([ints()], true)?.alias("mean"),
+            dsl::min_horizontal([ints()])?.alias("min"),
+            dsl::max_horizontal([ints()])?.alias("max"),
+            dsl::any_horizontal([bools()])?.alias("any"),
+            dsl::all_horizontal([bools()])?.alias("all"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(3), Some(3), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sum_strict")?.i32()?),
+        &[Some(3), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(1.5), Some(3.0), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("min")?.i32()?),
+        &[Some(1), Some(3), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("max")?.i32()?),
+        &[Some(2), Some(3), None]
+    );
+    // Kleene logic: a null only decides the result if no other value does.
+    assert_eq!(
+        Vec::from(out.column("any")?.bool()?),
+        &[Some(true), None, Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("all")?.bool()?),
+        &[None, Some(false), Some(false)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_cum_fold_returns() -> PolarsResult<()> {
+    let df = df![
+        "r1" => [0.5, 0.0],
+        "r2" => [1.0, 0.25],
+    ]?;
+
+    let growth = |include_init| {
+        cum_fold_exprs(
+            lit(1.0),
+            PlanCallback::new(|(acc, r): (Series, Series)| &acc * &(&r + 1.0)),
+            [col("r1"), col("r2")],
+            false,
+            None,
+            include_init,
+        )
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .select([growth(false).alias("growth")])
+        .collect()?;
+    let fields = out.column("growth")?.struct_()?.fields_as_series();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].name().as_str(), "r1");
+    assert_eq!(Vec::from(fields[0].f64()?), &[Some(1.5), Some(1.0)]);
+    assert_eq!(Vec::from(fields[1].f64()?), &[Some(3.0), Some(1.25)]);
+
+    let out = df.lazy().select([growth(true).alias("growth")]).collect()?;
+    let fields = out.column("growth")?.struct_()?.fields_as_series();
+    assert_eq!(fields.len(), 3);
+    assert_eq!(Vec::from(fields[0].f64()?), &[Some(1.0), Some(1.0)]);
+    Ok(())
+}
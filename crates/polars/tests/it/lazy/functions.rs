@@ -24,3 +24,44 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(feature = "range")]
+fn test_int_ranges_step() -> PolarsResult<()> {
+    let df = df![
+        "start" => [0i64, 10],
+        "end" => [5i64, 0],
+        "step" => [2i64, -4],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([int_ranges(col("start"), col("end"), col("step"), DataType::Int64).alias("r")])
+        .collect()?;
+    let r = out.column("r")?.list()?;
+    let values = |i| -> Vec<_> {
+        r.get_as_series(i)
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    };
+    assert_eq!(values(0), &[0, 2, 4]);
+    assert_eq!(values(1), &[10, 6, 2]);
+
+    // A zero step is an error rather than a panic.
+    let out = df
+        .lazy()
+        .select([int_ranges(
+            col("start"),
+            col("end"),
+            lit(0i64),
+            DataType::Int64,
+        )])
+        .collect();
+    assert!(out.is_err());
+
+    Ok(())
+}
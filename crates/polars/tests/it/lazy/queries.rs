@@ -197,6 +197,41 @@ fn test_unknown_supertype_ignore() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_map_multiple_with_dtype() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    let ratio = |s: &mut [Column]| {
+        let a = s[0].cast(&DataType::Float64)?;
+        &a / &s[1]
+    };
+    let lf = df.lazy().select([map_multiple_with_dtype(
+        ratio,
+        [col("A"), col("B")],
+        DataType::Float64,
+    )]);
+    assert_eq!(
+        lf.clone().collect_schema()?.get("A"),
+        Some(&DataType::Float64)
+    );
+    let out = lf.collect()?;
+    assert_eq!(
+        Vec::from(out.column("A")?.f64()?),
+        &[Some(0.2), Some(0.5), Some(1.0), Some(2.0), Some(5.0)]
+    );
+
+    let wrong = fruits_cars()
+        .lazy()
+        .select([map_multiple_with_dtype(
+            ratio,
+            [col("A"), col("B")],
+            DataType::Int64,
+        )])
+        .collect();
+    assert!(matches!(wrong, Err(PolarsError::SchemaMismatch(_))));
+    Ok(())
+}
+
 #[test]
 fn test_apply_multiple_columns() -> PolarsResult<()> {
     let df = fruits_cars();
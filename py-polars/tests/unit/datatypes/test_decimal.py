@@ -333,7 +333,7 @@ def test_decimal_aggregations() -> None:
             "sum": [D("10.20"), D("9100.13")],
             "min": [D("0.10"), D("100.01")],
             "max": [D("10.10"), D("9000.12")],
-            "mean": [5.1, 4550.065],
+            "mean": pl.Series([D("5.1"), D("4550.065")], dtype=pl.Decimal(38, 6)),
             "median": [5.1, 4550.065],
         }
     )
@@ -351,7 +351,7 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
+            "mean": pl.Series([D("2277.5825")], dtype=pl.Decimal(38, 6)),
             "median": [55.055],
         }
     )
@@ -1,7 +1,7 @@
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLSyntaxError
+from polars.exceptions import ComputeError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -160,26 +160,37 @@ def test_subquery_20732() -> None:
     assert res.to_dict(as_series=False) == {"id": [2], "s": ["b"]}
 
 
-def test_unsupported_subquery_comparisons() -> None:
-    """Test that using = with a subquery gives a helpful error message."""
-    df = pl.DataFrame({"value": [2000, 2000]})
-
-    for op, suggestion in (("=", "IN"), ("!=", "NOT IN")):
-        with pytest.raises(
-            SQLSyntaxError,
-            match=rf"subquery comparisons with '{op}' are not supported; use '{suggestion}' instead",
-        ):
-            pl.sql(f"SELECT * FROM df WHERE value {op} (SELECT MAX(e) FROM df)")
-
-    for op in ("<", "<=", ">", ">="):
-        with pytest.raises(
-            SQLSyntaxError,
-            match=rf"subquery comparisons with '{op}' are not supported",
-        ):
-            pl.sql(f"SELECT * FROM df WHERE (SELECT MAX(e) FROM df) {op} value")
-
-        with pytest.raises(
-            SQLSyntaxError,
-            match=rf"subquery comparisons with '{op}' are not supported",
-        ):
-            pl.sql(f"SELECT * FROM df WHERE value {op} (SELECT MAX(value) FROM df)")
+def test_scalar_subquery_comparisons() -> None:
+    df = pl.DataFrame({"value": [1000, 2000, 2000]})
+
+    for op, expected in (
+        ("=", [2000, 2000]),
+        ("!=", [1000]),
+        ("<", [1000]),
+        ("<=", [1000, 2000, 2000]),
+        (">", []),
+        (">=", [2000, 2000]),
+    ):
+        res = pl.sql(
+            f"SELECT * FROM df WHERE value {op} (SELECT MAX(value) FROM df)",
+            eager=True,
+        )
+        assert res["value"].to_list() == expected, op
+
+    res = pl.sql(
+        "SELECT value, value - (SELECT MIN(value) FROM df) AS diff FROM df",
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {
+        "value": [1000, 2000, 2000],
+        "diff": [0, 1000, 1000],
+    }
+
+    with pytest.raises(
+        SQLSyntaxError,
+        match="SQL subquery returns more than one column",
+    ):
+        pl.sql("SELECT * FROM df WHERE value > (SELECT value, value * 2 AS v2 FROM df)")
+
+    with pytest.raises(ComputeError, match="expected no or a single value"):
+        pl.sql("SELECT * FROM df WHERE value > (SELECT value FROM df)", eager=True)
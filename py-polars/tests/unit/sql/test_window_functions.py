@@ -553,12 +553,23 @@ def test_window_frame_validation() -> None:
     ):
         df.sql(query)
 
-    # Rejected: ROWS with incompatible bounds
+    # Custom ROWS frames are supported by aggregate functions
     query = """
         SELECT lbl, SUM(value) OVER (
             ORDER BY lbl
             ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
         ) AS sum_value
+        FROM self ORDER BY lbl ASC
+    """
+    assert df.sql(query).rows() == [("aa", 50), ("bb", -50), ("cc", -25)]
+    assert_sql_matches(df, query=query, compare_with="sqlite")
+
+    # Rejected: ROWS with incompatible bounds for a non-aggregate function
+    query = """
+        SELECT lbl, LAG(value) OVER (
+            ORDER BY lbl
+            ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+        ) AS lag_value
         FROM self
     """
     with pytest.raises(
@@ -8,7 +8,7 @@ import sys
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, SchemaError
+from polars.exceptions import ComputeError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 CATS = [
@@ -86,10 +86,20 @@ def test_concat_cat_mismatch() -> None:
     assert_frame_equal(pl.concat([df1, df2]), df12)
     assert_frame_equal(pl.concat([df3, df4]), df34)
 
+    # Different categories are unified into the global categories.
     for left in [df1, df2]:
         for right in [df3, df4]:
-            with pytest.raises(SchemaError):
-                pl.concat([left, right])
+            out = pl.concat([left, right])
+            assert out.schema["x"] == pl.Categorical()
+            assert (
+                out["x"].cast(pl.String).to_list()
+                == left["x"].cast(pl.String).to_list()
+                + right["x"].cast(pl.String).to_list()
+            )
+
+            out = pl.concat([left.lazy(), right.lazy()]).collect()
+            assert out.schema["x"] == pl.Categorical()
+            assert out.height == 6
 
     for li in range(len(CATS)):
         for ri in range(len(CATS)):
@@ -98,8 +108,9 @@ def test_concat_cat_mismatch() -> None:
 
             ldf = pl.DataFrame({"x": []}, schema={"x": pl.Categorical(CATS[li])})
             rdf = pl.DataFrame({"x": []}, schema={"x": pl.Categorical(CATS[ri])})
-            with pytest.raises(SchemaError):
-                pl.concat([ldf, rdf])
+            out = pl.concat([ldf, rdf])
+            assert out.schema["x"] == pl.Categorical()
+            assert out.height == 0
 
 
 def test_cat_overflow() -> None: